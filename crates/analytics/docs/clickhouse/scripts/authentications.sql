@@ -36,6 +36,7 @@ CREATE TABLE authentication_queue (
     `directory_server_id` Nullable(String),
     `acquirer_country_code` Nullable(String),
     `organization_id` String,
+    `psd2_sca_exemption_type` LowCardinality(Nullable(String)),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-authentication-events',
@@ -82,6 +83,7 @@ CREATE TABLE authentications (
     `directory_server_id` Nullable(String),
     `acquirer_country_code` Nullable(String),
     `organization_id` String,
+    `psd2_sca_exemption_type` LowCardinality(Nullable(String)),
     `sign_flag` Int8,
     INDEX authenticationConnectorIndex authentication_connector TYPE bloom_filter GRANULARITY 1,
     INDEX transStatusIndex trans_status TYPE bloom_filter GRANULARITY 1,
@@ -130,6 +132,7 @@ CREATE MATERIALIZED VIEW authentication_mv TO authentications (
     `directory_server_id` Nullable(String),
     `acquirer_country_code` Nullable(String),
     `organization_id` String,
+    `psd2_sca_exemption_type` LowCardinality(Nullable(String)),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -171,6 +174,7 @@ SELECT
     directory_server_id,
     acquirer_country_code,
     organization_id,
+    psd2_sca_exemption_type,
     sign_flag
 FROM
     authentication_queue
@@ -0,0 +1,114 @@
+CREATE TABLE recon_events_queue (
+    `recon_id` String,
+    `merchant_id` String,
+    `connector` LowCardinality(String),
+    `file_name` String,
+    `connector_transaction_id` String,
+    `transaction_type` LowCardinality(String),
+    `status` LowCardinality(String),
+    `payment_id` Nullable(String),
+    `attempt_id` Nullable(String),
+    `refund_id` Nullable(String),
+    `currency` LowCardinality(String),
+    `settled_amount` Nullable(Int64),
+    `expected_amount` Nullable(Int64),
+    `settled_fee` Nullable(Int64),
+    `expected_fee` Nullable(Int64),
+    `created_at_timestamp` DateTime64(3)
+) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
+kafka_topic_list = 'hyperswitch-recon-events',
+kafka_group_name = 'hyper',
+kafka_format = 'JSONEachRow',
+kafka_handle_error_mode = 'stream';
+
+CREATE TABLE recon_events (
+    `recon_id` String,
+    `merchant_id` LowCardinality(String),
+    `connector` LowCardinality(String),
+    `file_name` String,
+    `connector_transaction_id` String,
+    `transaction_type` LowCardinality(String),
+    `status` LowCardinality(String),
+    `payment_id` Nullable(String),
+    `attempt_id` Nullable(String),
+    `refund_id` Nullable(String),
+    `currency` LowCardinality(String),
+    `settled_amount` Nullable(Int64),
+    `expected_amount` Nullable(Int64),
+    `settled_fee` Nullable(Int64),
+    `expected_fee` Nullable(Int64),
+    `created_at` DateTime64(3),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
+    INDEX statusIndex status TYPE bloom_filter GRANULARITY 1,
+    INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1
+) ENGINE = MergeTree PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (
+        created_at,
+        merchant_id,
+        recon_id,
+        connector_transaction_id
+    ) TTL inserted_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW recon_events_mv TO recon_events (
+    `recon_id` String,
+    `merchant_id` String,
+    `connector` LowCardinality(String),
+    `file_name` String,
+    `connector_transaction_id` String,
+    `transaction_type` LowCardinality(String),
+    `status` LowCardinality(String),
+    `payment_id` Nullable(String),
+    `attempt_id` Nullable(String),
+    `refund_id` Nullable(String),
+    `currency` LowCardinality(String),
+    `settled_amount` Nullable(Int64),
+    `expected_amount` Nullable(Int64),
+    `settled_fee` Nullable(Int64),
+    `expected_fee` Nullable(Int64),
+    `created_at` DateTime64(3),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4)
+) AS
+SELECT
+    recon_id,
+    merchant_id,
+    connector,
+    file_name,
+    connector_transaction_id,
+    transaction_type,
+    status,
+    payment_id,
+    attempt_id,
+    refund_id,
+    currency,
+    settled_amount,
+    expected_amount,
+    settled_fee,
+    expected_fee,
+    created_at_timestamp AS created_at,
+    now() AS inserted_at
+FROM
+    recon_events_queue
+WHERE
+    length(_error) = 0;
+
+CREATE MATERIALIZED VIEW recon_events_parse_errors (
+    `topic` String,
+    `partition` Int64,
+    `offset` Int64,
+    `raw` String,
+    `error` String
+) ENGINE = MergeTree
+ORDER BY
+    (topic, partition, offset) SETTINGS index_granularity = 8192 AS
+SELECT
+    _topic AS topic,
+    _partition AS partition,
+    _offset AS offset,
+    _raw_message AS raw,
+    _error AS error
+FROM
+    recon_events_queue
+WHERE
+    length(_error) > 0;
\ No newline at end of file
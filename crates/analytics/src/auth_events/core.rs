@@ -170,6 +170,7 @@ pub async fn get_filters(
             AuthEventDimensions::AuthenticationConnector => fil.authentication_connector.map(|i| i.as_ref().to_string()),
             AuthEventDimensions::MessageVersion => fil.message_version,
             AuthEventDimensions::AcsReferenceNumber => fil.acs_reference_number,
+            AuthEventDimensions::Psd2ScaExemptionType => fil.psd2_sca_exemption_type.map(|i| i.as_ref().to_string()),
         })
         .collect::<Vec<String>>();
         res.query_data.push(AuthEventFilterValue {
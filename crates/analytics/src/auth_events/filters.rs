@@ -1,7 +1,9 @@
 use api_models::analytics::{auth_events::AuthEventDimensions, Granularity, TimeRange};
 use common_enums::DecoupledAuthenticationType;
 use common_utils::errors::ReportSwitchExt;
-use diesel_models::enums::{AuthenticationConnectors, AuthenticationStatus, TransactionStatus};
+use diesel_models::enums::{
+    AuthenticationConnectors, AuthenticationStatus, ScaExemptionType, TransactionStatus,
+};
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
@@ -60,4 +62,5 @@ pub struct AuthEventFilterRow {
     pub authentication_connector: Option<DBEnumWrapper<AuthenticationConnectors>>,
     pub message_version: Option<String>,
     pub acs_reference_number: Option<String>,
+    pub psd2_sca_exemption_type: Option<DBEnumWrapper<ScaExemptionType>>,
 }
@@ -46,6 +46,7 @@ pub struct AuthEventMetricRow {
     pub authentication_connector: Option<DBEnumWrapper<storage_enums::AuthenticationConnectors>>,
     pub message_version: Option<String>,
     pub acs_reference_number: Option<String>,
+    pub psd2_sca_exemption_type: Option<DBEnumWrapper<storage_enums::ScaExemptionType>>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
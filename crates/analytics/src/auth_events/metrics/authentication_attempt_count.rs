@@ -111,6 +111,7 @@ where
                         i.authentication_connector.as_ref().map(|i| i.0),
                         i.message_version.clone(),
                         i.acs_reference_number.clone(),
+                        i.psd2_sca_exemption_type.as_ref().map(|i| i.0),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
@@ -62,6 +62,15 @@ where
                 )
                 .attach_printable("Error adding acs reference number filter")?;
         }
+
+        if !self.psd2_sca_exemption_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    AuthEventDimensions::Psd2ScaExemptionType,
+                    &self.psd2_sca_exemption_type,
+                )
+                .attach_printable("Error adding psd2 sca exemption type filter")?;
+        }
         Ok(())
     }
 }
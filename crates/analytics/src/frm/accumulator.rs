@@ -6,6 +6,8 @@ use super::metrics::FrmMetricRow;
 pub struct FrmMetricsAccumulator {
     pub frm_triggered_attempts: TriggeredAttemptsAccumulator,
     pub frm_blocked_rate: BlockedRateAccumulator,
+    pub frm_false_negative_rate: FalseNegativeRateAccumulator,
+    pub frm_false_positive_rate: FalsePositiveRateAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -20,6 +22,18 @@ pub struct BlockedRateAccumulator {
     pub total: i64,
 }
 
+#[derive(Debug, Default)]
+pub struct FalseNegativeRateAccumulator {
+    pub reported_fraud: i64,
+    pub legit: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct FalsePositiveRateAccumulator {
+    pub disputes_won: i64,
+    pub flagged: i64,
+}
+
 pub trait FrmMetricAccumulator {
     type MetricOutput;
 
@@ -68,11 +82,71 @@ impl FrmMetricAccumulator for BlockedRateAccumulator {
     }
 }
 
+impl FrmMetricAccumulator for FalseNegativeRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &FrmMetricRow) {
+        if let Some(ref frm_status) = metrics.frm_status {
+            if frm_status.as_ref() == &storage_enums::FraudCheckStatus::Legit {
+                if metrics.feedback_type.is_some() {
+                    self.reported_fraud += metrics.count.unwrap_or_default();
+                }
+                self.legit += metrics.count.unwrap_or_default();
+            }
+        };
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.legit <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.reported_fraud).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.legit).ok()?),
+            )
+        }
+    }
+}
+
+impl FrmMetricAccumulator for FalsePositiveRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &FrmMetricRow) {
+        if let Some(ref frm_status) = metrics.frm_status {
+            if matches!(
+                frm_status.as_ref(),
+                storage_enums::FraudCheckStatus::Fraud
+                    | storage_enums::FraudCheckStatus::ManualReview
+            ) {
+                if let Some(ref dispute_status) = metrics.dispute_status {
+                    if dispute_status.as_ref() == &storage_enums::DisputeStatus::DisputeWon {
+                        self.disputes_won += metrics.count.unwrap_or_default();
+                    }
+                }
+                self.flagged += metrics.count.unwrap_or_default();
+            }
+        };
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.flagged <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.disputes_won).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.flagged).ok()?),
+            )
+        }
+    }
+}
+
 impl FrmMetricsAccumulator {
     pub fn collect(self) -> FrmMetricsBucketValue {
         FrmMetricsBucketValue {
             frm_blocked_rate: self.frm_blocked_rate.collect(),
             frm_triggered_attempts: self.frm_triggered_attempts.collect(),
+            frm_false_negative_rate: self.frm_false_negative_rate.collect(),
+            frm_false_positive_rate: self.frm_false_positive_rate.collect(),
         }
     }
 }
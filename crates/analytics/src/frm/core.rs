@@ -85,6 +85,12 @@ pub async fn get_metrics(
                 FrmMetrics::FrmTriggeredAttempts => metrics_builder
                     .frm_triggered_attempts
                     .add_metrics_bucket(&value),
+                FrmMetrics::FrmFalseNegativeRate => metrics_builder
+                    .frm_false_negative_rate
+                    .add_metrics_bucket(&value),
+                FrmMetrics::FrmFalsePositiveRate => metrics_builder
+                    .frm_false_positive_rate
+                    .add_metrics_bucket(&value),
             }
         }
 
@@ -181,6 +187,8 @@ pub async fn get_filters(
             FrmDimensions::FrmTransactionType => {
                 fil.frm_transaction_type.map(|i| i.as_ref().to_string())
             }
+            FrmDimensions::FeedbackType => fil.feedback_type.map(|i| i.as_ref().to_string()),
+            FrmDimensions::DisputeStatus => fil.dispute_status.map(|i| i.as_ref().to_string()),
         })
         .collect::<Vec<String>>();
         res.query_data.push(FrmFilterValue {
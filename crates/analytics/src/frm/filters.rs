@@ -3,7 +3,7 @@ use api_models::analytics::{
     Granularity, TimeRange,
 };
 use common_utils::errors::ReportSwitchExt;
-use diesel_models::enums::FraudCheckStatus;
+use diesel_models::enums::{DisputeStatus, FraudCheckStatus, FrmFeedbackType};
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
@@ -56,4 +56,6 @@ pub struct FrmFilterRow {
     pub frm_status: Option<DBEnumWrapper<FraudCheckStatus>>,
     pub frm_transaction_type: Option<DBEnumWrapper<FrmTransactionType>>,
     pub frm_name: Option<String>,
+    pub feedback_type: Option<DBEnumWrapper<FrmFeedbackType>>,
+    pub dispute_status: Option<DBEnumWrapper<DisputeStatus>>,
 }
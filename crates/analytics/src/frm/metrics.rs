@@ -5,9 +5,13 @@ use api_models::analytics::{
 use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
 mod frm_blocked_rate;
+mod frm_false_negative_rate;
+mod frm_false_positive_rate;
 mod frm_triggered_attempts;
 
 use frm_blocked_rate::FrmBlockedRate;
+use frm_false_negative_rate::FrmFalseNegativeRate;
+use frm_false_positive_rate::FrmFalsePositiveRate;
 use frm_triggered_attempts::FrmTriggeredAttempts;
 
 use crate::{
@@ -19,6 +23,8 @@ pub struct FrmMetricRow {
     pub frm_name: Option<String>,
     pub frm_status: Option<DBEnumWrapper<storage_enums::FraudCheckStatus>>,
     pub frm_transaction_type: Option<DBEnumWrapper<FrmTransactionType>>,
+    pub feedback_type: Option<DBEnumWrapper<storage_enums::FrmFeedbackType>>,
+    pub dispute_status: Option<DBEnumWrapper<storage_enums::DisputeStatus>>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -94,6 +100,30 @@ where
                     )
                     .await
             }
+            Self::FrmFalseNegativeRate => {
+                FrmFalseNegativeRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::FrmFalsePositiveRate => {
+                FrmFalsePositiveRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
         }
     }
 }
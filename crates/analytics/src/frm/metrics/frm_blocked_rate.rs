@@ -94,6 +94,8 @@ where
                         i.frm_name.as_ref().map(|i| i.to_string()),
                         None,
                         i.frm_transaction_type.as_ref().map(|i| i.0.to_string()),
+                        i.feedback_type.as_ref().map(|i| i.0.to_string()),
+                        i.dispute_status.as_ref().map(|i| i.0.to_string()),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
@@ -33,6 +33,18 @@ where
                 .attach_printable("Error adding frm transaction type filter")?;
         }
 
+        if !self.feedback_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(FrmDimensions::FeedbackType, &self.feedback_type)
+                .attach_printable("Error adding frm feedback type filter")?;
+        }
+
+        if !self.dispute_status.is_empty() {
+            builder
+                .add_filter_in_range_clause(FrmDimensions::DisputeStatus, &self.dispute_status)
+                .attach_printable("Error adding dispute status filter")?;
+        }
+
         Ok(())
     }
 }
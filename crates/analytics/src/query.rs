@@ -20,7 +20,8 @@ use api_models::{
     refunds::RefundStatus,
 };
 use common_enums::{
-    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
+    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, DisputeStatus,
+    FrmFeedbackType, ScaExemptionType, TransactionStatus,
 };
 use common_utils::{
     errors::{CustomResult, ParsingError},
@@ -527,6 +528,9 @@ impl_to_sql_for_to_string!(
     &DisputeDimensions,
     DisputeDimensions,
     DisputeStage,
+    DisputeStatus,
+    FrmFeedbackType,
+    ScaExemptionType,
     AuthEventDimensions,
     &AuthEventDimensions
 );
@@ -5,7 +5,8 @@ use api_models::{
     enums::{DisputeStage, DisputeStatus},
 };
 use common_enums::{
-    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
+    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, FrmFeedbackType,
+    ScaExemptionType, TransactionStatus,
 };
 use common_utils::{
     errors::{CustomResult, ParsingError},
@@ -97,12 +98,14 @@ db_type!(RefundStatus);
 db_type!(RefundType);
 db_type!(FraudCheckStatus);
 db_type!(FrmTransactionType);
+db_type!(FrmFeedbackType, TEXT);
 db_type!(DisputeStage);
 db_type!(DisputeStatus);
 db_type!(AuthenticationStatus);
 db_type!(TransactionStatus);
 db_type!(AuthenticationConnectors);
 db_type!(DecoupledAuthenticationType);
+db_type!(ScaExemptionType);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -236,6 +239,12 @@ impl<'a> FromRow<'a, PgRow> for super::auth_events::metrics::AuthEventMetricRow
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let psd2_sca_exemption_type: Option<DBEnumWrapper<ScaExemptionType>> = row
+            .try_get("psd2_sca_exemption_type")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let count: Option<i64> = row.try_get("count").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -255,6 +264,7 @@ impl<'a> FromRow<'a, PgRow> for super::auth_events::metrics::AuthEventMetricRow
             authentication_connector,
             message_version,
             acs_reference_number,
+            psd2_sca_exemption_type,
             count,
             start_bucket,
             end_bucket,
@@ -299,6 +309,12 @@ impl<'a> FromRow<'a, PgRow> for super::auth_events::filters::AuthEventFilterRow
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let psd2_sca_exemption_type: Option<DBEnumWrapper<ScaExemptionType>> = row
+            .try_get("psd2_sca_exemption_type")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             authentication_status,
             trans_status,
@@ -307,6 +323,7 @@ impl<'a> FromRow<'a, PgRow> for super::auth_events::filters::AuthEventFilterRow
             authentication_connector,
             message_version,
             acs_reference_number,
+            psd2_sca_exemption_type,
         })
     }
 }
@@ -392,6 +409,16 @@ impl<'a> FromRow<'a, PgRow> for super::frm::metrics::FrmMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let feedback_type: Option<DBEnumWrapper<FrmFeedbackType>> =
+            row.try_get("feedback_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let dispute_status: Option<DBEnumWrapper<DisputeStatus>> =
+            row.try_get("dispute_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -411,6 +438,8 @@ impl<'a> FromRow<'a, PgRow> for super::frm::metrics::FrmMetricRow {
             frm_name,
             frm_status,
             frm_transaction_type,
+            feedback_type,
+            dispute_status,
             total,
             count,
             start_bucket,
@@ -1026,10 +1055,22 @@ impl<'a> FromRow<'a, PgRow> for super::frm::filters::FrmFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let feedback_type: Option<DBEnumWrapper<FrmFeedbackType>> =
+            row.try_get("feedback_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let dispute_status: Option<DBEnumWrapper<DisputeStatus>> =
+            row.try_get("dispute_status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             frm_name,
             frm_status,
             frm_transaction_type,
+            feedback_type,
+            dispute_status,
         })
     }
 }
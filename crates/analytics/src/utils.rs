@@ -52,6 +52,7 @@ pub fn get_auth_event_dimensions() -> Vec<NameDescription> {
         AuthEventDimensions::AuthenticationConnector,
         AuthEventDimensions::MessageVersion,
         AuthEventDimensions::AcsReferenceNumber,
+        AuthEventDimensions::Psd2ScaExemptionType,
     ]
     .into_iter()
     .map(Into::into)
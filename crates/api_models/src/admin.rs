@@ -280,6 +280,33 @@ pub struct AuthenticationConnectorDetails {
     pub three_ds_requestor_url: String,
     /// Merchant app declaring their URL within the CReq message so that the Authentication app can call the Merchant app after OOB authentication has occurred.
     pub three_ds_requestor_app_url: Option<String>,
+    /// Policy used to decide which PSD2 SCA exemption, if any, is requested in the authentication request
+    pub three_ds_exemption_policy: Option<ThreeDsExemptionPolicy>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ThreeDsExemptionPolicy {
+    /// Currency in which the amount limits of the policy are configured, exemptions are only requested for payments in this currency
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+    /// Request the low value exemption for payments up to this amount
+    #[schema(value_type = Option<i64>, example = 3000)]
+    pub low_value_max_amount: Option<common_utils::types::MinorUnit>,
+    /// Fraud rate of the acquirer in percent. When provided, transaction risk analysis is requested for EUR payments within the amount limit allowed for this fraud rate
+    #[schema(example = 0.05)]
+    pub acquirer_fraud_rate: Option<f64>,
+    /// Request the trusted beneficiary exemption for customers who have whitelisted the merchant with their issuer
+    #[serde(default)]
+    pub trusted_beneficiary: bool,
+    /// Request the secure corporate payment exemption for payments with corporate cards, for profiles that only process such cards through dedicated corporate processes
+    #[serde(default)]
+    pub secure_corporate_payment: bool,
+    /// Minimum number of prior successful authentications of the customer before any exemption is requested
+    #[schema(example = 1)]
+    pub min_prior_authentications: Option<u32>,
+    /// Issuer countries for which exemptions are never requested
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US"]))]
+    pub excluded_issuer_countries: Option<Vec<api_enums::CountryAlpha2>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
@@ -690,6 +717,74 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// Additional endpoints to which outgoing webhooks are delivered, each with its own event
+    /// subscriptions and signing secrets
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpoint {
+    /// Identifier of the endpoint, unique within the profile
+    #[schema(max_length = 16, example = "orders_backend")]
+    pub endpoint_id: String,
+
+    /// The url to which webhooks are delivered
+    #[schema(value_type = String, example = "https://www.ekart.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// Event types delivered to this endpoint. All event types are delivered if not provided
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+
+    /// Secret used to sign the webhooks delivered to this endpoint. Required when the endpoint is
+    /// created, the secret is never returned in responses and is kept unchanged on updates which
+    /// do not provide it
+    #[schema(value_type = Option<String>, example = "whsec_4f8c1d2e")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<Secret<String>>,
+
+    /// Secret being rotated out. Webhooks are signed with both `signing_secret` and this secret
+    /// until `previous_signing_secret_expires_at`. Never returned in responses
+    #[schema(value_type = Option<String>)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_signing_secret: Option<Secret<String>>,
+
+    /// Time until which webhooks are also signed with `previous_signing_secret`
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_signing_secret_expires_at: Option<time::PrimitiveDateTime>,
+
+    /// If this property is true, webhooks are not delivered to this endpoint. Endpoints are
+    /// disabled automatically after sustained delivery failures
+    #[schema(example = false)]
+    pub disabled: Option<bool>,
+}
+
+impl WebhookDetails {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let endpoints = self.webhook_endpoints.as_deref().unwrap_or_default();
+        let mut endpoint_ids = HashSet::new();
+        for endpoint in endpoints {
+            if endpoint.endpoint_id.is_empty()
+                || endpoint.endpoint_id.len() > crate::consts::MAX_WEBHOOK_ENDPOINT_ID_LENGTH
+            {
+                return Err("webhook endpoint_id must be between 1 and 16 characters");
+            }
+            if !endpoint_ids.insert(endpoint.endpoint_id.as_str()) {
+                return Err("webhook endpoint_id must be unique within a profile");
+            }
+            if endpoint
+                .signing_secret
+                .as_ref()
+                .is_some_and(|signing_secret| signing_secret.peek().is_empty())
+            {
+                return Err("webhook endpoint signing_secret must not be empty");
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -1971,6 +2066,12 @@ pub struct ProfileCreate {
     /// Maximum number of auto retries allowed for a payment
     pub max_auto_retries_enabled: Option<u8>,
 
+    /// Indicates if auto retries are enabled for payouts
+    pub is_payout_auto_retries_enabled: Option<bool>,
+
+    /// Maximum number of auto retries allowed for a payout
+    pub max_payout_auto_retries_enabled: Option<u8>,
+
     /// Bool indicating if extended authentication must be requested for all payments
     #[schema(value_type = Option<bool>)]
     pub always_request_extended_authorization:
@@ -2277,6 +2378,13 @@ pub struct ProfileResponse {
     /// Maximum number of auto retries allowed for a payment
     pub max_auto_retries_enabled: Option<i16>,
 
+    /// Indicates if auto retries are enabled for payouts
+    #[schema(default = false, example = false)]
+    pub is_payout_auto_retries_enabled: bool,
+
+    /// Maximum number of auto retries allowed for a payout
+    pub max_payout_auto_retries_enabled: Option<i16>,
+
     /// Bool indicating if extended authentication must be requested for all payments
     #[schema(value_type = Option<bool>)]
     pub always_request_extended_authorization:
@@ -2583,6 +2691,12 @@ pub struct ProfileUpdate {
     /// Maximum number of auto retries allowed for a payment
     pub max_auto_retries_enabled: Option<u8>,
 
+    /// Indicates if auto retries are enabled for payouts
+    pub is_payout_auto_retries_enabled: Option<bool>,
+
+    /// Maximum number of auto retries allowed for a payout
+    pub max_payout_auto_retries_enabled: Option<u8>,
+
     /// Indicates if click to pay is enabled or not.
     #[schema(default = false, example = false)]
     pub is_click_to_pay_enabled: Option<bool>,
@@ -2863,6 +2977,44 @@ pub struct BusinessPaymentLinkConfig {
     pub allowed_domains: Option<HashSet<String>>,
     /// Toggle for HyperSwitch branding visibility
     pub branding_visibility: Option<bool>,
+    /// Identifier of a custom template uploaded for the profile, used for rendering payment links
+    #[schema(example = "plt_0196f3d0b2a47d91a2c5e1f0c3b9d8e7")]
+    pub template_id: Option<String>,
+    /// Strings made available to custom payment link templates, overriding the defaults.
+    /// Keyed by locale and then by string identifier, for example `{"fr": {"pay_now": "Payer"}}`
+    pub locale_bundles: Option<HashMap<String, HashMap<String, String>>>,
+}
+
+/// Usage limits applied to a payment link
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkUsagePolicy {
+    /// Maximum number of payment attempts that can be made through the payment link, `1` makes
+    /// the link single-use
+    #[schema(example = 1)]
+    pub max_uses: Option<u16>,
+    /// Maximum total amount of the payment attempts made through the payment link, in the lowest
+    /// denomination of the currency
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_total_amount: Option<common_utils::types::MinorUnit>,
+}
+
+impl PaymentLinkUsagePolicy {
+    pub fn validate(&self) -> Result<(), &str> {
+        if self.max_uses.is_some_and(|max_uses| max_uses == 0) {
+            return Err("`max_uses` in payment_link_config.usage_policy must be greater than 0");
+        }
+
+        if self.max_total_amount.is_some_and(|max_total_amount| {
+            max_total_amount <= common_utils::types::MinorUnit::zero()
+        }) {
+            return Err(
+                "`max_total_amount` in payment_link_config.usage_policy must be greater than 0",
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl BusinessPaymentLinkConfig {
@@ -3051,6 +3203,9 @@ pub struct PaymentLinkConfig {
     pub show_card_terms: Option<api_enums::PaymentLinkShowSdkTerms>,
     /// Boolean to control payment button text for setup mandate calls
     pub is_setup_mandate_flow: Option<bool>,
+    /// Usage limits applied to the payment link
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_policy: Option<PaymentLinkUsagePolicy>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
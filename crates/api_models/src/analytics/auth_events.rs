@@ -4,7 +4,8 @@ use std::{
 };
 
 use common_enums::{
-    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
+    AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, ScaExemptionType,
+    TransactionStatus,
 };
 
 use super::{NameDescription, TimeRange};
@@ -25,6 +26,8 @@ pub struct AuthEventFilters {
     pub message_version: Vec<String>,
     #[serde(default)]
     pub acs_reference_number: Vec<String>,
+    #[serde(default)]
+    pub psd2_sca_exemption_type: Vec<ScaExemptionType>,
 }
 
 #[derive(
@@ -53,6 +56,7 @@ pub enum AuthEventDimensions {
     AuthenticationConnector,
     MessageVersion,
     AcsReferenceNumber,
+    Psd2ScaExemptionType,
 }
 
 #[derive(
@@ -139,6 +143,7 @@ pub struct AuthEventMetricsBucketIdentifier {
     pub authentication_connector: Option<AuthenticationConnectors>,
     pub message_version: Option<String>,
     pub acs_reference_number: Option<String>,
+    pub psd2_sca_exemption_type: Option<ScaExemptionType>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -156,6 +161,7 @@ impl AuthEventMetricsBucketIdentifier {
         authentication_connector: Option<AuthenticationConnectors>,
         message_version: Option<String>,
         acs_reference_number: Option<String>,
+        psd2_sca_exemption_type: Option<ScaExemptionType>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -166,6 +172,7 @@ impl AuthEventMetricsBucketIdentifier {
             authentication_connector,
             message_version,
             acs_reference_number,
+            psd2_sca_exemption_type,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -180,6 +187,7 @@ impl Hash for AuthEventMetricsBucketIdentifier {
         self.authentication_connector.hash(state);
         self.message_version.hash(state);
         self.acs_reference_number.hash(state);
+        self.psd2_sca_exemption_type.hash(state);
         self.error_message.hash(state);
         self.time_bucket.hash(state);
     }
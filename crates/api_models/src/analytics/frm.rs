@@ -3,7 +3,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use common_enums::enums::FraudCheckStatus;
+use common_enums::enums::{DisputeStatus, FraudCheckStatus, FrmFeedbackType};
 
 #[derive(
     Clone,
@@ -34,6 +34,10 @@ pub struct FrmFilters {
     pub frm_name: Vec<String>,
     #[serde(default)]
     pub frm_transaction_type: Vec<FrmTransactionType>,
+    #[serde(default)]
+    pub feedback_type: Vec<FrmFeedbackType>,
+    #[serde(default)]
+    pub dispute_status: Vec<DisputeStatus>,
 }
 
 #[derive(
@@ -56,6 +60,8 @@ pub enum FrmDimensions {
     FrmStatus,
     FrmName,
     FrmTransactionType,
+    FeedbackType,
+    DisputeStatus,
 }
 
 #[derive(
@@ -75,11 +81,15 @@ pub enum FrmDimensions {
 pub enum FrmMetrics {
     FrmTriggeredAttempts,
     FrmBlockedRate,
+    FrmFalseNegativeRate,
+    FrmFalsePositiveRate,
 }
 
 pub mod metric_behaviour {
     pub struct FrmTriggeredAttempts;
     pub struct FrmBlockRate;
+    pub struct FrmFalseNegativeRate;
+    pub struct FrmFalsePositiveRate;
 }
 
 impl From<FrmMetrics> for NameDescription {
@@ -105,6 +115,8 @@ pub struct FrmMetricsBucketIdentifier {
     pub frm_status: Option<String>,
     pub frm_name: Option<String>,
     pub frm_transaction_type: Option<String>,
+    pub feedback_type: Option<String>,
+    pub dispute_status: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -117,6 +129,8 @@ impl Hash for FrmMetricsBucketIdentifier {
         self.frm_status.hash(state);
         self.frm_name.hash(state);
         self.frm_transaction_type.hash(state);
+        self.feedback_type.hash(state);
+        self.dispute_status.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -136,12 +150,16 @@ impl FrmMetricsBucketIdentifier {
         frm_status: Option<String>,
         frm_name: Option<String>,
         frm_transaction_type: Option<String>,
+        feedback_type: Option<String>,
+        dispute_status: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
             frm_status,
             frm_name,
             frm_transaction_type,
+            feedback_type,
+            dispute_status,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -152,6 +170,10 @@ impl FrmMetricsBucketIdentifier {
 pub struct FrmMetricsBucketValue {
     pub frm_triggered_attempts: Option<u64>,
     pub frm_blocked_rate: Option<f64>,
+    /// Percentage of payments marked legit that were later charged back or refunded as fraud
+    pub frm_false_negative_rate: Option<f64>,
+    /// Percentage of payments flagged as fraud or for review whose dispute was won by the merchant
+    pub frm_false_positive_rate: Option<f64>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -0,0 +1,80 @@
+use common_enums::{ApprovalAction, ApprovalRequestStatus, EntityType};
+use common_utils::{id_type, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+/// A single maker-checker rule, the action it covers needs a second user to approve it
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalRule {
+    /// The action that requires approval
+    pub action: ApprovalAction,
+
+    /// Only refunds of at least this amount require approval. Applicable only to `refund_create`,
+    /// every refund requires approval when this is not set.
+    pub min_amount: Option<MinorUnit>,
+}
+
+/// The maker-checker policy configured for an organization or a merchant
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalPolicy {
+    pub rules: Vec<ApprovalRule>,
+}
+
+impl ApprovalPolicy {
+    pub fn find_rule(&self, action: ApprovalAction) -> Option<&ApprovalRule> {
+        self.rules.iter().find(|rule| rule.action == action)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApprovalPolicyResponse {
+    /// The entity the policy was configured on, a merchant without its own policy inherits the
+    /// organization policy
+    pub entity_type: Option<EntityType>,
+    pub rules: Vec<ApprovalRule>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApprovalRequestResponse {
+    pub approval_request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub action: ApprovalAction,
+    pub status: ApprovalRequestStatus,
+    /// The resource the action applies to, such as the payment being refunded or the connector
+    /// account being updated
+    pub resource_id: Option<String>,
+    pub requested_by: String,
+    pub reviewed_by: Option<String>,
+    pub review_comment: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ApprovalRequestListConstraints {
+    pub status: Option<ApprovalRequestStatus>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalDecisionRequest {
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApprovalDecisionResponse {
+    pub approval_request: ApprovalRequestResponse,
+    /// The response of the approved action, present only when it was executed successfully.
+    /// Responses containing secrets, such as created API keys, are held back from the reviewer
+    /// and can be retrieved once by the requester.
+    pub result: Option<serde_json::Value>,
+}
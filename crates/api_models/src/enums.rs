@@ -139,6 +139,8 @@ pub enum FrmConnectors {
     /// Signifyd Risk Manager. Official docs: https://docs.signifyd.com/
     Signifyd,
     Riskified,
+    /// Native rules based fraud checks, evaluated without an external FRM provider
+    HyperswitchFrm,
 }
 
 #[derive(
@@ -431,6 +433,8 @@ pub enum LockerChoice {
 #[strum(serialize_all = "snake_case")]
 pub enum PmAuthConnectors {
     Plaid,
+    Truelayer,
+    GocardlessBankData,
 }
 
 pub fn convert_pm_auth_connector(connector_name: &str) -> Option<PmAuthConnectors> {
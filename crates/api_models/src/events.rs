@@ -32,6 +32,7 @@ use crate::{
         outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
     },
     api_keys::*,
+    approvals::*,
     cards_info::*,
     disputes::*,
     files::*,
@@ -78,6 +79,8 @@ impl_api_event_type!(
         MandateRevokedResponse,
         RetrievePaymentLinkRequest,
         PaymentLinkListConstraints,
+        PaymentLinkTemplateUploadRequest,
+        PaymentLinkTemplateUploadResponse,
         MandateId,
         DisputeListGetConstraints,
         RetrieveApiKeyResponse,
@@ -142,7 +145,13 @@ impl_api_event_type!(
         OrganizationCreateRequest,
         OrganizationUpdateRequest,
         OrganizationId,
-        CustomerListRequest
+        CustomerListRequest,
+        ApprovalPolicy,
+        ApprovalPolicyResponse,
+        ApprovalRequestResponse,
+        ApprovalRequestListConstraints,
+        ApprovalDecisionRequest,
+        ApprovalDecisionResponse
     )
 );
 
@@ -254,6 +254,24 @@ impl ApiEventMetric for PaymentMethodMigrateResponse {
 
 impl ApiEventMetric for PaymentMethodUpdate {}
 
+impl ApiEventMetric for payment_methods::PaymentMethodMigrationBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for payment_methods::CardVaultExportRequest {}
+
+impl ApiEventMetric for payment_methods::CardVaultExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for payment_methods::DefaultPaymentMethod {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
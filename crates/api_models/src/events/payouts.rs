@@ -1,9 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
-    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutRetrieveRequest,
+    PayoutActionRequest, PayoutBatchResponse, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutLinkInitiateRequest, PayoutListConstraints, PayoutListFilterConstraints,
+    PayoutListFilters, PayoutListResponse, PayoutRetrieveRequest,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -69,3 +69,11 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -2,6 +2,8 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use masking::PeekInterface;
 
 use crate::recon::{
+    ReconBankStatementUploadResponse, ReconSettlementFileUploadResponse,
+    ReconSettlementReportResponse, ReconSettlementRunRequest, ReconSettlementRunResponse,
     ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest, VerifyTokenResponse,
 };
 
@@ -23,6 +25,17 @@ impl ApiEventMetric for ReconStatusResponse {
     }
 }
 
+common_utils::impl_api_event_type!(
+    Recon,
+    (
+        ReconSettlementFileUploadResponse,
+        ReconBankStatementUploadResponse,
+        ReconSettlementRunRequest,
+        ReconSettlementRunResponse,
+        ReconSettlementReportResponse
+    )
+);
+
 impl ApiEventMetric for VerifyTokenResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::User {
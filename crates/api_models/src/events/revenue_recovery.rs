@@ -1,6 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::process_tracker::revenue_recovery::{RevenueRecoveryId, RevenueRecoveryResponse};
+use crate::process_tracker::revenue_recovery::{
+    RevenueRecoveryId, RevenueRecoveryResponse, RevenueRecoveryRetryTimelineResponse,
+};
 
 impl ApiEventMetric for RevenueRecoveryResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -12,3 +14,8 @@ impl ApiEventMetric for RevenueRecoveryId {
         Some(ApiEventsType::ProcessTracker)
     }
 }
+impl ApiEventMetric for RevenueRecoveryRetryTimelineResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
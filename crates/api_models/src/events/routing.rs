@@ -3,7 +3,8 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use crate::routing::{
     ContractBasedRoutingPayloadWrapper, ContractBasedRoutingSetupPayloadWrapper,
     DynamicRoutingUpdateConfigQuery, EliminationRoutingPayloadWrapper,
-    LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm, ProfileDefaultRoutingConfig,
+    LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm,
+    PaymentMethodEligibilityResponse, PaymentMethodEligibilityWrapper, ProfileDefaultRoutingConfig,
     RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord, RoutingKind,
     RoutingLinkWrapper, RoutingPayloadWrapper, RoutingRetrieveLinkQuery,
     RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery, RoutingVolumeSplit,
@@ -153,3 +154,15 @@ impl ApiEventMetric for RoutingVolumeSplit {
         Some(ApiEventsType::Routing)
     }
 }
+
+impl ApiEventMetric for PaymentMethodEligibilityWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for PaymentMethodEligibilityResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
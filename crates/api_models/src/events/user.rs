@@ -10,18 +10,25 @@ use crate::user::{
     dashboard_metadata::{
         GetMetaDataRequest, GetMetaDataResponse, GetMultipleMetaDataPayload, SetMetaDataRequest,
     },
-    AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginTotpResponse,
-    ChangePasswordRequest, CloneConnectorRequest, ConnectAccountRequest, CreateInternalUserRequest,
-    CreateTenantUserRequest, CreateUserAuthenticationMethodRequest, ForgotPasswordRequest,
+    scim::{
+        ScimGroupListResponse, ScimGroupRequest, ScimGroupResponse, ScimListQuery,
+        ScimPatchRequest, ScimTokenResponse, ScimUserListResponse, ScimUserRequest,
+        ScimUserResponse,
+    },
+    AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginPasskeySignInRequest,
+    BeginPasskeySignInResponse, BeginTotpResponse, ChangePasswordRequest, CloneConnectorRequest,
+    ConnectAccountRequest, CreateInternalUserRequest, CreateTenantUserRequest,
+    CreateUserAuthenticationMethodRequest, FinishPasskeyRegistrationRequest, ForgotPasswordRequest,
     GetSsoAuthUrlRequest, GetUserAuthenticationMethodsRequest, GetUserDetailsResponse,
     GetUserRoleDetailsRequest, GetUserRoleDetailsResponseV2, InviteUserRequest,
-    PlatformAccountCreateRequest, PlatformAccountCreateResponse, ReInviteUserRequest,
-    RecoveryCodes, ResetPasswordRequest, RotatePasswordRequest, SendVerifyEmailRequest,
-    SignUpRequest, SignUpWithMerchantIdRequest, SsoSignInRequest, SwitchMerchantRequest,
-    SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse, TwoFactorAuthStatusResponse,
-    TwoFactorStatus, UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest,
-    UserFromEmailRequest, UserMerchantAccountResponse, UserMerchantCreate,
-    UserOrgMerchantCreateRequest, VerifyEmailRequest, VerifyRecoveryCodeRequest, VerifyTotpRequest,
+    PasskeyOptionsResponse, PasskeyResponse, PasskeySignInRequest, PlatformAccountCreateRequest,
+    PlatformAccountCreateResponse, ReInviteUserRequest, RecoveryCodes, ResetPasswordRequest,
+    RotatePasswordRequest, SendVerifyEmailRequest, SignUpRequest, SignUpWithMerchantIdRequest,
+    SsoSignInRequest, SwitchMerchantRequest, SwitchOrganizationRequest, SwitchProfileRequest,
+    TokenResponse, TwoFactorAuthStatusResponse, TwoFactorStatus, UpdateUserAccountDetailsRequest,
+    UpdateUserAuthenticationMethodRequest, UserFromEmailRequest, UserMerchantAccountResponse,
+    UserMerchantCreate, UserOrgMerchantCreateRequest, VerifyEmailRequest, VerifyPasskeyRequest,
+    VerifyRecoveryCodeRequest, VerifyTotpRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -66,6 +73,22 @@ common_utils::impl_api_event_type!(
         VerifyRecoveryCodeRequest,
         VerifyTotpRequest,
         RecoveryCodes,
+        PasskeyOptionsResponse,
+        FinishPasskeyRegistrationRequest,
+        VerifyPasskeyRequest,
+        PasskeyResponse,
+        BeginPasskeySignInRequest,
+        BeginPasskeySignInResponse,
+        PasskeySignInRequest,
+        ScimTokenResponse,
+        ScimUserRequest,
+        ScimUserResponse,
+        ScimUserListResponse,
+        ScimGroupRequest,
+        ScimGroupResponse,
+        ScimGroupListResponse,
+        ScimListQuery,
+        ScimPatchRequest,
         GetUserAuthenticationMethodsRequest,
         CreateUserAuthenticationMethodRequest,
         UpdateUserAuthenticationMethodRequest,
@@ -2,6 +2,7 @@ pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
+pub mod approvals;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
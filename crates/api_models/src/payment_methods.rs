@@ -822,7 +822,7 @@ pub enum PaymentMethodResponseData {
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -2527,6 +2527,208 @@ pub enum MigrationStatus {
     Failed,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodMigrationBatchStatus {
+    /// The file has been accepted and is waiting to be processed
+    Scheduled,
+    /// The rows of the file are being validated and migrated
+    InProgress,
+    /// All rows of the file have been processed
+    Completed,
+    /// The batch could not be processed
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodMigrationRowStatus {
+    /// The row passed validation. Only reported for dry runs
+    Valid,
+    /// The payment method was migrated
+    Migrated,
+    /// The card was already present in an earlier row of the file, or saved as a payment method,
+    /// for the same customer
+    Duplicate,
+    /// The row failed validation
+    Invalid,
+    /// The row passed validation, but the payment method could not be migrated
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentMethodMigrationBatchResponse {
+    /// The identifier of the migration batch
+    #[schema(example = "pmbatch_c2Jv2FbSyqj0ygNc3Xy6")]
+    pub batch_id: String,
+    /// The identifier of the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The merchant connector account the connector mandate details of the rows belong to
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// Whether the rows are only validated, without migrating any payment method
+    pub dry_run: bool,
+    /// The status of the migration batch
+    pub status: PaymentMethodMigrationBatchStatus,
+    /// The number of rows in the uploaded file
+    pub total_rows: u32,
+    /// The number of rows which have been processed
+    pub processed_rows: u32,
+    /// The number of rows which passed validation, or were migrated if not a dry run
+    pub succeeded_rows: u32,
+    /// The number of rows skipped as duplicates of an earlier row or of a saved payment method
+    pub duplicate_rows: u32,
+    /// The number of rows which failed validation or could not be migrated
+    pub failed_rows: u32,
+    /// The time at which the batch was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// The result of a row of a payment method migration batch, as reported in the results file
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentMethodMigrationRowResult {
+    /// The line number of the record in the uploaded file, starting from 1
+    pub line_number: i64,
+    pub customer_id: id_type::CustomerId,
+    pub card_number_masked: Option<String>,
+    pub status: PaymentMethodMigrationRowStatus,
+    /// The payment method migrated from the row, or the saved payment method with the same card
+    /// if the row is a duplicate of it
+    pub payment_method_id: Option<String>,
+    /// The line number of the earlier row, if this row is a duplicate of a row of the batch
+    pub duplicate_of_line_number: Option<i64>,
+    pub card_migrated: Option<bool>,
+    pub network_token_migrated: Option<bool>,
+    pub connector_mandate_details_migrated: Option<bool>,
+    pub network_transaction_id_migrated: Option<bool>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardVaultExportRequest {
+    /// The PEM encoded RSA public key of the recipient, the exported cards are encrypted with it
+    #[schema(value_type = String)]
+    pub recipient_public_key: masking::Secret<String>,
+    /// The identifier of the recipient public key, set as the `kid` header of every encrypted file
+    pub recipient_key_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CardVaultExportStatus {
+    /// The export is waiting for a second user to approve it
+    PendingApproval,
+    /// The export was approved and is waiting to be picked up
+    Scheduled,
+    /// Some of the payment methods have been exported
+    InProgress,
+    /// All the payment methods have been exported and the manifest was written
+    Completed,
+    /// The export could not be completed
+    Failed,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardVaultExportFile {
+    /// The name of the file, to be used when downloading it
+    #[schema(example = "part-00000.jwe")]
+    pub file_name: String,
+    /// The number of cards in the file
+    pub record_count: u32,
+    /// The number of payment methods which could not be exported, listed in the file
+    pub failure_count: u32,
+    /// The hex encoded SHA-256 digest of the file
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardVaultExportResponse {
+    /// The identifier of the export
+    #[schema(example = "vexp_c2Jv2FbSyqj0ygNc3Xy6")]
+    pub export_id: String,
+    /// The identifier of the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The status of the export
+    pub status: CardVaultExportStatus,
+    /// The approval request raised for the export
+    pub approval_request_id: String,
+    /// The identifier of the recipient public key
+    pub recipient_key_id: Option<String>,
+    /// The number of card payment methods which have been processed
+    pub processed_payment_methods: u32,
+    /// The number of cards which have been exported
+    pub exported_cards: u32,
+    /// The number of card payment methods which could not be read from the locker
+    pub failed_payment_methods: u32,
+    /// The encrypted files written so far, the manifest is available once the export is completed
+    pub files: Vec<CardVaultExportFile>,
+}
+
+/// The manifest of a completed card vault export, written unencrypted as `manifest.json`
+/// alongside the encrypted files.
+///
+/// Every file listed in the manifest is a compact JWE (RFC 7516), encrypted with the recipient
+/// public key using `RSA-OAEP-256` for key management and `A256GCM` for content encryption. The
+/// plaintext of each file is a JSON encoded [`CardVaultExportFileContents`].
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct CardVaultExportManifest {
+    /// The version of the export format, incremented on breaking changes
+    pub format_version: u8,
+    pub export_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub recipient_key_id: Option<String>,
+    /// The JWE `alg` of the encrypted files
+    pub key_management_algorithm: String,
+    /// The JWE `enc` of the encrypted files
+    pub content_encryption_algorithm: String,
+    pub exported_cards: u32,
+    pub failed_payment_methods: u32,
+    /// The encrypted files of the export, in the order they were written
+    pub files: Vec<CardVaultExportFile>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub completed_at: time::PrimitiveDateTime,
+}
+
+/// The decrypted contents of a file of a card vault export
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct CardVaultExportFileContents {
+    pub records: Vec<CardVaultExportRecord>,
+    pub failures: Vec<CardVaultExportFailure>,
+}
+
+/// A card saved in the locker, along with the details needed to keep using it for recurring
+/// payments at another provider
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct CardVaultExportRecord {
+    pub payment_method_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub card_number: CardNumber,
+    pub card_exp_month: masking::Secret<String>,
+    pub card_exp_year: masking::Secret<String>,
+    pub card_holder_name: Option<masking::Secret<String>>,
+    pub card_network: Option<String>,
+    pub card_isin: Option<String>,
+    pub nick_name: Option<String>,
+    /// The network transaction id of the first customer initiated transaction, which is needed to
+    /// make merchant initiated transactions with the card at another provider
+    pub network_transaction_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// A card payment method which could not be read from the locker
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct CardVaultExportFailure {
+    pub payment_method_id: String,
+    pub customer_id: id_type::CustomerId,
+    pub error_message: String,
+}
+
 #[cfg(all(
     any(feature = "v2", feature = "v1"),
     not(feature = "payment_methods_v2")
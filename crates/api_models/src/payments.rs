@@ -8171,6 +8171,26 @@ pub struct PaymentLinkStatusDetails {
     pub setup_future_usage_applied: Option<common_enums::FutureUsage>,
 }
 
+/// Custom template used for rendering payment links
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkTemplateUploadRequest {
+    /// HTML template for the payment link. Only the documented `{{ variable }}` placeholders are
+    /// allowed, template tags such as `{% if %}` or `{% include %}` are rejected
+    #[schema(
+        example = "<html><head>{{ rendered_css }}</head><body>{{ rendered_js }}{{ payment_link_initiator }}{{ hyperloader_sdk_link }}</body></html>"
+    )]
+    pub template: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+pub struct PaymentLinkTemplateUploadResponse {
+    /// Identifier of the uploaded template, to be configured as `template_id` in the profile's
+    /// `payment_link_config`
+    #[schema(example = "plt_0196f3d0b2a47d91a2c5e1f0c3b9d8e7")]
+    pub template_id: String,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentLinkListConstraints {
@@ -8231,6 +8251,8 @@ pub struct PaymentCreatePaymentLinkConfig {
     #[schema(value_type = Option<PaymentLinkConfigRequest>)]
     /// Theme config for the particular payment
     pub theme_config: admin::PaymentLinkConfigRequest,
+    /// Usage limits applied to the payment link
+    pub usage_policy: Option<admin::PaymentLinkUsagePolicy>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -986,3 +986,99 @@ impl From<payout_method_utils::AdditionalPayoutMethodData> for PayoutMethodDataR
         }
     }
 }
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutBatchFileFormat {
+    /// A CSV file with a header row, where each row is a [`PayoutBatchRecord`]
+    #[default]
+    Csv,
+    /// A JSON array of payout create requests
+    Json,
+}
+
+/// A row of a payout batch CSV file
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PayoutBatchRecord {
+    pub payout_id: Option<String>,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub email: Option<Email>,
+    pub name: Option<Secret<String>>,
+    pub payout_token: Option<String>,
+    pub payout_method_id: Option<String>,
+    pub connector: Option<api_enums::PayoutConnectors>,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub description: Option<String>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub confirm: Option<bool>,
+    pub auto_fulfill: Option<bool>,
+    pub card_number: Option<CardNumber>,
+    pub card_expiry_month: Option<Secret<String>>,
+    pub card_expiry_year: Option<Secret<String>>,
+    pub card_holder_name: Option<Secret<String>>,
+    pub bank_name: Option<String>,
+    pub bank_country_code: Option<api_enums::CountryAlpha2>,
+    pub iban: Option<Secret<String>>,
+    pub bic: Option<Secret<String>>,
+    pub bank_account_number: Option<Secret<String>>,
+    pub bank_routing_number: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch has been validated and is waiting to be processed
+    Scheduled,
+    /// Payouts are being created for the rows of the batch
+    InProgress,
+    /// All rows of the batch have been processed
+    Completed,
+    /// The batch could not be processed
+    Failed,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier of the payout batch
+    #[schema(example = "pobatch_c2Jv2FbSyqj0ygNc3Xy6")]
+    pub batch_id: String,
+    /// The identifier of the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The status of the payout batch
+    pub status: PayoutBatchStatus,
+    /// The number of rows in the uploaded file
+    pub total_rows: u32,
+    /// The number of rows which have been processed
+    pub processed_rows: u32,
+    /// The number of rows for which payouts were created successfully
+    pub succeeded_rows: u32,
+    /// The number of rows which failed validation or for which payouts could not be created
+    pub failed_rows: u32,
+    /// The time at which the batch was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The results of the processed rows. This is only populated when retrieving a batch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<PayoutBatchRowResult>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBatchRowResult {
+    /// The row number of the record in the uploaded file, starting from 1
+    pub row_number: u32,
+    /// The identifier of the payout created for the row
+    pub payout_id: Option<String>,
+    /// The status of the payout created for the row
+    #[schema(value_type = Option<PayoutStatus>)]
+    pub status: Option<api_enums::PayoutStatus>,
+    /// The error code, if the row could not be processed
+    pub error_code: Option<String>,
+    /// The error message, if the row could not be processed
+    pub error_message: Option<String>,
+}
@@ -12,12 +12,14 @@ pub struct LinkTokenCreateRequest {
     pub payment_id: id_type::PaymentId, // payment_id to be passed in req body for redis pm_auth connector name fetch
     pub payment_method: PaymentMethod,  // payment_method to be used for filtering pm_auth connector
     pub payment_method_type: PaymentMethodType, // payment_method_type to be used for filtering pm_auth connector
+    pub institution_id: Option<String>, // bank to be pre-selected, required by providers without their own bank selection
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct LinkTokenCreateResponse {
-    pub link_token: String, // link_token received in response
-    pub connector: String,  // pm_auth connector name in response
+    pub link_token: String,           // link_token received in response
+    pub connector: String,            // pm_auth connector name in response
+    pub redirect_url: Option<String>, // hosted page to redirect the customer to, for redirect based providers
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -28,6 +30,7 @@ pub struct ExchangeTokenCreateRequest {
     pub payment_id: id_type::PaymentId,
     pub payment_method: PaymentMethod,
     pub payment_method_type: PaymentMethodType,
+    pub redirect_uri: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -19,3 +19,86 @@ pub struct RevenueRecoveryResponse {
 pub struct RevenueRecoveryId {
     pub revenue_recovery_id: id_type::GlobalPaymentId,
 }
+
+/// Retry strategy used by the smart retry algorithm of revenue recovery, configured per profile
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryRetryStrategy {
+    /// Maximum number of retries attempted for an invoice
+    pub max_retry_attempts: u16,
+    /// Interval between consecutive retries, the last interval is repeated for the remaining retries
+    pub retry_intervals_in_mins: Vec<u32>,
+    /// Error categories of the global status mapping for which the invoice is not retried any further
+    #[serde(default)]
+    #[schema(value_type = Vec<ErrorCategory>)]
+    pub hard_decline_error_categories: Vec<enums::ErrorCategory>,
+    /// Limits imposed by card networks on the number of attempts made on the same card
+    #[serde(default)]
+    pub card_network_retry_limits: Vec<CardNetworkRetryLimit>,
+    /// Shifts retries towards the days of the month on which customers are likely to have funds
+    pub payday_alignment: Option<PaydayAlignment>,
+}
+
+impl Default for RevenueRecoveryRetryStrategy {
+    fn default() -> Self {
+        Self {
+            max_retry_attempts: 8,
+            retry_intervals_in_mins: vec![60, 360, 1440, 2880, 4320],
+            hard_decline_error_categories: vec![
+                enums::ErrorCategory::FrmDecline,
+                enums::ErrorCategory::IssueWithPaymentMethod,
+            ],
+            card_network_retry_limits: vec![
+                CardNetworkRetryLimit {
+                    card_network: enums::CardNetwork::Visa,
+                    max_retry_attempts: 15,
+                    window_in_days: 30,
+                },
+                CardNetworkRetryLimit {
+                    card_network: enums::CardNetwork::Mastercard,
+                    max_retry_attempts: 35,
+                    window_in_days: 30,
+                },
+            ],
+            payday_alignment: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CardNetworkRetryLimit {
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: enums::CardNetwork,
+    /// Maximum number of attempts allowed on a card within the window
+    pub max_retry_attempts: u16,
+    /// Rolling window over which the attempts are counted
+    pub window_in_days: u16,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaydayAlignment {
+    /// Days of the month on which retries are preferred, days beyond the length of a month match its last day
+    pub days_of_month: Vec<u8>,
+    /// Maximum number of days a retry can be postponed to land on a preferred day
+    pub max_shift_in_days: u8,
+    /// Hour of the day (UTC) at which retries are made on a preferred day
+    pub hour_of_day: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RevenueRecoveryRetryTimelineResponse {
+    #[schema(value_type = String)]
+    pub id: id_type::GlobalPaymentId,
+    #[schema(value_type = RevenueRecoveryAlgorithmType, example = "smart")]
+    pub retry_algorithm: enums::RevenueRecoveryAlgorithmType,
+    /// Number of retries already made for the invoice
+    pub retry_count: i32,
+    /// Whether the last failure of the invoice was a hard decline, in which case no retries are planned
+    pub is_hard_decline: bool,
+    pub planned_retries: Vec<PlannedRetry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlannedRetry {
+    pub retry_count: i32,
+    pub scheduled_at: PrimitiveDateTime,
+}
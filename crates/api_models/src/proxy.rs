@@ -45,6 +45,10 @@ pub struct ProxyRequest {
     /// The type of token that is used to fetch sensitive data from the vault
     #[schema(value_type = TokenType, example = "payment_method_id")]
     pub token_type: TokenType,
+    /// The proxy route of the profile whose rules are to be applied to the request. If not
+    /// provided, `{{$field}}` references in the request body are substituted with vault data
+    #[schema(example = "card_charge")]
+    pub route_id: Option<String>,
 }
 
 #[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
@@ -65,5 +69,105 @@ pub struct ProxyResponse {
     pub response_headers: Headers,
 }
 
+/// Declarative rules for forwarding requests of a profile to a destination
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyRoute {
+    /// Unique identifier of the route within the profile
+    #[schema(example = "card_charge")]
+    pub route_id: String,
+    /// The hosts to which requests can be forwarded over `https` using this route. A host can be
+    /// followed by a port (`api.example.com:8443`), otherwise the default `https` port is required
+    #[schema(example = json!(["api.example.com"]))]
+    pub allowed_hosts: Vec<String>,
+    /// The format of the request body forwarded to the destination
+    #[serde(default)]
+    pub request_format: ProxyBodyFormat,
+    /// The fields of the request body to be populated with data from the vault
+    pub detokenize: Vec<ProxyDetokenizationRule>,
+    /// The fields of the response body to be stored in the vault and replaced with tokens, before
+    /// the response is returned
+    #[serde(default)]
+    pub tokenize_response: Vec<ProxyTokenizationRule>,
+}
+
+#[derive(Debug, Default, ToSchema, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyBodyFormat {
+    #[default]
+    Json,
+    FormUrlEncoded,
+    Xml,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyDetokenizationRule {
+    /// The location of the field in the request body. This is a JSONPath (`$.card.number`) for
+    /// JSON bodies, the field name for form bodies and an XPath (`/payment/card/number`) for XML
+    /// bodies
+    #[schema(example = "$.source.card.number")]
+    pub path: String,
+    /// The data from the vault to be populated in the field
+    pub data: ProxyDetokenizedData,
+}
+
+#[derive(Debug, ToSchema, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProxyDetokenizedData {
+    /// The card number
+    CardNumber,
+    /// The two digit card expiry month
+    CardExpiryMonth,
+    /// The four digit card expiry year
+    CardExpiryYear,
+    /// The card expiry date in the `MM/YY` format
+    CardExpiryDate,
+    /// The name of the card holder
+    CardHolderName,
+    /// The network token of the card
+    NetworkToken,
+    /// The two digit network token expiry month
+    NetworkTokenExpiryMonth,
+    /// The four digit network token expiry year
+    NetworkTokenExpiryYear,
+    /// A cryptogram generated by the token service for the network token
+    NetworkTokenCryptogram,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyTokenizationRule {
+    /// The JSONPath of the field in the response body to be tokenized
+    #[schema(example = "$.card.number")]
+    pub path: String,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProxyRoutesRequest {
+    /// The proxy routes of the profile. This replaces any routes configured earlier
+    pub routes: Vec<ProxyRoute>,
+}
+
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct ProxyRoutesResponse {
+    /// The identifier of the profile
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The proxy routes of the profile
+    pub routes: Vec<ProxyRoute>,
+}
+
 impl common_utils::events::ApiEventMetric for ProxyRequest {}
 impl common_utils::events::ApiEventMetric for ProxyResponse {}
+impl common_utils::events::ApiEventMetric for ProxyRoutesRequest {}
+
+impl common_utils::events::ApiEventMetric for ProxyRoutesResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -1,5 +1,6 @@
-use common_utils::{id_type, pii};
+use common_utils::{id_type, pii, types::MinorUnit};
 use masking::Secret;
+use time::PrimitiveDateTime;
 
 use crate::enums;
 
@@ -26,3 +27,197 @@ pub struct VerifyTokenResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acl: Option<String>,
 }
+
+/// Type of transaction a settlement line refers to
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettlementTransactionType {
+    Payment,
+    Refund,
+}
+
+/// A single line of a connector settlement file
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SettlementRecord {
+    /// Reference of the payment or refund at the connector
+    pub connector_transaction_id: String,
+    pub transaction_type: SettlementTransactionType,
+    /// Gross amount settled by the connector
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    /// Fee withheld by the connector for this transaction
+    pub fee: Option<MinorUnit>,
+    pub settlement_date: Option<String>,
+    /// Reference of the bank transfer in which the connector paid out this transaction
+    #[serde(default)]
+    pub payout_reference: Option<String>,
+    #[serde(skip_deserializing)]
+    pub line_number: Option<u64>,
+}
+
+/// A single line of a bank statement, crediting a connector payout to the merchant's account
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct BankStatementRecord {
+    /// Reference of the bank transfer, as found in the `payout_reference` of settlement lines
+    pub payout_reference: String,
+    /// Amount credited to the merchant's bank account
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub value_date: Option<String>,
+    #[serde(skip_deserializing)]
+    pub line_number: Option<u64>,
+}
+
+/// Outcome of reconciling a settlement line against Hyperswitch records
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconRecordStatus {
+    /// Settlement line agrees with the payment attempt or refund
+    Matched,
+    /// No payment attempt or refund exists for the connector transaction id
+    Missing,
+    /// Payment succeeded in Hyperswitch during the settlement period, but is not part of the
+    /// settlement file
+    MissingInSettlement,
+    /// Settled amount differs from the amount recorded in Hyperswitch
+    AmountMismatch,
+    /// Settled currency differs from the currency recorded in Hyperswitch
+    CurrencyMismatch,
+    /// Fee withheld by the connector differs from the expected fee
+    FeeMismatch,
+    /// Connector transaction id appears more than once in the settlement file
+    Duplicate,
+}
+
+/// Expected connector pricing, used to detect fee differences
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReconFeeConfig {
+    /// Fixed fee charged per transaction
+    #[serde(default)]
+    pub fixed_fee: MinorUnit,
+    /// Percentage fee charged per transaction, in basis points
+    #[serde(default)]
+    pub fee_basis_points: u32,
+    /// Maximum absolute difference allowed between expected and settled fee
+    #[serde(default)]
+    pub tolerance: MinorUnit,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconSettlementFileUploadResponse {
+    pub connector: enums::Connector,
+    pub file_name: String,
+}
+
+/// Outcome of reconciling a connector payout against the merchant's bank statement
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BankReconStatus {
+    /// Amount credited by the bank agrees with the net amount of the settlement lines
+    Matched,
+    /// Amount credited by the bank differs from the net amount of the settlement lines
+    AmountMismatch,
+    /// Settlement lines refer to a payout which was not credited by the bank
+    MissingInBankStatement,
+    /// Bank credited a payout which none of the settlement lines refer to
+    MissingInSettlement,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconBankStatementUploadResponse {
+    pub file_name: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReconSettlementRunRequest {
+    /// Connector which generated the settlement file
+    pub connector: enums::Connector,
+    /// Name of the settlement file in the connector's drop folder
+    pub file_name: String,
+    pub fee_config: Option<ReconFeeConfig>,
+    /// Period covered by the settlement file. When provided, payments captured through the
+    /// connector in this period which are not part of the settlement file are reported as well.
+    pub settlement_period: Option<common_utils::types::TimeRange>,
+    /// Name of an uploaded bank statement, used to reconcile connector payouts against the
+    /// amounts credited by the bank
+    pub bank_statement_file_name: Option<String>,
+}
+
+/// Lifecycle of a settlement recon run
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconRunStatus {
+    Scheduled,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconSettlementRunResponse {
+    pub recon_id: String,
+    pub status: ReconRunStatus,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconSettlementReportResponse {
+    pub recon_id: String,
+    pub status: ReconRunStatus,
+    /// Report of the run, available once the run is completed
+    pub report: Option<ReconSettlementReport>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReconRecordResult {
+    pub line_number: Option<u64>,
+    pub connector_transaction_id: String,
+    pub transaction_type: SettlementTransactionType,
+    pub status: ReconRecordStatus,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub currency: enums::Currency,
+    pub settled_amount: Option<MinorUnit>,
+    pub expected_amount: Option<MinorUnit>,
+    pub settled_fee: Option<MinorUnit>,
+    pub expected_fee: Option<MinorUnit>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct BankReconResult {
+    pub payout_reference: String,
+    pub currency: enums::Currency,
+    pub status: BankReconStatus,
+    /// Net amount of the settlement lines paid out in this transfer
+    pub expected_amount: Option<MinorUnit>,
+    /// Amount credited by the bank
+    pub credited_amount: Option<MinorUnit>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ReconSummary {
+    pub total_records: usize,
+    pub matched: usize,
+    pub missing: usize,
+    pub missing_in_settlement: usize,
+    pub amount_mismatch: usize,
+    pub currency_mismatch: usize,
+    pub fee_mismatch: usize,
+    pub duplicate: usize,
+    pub bank_matched: usize,
+    pub bank_mismatch: usize,
+    pub bank_missing: usize,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReconSettlementReport {
+    pub recon_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub connector: enums::Connector,
+    pub file_name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub summary: ReconSummary,
+    pub records: Vec<ReconRecordResult>,
+    #[serde(default)]
+    pub bank_records: Vec<BankReconResult>,
+}
@@ -45,6 +45,10 @@ pub struct RefundRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
+    /// Set to `true` when the payment is refunded because it was fraudulent. The refund is then reported to the fraud check connector that screened the payment
+    #[schema(example = false)]
+    pub is_fraudulent: Option<bool>,
+
     /// To indicate whether to refund needs to be instant or scheduled. Default value is instant
     #[schema(default = "Instant", example = "Instant")]
     pub refund_type: Option<RefundType>,
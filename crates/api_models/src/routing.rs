@@ -137,6 +137,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
+        DirKeyKind::DestinationCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
         DirKeyKind::MetaData,
@@ -1239,3 +1240,69 @@ impl RoutableConnectorChoiceWithBucketName {
         }
     }
 }
+
+/// Payment context in which the eligibility of the connectors of a profile is explained
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodEligibilityRequest {
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub billing_country: Option<common_enums::CountryAlpha2>,
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<common_enums::CardNetwork>,
+    /// Payment methods to be explained, all the payment methods enabled on the connectors are
+    /// explained if not provided
+    pub payment_methods: Option<Vec<PaymentMethodTypeChoice>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PaymentMethodTypeChoice {
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: common_enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: common_enums::PaymentMethodType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMethodEligibilityWrapper {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: PaymentMethodEligibilityRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaymentMethodEligibilityResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub eligibility: Vec<ConnectorPaymentMethodEligibility>,
+}
+
+/// Eligibility of a connector for a payment method in the payment context
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorPaymentMethodEligibility {
+    pub connector: RoutableConnectors,
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: common_enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: common_enums::PaymentMethodType,
+    pub is_eligible: bool,
+    /// Tree of the constraints which eliminated the connector for the payment method, present
+    /// only when the connector is not eligible
+    pub explanation: Option<EligibilityExplanation>,
+}
+
+/// Explanation of why a constraint on the connector could not be satisfied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EligibilityExplanation {
+    /// Description of the constraint which could not be satisfied, if the configuration provides
+    /// one
+    pub constraint: Option<String>,
+    /// Why the constraint was not satisfied
+    pub reason: String,
+    /// The constraints which in turn caused this one to be unsatisfied
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<EligibilityExplanation>)]
+    pub causes: Vec<Self>,
+}
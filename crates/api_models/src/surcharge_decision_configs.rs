@@ -14,13 +14,129 @@ use serde::{Deserialize, Serialize};
 pub struct SurchargeDetailsOutput {
     pub surcharge: SurchargeOutput,
     pub tax_on_surcharge: Option<Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>>,
+    /// The calculated surcharge amount is raised to this amount if it falls below it
+    pub min_surcharge_amount: Option<MinorUnit>,
+    /// The calculated surcharge amount is capped at this amount
+    pub max_surcharge_amount: Option<MinorUnit>,
+}
+
+impl SurchargeDetailsOutput {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let is_negative = |amount: &MinorUnit| amount.get_amount_as_i64() < 0;
+
+        if self.min_surcharge_amount.as_ref().is_some_and(is_negative)
+            || self.max_surcharge_amount.as_ref().is_some_and(is_negative)
+        {
+            return Err("Surcharge bounds cannot be negative");
+        }
+
+        if let (Some(min_surcharge_amount), Some(max_surcharge_amount)) =
+            (self.min_surcharge_amount, self.max_surcharge_amount)
+        {
+            if min_surcharge_amount > max_surcharge_amount {
+                return Err("min_surcharge_amount cannot be greater than max_surcharge_amount");
+            }
+        }
+
+        let fixed_amounts = match &self.surcharge {
+            SurchargeOutput::Fixed { amount } | SurchargeOutput::FixedAndRate { amount, .. } => {
+                vec![*amount]
+            }
+            SurchargeOutput::Rate(_) => Vec::new(),
+            SurchargeOutput::Tiered(tiers) => {
+                validate_surcharge_tiers(tiers)?;
+                tiers.iter().filter_map(|tier| tier.amount).collect()
+            }
+        };
+
+        if fixed_amounts.iter().any(is_negative) {
+            return Err("Surcharge amount cannot be negative");
+        }
+
+        if let Some(max_surcharge_amount) = self.max_surcharge_amount {
+            if fixed_amounts
+                .iter()
+                .any(|amount| *amount > max_surcharge_amount)
+            {
+                return Err("Fixed surcharge amount cannot be greater than max_surcharge_amount");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_surcharge_tiers(tiers: &[SurchargeTier]) -> Result<(), &'static str> {
+    if tiers.is_empty() {
+        return Err("Tiered surcharge must have at least one tier");
+    }
+
+    for tier in tiers {
+        if tier.min_amount.get_amount_as_i64() < 0 {
+            return Err("Surcharge tier min_amount cannot be negative");
+        }
+        if tier
+            .max_amount
+            .is_some_and(|max_amount| max_amount <= tier.min_amount)
+        {
+            return Err("Surcharge tier max_amount must be greater than its min_amount");
+        }
+        if tier.amount.is_none() && tier.percentage.is_none() {
+            return Err("Surcharge tier must have an amount, a percentage or both");
+        }
+    }
+
+    // Tiers have to be in ascending order of amount without overlapping each other, which also
+    // means that only the last tier can be unbounded
+    for (tier, next_tier) in tiers.iter().zip(tiers.iter().skip(1)) {
+        if tier
+            .max_amount
+            .map_or(true, |max_amount| max_amount > next_tier.min_amount)
+        {
+            return Err("Surcharge tiers must be in ascending order and must not overlap");
+        }
+    }
+
+    Ok(())
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type", content = "value")]
 pub enum SurchargeOutput {
-    Fixed { amount: MinorUnit },
+    Fixed {
+        amount: MinorUnit,
+    },
     Rate(Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>),
+    /// A fixed amount in addition to a percentage of the payment amount
+    FixedAndRate {
+        amount: MinorUnit,
+        percentage: Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>,
+    },
+    /// Surcharge decided by the band in which the payment amount falls.
+    /// No surcharge is applied when the payment amount does not fall in any of the tiers
+    Tiered(Vec<SurchargeTier>),
+}
+
+/// A band of payment amounts along with the surcharge to be applied for it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SurchargeTier {
+    /// Lower bound of the band (inclusive)
+    pub min_amount: MinorUnit,
+    /// Upper bound of the band (exclusive), the band is unbounded if not provided
+    pub max_amount: Option<MinorUnit>,
+    /// Fixed surcharge amount for the band
+    pub amount: Option<MinorUnit>,
+    /// Surcharge percentage for the band, applied in addition to the fixed amount
+    pub percentage: Option<Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>>,
+}
+
+impl SurchargeTier {
+    pub fn contains(&self, amount: MinorUnit) -> bool {
+        amount >= self.min_amount
+            && self
+                .max_amount
+                .map_or(true, |max_amount| amount < max_amount)
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -79,3 +195,52 @@ pub struct MerchantSurchargeConfigs {
 }
 
 pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(min_amount: i64, max_amount: Option<i64>, amount: i64) -> SurchargeTier {
+        SurchargeTier {
+            min_amount: MinorUnit::new(min_amount),
+            max_amount: max_amount.map(MinorUnit::new),
+            amount: Some(MinorUnit::new(amount)),
+            percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_surcharge_details_output_validation() {
+        let mut surcharge_details = SurchargeDetailsOutput {
+            surcharge: SurchargeOutput::Tiered(vec![
+                tier(0, Some(1000), 50),
+                tier(1000, None, 100),
+            ]),
+            tax_on_surcharge: None,
+            min_surcharge_amount: None,
+            max_surcharge_amount: Some(MinorUnit::new(100)),
+        };
+        assert_eq!(surcharge_details.validate(), Ok(()));
+
+        surcharge_details.surcharge =
+            SurchargeOutput::Tiered(vec![tier(0, None, 50), tier(1000, None, 100)]);
+        assert_eq!(
+            surcharge_details.validate(),
+            Err("Surcharge tiers must be in ascending order and must not overlap")
+        );
+
+        surcharge_details.surcharge = SurchargeOutput::Fixed {
+            amount: MinorUnit::new(150),
+        };
+        assert_eq!(
+            surcharge_details.validate(),
+            Err("Fixed surcharge amount cannot be greater than max_surcharge_amount")
+        );
+
+        surcharge_details.min_surcharge_amount = Some(MinorUnit::new(200));
+        assert_eq!(
+            surcharge_details.validate(),
+            Err("min_surcharge_amount cannot be greater than max_surcharge_amount")
+        );
+    }
+}
@@ -8,6 +8,7 @@ use crate::user_role::UserStatus;
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 #[cfg(feature = "control_center_theme")]
 pub mod theme;
 
@@ -241,6 +242,7 @@ pub struct TokenResponse {
 pub struct TwoFactorAuthStatusResponse {
     pub totp: bool,
     pub recovery_code: bool,
+    pub passkey: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -253,6 +255,7 @@ pub struct TwoFactorAuthAttempts {
 pub struct TwoFactorAuthStatusResponseWithAttempts {
     pub totp: TwoFactorAuthAttempts,
     pub recovery_code: TwoFactorAuthAttempts,
+    pub passkey: TwoFactorAuthAttempts,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -292,6 +295,50 @@ pub struct RecoveryCodes {
     pub recovery_codes: Vec<Secret<String>>,
 }
 
+/// Options to be passed as-is to `navigator.credentials.create()` or `navigator.credentials.get()`
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyOptionsResponse {
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FinishPasskeyRegistrationRequest {
+    pub name: Option<String>,
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct VerifyPasskeyRequest {
+    pub credential: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyResponse {
+    pub passkey_id: String,
+    pub name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct BeginPasskeySignInRequest {
+    pub email: pii::Email,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct BeginPasskeySignInResponse {
+    pub challenge_id: String,
+    pub options: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInRequest {
+    pub challenge_id: String,
+    pub credential: serde_json::Value,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
 #[serde(rename_all = "snake_case")]
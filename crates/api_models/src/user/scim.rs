@@ -0,0 +1,164 @@
+use common_utils::{id_type, pii};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const SCIM_GROUP_ROLE_EXTENSION_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:extension:hyperswitch:2.0:Group";
+
+#[derive(Debug, serde::Serialize)]
+pub struct ScimTokenResponse {
+    pub org_id: id_type::OrganizationId,
+    pub token: Secret<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    pub formatted: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimEmail {
+    pub value: pii::Email,
+    pub primary: Option<bool>,
+    #[serde(rename = "type")]
+    pub email_type: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+    pub user_name: pii::Email,
+    pub external_id: Option<String>,
+    pub name: Option<ScimName>,
+    pub display_name: Option<String>,
+    pub emails: Option<Vec<ScimEmail>>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub external_id: Option<String>,
+    pub user_name: pii::Email,
+    pub name: ScimName,
+    pub display_name: String,
+    pub emails: Vec<ScimEmail>,
+    pub active: bool,
+    pub groups: Vec<ScimMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimMember {
+    pub value: String,
+    pub display: Option<String>,
+}
+
+/// Maps a SCIM group to a Hyperswitch role at the organization, merchant or profile level
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupRoleMapping {
+    pub role_id: String,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupRequest {
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub members: Option<Vec<ScimMember>>,
+    #[serde(rename = "urn:ietf:params:scim:schemas:extension:hyperswitch:2.0:Group")]
+    pub role_mapping: ScimGroupRoleMapping,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResponse {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub external_id: Option<String>,
+    pub display_name: String,
+    pub members: Vec<ScimMember>,
+    #[serde(rename = "urn:ietf:params:scim:schemas:extension:hyperswitch:2.0:Group")]
+    pub role_mapping: ScimGroupRoleMapping,
+    pub meta: ScimMeta,
+}
+
+/// SCIM group as persisted for an organization, membership is derived from user roles
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ScimGroup {
+    pub group_id: String,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub role_mapping: ScimGroupRoleMapping,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    pub filter: Option<String>,
+    pub start_index: Option<u32>,
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse<T> {
+    pub schemas: Vec<String>,
+    pub total_results: usize,
+    pub start_index: u32,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<T>,
+}
+
+pub type ScimUserListResponse = ScimListResponse<ScimUserResponse>;
+pub type ScimGroupListResponse = ScimListResponse<ScimGroupResponse>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScimPatchOp {
+    #[serde(alias = "Add")]
+    Add,
+    #[serde(alias = "Remove")]
+    Remove,
+    #[serde(alias = "Replace")]
+    Replace,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ScimPatchOperation {
+    pub op: ScimPatchOp,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ScimPatchRequest {
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
@@ -159,6 +159,11 @@ pub struct OutgoingWebhookRequestContent {
         example = json!([["content-type", "application/json"], ["content-length", "1024"]]))
     ]
     pub headers: Vec<(String, Secret<String>)>,
+
+    /// The profile webhook endpoint to which the webhook was sent. Not present for webhooks sent
+    /// to the profile's `webhook_url`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint_id: Option<String>,
 }
 
 /// The response information (headers, body and status code) received for the webhook sent.
@@ -226,3 +231,118 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for resending all webhook events of a business profile within a time range.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEventsBulkResendRequest {
+    /// The business profile whose events are to be resent.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Resend events created after the specified time.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Resend events created before the specified time. Defaults to the current time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Resend only events of the specified types.
+    pub event_types: Option<HashSet<EventType>>,
+
+    /// Resend only events whose `is_overall_delivery_successful` field matches the specified
+    /// value.
+    pub is_delivered: Option<bool>,
+}
+
+/// The status of a bulk resend job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventsBulkResendStatus {
+    /// The job is scheduled and is yet to resend any events.
+    Scheduled,
+    /// The job has resent some of the events, the remaining events will be resent in subsequent
+    /// runs.
+    InProgress,
+    /// All events matching the constraints have been resent.
+    Completed,
+    /// The job was aborted before all events could be resent.
+    Failed,
+}
+
+/// The response body for a bulk resend job.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEventsBulkResendResponse {
+    /// The identifier for the bulk resend job.
+    #[schema(example = "bulk_resend_0196f3d0b2a47d91a2c5e1f0c3b9d8e7")]
+    pub batch_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The status of the job.
+    pub status: WebhookEventsBulkResendStatus,
+
+    /// The number of events matching the constraints when the job was created.
+    pub total_count: i64,
+
+    /// The number of events resent so far.
+    pub processed_count: i64,
+
+    /// The number of events that were delivered successfully on being resent.
+    pub succeeded_count: i64,
+
+    /// The number of events that could not be delivered on being resent.
+    pub failed_count: i64,
+
+    /// The identifiers of the initial delivery attempts of the events that could not be
+    /// delivered on being resent. Only the first 100 such events are included.
+    pub failed_event_ids: Vec<String>,
+
+    /// Time at which the job was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventsBulkResendResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventsBulkResendRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookEventsBulkResendRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventsBulkResendRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEventsBulkResendRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEventsBulkResendRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
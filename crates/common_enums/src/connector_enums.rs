@@ -250,8 +250,10 @@ pub enum Connector {
     Globalpay,
     Globepay,
     Gocardless,
+    GocardlessBankData,
     Gpayments,
     Hipay,
+    HyperswitchFrm,
     Helcim,
     Inespay,
     Iatapay,
@@ -297,6 +299,7 @@ pub enum Connector {
     Threedsecureio,
     // Tokenio,
     //Thunes,
+    Truelayer,
     Trustpay,
     Tsys,
     // UnifiedAuthenticationService,
@@ -475,8 +478,11 @@ impl Connector {
             | Self::Zsl
             | Self::Signifyd
             | Self::Plaid
+            | Self::Truelayer
+            | Self::GocardlessBankData
             | Self::Razorpay
             | Self::Riskified
+            | Self::HyperswitchFrm
             | Self::Threedsecureio
             | Self::Netcetera
             | Self::CtpMastercard
@@ -753,6 +759,9 @@ impl TryFrom<Connector> for RoutableConnectors {
             | Connector::Netcetera
             | Connector::Taxjar
             | Connector::Threedsecureio
+            | Connector::Truelayer
+            | Connector::GocardlessBankData
+            | Connector::HyperswitchFrm
             | Connector::Vgs
             | Connector::CtpVisa => Err("Invalid conversion. Not a routable connector"),
         }
@@ -289,6 +289,29 @@ pub enum FraudCheckStatus {
     TransactionFailure,
 }
 
+/// Fraud outcome learnt about a payment after it was scored by the frm connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmFeedbackType {
+    /// The customer raised a dispute against the payment
+    Chargeback,
+    /// The merchant refunded the payment because it was fraudulent
+    FraudulentRefund,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -1413,6 +1436,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    PaymentMethods,
 }
 
 impl EventClass {
@@ -1449,6 +1473,7 @@ impl EventClass {
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
             ]),
+            Self::PaymentMethods => HashSet::from([EventType::PaymentMethodUpdated]),
         }
     }
 }
@@ -1505,6 +1530,7 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    PaymentMethodUpdated,
 }
 
 #[derive(
@@ -2114,6 +2140,7 @@ pub enum PaymentType {
     Debug,
     Default,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
@@ -2128,6 +2155,8 @@ pub enum ScaExemptionType {
     #[default]
     LowValue,
     TransactionRiskAnalysis,
+    TrustedBeneficiary,
+    SecureCorporatePayment,
 }
 
 #[derive(
@@ -8293,6 +8322,12 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    WebhookEventsBulkResendWorkflow,
+    PayoutBatchWorkflow,
+    PaymentMethodMigrationBatchWorkflow,
+    CardVaultExportWorkflow,
+    ReconSettlementWorkflow,
+    FrmFeedbackWorkflow,
 }
 
 #[derive(Debug)]
@@ -8335,3 +8370,64 @@ pub enum TokenDataType {
     /// Fetch network token for the given payment method
     NetworkToken,
 }
+
+/// Actions that can be placed behind a maker-checker approval policy
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApprovalAction {
+    /// Creating a refund, optionally only above an amount threshold
+    RefundCreate,
+    /// Updating the credentials of a merchant connector account
+    ConnectorCredentialsUpdate,
+    /// Creating a new API key
+    ApiKeyCreate,
+    /// Updating an API key, such as extending its expiry
+    ApiKeyUpdate,
+    /// Exporting the cards saved in the locker to another provider, this always requires approval
+    CardVaultExport,
+}
+
+/// Lifecycle of a maker-checker approval request
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApprovalRequestStatus {
+    /// Waiting for a reviewer
+    #[default]
+    Pending,
+    /// Approved by a reviewer and the action was executed
+    Approved,
+    /// Rejected by a reviewer, the action was not executed
+    Rejected,
+    /// Approved by a reviewer but the action failed while executing
+    Failed,
+    /// Not reviewed within the expiry period, the action was not executed
+    Expired,
+}
@@ -127,6 +127,11 @@ impl MerchantId {
         format!("fingerprint_secret_{}", self.get_string_repr())
     }
 
+    /// get_approval_policy_key
+    pub fn get_approval_policy_key(&self) -> String {
+        format!("approval_policy_merchant_{}", self.get_string_repr())
+    }
+
     /// get_surcharge_dsk_key
     pub fn get_surcharge_dsk_key(&self) -> String {
         format!("surcharge_dsl_{}", self.get_string_repr())
@@ -22,3 +22,21 @@ impl OrganizationId {
         Self::try_from(std::borrow::Cow::from(org_id))
     }
 }
+
+/// All the keys that can be formed from organization id
+impl OrganizationId {
+    /// get_scim_token_key
+    pub fn get_scim_token_key(&self) -> String {
+        format!("scim_token_{}", self.get_string_repr())
+    }
+
+    /// get_scim_groups_key
+    pub fn get_scim_groups_key(&self) -> String {
+        format!("scim_groups_{}", self.get_string_repr())
+    }
+
+    /// get_approval_policy_key
+    pub fn get_approval_policy_key(&self) -> String {
+        format!("approval_policy_org_{}", self.get_string_repr())
+    }
+}
@@ -39,3 +39,11 @@ impl From<ProfileId> for router_env::opentelemetry::Value {
         Self::from(val.0 .0 .0)
     }
 }
+
+/// All the keys that can be formed from profile id
+impl ProfileId {
+    /// get_proxy_routes_key
+    pub fn get_proxy_routes_key(&self) -> String {
+        format!("proxy_routes_{}", self.get_string_repr())
+    }
+}
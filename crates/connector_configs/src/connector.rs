@@ -209,9 +209,11 @@ pub struct ConnectorConfig {
     pub globalpay: Option<ConnectorTomlConfig>,
     pub globepay: Option<ConnectorTomlConfig>,
     pub gocardless: Option<ConnectorTomlConfig>,
+    pub gocardless_bank_data: Option<ConnectorTomlConfig>,
     pub gpayments: Option<ConnectorTomlConfig>,
     pub hipay: Option<ConnectorTomlConfig>,
     pub helcim: Option<ConnectorTomlConfig>,
+    pub hyperswitch_frm: Option<ConnectorTomlConfig>,
     pub inespay: Option<ConnectorTomlConfig>,
     pub jpmorgan: Option<ConnectorTomlConfig>,
     pub klarna: Option<ConnectorTomlConfig>,
@@ -252,6 +254,7 @@ pub struct ConnectorConfig {
     pub stripebilling: Option<ConnectorTomlConfig>,
     pub signifyd: Option<ConnectorTomlConfig>,
     pub tokenio: Option<ConnectorTomlConfig>,
+    pub truelayer: Option<ConnectorTomlConfig>,
     pub trustpay: Option<ConnectorTomlConfig>,
     pub threedsecureio: Option<ConnectorTomlConfig>,
     pub netcetera: Option<ConnectorTomlConfig>,
@@ -343,6 +346,8 @@ impl ConnectorConfig {
         let connector_data = Self::new()?;
         match connector {
             PmAuthConnectors::Plaid => Ok(connector_data.plaid),
+            PmAuthConnectors::Truelayer => Ok(connector_data.truelayer),
+            PmAuthConnectors::GocardlessBankData => Ok(connector_data.gocardless_bank_data),
         }
     }
 
@@ -394,6 +399,7 @@ impl ConnectorConfig {
             Connector::Globalpay => Ok(connector_data.globalpay),
             Connector::Globepay => Ok(connector_data.globepay),
             Connector::Gocardless => Ok(connector_data.gocardless),
+            Connector::GocardlessBankData => Ok(connector_data.gocardless_bank_data),
             Connector::Gpayments => Ok(connector_data.gpayments),
             Connector::Hipay => Ok(connector_data.hipay),
             Connector::Helcim => Ok(connector_data.helcim),
@@ -427,12 +433,14 @@ impl ConnectorConfig {
             Connector::Recurly => Ok(connector_data.recurly),
             Connector::Redsys => Ok(connector_data.redsys),
             Connector::Riskified => Ok(connector_data.riskified),
+            Connector::HyperswitchFrm => Ok(connector_data.hyperswitch_frm),
             Connector::Shift4 => Ok(connector_data.shift4),
             Connector::Signifyd => Ok(connector_data.signifyd),
             Connector::Square => Ok(connector_data.square),
             Connector::Stax => Ok(connector_data.stax),
             Connector::Stripe => Ok(connector_data.stripe),
             Connector::Stripebilling => Ok(connector_data.stripebilling),
+            Connector::Truelayer => Ok(connector_data.truelayer),
             Connector::Trustpay => Ok(connector_data.trustpay),
             Connector::Threedsecureio => Ok(connector_data.threedsecureio),
             Connector::Taxjar => Ok(connector_data.taxjar),
@@ -2,3 +2,4 @@ pub mod common_config;
 pub mod connector;
 pub mod response_modifier;
 pub mod transformer;
+pub mod validation;
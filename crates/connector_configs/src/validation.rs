@@ -0,0 +1,250 @@
+use std::{fmt, str::FromStr};
+
+#[cfg(feature = "payouts")]
+use api_models::enums::PayoutConnectors;
+use api_models::enums::{
+    AuthenticationConnectors, Connector, PaymentMethod, PaymentMethodType, PmAuthConnectors,
+    TaxConnectors,
+};
+
+use crate::{
+    common_config::Provider,
+    connector::{ConnectorConfig, ConnectorTomlConfig},
+};
+
+/// Suffix of the keys holding the configuration of payout connectors
+#[cfg(feature = "payouts")]
+const PAYOUT_CONNECTOR_SUFFIX: &str = "_payout";
+
+/// Keys holding the configuration of the dummy connectors, which are only part of the
+/// [`Connector`] enum when the `dummy_connector` feature is enabled
+const DUMMY_CONNECTOR_KEYS: [&str; 3] = ["dummy_connector", "stripe_test", "paypal_test"];
+
+/// Problem found while validating a connector configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// The file is not valid TOML, or a field is missing or has an invalid value
+    InvalidConfig(String),
+    /// The key is not part of the connector configuration and would be ignored
+    UnknownKey(String),
+    /// The top level key does not correspond to any connector
+    UnknownConnector(String),
+    /// The payment method type is listed under a payment method it does not belong to
+    UnsupportedPaymentMethodType {
+        connector: String,
+        payment_method: PaymentMethod,
+        payment_method_type: PaymentMethodType,
+    },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidConfig(message) => write!(f, "invalid configuration: {message}"),
+            Self::UnknownKey(key) => write!(f, "unknown key `{key}`"),
+            Self::UnknownConnector(connector) => write!(f, "unknown connector `{connector}`"),
+            Self::UnsupportedPaymentMethodType {
+                connector,
+                payment_method,
+                payment_method_type,
+            } => write!(
+                f,
+                "`{connector}`: payment method type `{payment_method_type}` is not supported \
+                 for payment method `{payment_method}`"
+            ),
+        }
+    }
+}
+
+/// Validate the contents of a connector configuration file, such as `toml/development.toml`,
+/// returning all the issues found
+pub fn validate_connector_config(contents: &str) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    let parsed = serde_ignored::deserialize::<_, _, ConnectorConfig>(
+        toml::de::Deserializer::new(contents),
+        |path| issues.push(ValidationIssue::UnknownKey(format_path(&path))),
+    );
+    if let Err(error) = parsed {
+        issues.push(ValidationIssue::InvalidConfig(error.to_string()));
+        return issues;
+    }
+
+    let table = match toml::from_str::<toml::Table>(contents) {
+        Ok(table) => table,
+        Err(error) => {
+            issues.push(ValidationIssue::InvalidConfig(error.to_string()));
+            return issues;
+        }
+    };
+
+    for (connector, value) in table {
+        if !is_known_connector(&connector) {
+            issues.push(ValidationIssue::UnknownConnector(connector.clone()));
+        }
+
+        // Any deserialization errors have already been reported while parsing the whole file
+        let Ok(connector_config) = value.try_into::<ConnectorTomlConfig>() else {
+            continue;
+        };
+
+        for (payment_method, providers) in get_providers(connector_config) {
+            issues.extend(
+                providers
+                    .into_iter()
+                    .map(|provider| provider.payment_method_type)
+                    .filter(|payment_method_type| {
+                        !is_supported_payment_method_type(payment_method, *payment_method_type)
+                    })
+                    .map(
+                        |payment_method_type| ValidationIssue::UnsupportedPaymentMethodType {
+                            connector: connector.clone(),
+                            payment_method,
+                            payment_method_type,
+                        },
+                    ),
+            );
+        }
+    }
+
+    issues
+}
+
+fn is_known_connector(key: &str) -> bool {
+    #[cfg(feature = "payouts")]
+    if let Some(payout_connector) = key.strip_suffix(PAYOUT_CONNECTOR_SUFFIX) {
+        return PayoutConnectors::from_str(payout_connector).is_ok();
+    }
+
+    if DUMMY_CONNECTOR_KEYS.contains(&key) {
+        return true;
+    }
+
+    Connector::from_str(key).is_ok()
+        || AuthenticationConnectors::from_str(key).is_ok()
+        || PmAuthConnectors::from_str(key).is_ok()
+        || TaxConnectors::from_str(key).is_ok()
+}
+
+/// Format the path as a dotted key, leaving out the segments for options and newtypes which do
+/// not appear in the TOML file
+fn format_path(path: &serde_ignored::Path<'_>) -> String {
+    let (parent, segment) = match path {
+        serde_ignored::Path::Root => return String::new(),
+        serde_ignored::Path::Seq { parent, index } => (parent, index.to_string()),
+        serde_ignored::Path::Map { parent, key } => (parent, key.clone()),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => return format_path(parent),
+    };
+
+    match format_path(parent) {
+        parent if parent.is_empty() => segment,
+        parent => format!("{parent}.{segment}"),
+    }
+}
+
+fn get_providers(config: ConnectorTomlConfig) -> [(PaymentMethod, Vec<Provider>); 13] {
+    [
+        (PaymentMethod::BankTransfer, config.bank_transfer),
+        (PaymentMethod::BankRedirect, config.bank_redirect),
+        (PaymentMethod::BankDebit, config.bank_debit),
+        (PaymentMethod::OpenBanking, config.open_banking),
+        (PaymentMethod::PayLater, config.pay_later),
+        (PaymentMethod::Wallet, config.wallet),
+        (PaymentMethod::Crypto, config.crypto),
+        (PaymentMethod::Reward, config.reward),
+        (PaymentMethod::Upi, config.upi),
+        (PaymentMethod::Voucher, config.voucher),
+        (PaymentMethod::GiftCard, config.gift_card),
+        (PaymentMethod::CardRedirect, config.card_redirect),
+        (PaymentMethod::RealTimePayment, config.real_time_payment),
+    ]
+    .map(|(payment_method, providers)| (payment_method, providers.unwrap_or_default()))
+}
+
+fn is_supported_payment_method_type(
+    payment_method: PaymentMethod,
+    payment_method_type: PaymentMethodType,
+) -> bool {
+    // ACH, SEPA and BACS are supported both as bank debits and as bank transfers, and Swish both
+    // as a bank redirect and as a QR code wallet
+    PaymentMethod::from(payment_method_type) == payment_method
+        || matches!(
+            (payment_method, payment_method_type),
+            (
+                PaymentMethod::BankTransfer,
+                PaymentMethodType::Ach | PaymentMethodType::Sepa | PaymentMethodType::Bacs
+            ) | (PaymentMethod::Wallet, PaymentMethodType::Swish)
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_connector_configs_are_valid() {
+        for contents in [
+            include_str!("../toml/development.toml"),
+            include_str!("../toml/sandbox.toml"),
+            include_str!("../toml/production.toml"),
+        ] {
+            let issues = validate_connector_config(contents)
+                .into_iter()
+                .filter(|issue| !matches!(issue, ValidationIssue::UnknownKey(_)))
+                .collect::<Vec<_>>();
+
+            assert_eq!(issues, Vec::new());
+        }
+    }
+
+    #[test]
+    fn test_invalid_connector_config() {
+        let contents = r#"
+            [stripe]
+            unknown_field = true
+            [stripe.connector_auth.HeaderKey]
+            api_key = "Secret Key"
+            [[stripe.wallet]]
+            payment_method_type = "klarna"
+
+            [not_a_connector]
+            [not_a_connector.connector_auth.HeaderKey]
+            api_key = "Api Key"
+        "#;
+
+        let issues = validate_connector_config(contents);
+
+        assert!(issues.contains(&ValidationIssue::UnknownKey(
+            "stripe.unknown_field".to_string()
+        )));
+        assert!(issues.contains(&ValidationIssue::UnknownKey("not_a_connector".to_string())));
+        assert!(issues.contains(&ValidationIssue::UnknownConnector(
+            "not_a_connector".to_string()
+        )));
+        assert!(
+            issues.contains(&ValidationIssue::UnsupportedPaymentMethodType {
+                connector: "stripe".to_string(),
+                payment_method: PaymentMethod::Wallet,
+                payment_method_type: PaymentMethodType::Klarna,
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_required_field() {
+        let contents = r#"
+            [stripe]
+            [stripe.connector_auth.BodyKey]
+            api_key = "Api Key"
+        "#;
+
+        let issues = validate_connector_config(contents);
+
+        assert!(matches!(
+            issues.as_slice(),
+            [ValidationIssue::InvalidConfig(message)] if message.contains("key1")
+        ));
+    }
+}
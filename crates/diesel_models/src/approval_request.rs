@@ -0,0 +1,96 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::approval_requests};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = approval_requests, primary_key(approval_request_id), check_for_backend(diesel::pg::Pg))]
+pub struct ApprovalRequest {
+    pub approval_request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub action: storage_enums::ApprovalAction,
+    pub status: storage_enums::ApprovalRequestStatus,
+    pub resource_id: Option<String>,
+    pub payload: Encryption,
+    pub requested_by: String,
+    pub reviewed_by: Option<String>,
+    pub review_comment: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = approval_requests)]
+pub struct ApprovalRequestNew {
+    pub approval_request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub org_id: id_type::OrganizationId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub action: storage_enums::ApprovalAction,
+    pub status: storage_enums::ApprovalRequestStatus,
+    pub resource_id: Option<String>,
+    pub payload: Encryption,
+    pub requested_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = approval_requests)]
+pub struct ApprovalRequestUpdateInternal {
+    status: storage_enums::ApprovalRequestStatus,
+    reviewed_by: Option<String>,
+    review_comment: Option<String>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ApprovalRequestUpdate {
+    Review {
+        status: storage_enums::ApprovalRequestStatus,
+        reviewed_by: String,
+        review_comment: Option<String>,
+    },
+    ExecutionFailed {
+        error_message: String,
+    },
+    Expire,
+}
+
+impl From<ApprovalRequestUpdate> for ApprovalRequestUpdateInternal {
+    fn from(value: ApprovalRequestUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match value {
+            ApprovalRequestUpdate::Review {
+                status,
+                reviewed_by,
+                review_comment,
+            } => Self {
+                status,
+                reviewed_by: Some(reviewed_by),
+                review_comment,
+                error_message: None,
+                modified_at,
+            },
+            ApprovalRequestUpdate::ExecutionFailed { error_message } => Self {
+                status: storage_enums::ApprovalRequestStatus::Failed,
+                reviewed_by: None,
+                review_comment: None,
+                error_message: Some(error_message),
+                modified_at,
+            },
+            ApprovalRequestUpdate::Expire => Self {
+                status: storage_enums::ApprovalRequestStatus::Expired,
+                reviewed_by: None,
+                review_comment: None,
+                error_message: None,
+                modified_at,
+            },
+        }
+    }
+}
@@ -49,6 +49,8 @@ pub struct Authentication {
     pub acquirer_country_code: Option<String>,
     pub service_details: Option<serde_json::Value>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+    pub is_merchant_whitelisted: Option<bool>,
 }
 
 impl Authentication {
@@ -98,6 +100,8 @@ pub struct AuthenticationNew {
     pub acquirer_country_code: Option<String>,
     pub service_details: Option<serde_json::Value>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+    pub is_merchant_whitelisted: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -139,6 +143,8 @@ pub enum AuthenticationUpdate {
         connector_metadata: Option<serde_json::Value>,
         authentication_status: common_enums::AuthenticationStatus,
         ds_trans_id: Option<String>,
+        psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+        is_merchant_whitelisted: Option<bool>,
     },
     PostAuthenticationUpdate {
         trans_status: common_enums::TransactionStatus,
@@ -192,6 +198,8 @@ pub struct AuthenticationUpdateInternal {
     pub directory_server_id: Option<String>,
     pub acquirer_country_code: Option<String>,
     pub service_details: Option<serde_json::Value>,
+    pub psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+    pub is_merchant_whitelisted: Option<bool>,
 }
 
 impl Default for AuthenticationUpdateInternal {
@@ -225,6 +233,8 @@ impl Default for AuthenticationUpdateInternal {
             directory_server_id: Default::default(),
             acquirer_country_code: Default::default(),
             service_details: Default::default(),
+            psd2_sca_exemption_type: Default::default(),
+            is_merchant_whitelisted: Default::default(),
         }
     }
 }
@@ -260,6 +270,8 @@ impl AuthenticationUpdateInternal {
             directory_server_id,
             acquirer_country_code,
             service_details,
+            psd2_sca_exemption_type,
+            is_merchant_whitelisted,
         } = self;
         Authentication {
             connector_authentication_id: connector_authentication_id
@@ -294,6 +306,8 @@ impl AuthenticationUpdateInternal {
             directory_server_id: directory_server_id.or(source.directory_server_id),
             acquirer_country_code: acquirer_country_code.or(source.acquirer_country_code),
             service_details: service_details.or(source.service_details),
+            psd2_sca_exemption_type: psd2_sca_exemption_type.or(source.psd2_sca_exemption_type),
+            is_merchant_whitelisted: is_merchant_whitelisted.or(source.is_merchant_whitelisted),
             ..source
         }
     }
@@ -373,6 +387,8 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 connector_metadata,
                 authentication_status,
                 ds_trans_id,
+                psd2_sca_exemption_type,
+                is_merchant_whitelisted,
             } => Self {
                 trans_status: Some(trans_status),
                 authentication_type: Some(authentication_type),
@@ -384,6 +400,8 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 connector_metadata,
                 authentication_status: Some(authentication_status),
                 ds_trans_id,
+                psd2_sca_exemption_type,
+                is_merchant_whitelisted,
                 ..Default::default()
             },
             AuthenticationUpdate::PostAuthenticationUpdate {
@@ -59,6 +59,8 @@ pub struct Profile {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub always_request_extended_authorization:
         Option<primitive_wrappers::AlwaysRequestExtendedAuthorization>,
     pub is_click_to_pay_enabled: bool,
@@ -117,6 +119,8 @@ pub struct ProfileNew {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
     pub authentication_product_ids:
         Option<common_types::payments::AuthenticationConnectorAccountMap>,
@@ -169,6 +173,8 @@ pub struct ProfileUpdateInternal {
     pub is_network_tokenization_enabled: Option<bool>,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub always_request_extended_authorization:
         Option<primitive_wrappers::AlwaysRequestExtendedAuthorization>,
     pub is_click_to_pay_enabled: Option<bool>,
@@ -222,6 +228,8 @@ impl ProfileUpdateInternal {
             is_network_tokenization_enabled,
             is_auto_retries_enabled,
             max_auto_retries_enabled,
+            is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled,
             always_request_extended_authorization,
             is_click_to_pay_enabled,
             authentication_product_ids,
@@ -293,6 +301,10 @@ impl ProfileUpdateInternal {
                 .unwrap_or(source.is_network_tokenization_enabled),
             is_auto_retries_enabled: is_auto_retries_enabled.or(source.is_auto_retries_enabled),
             max_auto_retries_enabled: max_auto_retries_enabled.or(source.max_auto_retries_enabled),
+            is_payout_auto_retries_enabled: is_payout_auto_retries_enabled
+                .or(source.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: max_payout_auto_retries_enabled
+                .or(source.max_payout_auto_retries_enabled),
             always_request_extended_authorization: always_request_extended_authorization
                 .or(source.always_request_extended_authorization),
             is_click_to_pay_enabled: is_click_to_pay_enabled
@@ -362,6 +374,8 @@ pub struct Profile {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub always_request_extended_authorization:
         Option<primitive_wrappers::AlwaysRequestExtendedAuthorization>,
     pub is_click_to_pay_enabled: bool,
@@ -439,6 +453,8 @@ pub struct ProfileNew {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: bool,
     pub authentication_product_ids:
         Option<common_types::payments::AuthenticationConnectorAccountMap>,
@@ -497,6 +513,8 @@ pub struct ProfileUpdateInternal {
     pub is_network_tokenization_enabled: Option<bool>,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: Option<bool>,
     pub authentication_product_ids:
         Option<common_types::payments::AuthenticationConnectorAccountMap>,
@@ -560,6 +578,8 @@ impl ProfileUpdateInternal {
             is_network_tokenization_enabled,
             is_auto_retries_enabled,
             max_auto_retries_enabled,
+            is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled,
             is_click_to_pay_enabled,
             authentication_product_ids,
             three_ds_decision_manager_config,
@@ -637,6 +657,10 @@ impl ProfileUpdateInternal {
                 .unwrap_or(source.is_network_tokenization_enabled),
             is_auto_retries_enabled: is_auto_retries_enabled.or(source.is_auto_retries_enabled),
             max_auto_retries_enabled: max_auto_retries_enabled.or(source.max_auto_retries_enabled),
+            is_payout_auto_retries_enabled: is_payout_auto_retries_enabled
+                .or(source.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: max_payout_auto_retries_enabled
+                .or(source.max_payout_auto_retries_enabled),
             always_request_extended_authorization: None,
             is_click_to_pay_enabled: is_click_to_pay_enabled
                 .unwrap_or(source.is_click_to_pay_enabled),
@@ -675,6 +699,20 @@ pub struct AuthenticationConnectorDetails {
     pub authentication_connectors: Vec<AuthenticationConnectors>,
     pub three_ds_requestor_url: String,
     pub three_ds_requestor_app_url: Option<String>,
+    pub three_ds_exemption_policy: Option<ThreeDsExemptionPolicy>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ThreeDsExemptionPolicy {
+    pub currency: common_enums::Currency,
+    pub low_value_max_amount: Option<common_utils::types::MinorUnit>,
+    pub acquirer_fraud_rate: Option<f64>,
+    #[serde(default)]
+    pub trusted_beneficiary: bool,
+    #[serde(default)]
+    pub secure_corporate_payment: bool,
+    pub min_prior_authentications: Option<u32>,
+    pub excluded_issuer_countries: Option<Vec<common_enums::CountryAlpha2>>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(AuthenticationConnectorDetails);
@@ -730,10 +768,24 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub url: Secret<String>,
+    pub enabled_events: Option<Vec<common_enums::EventType>>,
+    /// Signing secrets are encrypted with the merchant's key
+    pub signing_secret: Encryption,
+    pub previous_signing_secret: Option<Encryption>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_signing_secret_expires_at: Option<time::PrimitiveDateTime>,
+    pub disabled: Option<bool>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct BusinessPaymentLinkConfig {
@@ -743,6 +795,8 @@ pub struct BusinessPaymentLinkConfig {
     pub business_specific_configs: Option<HashMap<String, PaymentLinkConfigRequest>>,
     pub allowed_domains: Option<HashSet<String>>,
     pub branding_visibility: Option<bool>,
+    pub template_id: Option<String>,
+    pub locale_bundles: Option<HashMap<String, HashMap<String, String>>>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
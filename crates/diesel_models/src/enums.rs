@@ -79,6 +79,7 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PaymentMethodDetails,
 }
 
 // Refund
@@ -27,6 +27,8 @@ pub struct FraudCheck {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    pub feedback_type: Option<storage_enums::FrmFeedbackType>,
+    pub dispute_status: Option<storage_enums::DisputeStatus>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -49,6 +51,8 @@ pub struct FraudCheckNew {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+    pub feedback_type: Option<storage_enums::FrmFeedbackType>,
+    pub dispute_status: Option<storage_enums::DisputeStatus>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +72,11 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    FeedbackUpdate {
+        feedback_type: Option<storage_enums::FrmFeedbackType>,
+        dispute_status: Option<storage_enums::DisputeStatus>,
+        last_step: FraudCheckLastStep,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -81,6 +90,8 @@ pub struct FraudCheckUpdateInternal {
     metadata: Option<serde_json::Value>,
     last_step: FraudCheckLastStep,
     payment_capture_method: Option<storage_enums::CaptureMethod>,
+    feedback_type: Option<storage_enums::FrmFeedbackType>,
+    dispute_status: Option<storage_enums::DisputeStatus>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -113,6 +124,16 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_error: error_message,
                 ..Default::default()
             },
+            FraudCheckUpdate::FeedbackUpdate {
+                feedback_type,
+                dispute_status,
+                last_step,
+            } => Self {
+                feedback_type,
+                dispute_status,
+                last_step,
+                ..Default::default()
+            },
         }
     }
 }
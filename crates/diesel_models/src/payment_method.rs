@@ -260,6 +260,8 @@ pub enum PaymentMethodUpdate {
         network_token_locker_id: Option<String>,
         network_token_payment_method_data: Option<Encryption>,
     },
+    /// Unlinks the network token from the payment method, the card itself remains usable
+    NetworkTokenDataClear,
     ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
         connector_mandate_details: Option<pii::SecretSerdeValue>,
         network_transaction_id: Option<Secret<String>>,
@@ -402,15 +404,15 @@ pub struct PaymentMethodUpdateInternal {
     network_transaction_id: Option<String>,
     status: Option<storage_enums::PaymentMethodStatus>,
     locker_id: Option<String>,
-    network_token_requestor_reference_id: Option<String>,
+    network_token_requestor_reference_id: Option<Option<String>>,
     payment_method: Option<storage_enums::PaymentMethod>,
     connector_mandate_details: Option<serde_json::Value>,
     updated_by: Option<String>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
     last_modified: PrimitiveDateTime,
-    network_token_locker_id: Option<String>,
-    network_token_payment_method_data: Option<Encryption>,
+    network_token_locker_id: Option<Option<String>>,
+    network_token_payment_method_data: Option<Option<Encryption>>,
     scheme: Option<String>,
 }
 
@@ -473,10 +475,11 @@ impl PaymentMethodUpdateInternal {
             updated_by: updated_by.or(source.updated_by),
             version: source.version,
             network_token_requestor_reference_id: network_token_requestor_reference_id
-                .or(source.network_token_requestor_reference_id),
-            network_token_locker_id: network_token_locker_id.or(source.network_token_locker_id),
+                .unwrap_or(source.network_token_requestor_reference_id),
+            network_token_locker_id: network_token_locker_id
+                .unwrap_or(source.network_token_locker_id),
             network_token_payment_method_data: network_token_payment_method_data
-                .or(source.network_token_payment_method_data),
+                .unwrap_or(source.network_token_payment_method_data),
         }
     }
 }
@@ -625,15 +628,16 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_transaction_id: None,
                 status,
                 locker_id,
-                network_token_requestor_reference_id,
+                network_token_requestor_reference_id: network_token_requestor_reference_id
+                    .map(Some),
                 payment_method,
                 connector_mandate_details: None,
                 updated_by: None,
                 payment_method_issuer,
                 payment_method_type,
                 last_modified: common_utils::date_time::now(),
-                network_token_locker_id,
-                network_token_payment_method_data,
+                network_token_locker_id: network_token_locker_id.map(Some),
+                network_token_payment_method_data: network_token_payment_method_data.map(Some),
                 scheme: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
@@ -673,9 +677,28 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
                 network_transaction_id: None,
-                network_token_requestor_reference_id,
-                network_token_locker_id,
-                network_token_payment_method_data,
+                network_token_requestor_reference_id: network_token_requestor_reference_id
+                    .map(Some),
+                network_token_locker_id: network_token_locker_id.map(Some),
+                network_token_payment_method_data: network_token_payment_method_data.map(Some),
+                scheme: None,
+            },
+            PaymentMethodUpdate::NetworkTokenDataClear => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_transaction_id: None,
+                network_token_requestor_reference_id: Some(None),
+                network_token_locker_id: Some(None),
+                network_token_payment_method_data: Some(None),
                 scheme: None,
             },
             PaymentMethodUpdate::ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
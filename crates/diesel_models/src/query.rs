@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod approval_request;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
@@ -0,0 +1,92 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    approval_request::{
+        ApprovalRequest, ApprovalRequestNew, ApprovalRequestUpdate, ApprovalRequestUpdateInternal,
+    },
+    enums as storage_enums,
+    schema::approval_requests::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ApprovalRequestNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ApprovalRequest> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ApprovalRequest {
+    pub async fn find_by_merchant_id_approval_request_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::approval_request_id.eq(approval_request_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        status: Option<storage_enums::ApprovalRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Updates the request only if it is still in `current_status`, so that two reviewers cannot
+    /// act on the same request concurrently
+    pub async fn update_by_merchant_id_approval_request_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+        current_status: storage_enums::ApprovalRequestStatus,
+        approval_request_update: ApprovalRequestUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::approval_request_id.eq(approval_request_id.to_owned()))
+                .and(dsl::status.eq(current_status)),
+            ApprovalRequestUpdateInternal::from(approval_request_update),
+        )
+        .await
+    }
+}
@@ -181,6 +181,66 @@ impl Event {
             .attach_printable("Error filtering events by constraints")
     }
 
+    /// Lists initial delivery attempts in ascending order of `(created_at, event_id)`, starting
+    /// after the specified `(created_at, event_id)` key, if any.
+    ///
+    /// Unlike offset based pagination, the pages remain consistent when the listed events are
+    /// updated between page fetches.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_initial_attempts_by_profile_id_constraints_after_key(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::business_profile_id.eq(profile_id.to_owned())),
+            )
+            .order((dsl::created_at.asc(), dsl::event_id.asc()))
+            .into_boxed();
+
+        if let Some((last_created_at, last_event_id)) = after_key {
+            query = query.filter(
+                dsl::created_at.gt(last_created_at).or(dsl::created_at
+                    .eq(last_created_at)
+                    .and(dsl::event_id.gt(last_event_id))),
+            );
+        }
+
+        query = Self::apply_filters(
+            query,
+            None,
+            (dsl::created_at, created_after, created_before),
+            Some(limit),
+            None,
+            event_types,
+            is_delivered,
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering events by constraints")
+    }
+
     pub async fn list_by_profile_id_initial_attempt_id(
         conn: &PgPooledConn,
         profile_id: &common_utils::id_type::ProfileId,
@@ -67,6 +67,21 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_network_token_requestor_reference_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::network_token_requestor_reference_id
+                    .eq(network_token_requestor_reference_id.to_owned()),
+            ),
+        )
+        .await
+    }
+
     pub async fn find_by_payment_method_id(
         conn: &PgPooledConn,
         payment_method_id: &str,
@@ -164,6 +179,47 @@ impl PaymentMethod {
         .attach_printable("Failed to get a count of payment methods")
     }
 
+    /// Lists the payment methods of the merchant with the specified status ordered by
+    /// `(created_at, payment_method_id)`, starting after the specified key, if any.
+    ///
+    /// Unlike offset based pagination, no payment method is skipped or listed twice when payment
+    /// methods change status between page fetches.
+    pub async fn find_by_merchant_id_status_after_key(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: storage_enums::PaymentMethodStatus,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::status.eq(status)),
+            )
+            .order((dsl::created_at.asc(), dsl::payment_method_id.asc()))
+            .limit(limit)
+            .into_boxed();
+
+        if let Some((last_created_at, last_payment_method_id)) = after_key {
+            query = query.filter(
+                dsl::created_at.gt(last_created_at).or(dsl::created_at
+                    .eq(last_created_at)
+                    .and(dsl::payment_method_id.gt(last_payment_method_id))),
+            );
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to list payment methods of the merchant")
+    }
+
     pub async fn find_by_customer_id_merchant_id_status(
         conn: &PgPooledConn,
         customer_id: &common_utils::id_type::CustomerId,
@@ -2,6 +2,7 @@ use common_utils::pii;
 use diesel::{associations::HasTable, ExpressionMethods};
 
 pub mod sample_data;
+pub mod scim_user;
 pub mod theme;
 
 use crate::{
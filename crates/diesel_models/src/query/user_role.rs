@@ -179,6 +179,40 @@ impl UserRole {
             .await
     }
 
+    /// Deletes the v2 user role with `role_id` held by the user at exactly the given entity,
+    /// a missing merchant or profile only matches roles without one
+    pub async fn delete_by_user_id_tenant_id_org_id_role_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        tenant_id: id_type::TenantId,
+        org_id: id_type::OrganizationId,
+        merchant_id: Option<id_type::MerchantId>,
+        profile_id: Option<id_type::ProfileId>,
+        role_id: String,
+    ) -> StorageResult<bool> {
+        let mut entity_predicate: Box<
+            dyn diesel::BoxableExpression<<Self as HasTable>::Table, Pg, SqlType = Nullable<Bool>>,
+        > = Box::new(dsl::tenant_id.eq(tenant_id).and(dsl::org_id.eq(org_id)));
+
+        entity_predicate = match merchant_id {
+            Some(merchant_id) => Box::new(entity_predicate.and(dsl::merchant_id.eq(merchant_id))),
+            None => Box::new(entity_predicate.and(dsl::merchant_id.is_null())),
+        };
+
+        entity_predicate = match profile_id {
+            Some(profile_id) => Box::new(entity_predicate.and(dsl::profile_id.eq(profile_id))),
+            None => Box::new(entity_predicate.and(dsl::profile_id.is_null())),
+        };
+
+        let predicate = dsl::user_id
+            .eq(user_id)
+            .and(dsl::role_id.eq(role_id))
+            .and(dsl::version.eq(UserRoleVersion::V2))
+            .and(entity_predicate);
+
+        generics::generic_delete::<<Self as HasTable>::Table, _>(conn, predicate).await
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn generic_user_roles_list_for_user(
         conn: &PgPooledConn,
@@ -64,6 +64,18 @@ mod composite_key {
             self.1
         }
     }
+    impl CompositeKey for <schema::scim_users::table as diesel::Table>::PrimaryKey {
+        type UK = schema::scim_users::dsl::user_id;
+        fn get_local_unique_key(&self) -> Self::UK {
+            self.1
+        }
+    }
+    impl CompositeKey for <schema_v2::scim_users::table as diesel::Table>::PrimaryKey {
+        type UK = schema_v2::scim_users::dsl::user_id;
+        fn get_local_unique_key(&self) -> Self::UK {
+            self.1
+        }
+    }
 }
 
 /// This macro will implement the `GetPrimaryKey` trait for all the tables with single primary key.
@@ -140,5 +152,7 @@ impl_get_primary_key_for_composite!(
     schema::blocklist::table,
     schema::incremental_authorization::table,
     schema_v2::incremental_authorization::table,
-    schema_v2::blocklist::table
+    schema_v2::blocklist::table,
+    schema::scim_users::table,
+    schema_v2::scim_users::table
 );
@@ -57,6 +57,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    approval_requests (approval_request_id) {
+        #[max_length = 64]
+        approval_request_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        action -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        resource_id -> Nullable<Varchar>,
+        payload -> Bytea,
+        #[max_length = 64]
+        requested_by -> Varchar,
+        #[max_length = 64]
+        reviewed_by -> Nullable<Varchar>,
+        #[max_length = 1024]
+        review_comment -> Nullable<Varchar>,
+        #[max_length = 1024]
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -123,6 +156,8 @@ diesel::table! {
         service_details -> Nullable<Jsonb>,
         #[max_length = 32]
         organization_id -> Varchar,
+        psd2_sca_exemption_type -> Nullable<ScaExemptionType>,
+        is_merchant_whitelisted -> Nullable<Bool>,
     }
 }
 
@@ -214,6 +249,8 @@ diesel::table! {
         is_network_tokenization_enabled -> Bool,
         is_auto_retries_enabled -> Nullable<Bool>,
         max_auto_retries_enabled -> Nullable<Int2>,
+        is_payout_auto_retries_enabled -> Nullable<Bool>,
+        max_payout_auto_retries_enabled -> Nullable<Int2>,
         always_request_extended_authorization -> Nullable<Bool>,
         is_click_to_pay_enabled -> Bool,
         authentication_product_ids -> Nullable<Jsonb>,
@@ -471,7 +508,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 128]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -542,6 +579,9 @@ diesel::table! {
         #[max_length = 64]
         last_step -> Varchar,
         payment_capture_method -> Nullable<CaptureMethod>,
+        #[max_length = 64]
+        feedback_type -> Nullable<Varchar>,
+        dispute_status -> Nullable<DisputeStatus>,
     }
 }
 
@@ -1416,6 +1456,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    scim_users (org_id, user_id) {
+        #[max_length = 64]
+        org_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 64]
+        tenant_id -> Varchar,
+        #[max_length = 255]
+        external_id -> Nullable<Varchar>,
+        active -> Bool,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+        is_user_created_by_scim -> Bool,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1558,12 +1618,14 @@ diesel::table! {
         totp_recovery_codes -> Nullable<Array<Nullable<Text>>>,
         last_password_modified_at -> Nullable<Timestamp>,
         lineage_context -> Nullable<Jsonb>,
+        passkeys -> Nullable<Bytea>,
     }
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    approval_requests,
     authentication,
     blocklist,
     blocklist_fingerprint,
@@ -1601,6 +1663,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    scim_users,
     themes,
     unified_translations,
     user_authentication_methods,
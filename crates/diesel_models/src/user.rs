@@ -7,6 +7,7 @@ use crate::{diesel_impl::OptionalDieselArray, enums::TotpStatus, schema::users};
 
 pub mod dashboard_metadata;
 pub mod sample_data;
+pub mod scim_user;
 pub mod theme;
 
 #[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
@@ -25,6 +26,7 @@ pub struct User {
     pub totp_recovery_codes: Option<Vec<Secret<String>>>,
     pub last_password_modified_at: Option<PrimitiveDateTime>,
     pub lineage_context: Option<LineageContext>,
+    pub passkeys: Option<Encryption>,
 }
 
 #[derive(
@@ -44,6 +46,7 @@ pub struct UserNew {
     pub totp_recovery_codes: Option<Vec<Secret<String>>>,
     pub last_password_modified_at: Option<PrimitiveDateTime>,
     pub lineage_context: Option<LineageContext>,
+    pub passkeys: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -58,6 +61,7 @@ pub struct UserUpdateInternal {
     totp_recovery_codes: Option<Vec<Secret<String>>>,
     last_password_modified_at: Option<PrimitiveDateTime>,
     lineage_context: Option<LineageContext>,
+    passkeys: Option<Encryption>,
 }
 
 #[derive(Debug)]
@@ -78,6 +82,9 @@ pub enum UserUpdate {
     LineageContextUpdate {
         lineage_context: LineageContext,
     },
+    PasskeyUpdate {
+        passkeys: Encryption,
+    },
 }
 
 impl From<UserUpdate> for UserUpdateInternal {
@@ -94,6 +101,7 @@ impl From<UserUpdate> for UserUpdateInternal {
                 totp_recovery_codes: None,
                 last_password_modified_at: None,
                 lineage_context: None,
+                passkeys: None,
             },
             UserUpdate::AccountUpdate { name, is_verified } => Self {
                 name,
@@ -105,6 +113,7 @@ impl From<UserUpdate> for UserUpdateInternal {
                 totp_recovery_codes: None,
                 last_password_modified_at: None,
                 lineage_context: None,
+                passkeys: None,
             },
             UserUpdate::TotpUpdate {
                 totp_status,
@@ -120,6 +129,7 @@ impl From<UserUpdate> for UserUpdateInternal {
                 totp_recovery_codes,
                 last_password_modified_at: None,
                 lineage_context: None,
+                passkeys: None,
             },
             UserUpdate::PasswordUpdate { password } => Self {
                 name: None,
@@ -131,6 +141,7 @@ impl From<UserUpdate> for UserUpdateInternal {
                 totp_secret: None,
                 totp_recovery_codes: None,
                 lineage_context: None,
+                passkeys: None,
             },
             UserUpdate::LineageContextUpdate { lineage_context } => Self {
                 name: None,
@@ -142,6 +153,19 @@ impl From<UserUpdate> for UserUpdateInternal {
                 totp_secret: None,
                 totp_recovery_codes: None,
                 lineage_context: Some(lineage_context),
+                passkeys: None,
+            },
+            UserUpdate::PasskeyUpdate { passkeys } => Self {
+                name: None,
+                password: None,
+                is_verified: None,
+                last_modified_at,
+                last_password_modified_at: None,
+                totp_status: None,
+                totp_secret: None,
+                totp_recovery_codes: None,
+                lineage_context: None,
+                passkeys: Some(passkeys),
             },
         }
     }
@@ -0,0 +1,67 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::scim_users;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = scim_users, primary_key(org_id, user_id), check_for_backend(diesel::pg::Pg))]
+pub struct ScimUser {
+    pub org_id: id_type::OrganizationId,
+    pub user_id: String,
+    pub tenant_id: id_type::TenantId,
+    pub external_id: Option<String>,
+    pub active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    /// Whether the user was created when provisioning them in the organization, as opposed to an
+    /// existing member of the organization being linked
+    pub is_user_created_by_scim: bool,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = scim_users)]
+pub struct ScimUserNew {
+    pub org_id: id_type::OrganizationId,
+    pub user_id: String,
+    pub tenant_id: id_type::TenantId,
+    pub external_id: Option<String>,
+    pub active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+    /// Whether the user was created when provisioning them in the organization, as opposed to an
+    /// existing member of the organization being linked
+    pub is_user_created_by_scim: bool,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = scim_users)]
+pub struct ScimUserUpdateInternal {
+    external_id: Option<String>,
+    active: Option<bool>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ScimUserUpdate {
+    Update {
+        external_id: Option<String>,
+        active: Option<bool>,
+    },
+}
+
+impl From<ScimUserUpdate> for ScimUserUpdateInternal {
+    fn from(value: ScimUserUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match value {
+            ScimUserUpdate::Update {
+                external_id,
+                active,
+            } => Self {
+                external_id,
+                active,
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -45,6 +45,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             capture_method: Some(enums::CaptureMethod::Automatic),
             business_country: Some(enums::Country::UnitedStatesOfAmerica),
             billing_country: Some(enums::Country::France),
+            destination_country: None,
             business_label: None,
             setup_future_usage: None,
         },
@@ -26,6 +26,8 @@ pub struct PaymentInput {
     pub capture_method: Option<enums::CaptureMethod>,
     pub business_country: Option<enums::Country>,
     pub billing_country: Option<enums::Country>,
+    /// Country of the account receiving the funds, only applicable to payouts
+    pub destination_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
 }
@@ -140,6 +140,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -180,6 +181,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -221,6 +223,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -262,6 +265,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -303,6 +307,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -344,6 +349,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -385,6 +391,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -426,6 +433,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -467,6 +475,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
             },
@@ -510,6 +519,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -551,6 +561,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -601,6 +612,7 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                destination_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -629,4 +641,50 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_destination_country() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["adyen"]
+        {
+           destination_country = Germany
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(1000),
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: None,
+                capture_method: None,
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                destination_country: Some(enums::Country::Germany),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::BankTransfer),
+                payment_method_type: Some(enums::PaymentMethodType::SepaBankTransfer),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_other_country = inp.clone();
+        inp_other_country.payment.destination_country = Some(enums::Country::France);
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result = backend.execute(inp).expect("Execution");
+        let result_other_country = backend.execute(inp_other_country).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+        assert!(result_other_country.rule_name.is_none());
+    }
 }
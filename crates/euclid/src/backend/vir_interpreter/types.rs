@@ -92,6 +92,10 @@ impl Context {
         if let Some(country) = payment.billing_country {
             enum_values.insert(EuclidValue::BillingCountry(country));
         }
+
+        if let Some(country) = payment.destination_country {
+            enum_values.insert(EuclidValue::DestinationCountry(country));
+        }
         if let Some(card_bin) = payment.card_bin {
             enum_values.insert(EuclidValue::CardBin(StrValue { value: card_bin }));
         }
@@ -5,8 +5,8 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     dssa::types,
-    frontend::dir,
-    types::{DataType, Metadata},
+    frontend::{ast, dir},
+    types::{DataType, Metadata, NumValueRefinement},
 };
 
 pub mod euclid_graph_prelude {
@@ -21,7 +21,14 @@ pub mod euclid_graph_prelude {
     };
 }
 
-impl cgraph::KeyNode for dir::DirKey {}
+impl cgraph::KeyNode for dir::DirKey {
+    fn describe(&self) -> String {
+        match &self.value {
+            Some(value) => format!("{}[{value}]", self.kind),
+            None => self.kind.to_string(),
+        }
+    }
+}
 
 impl cgraph::NodeViz for dir::DirKey {
     fn viz(&self) -> String {
@@ -35,6 +42,27 @@ impl cgraph::ValueNode for dir::DirValue {
     fn get_key(&self) -> Self::Key {
         Self::get_key(self)
     }
+
+    fn describe(&self) -> String {
+        let kind = Self::get_key(self).kind;
+        match self {
+            Self::PaymentAmount(num_value) | Self::AcquirerFraudRate(num_value) => {
+                let comparison = match num_value.refinement {
+                    None => "=",
+                    Some(NumValueRefinement::NotEqual) => "!=",
+                    Some(NumValueRefinement::GreaterThan) => ">",
+                    Some(NumValueRefinement::LessThan) => "<",
+                    Some(NumValueRefinement::GreaterThanEqual) => ">=",
+                    Some(NumValueRefinement::LessThanEqual) => "<=",
+                };
+                format!(
+                    "{kind} {comparison} {}",
+                    num_value.number.get_amount_as_i64()
+                )
+            }
+            _ => format!("{kind} = {}", cgraph::NodeViz::viz(self)),
+        }
+    }
 }
 
 impl cgraph::NodeViz for dir::DirValue {
@@ -58,6 +86,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CaptureMethod(cm) => cm.to_string(),
             Self::BusinessCountry(bc) => bc.to_string(),
             Self::BillingCountry(bc) => bc.to_string(),
+            Self::DestinationCountry(dc) => dc.to_string(),
             Self::Connector(conn) => conn.connector.to_string(),
             Self::MetaData(mv) => format!("[{} = {}]", mv.key, mv.value),
             Self::MandateAcceptanceType(mat) => mat.to_string(),
@@ -86,6 +115,92 @@ impl cgraph::NodeViz for dir::DirValue {
     }
 }
 
+/// Form in which the values of a graph are cached, for instance in redis.
+///
+/// Connector values can not be deserialized as [`dir::DirValue`]s, so that they are never
+/// accepted in requests, and are kept apart in this form instead.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CachedDirValue {
+    Connector(Box<ast::ConnectorChoice>),
+    Value(dir::DirValue),
+}
+
+/// Form in which the keys of a graph are cached, see [`CachedDirValue`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CachedDirKey {
+    Connector { value: Option<String> },
+    Key(dir::DirKey),
+}
+
+impl From<dir::DirValue> for CachedDirValue {
+    fn from(value: dir::DirValue) -> Self {
+        match value {
+            dir::DirValue::Connector(connector) => Self::Connector(connector),
+            value => Self::Value(value),
+        }
+    }
+}
+
+impl From<CachedDirValue> for dir::DirValue {
+    fn from(value: CachedDirValue) -> Self {
+        match value {
+            CachedDirValue::Connector(connector) => Self::Connector(connector),
+            CachedDirValue::Value(value) => value,
+        }
+    }
+}
+
+impl From<dir::DirKey> for CachedDirKey {
+    fn from(key: dir::DirKey) -> Self {
+        match key.kind {
+            dir::DirKeyKind::Connector => Self::Connector { value: key.value },
+            _ => Self::Key(key),
+        }
+    }
+}
+
+impl From<CachedDirKey> for dir::DirKey {
+    fn from(key: CachedDirKey) -> Self {
+        match key {
+            CachedDirKey::Connector { value } => Self::new(dir::DirKeyKind::Connector, value),
+            CachedDirKey::Key(key) => key,
+        }
+    }
+}
+
+impl cgraph::KeyNode for CachedDirKey {}
+
+impl cgraph::ValueNode for CachedDirValue {
+    type Key = CachedDirKey;
+
+    fn get_key(&self) -> Self::Key {
+        match self {
+            Self::Connector(_) => CachedDirKey::Connector { value: None },
+            Self::Value(value) => value.get_key().into(),
+        }
+    }
+}
+
+/// Convert a graph into the form in which it is cached
+pub fn to_cached_graph(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+) -> cgraph::SerializedConstraintGraph<CachedDirValue> {
+    graph
+        .to_serialized()
+        .map_values(CachedDirValue::from, CachedDirKey::from)
+}
+
+/// Rebuild a graph from the form in which it is cached
+pub fn from_cached_graph(
+    cached_graph: cgraph::SerializedConstraintGraph<CachedDirValue>,
+) -> Result<cgraph::ConstraintGraph<dir::DirValue>, cgraph::GraphError<dir::DirValue>> {
+    cgraph::ConstraintGraph::from_serialized(
+        cached_graph.map_values(dir::DirValue::from, dir::DirKey::from),
+    )
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "type", content = "details", rename_all = "snake_case")]
 pub enum AnalysisError<V: cgraph::ValueNode> {
@@ -255,6 +370,14 @@ pub trait CgraphExt {
         domains: Option<&[String]>,
     ) -> Result<bool, cgraph::GraphError<dir::DirValue>>;
 
+    /// Explain why the value is not valid in the context, `None` if it is valid
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<Option<cgraph::Explanation>, cgraph::GraphError<dir::DirValue>>;
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -372,6 +495,52 @@ impl CgraphExt for cgraph::ConstraintGraph<dir::DirValue> {
         }
     }
 
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        domains: Option<&[String]>,
+    ) -> Result<Option<cgraph::Explanation>, cgraph::GraphError<dir::DirValue>> {
+        let Some(node_id) = self.value_map.get(&cgraph::NodeValue::Value(val.clone())) else {
+            return Ok(Some(cgraph::Explanation {
+                constraint: None,
+                reason: format!("{} is not configured", cgraph::ValueNode::describe(&val)),
+                causes: Vec::new(),
+            }));
+        };
+
+        // The trace only lives as long as the memoization it is stored in
+        let mut memo = cgraph::Memoization::new();
+        let result = self.check_node(
+            analysis_ctx,
+            *node_id,
+            cgraph::Relation::Positive,
+            cgraph::Strength::Weak,
+            &mut memo,
+            &mut cgraph::CycleCheck::new(),
+            domains,
+        );
+
+        match result {
+            Ok(()) => Ok(None),
+            Err(error) => {
+                let trace = error.get_analysis_trace()?;
+                Ok(Some(
+                    cgraph::Explanation::from_trace(&trace).unwrap_or_else(|| {
+                        cgraph::Explanation {
+                            constraint: None,
+                            reason: format!(
+                                "{} is not valid in the context",
+                                cgraph::ValueNode::describe(&val)
+                            ),
+                            causes: Vec::new(),
+                        }
+                    }),
+                ))
+            }
+        }
+    }
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -1127,4 +1296,110 @@ mod test {
 
         assert!(_result.is_ok());
     }
+
+    #[test]
+    fn test_value_validity_explanation() {
+        let graph = knowledge! {
+            PaymentMethod(Card) ->> CaptureMethod(Automatic);
+        };
+
+        let explanation = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([
+                    dirval!(CaptureMethod = Automatic),
+                    dirval!(PaymentMethod = Card),
+                ]),
+                None,
+            )
+            .expect("Graph error");
+        assert!(explanation.is_none());
+
+        let explanation = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([
+                    dirval!(CaptureMethod = Automatic),
+                    dirval!(PaymentMethod = Wallet),
+                ]),
+                None,
+            )
+            .expect("Graph error")
+            .expect("Explanation for an invalid value");
+
+        assert_eq!(
+            explanation.get_root_causes(),
+            vec![format!(
+                "{} is required but was not present in the context",
+                cgraph::ValueNode::describe(&dirval!(PaymentMethod = Card))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_cached_graph_round_trip() {
+        let mut builder = cgraph::ConstraintGraphBuilder::new();
+        let card = builder.make_value_node(
+            dirval!(PaymentMethod = Card).into(),
+            Some("card payments"),
+            None::<()>,
+        );
+        let connector_key = builder.make_value_node(
+            cgraph::NodeValue::Key(dir::DirKey::new(dir::DirKeyKind::Connector, None)),
+            None,
+            None::<()>,
+        );
+        let stripe = builder.make_value_node(dirval!(Connector = Stripe).into(), None, None::<()>);
+        let aggregator = builder
+            .make_all_aggregator(
+                &[
+                    (card, cgraph::Relation::Positive, cgraph::Strength::Strong),
+                    (
+                        connector_key,
+                        cgraph::Relation::Positive,
+                        cgraph::Strength::Weak,
+                    ),
+                ],
+                None,
+                None::<()>,
+                None,
+            )
+            .expect("All aggregator");
+        builder
+            .make_edge(
+                aggregator,
+                stripe,
+                cgraph::Strength::Strong,
+                cgraph::Relation::Positive,
+                None::<cgraph::DomainId>,
+            )
+            .expect("Edge");
+        let graph = builder.build();
+
+        // Connector values are not deserializable as dir values, which rules out the plain
+        // serialized form for caching
+        let serialized = serde_json::to_string(&graph.to_serialized()).expect("Serialization");
+        assert!(
+            serde_json::from_str::<cgraph::SerializedConstraintGraph<dir::DirValue>>(&serialized)
+                .is_err()
+        );
+
+        let cached = serde_json::to_string(&to_cached_graph(&graph)).expect("Serialization");
+        let cached_graph =
+            from_cached_graph(serde_json::from_str(&cached).expect("Deserialization"))
+                .expect("Cached graph");
+        assert!(graph.diff(&cached_graph).expect("Diff").is_empty());
+
+        let result = cached_graph.key_value_analysis(
+            dirval!(Connector = Stripe),
+            &AnalysisContext::from_dir_values([
+                dirval!(Connector = Stripe),
+                dirval!(PaymentMethod = Card),
+            ]),
+            &mut cgraph::Memoization::new(),
+            &mut CycleCheck::new(),
+            None,
+        );
+        assert!(result.is_ok());
+    }
 }
@@ -239,6 +239,7 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CaptureMethod => lower_enum!(CaptureMethod, value),
         dir::DirKeyKind::BusinessCountry => lower_enum!(BusinessCountry, value),
         dir::DirKeyKind::BillingCountry => lower_enum!(BillingCountry, value),
+        dir::DirKeyKind::DestinationCountry => lower_enum!(DestinationCountry, value),
         dir::DirKeyKind::SetupFutureUsage => lower_enum!(SetupFutureUsage, value),
         dir::DirKeyKind::UpiType => lower_enum!(UpiType, value),
         dir::DirKeyKind::OpenBankingType => lower_enum!(OpenBankingType, value),
@@ -45,7 +45,7 @@ macro_rules! dirval {
     }};
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct DirKey {
     pub kind: DirKeyKind,
     pub value: Option<String>,
@@ -64,6 +64,7 @@ impl DirKey {
     PartialEq,
     Eq,
     serde::Serialize,
+    serde::Deserialize,
     strum::Display,
     strum::EnumIter,
     strum::VariantNames,
@@ -241,6 +242,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "billing_country")]
     BillingCountry,
+    #[strum(
+        serialize = "destination_country",
+        detailed_message = "Country of the account or wallet receiving the payout",
+        props(Category = "Payouts")
+    )]
+    #[serde(rename = "destination_country")]
+    DestinationCountry,
     #[serde(skip_deserializing, rename = "connector")]
     Connector,
     #[strum(
@@ -376,6 +384,7 @@ impl DirKeyKind {
             Self::CaptureMethod => types::DataType::EnumVariant,
             Self::BusinessCountry => types::DataType::EnumVariant,
             Self::BillingCountry => types::DataType::EnumVariant,
+            Self::DestinationCountry => types::DataType::EnumVariant,
             Self::Connector => types::DataType::EnumVariant,
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
@@ -495,6 +504,11 @@ impl DirKeyKind {
                     .map(DirValue::BillingCountry)
                     .collect(),
             ),
+            Self::DestinationCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::DestinationCountry)
+                    .collect(),
+            ),
             Self::Connector => Some(
                 common_enums::RoutableConnectors::iter()
                     .map(|connector| {
@@ -560,7 +574,15 @@ impl DirKeyKind {
 }
 
 #[derive(
-    Debug, Clone, Hash, PartialEq, Eq, serde::Serialize, strum::Display, strum::VariantNames,
+    Debug,
+    Clone,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::VariantNames,
 )]
 #[serde(tag = "key", content = "value")]
 pub enum DirValue {
@@ -612,6 +634,8 @@ pub enum DirValue {
     BusinessCountry(enums::Country),
     #[serde(rename = "billing_country")]
     BillingCountry(enums::Country),
+    #[serde(rename = "destination_country")]
+    DestinationCountry(enums::Country),
     #[serde(skip_deserializing, rename = "connector")]
     Connector(Box<ast::ConnectorChoice>),
     #[serde(rename = "business_label")]
@@ -650,6 +674,7 @@ impl DirValue {
             Self::RewardType(_) => (DirKeyKind::RewardType, None),
             Self::BusinessCountry(_) => (DirKeyKind::BusinessCountry, None),
             Self::BillingCountry(_) => (DirKeyKind::BillingCountry, None),
+            Self::DestinationCountry(_) => (DirKeyKind::DestinationCountry, None),
             Self::BankTransferType(_) => (DirKeyKind::BankTransferType, None),
             Self::UpiType(_) => (DirKeyKind::UpiType, None),
             Self::CardType(_) => (DirKeyKind::CardType, None),
@@ -705,6 +730,7 @@ impl DirValue {
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
             Self::BillingCountry(_) => None,
+            Self::DestinationCountry(_) => None,
             Self::Connector(_) => None,
             Self::BankTransferType(_) => None,
             Self::UpiType(_) => None,
@@ -764,6 +790,7 @@ impl DirValue {
             (Self::PaymentCurrency(pc1), Self::PaymentCurrency(pc2)) => pc1 == pc2,
             (Self::BusinessCountry(c1), Self::BusinessCountry(c2)) => c1 == c2,
             (Self::BillingCountry(c1), Self::BillingCountry(c2)) => c1 == c2,
+            (Self::DestinationCountry(c1), Self::DestinationCountry(c2)) => c1 == c2,
             (Self::PaymentType(pt1), Self::PaymentType(pt2)) => pt1 == pt2,
             (Self::MandateType(mt1), Self::MandateType(mt2)) => mt1 == mt2,
             (Self::MandateAcceptanceType(mat1), Self::MandateAcceptanceType(mat2)) => mat1 == mat2,
@@ -820,6 +847,14 @@ pub enum PayoutDirKeyKind {
     #[serde(rename = "billing_country")]
     BillingCountry,
 
+    #[strum(
+        serialize = "destination_country",
+        detailed_message = "Country of the account or wallet receiving the payout",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "destination_country")]
+    DestinationCountry,
+
     #[strum(
         serialize = "business_label",
         detailed_message = "Identifier for business unit",
@@ -870,6 +905,8 @@ pub enum PayoutDirValue {
     BusinessCountry(enums::Country),
     #[serde(rename = "billing_country")]
     BillingCountry(enums::Country),
+    #[serde(rename = "destination_country")]
+    DestinationCountry(enums::Country),
     #[serde(rename = "business_label")]
     BusinessLabel(types::StrValue),
     #[serde(rename = "amount")]
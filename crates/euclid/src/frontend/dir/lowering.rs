@@ -251,6 +251,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::PaymentCurrency(pc) => EuclidValue::PaymentCurrency(pc),
         dir::DirValue::BusinessCountry(buc) => EuclidValue::BusinessCountry(buc),
         dir::DirValue::BillingCountry(bic) => EuclidValue::BillingCountry(bic),
+        dir::DirValue::DestinationCountry(dc) => EuclidValue::DestinationCountry(dc),
         dir::DirValue::MandateAcceptanceType(mat) => EuclidValue::MandateAcceptanceType(mat),
         dir::DirValue::MandateType(mt) => EuclidValue::MandateType(mt),
         dir::DirValue::PaymentType(pt) => EuclidValue::PaymentType(pt),
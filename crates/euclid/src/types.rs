@@ -59,6 +59,8 @@ pub enum EuclidKey {
     BusinessCountry,
     #[strum(serialize = "billing_country")]
     BillingCountry,
+    #[strum(serialize = "destination_country")]
+    DestinationCountry,
     #[strum(serialize = "business_label")]
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
@@ -151,6 +153,7 @@ impl EuclidKey {
             Self::PaymentCurrency => DataType::EnumVariant,
             Self::BusinessCountry => DataType::EnumVariant,
             Self::BillingCountry => DataType::EnumVariant,
+            Self::DestinationCountry => DataType::EnumVariant,
             Self::MandateType => DataType::EnumVariant,
             Self::MandateAcceptanceType => DataType::EnumVariant,
             Self::PaymentType => DataType::EnumVariant,
@@ -276,6 +279,7 @@ pub enum EuclidValue {
     PaymentCurrency(enums::Currency),
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
+    DestinationCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
     IssuerName(StrValue),
@@ -311,6 +315,7 @@ impl EuclidValue {
             Self::PaymentCurrency(_) => EuclidKey::PaymentCurrency,
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
+            Self::DestinationCountry(_) => EuclidKey::DestinationCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
             Self::IssuerName(_) => EuclidKey::IssuerName,
@@ -8,7 +8,8 @@ use std::{
 };
 
 use api_models::{
-    enums as api_model_enums, routing::ConnectorSelection,
+    enums as api_model_enums,
+    routing::{ConnectorSelection, PaymentMethodEligibilityRequest, PaymentMethodTypeChoice},
     surcharge_decision_configs::SurchargeDecisionConfigs,
 };
 use common_enums::RoutableConnectors;
@@ -40,6 +41,7 @@ use strum::IntoEnumIterator;
 struct SeedData {
     cgraph: hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>,
     connectors: Vec<ast::ConnectorChoice>,
+    payment_methods: Vec<PaymentMethodTypeChoice>,
 }
 
 static SEED_DATA: OnceLock<SeedData> = OnceLock::new();
@@ -109,6 +111,7 @@ pub fn seed_knowledge_graph(mcas: JsValue) -> JsResult {
         .collect::<Result<_, _>>()
         .map_err(|_| "invalid connector name received")
         .err_to_js()?;
+    let payment_methods = kgraph_utils::eligibility::get_enabled_payment_methods(&mcas);
     let pm_filter = kgraph_utils::types::PaymentMethodFilters(HashMap::new());
     let config = kgraph_utils::types::CountryCurrencyFilter {
         connector_configs: HashMap::new(),
@@ -125,6 +128,7 @@ pub fn seed_knowledge_graph(mcas: JsValue) -> JsResult {
         .set(SeedData {
             cgraph: analysis_graph,
             connectors,
+            payment_methods,
         })
         .map_err(|_| "Knowledge Graph has been already seeded".to_string())
         .err_to_js()?;
@@ -195,6 +199,35 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
 }
 
+/// This function allows the frontend to explain which of the merchant's configured connectors
+/// are eligible for each payment method in a payment context, and why the others are not.
+/// The payment methods enabled on the connectors are checked unless the request specifies them.
+#[wasm_bindgen(js_name = explainPaymentMethodEligibility)]
+pub fn explain_payment_method_eligibility(request: JsValue) -> JsResult {
+    let seed_data = SEED_DATA.get().ok_or("Data not seeded").err_to_js()?;
+    let request: PaymentMethodEligibilityRequest = serde_wasm_bindgen::from_value(request)?;
+
+    let connectors: Vec<RoutableConnectors> = seed_data
+        .connectors
+        .iter()
+        .map(|choice| choice.connector)
+        .collect();
+    let payment_methods = request
+        .payment_methods
+        .as_deref()
+        .unwrap_or(&seed_data.payment_methods);
+
+    let eligibility = kgraph_utils::eligibility::explain_payment_method_eligibility(
+        &seed_data.cgraph,
+        &connectors,
+        payment_methods,
+        &request,
+    )
+    .err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&eligibility)?)
+}
+
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
@@ -278,6 +311,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentCurrency => dir_enums::PaymentCurrency::VARIANTS,
         dir::DirKeyKind::BusinessCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::BillingCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::DestinationCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::BankTransferType => dir_enums::BankTransferType::VARIANTS,
         dir::DirKeyKind::UpiType => dir_enums::UpiType::VARIANTS,
         dir::DirKeyKind::SetupFutureUsage => dir_enums::SetupFutureUsage::VARIANTS,
@@ -412,6 +446,7 @@ pub fn get_payout_variant_values(key: &str) -> Result<JsValue, JsValue> {
     let variants: &[&str] = match key {
         dir::PayoutDirKeyKind::BusinessCountry => dir_enums::BusinessCountry::VARIANTS,
         dir::PayoutDirKeyKind::BillingCountry => dir_enums::BillingCountry::VARIANTS,
+        dir::PayoutDirKeyKind::DestinationCountry => dir_enums::DestinationCountry::VARIANTS,
         dir::PayoutDirKeyKind::PayoutType => dir_enums::PayoutType::VARIANTS,
         dir::PayoutDirKeyKind::WalletType => dir_enums::PayoutWalletType::VARIANTS,
         dir::PayoutDirKeyKind::BankTransferType => dir_enums::PayoutBankTransferType::VARIANTS,
@@ -18,6 +18,7 @@ use http_body_util::combinators::UnsyncBoxBody;
 use hyper::body::Bytes;
 #[cfg(feature = "dynamic_routing")]
 use hyper_util::client::legacy::connect::HttpConnector;
+use redis_interface::RedisConnectionPool;
 #[cfg(feature = "dynamic_routing")]
 use router_env::logger;
 use serde;
@@ -51,8 +52,13 @@ impl GrpcClientSettings {
     ///
     /// This function will panic if it fails to establish a connection with the gRPC server.
     /// This function will be called at service startup.
+    /// The redis connection is used by the services which are configured to run embedded.
     #[allow(clippy::expect_used)]
-    pub async fn get_grpc_client_interface(&self) -> Arc<GrpcClients> {
+    #[cfg_attr(not(feature = "dynamic_routing"), allow(unused_variables))]
+    pub async fn get_grpc_client_interface(
+        &self,
+        redis_conn: Option<Arc<RedisConnectionPool>>,
+    ) -> Arc<GrpcClients> {
         #[cfg(feature = "dynamic_routing")]
         let client =
             hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
@@ -63,7 +69,7 @@ impl GrpcClientSettings {
         let dynamic_routing_connection = self
             .dynamic_routing_client
             .clone()
-            .get_dynamic_routing_connection(client.clone())
+            .get_dynamic_routing_connection(client.clone(), redis_conn)
             .await
             .expect("Failed to establish a connection with the Dynamic Routing Server");
 
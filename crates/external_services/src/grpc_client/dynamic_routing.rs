@@ -1,7 +1,11 @@
 /// Module for Contract based routing
 pub mod contract_routing_client;
+/// Redis backed, in-process implementation of elimination routing
+pub mod embedded_elimination;
+/// Redis backed, in-process implementation of success rate based routing
+pub mod embedded_success_rate;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use common_utils::errors::CustomResult;
 use router_env::logger;
@@ -13,6 +17,11 @@ pub mod success_rate_client;
 
 pub use contract_routing_client::ContractScoreCalculatorClient;
 pub use elimination_based_client::EliminationAnalyserClient;
+use elimination_based_client::EliminationBasedRouting;
+pub use embedded_elimination::EmbeddedEliminationAnalyser;
+pub use embedded_success_rate::EmbeddedSuccessRateCalculator;
+use redis_interface::RedisConnectionPool;
+use success_rate_client::SuccessBasedDynamicRouting;
 pub use success_rate_client::SuccessRateCalculatorClient;
 
 use super::Client;
@@ -41,17 +50,20 @@ pub enum DynamicRoutingError {
     /// Error from Dynamic Routing Server while perfrming elimination
     #[error("Error from Dynamic Routing Server while perfrming elimination : {0}")]
     EliminationRateRoutingFailure(String),
+    /// Error while reading or writing the embedded dynamic routing state
+    #[error("Error while accessing the embedded dynamic routing state : {0}")]
+    EmbeddedStateFailure(String),
 }
 
 /// Type that consists of all the services provided by the client
 #[derive(Debug, Clone)]
 pub struct RoutingStrategy {
     /// success rate service for Dynamic Routing
-    pub success_rate_client: Option<SuccessRateCalculatorClient<Client>>,
+    pub success_rate_client: Option<Box<dyn SuccessBasedDynamicRouting>>,
     /// contract based routing service for Dynamic Routing
     pub contract_based_client: Option<ContractScoreCalculatorClient<Client>>,
     /// elimination service for Dynamic Routing
-    pub elimination_based_client: Option<EliminationAnalyserClient<Client>>,
+    pub elimination_based_client: Option<Box<dyn EliminationBasedRouting>>,
 }
 
 /// Contains the Dynamic Routing Client Config
@@ -67,31 +79,78 @@ pub enum DynamicRoutingClientConfig {
         /// Service name
         service: String,
     },
+    /// If success rate and elimination routing have to be computed in-process, with their state
+    /// kept in redis, instead of calling the dynamic routing gRPC server
+    Embedded {
+        /// Configs for the embedded dynamic routing implementation
+        embedded: EmbeddedDynamicRoutingConfig,
+    },
     #[default]
     /// If the dynamic routing client config has been disabled
     Disabled,
 }
 
+/// Configs for the embedded dynamic routing implementation
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct EmbeddedDynamicRoutingConfig {
+    /// Time (in seconds) for which the success rate windows and elimination buckets of an entity
+    /// are retained in redis after their last update
+    pub state_ttl_in_secs: i64,
+}
+
+impl Default for EmbeddedDynamicRoutingConfig {
+    fn default() -> Self {
+        Self {
+            // 7 days
+            state_ttl_in_secs: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
 impl DynamicRoutingClientConfig {
     /// establish connection with the server
     pub async fn get_dynamic_routing_connection(
         self,
         client: Client,
+        redis_conn: Option<Arc<RedisConnectionPool>>,
     ) -> Result<RoutingStrategy, Box<dyn std::error::Error>> {
-        let (success_rate_client, contract_based_client, elimination_based_client) = match self {
+        let (success_rate_client, contract_based_client, elimination_based_client): (
+            Option<Box<dyn SuccessBasedDynamicRouting>>,
+            Option<ContractScoreCalculatorClient<Client>>,
+            Option<Box<dyn EliminationBasedRouting>>,
+        ) = match self {
             Self::Enabled { host, port, .. } => {
                 let uri = format!("http://{}:{}", host, port).parse::<tonic::transport::Uri>()?;
                 logger::info!("Connection established with dynamic routing gRPC Server");
                 (
-                    Some(SuccessRateCalculatorClient::with_origin(
+                    Some(Box::new(SuccessRateCalculatorClient::with_origin(
                         client.clone(),
                         uri.clone(),
-                    )),
+                    ))),
                     Some(ContractScoreCalculatorClient::with_origin(
                         client.clone(),
                         uri.clone(),
                     )),
-                    Some(EliminationAnalyserClient::with_origin(client, uri)),
+                    Some(Box::new(EliminationAnalyserClient::with_origin(
+                        client, uri,
+                    ))),
+                )
+            }
+            Self::Embedded { embedded } => {
+                let redis_conn = redis_conn
+                    .ok_or("Redis connection is required for embedded dynamic routing")?;
+                logger::info!("Using the embedded dynamic routing implementation");
+                (
+                    Some(Box::new(EmbeddedSuccessRateCalculator::new(
+                        redis_conn.clone(),
+                        embedded.state_ttl_in_secs,
+                    ))),
+                    None,
+                    Some(Box::new(EmbeddedEliminationAnalyser::new(
+                        redis_conn,
+                        embedded.state_ttl_in_secs,
+                    ))),
                 )
             }
             Self::Disabled => (None, None, None),
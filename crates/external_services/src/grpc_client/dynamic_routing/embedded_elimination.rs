@@ -0,0 +1,325 @@
+use std::{collections::HashMap, sync::Arc};
+
+use api_models::routing::{
+    EliminationAnalyserConfig as EliminationConfig, RoutableConnectorChoice,
+    RoutableConnectorChoiceWithBucketName,
+};
+use common_utils::{date_time, ext_traits::OptionExt, transformers::ForeignTryFrom};
+use error_stack::ResultExt;
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    elimination_based_client::{
+        elimination_rate::{
+            invalidate_bucket_response::InvalidationStatus,
+            update_elimination_bucket_response::UpdationStatus, BucketInformation,
+            EliminationInformation, LabelWithStatus,
+        },
+        EliminationBasedRouting, EliminationBucketConfig, EliminationResponse,
+        InvalidateBucketResponse, UpdateEliminationBucketResponse,
+    },
+    DynamicRoutingError, DynamicRoutingResult,
+};
+use crate::grpc_client::GrpcHeaders;
+
+/// Redis key prefix under which the elimination buckets are stored
+const ELIMINATION_KEY_PREFIX: &str = "dynamic_routing_elimination";
+
+/// Leaks and fills the reported bucket of each label in a single step, so that concurrent
+/// failures of a label are all counted. The buckets of a label are stored as a JSON object of
+/// [`LeakyBucket`]s keyed by the bucket name.
+///
+/// KEYS: buckets hash
+/// ARGV: now, ttl, bucket size, bucket leak interval in seconds, followed by the hash field and
+/// bucket name of every reported label
+const UPDATE_BUCKETS_SCRIPT: &str = r#"
+local key = KEYS[1]
+local now, ttl = tonumber(ARGV[1]), ARGV[2]
+local bucket_size, leak_interval = tonumber(ARGV[3]), tonumber(ARGV[4])
+
+for i = 5, #ARGV, 2 do
+    local field, bucket_name = ARGV[i], ARGV[i + 1]
+    local stored_buckets = redis.call('HGET', key, field)
+    local buckets = stored_buckets and cjson.decode(stored_buckets) or {}
+    local bucket = buckets[bucket_name] or {count = 0, last_leaked_at = now}
+
+    if leak_interval == 0 then
+        bucket.count, bucket.last_leaked_at = 0, now
+    else
+        local leaks = math.floor((now - bucket.last_leaked_at) / leak_interval)
+        if leaks > 0 then
+            bucket.count = math.max(bucket.count - leaks, 0)
+            bucket.last_leaked_at = bucket.last_leaked_at + leaks * leak_interval
+        end
+    end
+
+    if bucket.count == 0 then
+        bucket.last_leaked_at = now
+    end
+    bucket.count = math.min(bucket.count + 1, bucket_size)
+
+    buckets[bucket_name] = bucket
+    redis.call('HSET', key, field, cjson.encode(buckets))
+end
+redis.call('EXPIRE', key, ttl)
+
+return 1
+"#;
+
+/// Elimination analyser which keeps leaky buckets in redis and evaluates them in-process.
+///
+/// Every failure reported for a label fills the bucket named after its error category by one,
+/// and the bucket leaks one entry per `bucket_leak_interval_in_secs`. A label stays eliminated
+/// while any of its buckets is full.
+#[derive(Clone)]
+pub struct EmbeddedEliminationAnalyser {
+    redis_conn: Arc<RedisConnectionPool>,
+    state_ttl_in_secs: i64,
+}
+
+impl std::fmt::Debug for EmbeddedEliminationAnalyser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddedEliminationAnalyser")
+            .field("state_ttl_in_secs", &self.state_ttl_in_secs)
+            .finish()
+    }
+}
+
+/// Leaky bucket for a single error category of a label
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+struct LeakyBucket {
+    count: u64,
+    /// Unix timestamp at which the bucket last leaked
+    last_leaked_at: i64,
+}
+
+impl LeakyBucket {
+    fn leak(&mut self, config: &EliminationBucketConfig, now: i64) {
+        let leak_interval = i64::try_from(config.bucket_leak_interval_in_secs).unwrap_or(i64::MAX);
+        if leak_interval == 0 {
+            self.count = 0;
+            self.last_leaked_at = now;
+            return;
+        }
+
+        let leaks = now.saturating_sub(self.last_leaked_at) / leak_interval;
+        if leaks > 0 {
+            self.count = self
+                .count
+                .saturating_sub(u64::try_from(leaks).unwrap_or(u64::MAX));
+            self.last_leaked_at = self
+                .last_leaked_at
+                .saturating_add(leaks.saturating_mul(leak_interval));
+        }
+    }
+
+    fn is_full(&self, config: &EliminationBucketConfig) -> bool {
+        self.count >= config.bucket_size
+    }
+}
+
+/// Buckets of a single label, keyed by the bucket name
+type LabelBuckets = HashMap<String, LeakyBucket>;
+
+impl EmbeddedEliminationAnalyser {
+    /// Create an elimination analyser backed by the given redis connection
+    pub fn new(redis_conn: Arc<RedisConnectionPool>, state_ttl_in_secs: i64) -> Self {
+        Self {
+            redis_conn,
+            state_ttl_in_secs,
+        }
+    }
+
+    fn get_redis_key(tenant_id: &str, id: &str) -> String {
+        format!("{ELIMINATION_KEY_PREFIX}_{tenant_id}_{id}")
+    }
+
+    fn get_hash_field(params: &str, label: &str) -> String {
+        format!("{params}:{label}")
+    }
+
+    fn get_bucket_config(
+        config: Option<EliminationConfig>,
+    ) -> DynamicRoutingResult<EliminationBucketConfig> {
+        config
+            .get_required_value("elimination_analyser_config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "elimination_analyser_config".to_string(),
+            })
+            .and_then(EliminationBucketConfig::foreign_try_from)
+    }
+
+    async fn fetch_buckets(
+        &self,
+        key: &str,
+        params: &str,
+        label: &str,
+    ) -> DynamicRoutingResult<LabelBuckets> {
+        match self
+            .redis_conn
+            .get_hash_field_and_deserialize::<LabelBuckets>(
+                &key.into(),
+                &Self::get_hash_field(params, label),
+                "LabelBuckets",
+            )
+            .await
+        {
+            Ok(buckets) => Ok(buckets),
+            Err(error) if error.current_context() == &RedisError::NotFound => {
+                Ok(LabelBuckets::new())
+            }
+            Err(error) => Err(
+                error.change_context(DynamicRoutingError::EmbeddedStateFailure(
+                    "Failed to fetch the elimination buckets".to_string(),
+                )),
+            ),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EliminationBasedRouting for EmbeddedEliminationAnalyser {
+    #[instrument(skip_all)]
+    async fn perform_elimination_routing(
+        &self,
+        id: String,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<EliminationResponse> {
+        let config = Self::get_bucket_config(configs)?;
+        let key = Self::get_redis_key(&headers.tenant_id, &id);
+        let now = date_time::now_unix_timestamp();
+
+        let mut labels_with_status = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let label = conn_choice.to_string();
+            let mut buckets = self.fetch_buckets(&key, &params, &label).await?;
+
+            let mut full_buckets = buckets
+                .iter_mut()
+                .filter_map(|(bucket_name, bucket)| {
+                    bucket.leak(&config, now);
+                    bucket.is_full(&config).then(|| bucket_name.clone())
+                })
+                .collect::<Vec<_>>();
+            full_buckets.sort();
+
+            labels_with_status.push(LabelWithStatus {
+                label,
+                elimination_information: Some(EliminationInformation {
+                    entity: Some(BucketInformation {
+                        is_eliminated: !full_buckets.is_empty(),
+                        bucket_name: full_buckets,
+                    }),
+                    global: None,
+                }),
+            });
+        }
+
+        let response = EliminationResponse { labels_with_status };
+
+        logger::info!(embedded_dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_elimination_bucket_config(
+        &self,
+        id: String,
+        params: String,
+        report: Vec<RoutableConnectorChoiceWithBucketName>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateEliminationBucketResponse> {
+        let config = Self::get_bucket_config(configs)?;
+        let key = Self::get_redis_key(&headers.tenant_id, &id);
+
+        if !report.is_empty() {
+            let mut values = vec![
+                date_time::now_unix_timestamp().to_string(),
+                self.state_ttl_in_secs.to_string(),
+                config.bucket_size.to_string(),
+                config.bucket_leak_interval_in_secs.to_string(),
+            ];
+            for conn_choice_with_bucket in report {
+                values.push(Self::get_hash_field(
+                    &params,
+                    &conn_choice_with_bucket
+                        .routable_connector_choice
+                        .to_string(),
+                ));
+                values.push(conn_choice_with_bucket.bucket_name);
+            }
+
+            self.redis_conn
+                .evaluate_redis_script::<_, i64>(
+                    UPDATE_BUCKETS_SCRIPT,
+                    vec![RedisKey::from(key.as_str()).tenant_aware_key(&self.redis_conn)],
+                    values,
+                )
+                .await
+                .change_context(DynamicRoutingError::EmbeddedStateFailure(
+                    "Failed to update the elimination buckets".to_string(),
+                ))?;
+        }
+
+        Ok(UpdateEliminationBucketResponse {
+            status: UpdationStatus::BucketUpdationSucceeded.into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_elimination_bucket(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateBucketResponse> {
+        self.redis_conn
+            .delete_key(&Self::get_redis_key(&headers.tenant_id, &id).into())
+            .await
+            .change_context(DynamicRoutingError::EmbeddedStateFailure(
+                "Failed to invalidate the elimination buckets".to_string(),
+            ))?;
+
+        Ok(InvalidateBucketResponse {
+            status: InvalidationStatus::BucketInvalidationSucceeded.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaky_bucket_elimination_and_recovery() {
+        let config = EliminationBucketConfig {
+            bucket_size: 2,
+            bucket_leak_interval_in_secs: 60,
+        };
+        // Buckets are filled by the update script, two failures at 0 and 10 fill the bucket
+        let mut bucket = LeakyBucket {
+            count: 2,
+            last_leaked_at: 0,
+        };
+
+        bucket.leak(&config, 20);
+        assert!(bucket.is_full(&config));
+
+        // One entry leaks out after a single interval, which lifts the elimination
+        bucket.leak(&config, 70);
+        assert_eq!(
+            bucket,
+            LeakyBucket {
+                count: 1,
+                last_leaked_at: 60,
+            }
+        );
+        assert!(!bucket.is_full(&config));
+    }
+}
@@ -0,0 +1,524 @@
+use std::sync::Arc;
+
+use api_models::routing::{
+    RoutableConnectorChoice, RoutableConnectorChoiceWithStatus, SuccessBasedRoutingConfig,
+};
+use common_utils::{date_time, ext_traits::OptionExt, transformers::ForeignTryFrom};
+use error_stack::ResultExt;
+use rand::seq::SliceRandom;
+use redis_interface::{RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing};
+
+use super::{
+    success_rate_client::{
+        success_rate::{
+            invalidate_windows_response::InvalidationStatus,
+            update_success_rate_window_response::UpdationStatus, LabelWithScore, RoutingApproach,
+        },
+        CalGlobalSuccessRateConfig, CalGlobalSuccessRateResponse, CalSuccessRateConfig,
+        CalSuccessRateResponse, InvalidateWindowsResponse, ProtoSpecificityLevel,
+        SuccessBasedDynamicRouting, UpdateSuccessRateWindowConfig, UpdateSuccessRateWindowResponse,
+    },
+    DynamicRoutingError, DynamicRoutingResult,
+};
+use crate::grpc_client::GrpcHeaders;
+
+/// Redis key prefix under which the success rate windows are stored
+const SUCCESS_RATE_KEY_PREFIX: &str = "dynamic_routing_success_rate";
+/// Entity id used for the windows that are shared across all the entities of a tenant
+const GLOBAL_ENTITY_ID: &str = "global";
+
+/// Records the status of a transaction in the window of each label in a single step, so that
+/// concurrent updates of the same window are all counted. See [`SuccessRateWindow`] for the
+/// format of a window.
+///
+/// KEYS: windows hash
+/// ARGV: now, ttl, max aggregates size, max total count of a block (-1 when every transaction is
+/// a block of its own), block duration in seconds (-1 when blocks are not time bound), followed by
+/// the hash field and status (1 or 0) of every label
+const UPDATE_WINDOWS_SCRIPT: &str = r#"
+local key = KEYS[1]
+local now, ttl, max_aggregates_size = tonumber(ARGV[1]), ARGV[2], tonumber(ARGV[3])
+local max_total_count, block_duration = tonumber(ARGV[4]), tonumber(ARGV[5])
+
+for i = 6, #ARGV, 2 do
+    local field, is_success = ARGV[i], ARGV[i + 1] == '1'
+    local window = {}
+    for value in string.gmatch(redis.call('HGET', key, field) or '', '[^,]+') do
+        window[#window + 1] = tonumber(value)
+    end
+    if #window < 3 or (#window - 3) % 2 ~= 0 then
+        window = {now, 0, 0}
+    end
+
+    if window[3] == 0 then
+        window[1] = now
+    end
+    window[3] = window[3] + 1
+    if is_success then
+        window[2] = window[2] + 1
+    end
+
+    local is_block_complete = max_total_count < 0 or window[3] >= max_total_count
+        or (block_duration >= 0 and now - window[1] >= block_duration)
+    if is_block_complete then
+        window[#window + 1] = window[2]
+        window[#window + 1] = window[3]
+        window[2], window[3] = 0, 0
+        while (#window - 3) / 2 > max_aggregates_size do
+            table.remove(window, 4)
+            table.remove(window, 4)
+        end
+    end
+
+    redis.call('HSET', key, field, table.concat(window, ','))
+end
+redis.call('EXPIRE', key, ttl)
+
+return 1
+"#;
+
+/// Success rate calculator which keeps the windows in redis and computes the scores in-process.
+///
+/// The windows of an entity are stored in a single redis hash, with one field per
+/// `params` (the dimensions such as payment method and currency) and label combination.
+#[derive(Clone)]
+pub struct EmbeddedSuccessRateCalculator {
+    redis_conn: Arc<RedisConnectionPool>,
+    state_ttl_in_secs: i64,
+}
+
+impl std::fmt::Debug for EmbeddedSuccessRateCalculator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddedSuccessRateCalculator")
+            .field("state_ttl_in_secs", &self.state_ttl_in_secs)
+            .finish()
+    }
+}
+
+/// Count of the successful and total transactions in a block
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Aggregate {
+    success_count: u32,
+    total_count: u32,
+}
+
+/// Sliding window of aggregates for a single label.
+///
+/// Windows are stored as comma separated integers: the unix timestamp at which the current block
+/// received its first transaction, the success and total count of the current block, followed by
+/// the success and total count of every completed block, oldest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct SuccessRateWindow {
+    /// Completed blocks, oldest first
+    aggregates: Vec<Aggregate>,
+    /// Block which is currently being filled
+    current_block: Aggregate,
+}
+
+impl SuccessRateWindow {
+    fn parse(value: &str) -> Option<Self> {
+        let counts = value
+            .split(',')
+            .skip(1)
+            .map(|count| count.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let mut blocks = counts.chunks(2).map(|block| match block {
+            [success_count, total_count] => Some(Aggregate {
+                success_count: *success_count,
+                total_count: *total_count,
+            }),
+            _ => None,
+        });
+
+        Some(Self {
+            current_block: blocks.next().flatten()?,
+            aggregates: blocks.collect::<Option<Vec<_>>>()?,
+        })
+    }
+
+    fn score(&self, min_aggregates_size: u32, default_success_rate: f64) -> f64 {
+        let aggregates_size = u32::try_from(self.aggregates.len()).unwrap_or(u32::MAX);
+        if aggregates_size < min_aggregates_size {
+            return default_success_rate;
+        }
+
+        let (success_count, total_count) = self
+            .aggregates
+            .iter()
+            .chain(std::iter::once(&self.current_block))
+            .fold((0u32, 0u32), |(success, total), aggregate| {
+                (
+                    success.saturating_add(aggregate.success_count),
+                    total.saturating_add(aggregate.total_count),
+                )
+            });
+
+        if total_count == 0 {
+            default_success_rate
+        } else {
+            f64::from(success_count) / f64::from(total_count) * 100.0
+        }
+    }
+}
+
+impl EmbeddedSuccessRateCalculator {
+    /// Create a success rate calculator backed by the given redis connection
+    pub fn new(redis_conn: Arc<RedisConnectionPool>, state_ttl_in_secs: i64) -> Self {
+        Self {
+            redis_conn,
+            state_ttl_in_secs,
+        }
+    }
+
+    fn get_redis_key(tenant_id: &str, id: &str) -> String {
+        format!("{SUCCESS_RATE_KEY_PREFIX}_{tenant_id}_{id}")
+    }
+
+    fn get_hash_field(params: &str, label: &str) -> String {
+        format!("{params}:{label}")
+    }
+
+    async fn fetch_windows(
+        &self,
+        key: &str,
+        params: &str,
+        labels: &[String],
+    ) -> DynamicRoutingResult<Vec<SuccessRateWindow>> {
+        let mut windows = Vec::with_capacity(labels.len());
+        for label in labels {
+            let window = self
+                .redis_conn
+                .get_hash_field::<Option<String>>(&key.into(), &Self::get_hash_field(params, label))
+                .await
+                .change_context(DynamicRoutingError::EmbeddedStateFailure(
+                    "Failed to fetch the success rate window".to_string(),
+                ))?
+                .and_then(|value| {
+                    SuccessRateWindow::parse(&value).or_else(|| {
+                        logger::warn!(window = %value, "Ignoring malformed success rate window");
+                        None
+                    })
+                })
+                .unwrap_or_default();
+            windows.push(window);
+        }
+        Ok(windows)
+    }
+
+    async fn update_windows(
+        &self,
+        key: &str,
+        params: &str,
+        labels_with_status: Vec<(String, bool)>,
+        config: &UpdateSuccessRateWindowConfig,
+    ) -> DynamicRoutingResult<()> {
+        let (max_total_count, block_duration_in_secs) = config
+            .current_block_threshold
+            .as_ref()
+            .map_or((-1, -1), |threshold| {
+                (
+                    i64::try_from(threshold.max_total_count).unwrap_or(i64::MAX),
+                    threshold.duration_in_mins.map_or(-1, |duration| {
+                        i64::try_from(duration.saturating_mul(60)).unwrap_or(i64::MAX)
+                    }),
+                )
+            });
+
+        let mut values = vec![
+            date_time::now_unix_timestamp().to_string(),
+            self.state_ttl_in_secs.to_string(),
+            config.max_aggregates_size.to_string(),
+            max_total_count.to_string(),
+            block_duration_in_secs.to_string(),
+        ];
+        for (label, status) in labels_with_status {
+            values.push(Self::get_hash_field(params, &label));
+            values.push(if status { "1" } else { "0" }.to_string());
+        }
+
+        self.redis_conn
+            .evaluate_redis_script::<_, i64>(
+                UPDATE_WINDOWS_SCRIPT,
+                vec![RedisKey::from(key).tenant_aware_key(&self.redis_conn)],
+                values,
+            )
+            .await
+            .change_context(DynamicRoutingError::EmbeddedStateFailure(
+                "Failed to update the success rate windows".to_string(),
+            ))?;
+        Ok(())
+    }
+
+    async fn calculate_scores(
+        &self,
+        key: &str,
+        params: &str,
+        labels: Vec<String>,
+        window_labels: &[String],
+        min_aggregates_size: u32,
+        default_success_rate: f64,
+    ) -> DynamicRoutingResult<Vec<LabelWithScore>> {
+        let windows = self.fetch_windows(key, params, window_labels).await?;
+        let mut labels_with_score = labels
+            .into_iter()
+            .zip(windows)
+            .map(|(label, window)| LabelWithScore {
+                score: window.score(min_aggregates_size, default_success_rate),
+                label,
+            })
+            .collect::<Vec<_>>();
+        labels_with_score.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(labels_with_score)
+    }
+}
+
+/// Decides whether the labels are explored instead of being ranked by their score, for the given
+/// percentage of the requests
+fn should_explore(exploration_percent: Option<f64>) -> bool {
+    exploration_percent.is_some_and(|percent| rand::random::<f64>() * 100.0 < percent)
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for EmbeddedSuccessRateCalculator {
+    #[instrument(skip_all)]
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let config = success_rate_based_config
+            .config
+            .get_required_value("config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "config".to_string(),
+            })
+            .and_then(CalSuccessRateConfig::foreign_try_from)?;
+
+        let labels = label_input
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let is_global = config.specificity_level == Some(ProtoSpecificityLevel::Global.into());
+        let (key, window_labels) = if is_global {
+            (
+                Self::get_redis_key(&headers.tenant_id, GLOBAL_ENTITY_ID),
+                label_input
+                    .iter()
+                    .map(|conn_choice| conn_choice.connector.to_string())
+                    .collect::<Vec<_>>(),
+            )
+        } else {
+            (Self::get_redis_key(&headers.tenant_id, &id), labels.clone())
+        };
+
+        let mut labels_with_score = self
+            .calculate_scores(
+                &key,
+                &params,
+                labels,
+                &window_labels,
+                config.min_aggregates_size,
+                config.default_success_rate,
+            )
+            .await?;
+
+        // Exploration routes to the labels in a random order, so that labels which are not
+        // ranked first keep receiving the transactions their windows are built from
+        let routing_approach = if should_explore(config.exploration_percent) {
+            labels_with_score.shuffle(&mut rand::thread_rng());
+            RoutingApproach::Exploration
+        } else {
+            RoutingApproach::Exploitation
+        };
+
+        let response = CalSuccessRateResponse {
+            labels_with_score,
+            routing_approach: routing_approach.into(),
+        };
+
+        logger::info!(embedded_dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let config = success_rate_based_config
+            .config
+            .get_required_value("config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "config".to_string(),
+            })
+            .and_then(UpdateSuccessRateWindowConfig::foreign_try_from)?;
+
+        let labels_with_status = label_input
+            .iter()
+            .map(|conn_choice| {
+                (
+                    conn_choice.routable_connector_choice.to_string(),
+                    conn_choice.status,
+                )
+            })
+            .collect();
+
+        let global_labels_with_status = label_input
+            .iter()
+            .map(|conn_choice| {
+                (
+                    conn_choice.routable_connector_choice.connector.to_string(),
+                    conn_choice.status,
+                )
+            })
+            .collect();
+
+        self.update_windows(
+            &Self::get_redis_key(&headers.tenant_id, &id),
+            &params,
+            labels_with_status,
+            &config,
+        )
+        .await?;
+
+        self.update_windows(
+            &Self::get_redis_key(&headers.tenant_id, GLOBAL_ENTITY_ID),
+            &params,
+            global_labels_with_status,
+            &config,
+        )
+        .await?;
+
+        Ok(UpdateSuccessRateWindowResponse {
+            status: UpdationStatus::WindowUpdationSucceeded.into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_success_rate_routing_keys(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateWindowsResponse> {
+        self.redis_conn
+            .delete_key(&Self::get_redis_key(&headers.tenant_id, &id).into())
+            .await
+            .change_context(DynamicRoutingError::EmbeddedStateFailure(
+                "Failed to invalidate the success rate windows".to_string(),
+            ))?;
+
+        Ok(InvalidateWindowsResponse {
+            status: InvalidationStatus::WindowInvalidationSucceeded.into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn calculate_entity_and_global_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalGlobalSuccessRateResponse> {
+        let config = success_rate_based_config
+            .config
+            .get_required_value("config")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "config".to_string(),
+            })
+            .and_then(CalGlobalSuccessRateConfig::foreign_try_from)?;
+
+        let labels = label_input
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let global_labels = label_input
+            .iter()
+            .map(|conn_choice| conn_choice.connector.to_string())
+            .collect::<Vec<_>>();
+
+        let entity_scores_with_labels = self
+            .calculate_scores(
+                &Self::get_redis_key(&headers.tenant_id, &id),
+                &params,
+                labels.clone(),
+                &labels,
+                config.entity_min_aggregates_size,
+                config.entity_default_success_rate,
+            )
+            .await?;
+
+        let global_scores_with_labels = self
+            .calculate_scores(
+                &Self::get_redis_key(&headers.tenant_id, GLOBAL_ENTITY_ID),
+                &params,
+                global_labels.clone(),
+                &global_labels,
+                config.entity_min_aggregates_size,
+                config.entity_default_success_rate,
+            )
+            .await?;
+
+        let response = CalGlobalSuccessRateResponse {
+            entity_scores_with_labels,
+            global_scores_with_labels,
+        };
+
+        logger::info!(embedded_dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_success_rate_window_parse_and_score() {
+        let window = SuccessRateWindow::parse("1700000000,1,1,2,2,0,2").unwrap();
+        assert_eq!(
+            window,
+            SuccessRateWindow {
+                aggregates: vec![
+                    Aggregate {
+                        success_count: 2,
+                        total_count: 2,
+                    },
+                    Aggregate {
+                        success_count: 0,
+                        total_count: 2,
+                    },
+                ],
+                current_block: Aggregate {
+                    success_count: 1,
+                    total_count: 1,
+                },
+            }
+        );
+        assert_eq!(window.score(2, 50.0), 60.0);
+        assert_eq!(window.score(3, 10.0), 10.0);
+
+        assert_eq!(SuccessRateWindow::parse("1700000000,1,1,2"), None);
+        assert_eq!(SuccessRateWindow::parse("{\"aggregates\":[]}"), None);
+    }
+
+    #[test]
+    fn test_exploration_follows_percentage() {
+        assert!(!should_explore(None));
+        assert!(!should_explore(Some(0.0)));
+        assert!(should_explore(Some(100.0)));
+    }
+}
@@ -293,6 +293,7 @@ impl
                 },
                 authentication_value: response_auth.authentication_value,
                 ds_trans_id: Some(response_auth.ds_trans_id),
+                is_merchant_whitelisted: None,
                 connector_metadata: None,
             });
         Ok(Self {
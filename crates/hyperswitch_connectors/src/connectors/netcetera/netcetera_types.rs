@@ -189,21 +189,22 @@ impl ThreeDSRequestor {
         force_3ds_challenge: bool,
         message_version: SemanticVersion,
     ) -> Self {
-        // if sca exemption is provided, we need to set the challenge indicator to NoChallengeRequestedTransactionalRiskAnalysis
+        // if sca exemption is provided, we need to set the challenge indicator matching the requested exemption
         let three_ds_requestor_challenge_ind = if force_3ds_challenge {
             Some(SingleOrListElement::get_version_checked(
                 message_version,
                 ThreeDSRequestorChallengeIndicator::ChallengeRequestedMandate,
             ))
-        } else if let Some(common_enums::ScaExemptionType::TransactionRiskAnalysis) =
-            psd2_sca_exemption_type
-        {
-            Some(SingleOrListElement::get_version_checked(
-                message_version,
-                ThreeDSRequestorChallengeIndicator::NoChallengeRequestedTransactionalRiskAnalysis,
-            ))
         } else {
-            None
+            psd2_sca_exemption_type.map(|exemption_type| {
+                SingleOrListElement::get_version_checked(
+                    message_version.clone(),
+                    ThreeDSRequestorChallengeIndicator::from_exemption_type(
+                        exemption_type,
+                        &message_version,
+                    ),
+                )
+            })
         };
 
         Self {
@@ -270,6 +271,8 @@ pub struct ThreeDSRequestorAuthenticationInformation {
 ///  - 07 -> No challenge requested (strong consumer authentication is already performed)
 ///  - 08 -> No challenge requested (utilise whitelist exemption if no challenge required)
 ///  - 09 -> Challenge requested (whitelist prompt requested if challenge required).
+///  - 10 -> No challenge requested (utilise low value exemption), EMV 3DS 2.3.1 and later
+///  - 11 -> No challenge requested (secure corporate payment exemption), EMV 3DS 2.3.1 and later
 ///  - Additionally, 80-99 can be used for PS-specific values, regardless of protocol version.
 ///
 /// If the element is not provided, the expected action is that the ACS would interpret as 01 -> No preference.
@@ -299,6 +302,38 @@ pub enum ThreeDSRequestorChallengeIndicator {
     NoChallengeRequestedWhitelistExemption,
     #[serde(rename = "09")]
     ChallengeRequestedWhitelistPrompt,
+    #[serde(rename = "10")]
+    NoChallengeRequestedLowValueExemption,
+    #[serde(rename = "11")]
+    NoChallengeRequestedSecureCorporatePayment,
+}
+
+impl ThreeDSRequestorChallengeIndicator {
+    /// Low value and secure corporate payment indicators were introduced with EMV 3DS 2.3.1, older
+    /// versions fall back to a plain no challenge request
+    fn from_exemption_type(
+        exemption_type: common_enums::ScaExemptionType,
+        message_version: &SemanticVersion,
+    ) -> Self {
+        let is_version_2_3_or_above =
+            message_version.get_major() >= 2 && message_version.get_minor() >= 3;
+        match exemption_type {
+            common_enums::ScaExemptionType::TransactionRiskAnalysis => {
+                Self::NoChallengeRequestedTransactionalRiskAnalysis
+            }
+            common_enums::ScaExemptionType::TrustedBeneficiary => {
+                Self::NoChallengeRequestedWhitelistExemption
+            }
+            common_enums::ScaExemptionType::LowValue if is_version_2_3_or_above => {
+                Self::NoChallengeRequestedLowValueExemption
+            }
+            common_enums::ScaExemptionType::SecureCorporatePayment if is_version_2_3_or_above => {
+                Self::NoChallengeRequestedSecureCorporatePayment
+            }
+            common_enums::ScaExemptionType::LowValue
+            | common_enums::ScaExemptionType::SecureCorporatePayment => Self::NoChallengeRequested,
+        }
+    }
 }
 
 /// This field contains information about how the 3DS Requestor authenticated the cardholder as part of a previous 3DS transaction.
@@ -1258,7 +1293,6 @@ pub struct MerchantData {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
 pub enum WhitelistStatus {
     /// 3DS Requestor is whitelisted by cardholder
     Y,
@@ -1274,6 +1308,17 @@ pub enum WhitelistStatus {
     U,
 }
 
+impl WhitelistStatus {
+    /// Whether the merchant is whitelisted, `None` when the status is pending or unknown
+    pub fn is_whitelisted(&self) -> Option<bool> {
+        match self {
+            Self::Y => Some(true),
+            Self::N | Self::E | Self::R => Some(false),
+            Self::P | Self::U => None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde_with::skip_serializing_none]
@@ -157,12 +157,20 @@ impl
                     }
                     Some(ACSChallengeMandatedIndicator::N) | None => AuthNFlowType::Frictionless,
                 };
+                // trustListStatus replaces whiteListStatus from EMV 3DS 2.3.1
+                let is_merchant_whitelisted = response
+                    .authentication_response
+                    .trust_list_status
+                    .as_ref()
+                    .or(response.authentication_response.white_list_status.as_ref())
+                    .and_then(netcetera_types::WhitelistStatus::is_whitelisted);
                 Ok(AuthenticationResponseData::AuthNResponse {
                     authn_flow_type,
                     authentication_value: response.authentication_value,
                     trans_status: response.trans_status,
                     connector_metadata: None,
                     ds_trans_id: response.authentication_response.ds_trans_id,
+                    is_merchant_whitelisted,
                 })
             }
             NetceteraAuthenticationResponse::Error(error_response) => Err(ErrorResponse {
@@ -658,6 +666,8 @@ pub struct AuthenticationResponse {
     #[serde(rename = "dsTransID")]
     pub ds_trans_id: Option<String>,
     pub acs_signed_content: Option<String>,
+    pub white_list_status: Option<netcetera_types::WhitelistStatus>,
+    pub trust_list_status: Option<netcetera_types::WhitelistStatus>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -183,6 +183,7 @@ impl
                     authentication_value: response.authentication_value,
                     connector_metadata: None,
                     ds_trans_id: Some(response.ds_trans_id),
+                    is_merchant_whitelisted: None,
                 })
             }
             ThreedsecureioAuthenticationResponse::Error(err_response) => match *err_response {
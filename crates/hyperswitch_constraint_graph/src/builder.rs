@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -42,7 +42,7 @@ pub struct ConstraintGraphBuilder<V: ValueNode> {
     domain_identifier_map: FxHashMap<DomainIdentifier, DomainId>,
     value_map: FxHashMap<NodeValue<V>, NodeId>,
     edges_map: FxHashMap<(NodeId, NodeId, Option<DomainId>), EdgeId>,
-    node_info: DenseMap<NodeId, Option<&'static str>>,
+    node_info: DenseMap<NodeId, Option<Cow<'static, str>>>,
     node_metadata: DenseMap<NodeId, Option<Arc<dyn Metadata>>>,
 }
 
@@ -118,7 +118,7 @@ where
     ) -> NodeId {
         self.value_map.get(&value).copied().unwrap_or_else(|| {
             let node_id = self.nodes.push(Node::new(NodeType::Value(value.clone())));
-            let _node_info_id = self.node_info.push(info);
+            let _node_info_id = self.node_info.push(info.map(Cow::Borrowed));
 
             let _node_metadata_id = self
                 .node_metadata
@@ -201,7 +201,7 @@ where
             .try_for_each(|(node_id, _, _)| self.ensure_node_exists(*node_id))?;
 
         let aggregator_id = self.nodes.push(Node::new(NodeType::AllAggregator));
-        let _aggregator_info_id = self.node_info.push(info);
+        let _aggregator_info_id = self.node_info.push(info.map(Cow::Borrowed));
 
         let _node_metadata_id = self
             .node_metadata
@@ -226,7 +226,7 @@ where
             .try_for_each(|(node_id, _, _)| self.ensure_node_exists(*node_id))?;
 
         let aggregator_id = self.nodes.push(Node::new(NodeType::AnyAggregator));
-        let _aggregator_info_id = self.node_info.push(info);
+        let _aggregator_info_id = self.node_info.push(info.map(Cow::Borrowed));
 
         let _node_metadata_id = self
             .node_metadata
@@ -262,7 +262,7 @@ where
             .push(Node::new(NodeType::InAggregator(FxHashSet::from_iter(
                 values,
             ))));
-        let _aggregator_info_id = self.node_info.push(info);
+        let _aggregator_info_id = self.node_info.push(info.map(Cow::Borrowed));
 
         let _node_metadata_id = self
             .node_metadata
@@ -271,6 +271,33 @@ where
         Ok(node_id)
     }
 
+    /// Attach the info and metadata of a node imported from another graph, retaining any that
+    /// the node already has
+    pub(crate) fn inherit_node_details(
+        &mut self,
+        node_id: NodeId,
+        info: Option<Cow<'static, str>>,
+        metadata: Option<Arc<dyn Metadata>>,
+    ) -> Result<(), GraphError<V>> {
+        let node_info = self
+            .node_info
+            .get_mut(node_id)
+            .ok_or(GraphError::NodeNotFound)?;
+        if node_info.is_none() {
+            *node_info = info;
+        }
+
+        let node_metadata = self
+            .node_metadata
+            .get_mut(node_id)
+            .ok_or(GraphError::NodeNotFound)?;
+        if node_metadata.is_none() {
+            *node_metadata = metadata;
+        }
+
+        Ok(())
+    }
+
     fn ensure_node_exists(&self, id: NodeId) -> Result<(), GraphError<V>> {
         if self.nodes.contains_key(id) {
             Ok(())
@@ -0,0 +1,249 @@
+use std::hash::{Hash, Hasher};
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+
+use crate::{
+    error::GraphError,
+    graph::ConstraintGraph,
+    types::{NodeId, NodeType, NodeValue, ValueNode},
+};
+
+/// Difference between the value nodes of two graphs, and the constraints leading to them
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GraphDiff<V: ValueNode> {
+    /// Value nodes only present in the new graph
+    pub added: Vec<NodeValue<V>>,
+    /// Value nodes only present in the old graph
+    pub removed: Vec<NodeValue<V>>,
+    /// Value nodes present in both graphs, but with different constraints leading to them
+    pub modified: Vec<NodeValue<V>>,
+}
+
+impl<V: ValueNode> GraphDiff<V> {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl<V> ConstraintGraph<V>
+where
+    V: ValueNode,
+{
+    /// Compare the graph with a newer version of it.
+    ///
+    /// Nodes are compared structurally rather than by their ids, so graphs built in a different
+    /// order, or merged from different subgraphs, compare equal as long as they encode the same
+    /// constraints.
+    pub fn diff(&self, new: &Self) -> Result<GraphDiff<V>, GraphError<V>> {
+        let old_signatures = self.get_value_signatures()?;
+        let new_signatures = new.get_value_signatures()?;
+
+        let mut diff = GraphDiff {
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        };
+
+        for (value, old_signature) in &old_signatures {
+            match new_signatures.get(value) {
+                None => diff.removed.push((*value).clone()),
+                Some(new_signature) if new_signature != old_signature => {
+                    diff.modified.push((*value).clone())
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.added.extend(
+            new_signatures
+                .keys()
+                .filter(|value| !old_signatures.contains_key(*value))
+                .map(|value| (*value).clone()),
+        );
+
+        Ok(diff)
+    }
+
+    fn get_value_signatures(&self) -> Result<FxHashMap<&NodeValue<V>, u64>, GraphError<V>> {
+        let mut signatures = FxHashMap::default();
+        let mut visiting = FxHashSet::default();
+        let mut value_signatures = FxHashMap::default();
+
+        for (value, node_id) in &self.value_map {
+            let signature = self.get_node_signature(*node_id, &mut signatures, &mut visiting)?;
+            value_signatures.insert(value, signature);
+        }
+
+        Ok(value_signatures)
+    }
+
+    /// Hash of the node along with all the constraints leading to it, independent of the ids
+    /// assigned to the nodes in this graph
+    fn get_node_signature(
+        &self,
+        node_id: NodeId,
+        signatures: &mut FxHashMap<NodeId, u64>,
+        visiting: &mut FxHashSet<NodeId>,
+    ) -> Result<u64, GraphError<V>> {
+        if let Some(signature) = signatures.get(&node_id) {
+            return Ok(*signature);
+        }
+
+        let node = self.nodes.get(node_id).ok_or(GraphError::NodeNotFound)?;
+        let mut hasher = FxHasher::default();
+        match &node.node_type {
+            NodeType::AllAggregator => "all_aggregator".hash(&mut hasher),
+            NodeType::AnyAggregator => "any_aggregator".hash(&mut hasher),
+            NodeType::InAggregator(expected) => {
+                "in_aggregator".hash(&mut hasher);
+                let mut value_hashes = expected.iter().map(get_hash).collect::<Vec<_>>();
+                value_hashes.sort_unstable();
+                value_hashes.hash(&mut hasher);
+            }
+            NodeType::Value(node_value) => {
+                "value".hash(&mut hasher);
+                node_value.hash(&mut hasher);
+            }
+        }
+
+        // A node on a cycle is identified only by its own contents when it is reached again
+        if !visiting.insert(node_id) {
+            return Ok(hasher.finish());
+        }
+
+        let mut pred_hashes = Vec::with_capacity(node.preds.len());
+        for edge_id in &node.preds {
+            let edge = self.edges.get(*edge_id).ok_or(GraphError::EdgeNotFound)?;
+            let domain = edge
+                .domain
+                .map(|domain_id| {
+                    self.domain
+                        .get(domain_id)
+                        .map(|domain| domain.domain_identifier.into_inner())
+                        .ok_or(GraphError::DomainNotFound)
+                })
+                .transpose()?;
+            let pred_signature = self.get_node_signature(edge.pred, signatures, visiting)?;
+
+            pred_hashes.push(get_hash(&(
+                pred_signature,
+                edge.relation,
+                edge.strength,
+                domain,
+            )));
+        }
+        pred_hashes.sort_unstable();
+        pred_hashes.hash(&mut hasher);
+
+        visiting.remove(&node_id);
+        let signature = hasher.finish();
+        signatures.insert(node_id, signature);
+
+        Ok(signature)
+    }
+}
+
+fn get_hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{
+        builder::ConstraintGraphBuilder,
+        types::{DomainId, KeyNode, Relation, Strength},
+    };
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+    struct TestKey;
+
+    impl KeyNode for TestKey {}
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+    struct TestValue(u8);
+
+    impl ValueNode for TestValue {
+        type Key = TestKey;
+
+        fn get_key(&self) -> Self::Key {
+            TestKey
+        }
+    }
+
+    /// Build a graph in which each of the given values requires the value before it, with the
+    /// nodes created in reverse order when `reversed` is set
+    fn build_chain(
+        values: &[u8],
+        relation: Relation,
+        reversed: bool,
+    ) -> ConstraintGraph<TestValue> {
+        let mut builder = ConstraintGraphBuilder::new();
+        let mut node_values = values
+            .iter()
+            .map(|value| NodeValue::Value(TestValue(*value)))
+            .collect::<Vec<_>>();
+        if reversed {
+            node_values.reverse();
+        }
+        let mut node_ids = node_values
+            .into_iter()
+            .map(|value| builder.make_value_node(value, None, None::<()>))
+            .collect::<Vec<_>>();
+        if reversed {
+            node_ids.reverse();
+        }
+
+        for (pred, succ) in node_ids.iter().zip(node_ids.iter().skip(1)) {
+            builder
+                .make_edge(*pred, *succ, Strength::Strong, relation, None::<DomainId>)
+                .unwrap();
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_diff_ignores_node_order() {
+        let graph = build_chain(&[1, 2, 3], Relation::Positive, false);
+        let reordered_graph = build_chain(&[1, 2, 3], Relation::Positive, true);
+
+        assert!(graph.diff(&reordered_graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_of_added_and_removed_values() {
+        let graph = build_chain(&[1, 2], Relation::Positive, false);
+        let new_graph = build_chain(&[1, 3], Relation::Positive, false);
+
+        let diff = graph.diff(&new_graph).unwrap();
+        assert_eq!(diff.added, vec![NodeValue::Value(TestValue(3))]);
+        assert_eq!(diff.removed, vec![NodeValue::Value(TestValue(2))]);
+        assert!(diff.modified.is_empty());
+    }
+
+    #[test]
+    fn test_diff_of_modified_constraints() {
+        let graph = build_chain(&[1, 2, 3], Relation::Positive, false);
+        let new_graph = build_chain(&[1, 2, 3], Relation::Negative, false);
+
+        // The constraints leading to the first value are unchanged, whereas the change in the
+        // constraint on the second value carries over to the third
+        let mut modified = graph.diff(&new_graph).unwrap().modified;
+        modified.sort_by_key(|value| match value {
+            NodeValue::Value(TestValue(value)) => *value,
+            NodeValue::Key(_) => 0,
+        });
+        assert_eq!(
+            modified,
+            vec![
+                NodeValue::Value(TestValue(2)),
+                NodeValue::Value(TestValue(3))
+            ]
+        );
+    }
+}
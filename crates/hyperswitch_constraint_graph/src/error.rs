@@ -1,4 +1,7 @@
-use std::sync::{Arc, Weak};
+use std::{
+    borrow::Cow,
+    sync::{Arc, Weak},
+};
 
 use crate::types::{Metadata, NodeValue, Relation, RelationResolution, ValueNode};
 
@@ -16,19 +19,19 @@ pub enum AnalysisTrace<V: ValueNode> {
         value: NodeValue<V>,
         relation: Relation,
         predecessors: Option<ValueTracePredecessor<V>>,
-        info: Option<&'static str>,
+        info: Option<Cow<'static, str>>,
         metadata: Option<Arc<dyn Metadata>>,
     },
 
     AllAggregation {
         unsatisfied: Vec<Weak<AnalysisTrace<V>>>,
-        info: Option<&'static str>,
+        info: Option<Cow<'static, str>>,
         metadata: Option<Arc<dyn Metadata>>,
     },
 
     AnyAggregation {
         unsatisfied: Vec<Weak<AnalysisTrace<V>>>,
-        info: Option<&'static str>,
+        info: Option<Cow<'static, str>>,
         metadata: Option<Arc<dyn Metadata>>,
     },
 
@@ -36,7 +39,7 @@ pub enum AnalysisTrace<V: ValueNode> {
         expected: Vec<V>,
         found: Option<V>,
         relation: Relation,
-        info: Option<&'static str>,
+        info: Option<Cow<'static, str>>,
         metadata: Option<Arc<dyn Metadata>>,
     },
     Contradiction {
@@ -63,6 +66,8 @@ pub enum GraphError<V: ValueNode> {
     ValueNodeNotFound(V),
     #[error("No values provided for an 'in' aggregator node")]
     NoInAggregatorValues,
+    #[error("Serialized graph is of version {found}, expected version {expected}")]
+    SerializationVersionMismatch { found: u16, expected: u16 },
     #[error("Error during analysis: {0:#?}")]
     AnalysisError(Weak<AnalysisTrace<V>>),
 }
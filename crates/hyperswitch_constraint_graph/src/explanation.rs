@@ -0,0 +1,152 @@
+use std::sync::Weak;
+
+use crate::{
+    error::{AnalysisTrace, ValueTracePredecessor},
+    types::{KeyNode, NodeValue, Relation, ValueNode},
+};
+
+/// Human readable explanation of why a node in the graph could not be satisfied, built from the
+/// [`AnalysisTrace`] of a failed check.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Explanation {
+    /// The `info` of the node which could not be satisfied, if the graph provides one
+    pub constraint: Option<String>,
+    pub reason: String,
+    /// The constraints which in turn caused this one to be unsatisfied
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<Self>,
+}
+
+impl Explanation {
+    /// Build the explanation tree for an analysis trace.
+    ///
+    /// The trace is made up of weak references owned by the [`Memoization`](crate::Memoization)
+    /// used for the check, so this has to be called while it is still alive. Parts of the trace
+    /// which are no longer available are left out of the explanation.
+    pub fn from_trace<V: ValueNode>(trace: &Weak<AnalysisTrace<V>>) -> Option<Self> {
+        trace
+            .upgrade()
+            .map(|trace| Self::from_analysis_trace(&trace))
+    }
+
+    fn from_analysis_trace<V: ValueNode>(trace: &AnalysisTrace<V>) -> Self {
+        match trace {
+            AnalysisTrace::Value {
+                value,
+                relation,
+                predecessors,
+                info,
+                ..
+            } => {
+                let value = describe_node_value(value);
+                let (reason, causes) = match (relation, predecessors) {
+                    (Relation::Positive, None) => (
+                        format!("{value} is required but was not present in the context"),
+                        Vec::new(),
+                    ),
+                    (Relation::Negative, None) => (
+                        format!("{value} is not allowed but was present in the context"),
+                        Vec::new(),
+                    ),
+                    (_, Some(ValueTracePredecessor::Mandatory(predecessor))) => (
+                        format!("{value} was rejected as a mandatory constraint was not satisfied"),
+                        Self::from_traces(std::iter::once(predecessor.as_ref())),
+                    ),
+                    (_, Some(ValueTracePredecessor::OneOf(predecessors))) => (
+                        format!("{value} was rejected as none of its constraints were satisfied"),
+                        Self::from_traces(predecessors),
+                    ),
+                };
+
+                Self {
+                    constraint: info.as_deref().map(ToString::to_string),
+                    reason,
+                    causes,
+                }
+            }
+
+            AnalysisTrace::AllAggregation {
+                unsatisfied, info, ..
+            } => Self {
+                constraint: info.as_deref().map(ToString::to_string),
+                reason: "All of the following constraints must be satisfied".to_string(),
+                causes: Self::from_traces(unsatisfied),
+            },
+
+            AnalysisTrace::AnyAggregation {
+                unsatisfied, info, ..
+            } => Self {
+                constraint: info.as_deref().map(ToString::to_string),
+                reason: "At least one of the following constraints must be satisfied".to_string(),
+                causes: Self::from_traces(unsatisfied),
+            },
+
+            AnalysisTrace::InAggregation {
+                expected,
+                found,
+                relation,
+                info,
+                ..
+            } => {
+                let mut expected = expected.iter().map(ValueNode::describe).collect::<Vec<_>>();
+                expected.sort();
+                let expected = expected.join(", ");
+
+                let reason = match (relation, found) {
+                    (Relation::Positive, Some(found)) => format!(
+                        "{} is not one of the accepted values [{expected}]",
+                        found.describe()
+                    ),
+                    (Relation::Positive, None) => {
+                        format!("One of [{expected}] is required but none was present")
+                    }
+                    (Relation::Negative, Some(found)) => format!(
+                        "{} is one of the disallowed values [{expected}]",
+                        found.describe()
+                    ),
+                    (Relation::Negative, None) => {
+                        format!("None of [{expected}] is allowed")
+                    }
+                };
+
+                Self {
+                    constraint: info.as_deref().map(ToString::to_string),
+                    reason,
+                    causes: Vec::new(),
+                }
+            }
+
+            AnalysisTrace::Contradiction { .. } => Self {
+                constraint: None,
+                reason: "The constraints contradict each other".to_string(),
+                causes: Vec::new(),
+            },
+        }
+    }
+
+    fn from_traces<'a, V: ValueNode + 'a>(
+        traces: impl IntoIterator<Item = &'a Weak<AnalysisTrace<V>>>,
+    ) -> Vec<Self> {
+        traces.into_iter().filter_map(Self::from_trace).collect()
+    }
+
+    /// Collect the reasons of the leaves of the explanation tree, which are the constraints that
+    /// were actually violated by the context
+    pub fn get_root_causes(&self) -> Vec<&str> {
+        if self.causes.is_empty() {
+            vec![self.reason.as_str()]
+        } else {
+            self.causes
+                .iter()
+                .flat_map(|cause| cause.get_root_causes())
+                .collect()
+        }
+    }
+}
+
+fn describe_node_value<V: ValueNode>(value: &NodeValue<V>) -> String {
+    match value {
+        NodeValue::Key(key) => key.describe(),
+        NodeValue::Value(value) => value.describe(),
+    }
+}
@@ -1,4 +1,7 @@
-use std::sync::{Arc, Weak};
+use std::{
+    borrow::Cow,
+    sync::{Arc, Weak},
+};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -32,7 +35,7 @@ pub struct ConstraintGraph<V: ValueNode> {
     pub nodes: DenseMap<NodeId, Node<V>>,
     pub edges: DenseMap<EdgeId, Edge>,
     pub value_map: FxHashMap<NodeValue<V>, NodeId>,
-    pub node_info: DenseMap<NodeId, Option<&'static str>>,
+    pub node_info: DenseMap<NodeId, Option<Cow<'static, str>>>,
     pub node_metadata: DenseMap<NodeId, Option<Arc<dyn Metadata>>>,
 }
 
@@ -197,7 +200,7 @@ where
         if !unsatisfied.is_empty() {
             let err = Arc::new(AnalysisTrace::AllAggregation {
                 unsatisfied,
-                info: self.node_info.get(vald.node_id).copied().flatten(),
+                info: self.node_info.get(vald.node_id).cloned().flatten(),
                 metadata: self.node_metadata.get(vald.node_id).cloned().flatten(),
             });
 
@@ -264,7 +267,7 @@ where
         } else {
             let err = Arc::new(AnalysisTrace::AnyAggregation {
                 unsatisfied: unsatisfied.clone(),
-                info: self.node_info.get(vald.node_id).copied().flatten(),
+                info: self.node_info.get(vald.node_id).cloned().flatten(),
                 metadata: self.node_metadata.get(vald.node_id).cloned().flatten(),
             });
 
@@ -305,7 +308,7 @@ where
                     expected: expected.iter().cloned().collect(),
                     found: None,
                     relation: vald.relation,
-                    info: self.node_info.get(vald.node_id).copied().flatten(),
+                    info: self.node_info.get(vald.node_id).cloned().flatten(),
                     metadata: self.node_metadata.get(vald.node_id).cloned().flatten(),
                 });
 
@@ -324,7 +327,7 @@ where
                     expected: expected.iter().cloned().collect(),
                     found: Some(ctx_value.clone()),
                     relation: vald.relation,
-                    info: self.node_info.get(vald.node_id).copied().flatten(),
+                    info: self.node_info.get(vald.node_id).cloned().flatten(),
                     metadata: self.node_metadata.get(vald.node_id).cloned().flatten(),
                 });
 
@@ -402,7 +405,7 @@ where
                     let err = Arc::new(AnalysisTrace::Value {
                         value: val.clone(),
                         relation: vald.relation,
-                        info: self.node_info.get(vald.node_id).copied().flatten(),
+                        info: self.node_info.get(vald.node_id).cloned().flatten(),
                         metadata: self.node_metadata.get(vald.node_id).cloned().flatten(),
                         predecessors: Some(error::ValueTracePredecessor::Mandatory(Box::new(
                             trace.get_analysis_trace()?,
@@ -437,7 +440,7 @@ where
             let err = Arc::new(AnalysisTrace::Value {
                 value: val.clone(),
                 relation: vald.relation,
-                info: self.node_info.get(vald.node_id).copied().flatten(),
+                info: self.node_info.get(vald.node_id).cloned().flatten(),
                 metadata: self.node_metadata.get(vald.node_id).cloned().flatten(),
                 predecessors: Some(error::ValueTracePredecessor::OneOf(errors.clone())),
             });
@@ -469,7 +472,7 @@ where
                 value: val.clone(),
                 relation,
                 predecessors: None,
-                info: self.node_info.get(node_id).copied().flatten(),
+                info: self.node_info.get(node_id).cloned().flatten(),
                 metadata: self.node_metadata.get(node_id).cloned().flatten(),
             });
             memo.insert((node_id, relation, strength), Err(Arc::clone(&err)));
@@ -483,97 +486,74 @@ where
     }
 
     pub fn combine(g1: &Self, g2: &Self) -> Result<Self, GraphError<V>> {
+        Self::merge([g1, g2])
+    }
+
+    /// Merge any number of graphs into a single graph.
+    ///
+    /// Domains and value nodes are shared across the graphs, while every aggregator node is
+    /// kept as is. The info and metadata of a value node present in multiple graphs are taken
+    /// from the first graph which has them.
+    pub fn merge<'a>(graphs: impl IntoIterator<Item = &'a Self>) -> Result<Self, GraphError<V>>
+    where
+        V: 'a,
+    {
         let mut node_builder = builder::ConstraintGraphBuilder::new();
-        let mut g1_old2new_id = DenseMap::<NodeId, NodeId>::new();
-        let mut g2_old2new_id = DenseMap::<NodeId, NodeId>::new();
-        let mut g1_old2new_domain_id = DenseMap::<DomainId, DomainId>::new();
-        let mut g2_old2new_domain_id = DenseMap::<DomainId, DomainId>::new();
-
-        let add_domain = |node_builder: &mut builder::ConstraintGraphBuilder<V>,
-                          domain: DomainInfo|
-         -> Result<DomainId, GraphError<V>> {
+        for graph in graphs {
+            graph.add_to_builder(&mut node_builder)?;
+        }
+
+        Ok(node_builder.build())
+    }
+
+    fn add_to_builder(
+        &self,
+        node_builder: &mut builder::ConstraintGraphBuilder<V>,
+    ) -> Result<(), GraphError<V>> {
+        let mut old2new_id = DenseMap::<NodeId, NodeId>::new();
+
+        for domain in self.domain.values() {
             node_builder.make_domain(
                 domain.domain_identifier.into_inner(),
                 &domain.domain_description,
-            )
-        };
+            )?;
+        }
 
-        let add_node = |node_builder: &mut builder::ConstraintGraphBuilder<V>,
-                        node: &Node<V>|
-         -> Result<NodeId, GraphError<V>> {
-            match &node.node_type {
+        for (old_node_id, node) in self.nodes.iter() {
+            let new_node_id = match &node.node_type {
                 NodeType::Value(node_value) => {
-                    Ok(node_builder.make_value_node(node_value.clone(), None, None::<()>))
+                    node_builder.make_value_node(node_value.clone(), None, None::<()>)
                 }
 
                 NodeType::AllAggregator => {
-                    Ok(node_builder.make_all_aggregator(&[], None, None::<()>, None)?)
+                    node_builder.make_all_aggregator(&[], None, None::<()>, None)?
                 }
 
                 NodeType::AnyAggregator => {
-                    Ok(node_builder.make_any_aggregator(&[], None, None::<()>, None)?)
+                    node_builder.make_any_aggregator(&[], None, None::<()>, None)?
                 }
 
-                NodeType::InAggregator(expected) => Ok(node_builder.make_in_aggregator(
+                NodeType::InAggregator(expected) => node_builder.make_in_aggregator(
                     expected.iter().cloned().collect(),
                     None,
                     None::<()>,
-                )?),
-            }
-        };
-
-        for (_old_domain_id, domain) in g1.domain.iter() {
-            let new_domain_id = add_domain(&mut node_builder, domain.clone())?;
-            g1_old2new_domain_id.push(new_domain_id);
-        }
-
-        for (_old_domain_id, domain) in g2.domain.iter() {
-            let new_domain_id = add_domain(&mut node_builder, domain.clone())?;
-            g2_old2new_domain_id.push(new_domain_id);
-        }
-
-        for (_old_node_id, node) in g1.nodes.iter() {
-            let new_node_id = add_node(&mut node_builder, node)?;
-            g1_old2new_id.push(new_node_id);
-        }
-
-        for (_old_node_id, node) in g2.nodes.iter() {
-            let new_node_id = add_node(&mut node_builder, node)?;
-            g2_old2new_id.push(new_node_id);
-        }
-
-        for edge in g1.edges.values() {
-            let new_pred_id = g1_old2new_id
-                .get(edge.pred)
-                .ok_or(GraphError::NodeNotFound)?;
-            let new_succ_id = g1_old2new_id
-                .get(edge.succ)
-                .ok_or(GraphError::NodeNotFound)?;
-            let domain_ident = edge
-                .domain
-                .map(|domain_id| g1.domain.get(domain_id).ok_or(GraphError::DomainNotFound))
-                .transpose()?
-                .map(|domain| domain.domain_identifier.clone());
+                )?,
+            };
 
-            node_builder.make_edge(
-                *new_pred_id,
-                *new_succ_id,
-                edge.strength,
-                edge.relation,
-                domain_ident,
+            node_builder.inherit_node_details(
+                new_node_id,
+                self.node_info.get(old_node_id).cloned().flatten(),
+                self.node_metadata.get(old_node_id).cloned().flatten(),
             )?;
+            old2new_id.push(new_node_id);
         }
 
-        for edge in g2.edges.values() {
-            let new_pred_id = g2_old2new_id
-                .get(edge.pred)
-                .ok_or(GraphError::NodeNotFound)?;
-            let new_succ_id = g2_old2new_id
-                .get(edge.succ)
-                .ok_or(GraphError::NodeNotFound)?;
+        for edge in self.edges.values() {
+            let new_pred_id = old2new_id.get(edge.pred).ok_or(GraphError::NodeNotFound)?;
+            let new_succ_id = old2new_id.get(edge.succ).ok_or(GraphError::NodeNotFound)?;
             let domain_ident = edge
                 .domain
-                .map(|domain_id| g2.domain.get(domain_id).ok_or(GraphError::DomainNotFound))
+                .map(|domain_id| self.domain.get(domain_id).ok_or(GraphError::DomainNotFound))
                 .transpose()?
                 .map(|domain| domain.domain_identifier.clone());
 
@@ -586,7 +566,7 @@ where
             )?;
         }
 
-        Ok(node_builder.build())
+        Ok(())
     }
 }
 
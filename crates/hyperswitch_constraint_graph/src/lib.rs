@@ -1,12 +1,18 @@
 pub mod builder;
 mod dense_map;
+pub mod diff;
 pub mod error;
+pub mod explanation;
 pub mod graph;
+pub mod serialization;
 pub mod types;
 
 pub use builder::ConstraintGraphBuilder;
+pub use diff::GraphDiff;
 pub use error::{AnalysisTrace, GraphError};
+pub use explanation::Explanation;
 pub use graph::ConstraintGraph;
+pub use serialization::SerializedConstraintGraph;
 #[cfg(feature = "viz")]
 pub use types::NodeViz;
 pub use types::{
@@ -0,0 +1,342 @@
+use std::borrow::Cow;
+
+use crate::{
+    builder::ConstraintGraphBuilder,
+    dense_map::DenseMap,
+    error::GraphError,
+    graph::ConstraintGraph,
+    types::{DomainId, NodeId, NodeType, NodeValue, Relation, Strength, ValueNode},
+};
+
+/// Version of the serialized form, to be bumped whenever the form changes in a way that older
+/// serialized graphs can no longer be read
+pub const SERIALIZATION_VERSION: u16 = 1;
+
+/// Self contained form of a [`ConstraintGraph`] which can be serialized, for instance to be
+/// cached outside the process that built it.
+///
+/// Nodes, edges and domains refer to each other by their position in the respective lists.
+/// Node metadata is not part of the serialized form and is dropped during serialization.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "V: serde::Deserialize<'de>, V::Key: serde::Deserialize<'de>"))]
+pub struct SerializedConstraintGraph<V: ValueNode> {
+    pub version: u16,
+    pub domains: Vec<SerializedDomain>,
+    pub nodes: Vec<SerializedNode<V>>,
+    pub edges: Vec<SerializedEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SerializedDomain {
+    pub identifier: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "V: serde::Deserialize<'de>, V::Key: serde::Deserialize<'de>"))]
+pub struct SerializedNode<V: ValueNode> {
+    pub node_type: SerializedNodeType<V>,
+    pub info: Option<Cow<'static, str>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+#[serde(bound(deserialize = "V: serde::Deserialize<'de>, V::Key: serde::Deserialize<'de>"))]
+pub enum SerializedNodeType<V: ValueNode> {
+    AllAggregator,
+    AnyAggregator,
+    InAggregator(Vec<V>),
+    Value(NodeValue<V>),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SerializedEdge {
+    pub strength: Strength,
+    pub relation: Relation,
+    pub pred: NodeId,
+    pub succ: NodeId,
+    pub domain: Option<DomainId>,
+}
+
+impl<V> SerializedConstraintGraph<V>
+where
+    V: ValueNode,
+{
+    /// Convert the values and keys of the graph into another type, for instance into a form
+    /// which is serialized differently
+    pub fn map_values<W, F, G>(self, value_fn: F, key_fn: G) -> SerializedConstraintGraph<W>
+    where
+        W: ValueNode,
+        F: Fn(V) -> W,
+        G: Fn(V::Key) -> W::Key,
+    {
+        let nodes = self
+            .nodes
+            .into_iter()
+            .map(|node| SerializedNode {
+                node_type: match node.node_type {
+                    SerializedNodeType::AllAggregator => SerializedNodeType::AllAggregator,
+                    SerializedNodeType::AnyAggregator => SerializedNodeType::AnyAggregator,
+                    SerializedNodeType::InAggregator(expected) => SerializedNodeType::InAggregator(
+                        expected.into_iter().map(&value_fn).collect(),
+                    ),
+                    SerializedNodeType::Value(NodeValue::Key(key)) => {
+                        SerializedNodeType::Value(NodeValue::Key(key_fn(key)))
+                    }
+                    SerializedNodeType::Value(NodeValue::Value(value)) => {
+                        SerializedNodeType::Value(NodeValue::Value(value_fn(value)))
+                    }
+                },
+                info: node.info,
+            })
+            .collect();
+
+        SerializedConstraintGraph {
+            version: self.version,
+            domains: self.domains,
+            nodes,
+            edges: self.edges,
+        }
+    }
+}
+
+impl<V> ConstraintGraph<V>
+where
+    V: ValueNode,
+{
+    pub fn to_serialized(&self) -> SerializedConstraintGraph<V> {
+        let domains = self
+            .domain
+            .values()
+            .map(|domain| SerializedDomain {
+                identifier: domain.domain_identifier.into_inner(),
+                description: domain.domain_description.clone(),
+            })
+            .collect();
+
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(node_id, node)| SerializedNode {
+                node_type: match &node.node_type {
+                    NodeType::AllAggregator => SerializedNodeType::AllAggregator,
+                    NodeType::AnyAggregator => SerializedNodeType::AnyAggregator,
+                    NodeType::InAggregator(expected) => {
+                        SerializedNodeType::InAggregator(expected.iter().cloned().collect())
+                    }
+                    NodeType::Value(node_value) => SerializedNodeType::Value(node_value.clone()),
+                },
+                info: self.node_info.get(node_id).cloned().flatten(),
+            })
+            .collect();
+
+        let edges = self
+            .edges
+            .values()
+            .map(|edge| SerializedEdge {
+                strength: edge.strength,
+                relation: edge.relation,
+                pred: edge.pred,
+                succ: edge.succ,
+                domain: edge.domain,
+            })
+            .collect();
+
+        SerializedConstraintGraph {
+            version: SERIALIZATION_VERSION,
+            domains,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Rebuild a graph from its serialized form, validating that all the references within it
+    /// are consistent
+    pub fn from_serialized(
+        serialized: SerializedConstraintGraph<V>,
+    ) -> Result<Self, GraphError<V>> {
+        if serialized.version != SERIALIZATION_VERSION {
+            return Err(GraphError::SerializationVersionMismatch {
+                found: serialized.version,
+                expected: SERIALIZATION_VERSION,
+            });
+        }
+
+        let mut builder = ConstraintGraphBuilder::new();
+        let mut old2new_domain_id = DenseMap::<DomainId, DomainId>::new();
+        let mut old2new_id = DenseMap::<NodeId, NodeId>::new();
+
+        for domain in serialized.domains {
+            let new_domain_id = builder.make_domain(domain.identifier, &domain.description)?;
+            old2new_domain_id.push(new_domain_id);
+        }
+
+        for node in serialized.nodes {
+            let new_node_id = match node.node_type {
+                SerializedNodeType::AllAggregator => {
+                    builder.make_all_aggregator(&[], None, None::<()>, None)?
+                }
+                SerializedNodeType::AnyAggregator => {
+                    builder.make_any_aggregator(&[], None, None::<()>, None)?
+                }
+                SerializedNodeType::InAggregator(expected) => {
+                    builder.make_in_aggregator(expected, None, None::<()>)?
+                }
+                SerializedNodeType::Value(node_value) => {
+                    builder.make_value_node(node_value, None, None::<()>)
+                }
+            };
+
+            builder.inherit_node_details(new_node_id, node.info, None)?;
+            old2new_id.push(new_node_id);
+        }
+
+        for edge in serialized.edges {
+            let new_pred_id = old2new_id.get(edge.pred).ok_or(GraphError::NodeNotFound)?;
+            let new_succ_id = old2new_id.get(edge.succ).ok_or(GraphError::NodeNotFound)?;
+            let new_domain_id = edge
+                .domain
+                .map(|domain_id| {
+                    old2new_domain_id
+                        .get(domain_id)
+                        .copied()
+                        .ok_or(GraphError::DomainNotFound)
+                })
+                .transpose()?;
+
+            builder.make_edge(
+                *new_pred_id,
+                *new_succ_id,
+                edge.strength,
+                edge.relation,
+                new_domain_id,
+            )?;
+        }
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use super::*;
+    use crate::{dense_map::EntityId, types::KeyNode};
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct TestKey;
+
+    impl KeyNode for TestKey {}
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct TestValue(u8);
+
+    impl ValueNode for TestValue {
+        type Key = TestKey;
+
+        fn get_key(&self) -> Self::Key {
+            TestKey
+        }
+    }
+
+    fn build_graph() -> ConstraintGraph<TestValue> {
+        let mut builder = ConstraintGraphBuilder::new();
+        let domain_id = builder
+            .make_domain("test_domain".to_string(), "Test domain")
+            .unwrap();
+        let first = builder.make_value_node(
+            NodeValue::Value(TestValue(1)),
+            Some("first value"),
+            None::<()>,
+        );
+        let second = builder.make_value_node(NodeValue::Value(TestValue(2)), None, None::<()>);
+        let key = builder.make_value_node(NodeValue::Key(TestKey), None, None::<()>);
+        let in_aggregator = builder
+            .make_in_aggregator(vec![TestValue(3), TestValue(4)], None, None::<()>)
+            .unwrap();
+        let all_aggregator = builder
+            .make_all_aggregator(
+                &[
+                    (first, Relation::Positive, Strength::Strong),
+                    (key, Relation::Negative, Strength::Weak),
+                    (in_aggregator, Relation::Positive, Strength::Normal),
+                ],
+                Some("all of the values"),
+                None::<()>,
+                Some(domain_id),
+            )
+            .unwrap();
+        builder
+            .make_edge(
+                all_aggregator,
+                second,
+                Strength::Strong,
+                Relation::Positive,
+                Some(domain_id),
+            )
+            .unwrap();
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let graph = build_graph();
+        let serialized = graph.to_serialized();
+        assert_eq!(serialized.version, SERIALIZATION_VERSION);
+        assert_eq!(serialized.nodes.len(), 5);
+        assert_eq!(
+            serialized.nodes[0].info.as_deref(),
+            Some("first value"),
+            "node info is kept in the serialized form"
+        );
+
+        let deserialized_graph = ConstraintGraph::from_serialized(serialized).unwrap();
+        assert!(graph.diff(&deserialized_graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_map_values_round_trip() {
+        let graph = build_graph();
+        let mapped = graph
+            .to_serialized()
+            .map_values(|value| TestValue(value.0 + 10), |key| key);
+        assert!(mapped.nodes.iter().any(|node| matches!(
+            node.node_type,
+            SerializedNodeType::Value(NodeValue::Value(TestValue(11)))
+        )));
+
+        let restored = mapped.map_values(|value| TestValue(value.0 - 10), |key| key);
+        let restored_graph = ConstraintGraph::from_serialized(restored).unwrap();
+        assert!(graph.diff(&restored_graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_deserialization_rejects_other_versions() {
+        let mut serialized = build_graph().to_serialized();
+        serialized.version = SERIALIZATION_VERSION + 1;
+
+        assert!(matches!(
+            ConstraintGraph::from_serialized(serialized),
+            Err(GraphError::SerializationVersionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_deserialization_rejects_dangling_references() {
+        let mut serialized = build_graph().to_serialized();
+        serialized.edges[0].domain = Some(DomainId::with_id(5));
+        assert!(matches!(
+            ConstraintGraph::from_serialized(serialized),
+            Err(GraphError::DomainNotFound)
+        ));
+
+        let mut serialized = build_graph().to_serialized();
+        serialized.edges[0].pred = NodeId::with_id(10);
+        assert!(matches!(
+            ConstraintGraph::from_serialized(serialized),
+            Err(GraphError::NodeNotFound)
+        ));
+    }
+}
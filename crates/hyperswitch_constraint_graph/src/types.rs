@@ -9,12 +9,22 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{dense_map::impl_entity, error::AnalysisTrace};
 
-pub trait KeyNode: fmt::Debug + Clone + hash::Hash + serde::Serialize + PartialEq + Eq {}
+pub trait KeyNode: fmt::Debug + Clone + hash::Hash + serde::Serialize + PartialEq + Eq {
+    /// Human readable description of the key, used while explaining analysis traces
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+}
 
 pub trait ValueNode: fmt::Debug + Clone + hash::Hash + serde::Serialize + PartialEq + Eq {
     type Key: KeyNode;
 
     fn get_key(&self) -> Self::Key;
+
+    /// Human readable description of the value, used while explaining analysis traces
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
 }
 
 #[cfg(feature = "viz")]
@@ -22,7 +32,7 @@ pub trait NodeViz {
     fn viz(&self) -> String;
 }
 
-#[derive(Debug, Clone, Copy, serde::Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 #[serde(transparent)]
 pub struct NodeId(usize);
 
@@ -53,8 +63,9 @@ pub enum NodeType<V: ValueNode> {
     Value(NodeValue<V>),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
+#[serde(bound(deserialize = "V: serde::Deserialize<'de>, V::Key: serde::Deserialize<'de>"))]
 pub enum NodeValue<V: ValueNode> {
     Key(<V as ValueNode>::Key),
     Value(V),
@@ -72,7 +83,17 @@ pub struct EdgeId(usize);
 impl_entity!(EdgeId);
 
 #[derive(
-    Debug, Clone, Copy, serde::Serialize, PartialEq, Eq, Hash, strum::Display, PartialOrd, Ord,
+    Debug,
+    Clone,
+    Copy,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    strum::Display,
+    PartialOrd,
+    Ord,
 )]
 pub enum Strength {
     Weak,
@@ -86,7 +107,9 @@ impl Strength {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, strum::Display, serde::Serialize, serde::Deserialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Relation {
     Positive,
@@ -134,7 +157,8 @@ pub struct Edge {
     pub domain: Option<DomainId>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
 pub struct DomainId(usize);
 
 impl_entity!(DomainId);
@@ -1,4 +1,7 @@
-use std::{collections::HashSet, fmt::Display};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
 use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
@@ -74,6 +77,14 @@ pub struct PaymentLinkFormData {
     pub css_script: String,
     pub sdk_url: String,
     pub html_meta_tags: String,
+    pub custom_template: Option<PaymentLinkCustomTemplate>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentLinkCustomTemplate {
+    pub template: String,
+    pub locale: String,
+    pub locale_bundles: HashMap<String, HashMap<String, String>>,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
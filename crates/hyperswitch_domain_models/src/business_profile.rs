@@ -62,6 +62,8 @@ pub struct Profile {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: bool,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: bool,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub always_request_extended_authorization:
         Option<primitive_wrappers::AlwaysRequestExtendedAuthorization>,
     pub is_click_to_pay_enabled: bool,
@@ -116,6 +118,8 @@ pub struct ProfileSetter {
     pub is_network_tokenization_enabled: bool,
     pub is_auto_retries_enabled: bool,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: bool,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub always_request_extended_authorization:
         Option<primitive_wrappers::AlwaysRequestExtendedAuthorization>,
     pub is_click_to_pay_enabled: bool,
@@ -176,6 +180,8 @@ impl From<ProfileSetter> for Profile {
             is_network_tokenization_enabled: value.is_network_tokenization_enabled,
             is_auto_retries_enabled: value.is_auto_retries_enabled,
             max_auto_retries_enabled: value.max_auto_retries_enabled,
+            is_payout_auto_retries_enabled: value.is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled: value.max_payout_auto_retries_enabled,
             always_request_extended_authorization: value.always_request_extended_authorization,
             is_click_to_pay_enabled: value.is_click_to_pay_enabled,
             authentication_product_ids: value.authentication_product_ids,
@@ -237,6 +243,8 @@ pub struct ProfileGeneralUpdate {
     pub is_network_tokenization_enabled: Option<bool>,
     pub is_auto_retries_enabled: Option<bool>,
     pub max_auto_retries_enabled: Option<i16>,
+    pub is_payout_auto_retries_enabled: Option<bool>,
+    pub max_payout_auto_retries_enabled: Option<i16>,
     pub is_click_to_pay_enabled: Option<bool>,
     pub authentication_product_ids:
         Option<common_types::payments::AuthenticationConnectorAccountMap>,
@@ -271,6 +279,9 @@ pub enum ProfileUpdate {
     NetworkTokenizationUpdate {
         is_network_tokenization_enabled: bool,
     },
+    WebhookDetailsUpdate {
+        webhook_details: WebhookDetails,
+    },
     CardTestingSecretKeyUpdate {
         card_testing_secret_key: OptionalEncryptableName,
     },
@@ -314,6 +325,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_network_tokenization_enabled,
                     is_auto_retries_enabled,
                     max_auto_retries_enabled,
+                    is_payout_auto_retries_enabled,
+                    max_payout_auto_retries_enabled,
                     is_click_to_pay_enabled,
                     authentication_product_ids,
                     card_testing_guard_config,
@@ -361,6 +374,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_network_tokenization_enabled,
                     is_auto_retries_enabled,
                     max_auto_retries_enabled,
+                    is_payout_auto_retries_enabled,
+                    max_payout_auto_retries_enabled,
                     always_request_extended_authorization: None,
                     is_click_to_pay_enabled,
                     authentication_product_ids,
@@ -413,6 +428,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 always_request_extended_authorization: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
@@ -462,6 +479,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 always_request_extended_authorization: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
@@ -511,6 +530,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 always_request_extended_authorization: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
@@ -560,6 +581,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 always_request_extended_authorization: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
@@ -609,6 +632,57 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: Some(is_network_tokenization_enabled),
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+            },
+            ProfileUpdate::WebhookDetailsUpdate { webhook_details } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: Some(webhook_details),
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 always_request_extended_authorization: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
@@ -658,6 +732,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 always_request_extended_authorization: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
@@ -727,6 +803,8 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: Some(self.is_auto_retries_enabled),
             max_auto_retries_enabled: self.max_auto_retries_enabled,
+            is_payout_auto_retries_enabled: Some(self.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: self.max_payout_auto_retries_enabled,
             always_request_extended_authorization: self.always_request_extended_authorization,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
             authentication_product_ids: self.authentication_product_ids,
@@ -807,6 +885,10 @@ impl super::behaviour::Conversion for Profile {
                 is_network_tokenization_enabled: item.is_network_tokenization_enabled,
                 is_auto_retries_enabled: item.is_auto_retries_enabled.unwrap_or(false),
                 max_auto_retries_enabled: item.max_auto_retries_enabled,
+                is_payout_auto_retries_enabled: item
+                    .is_payout_auto_retries_enabled
+                    .unwrap_or(false),
+                max_payout_auto_retries_enabled: item.max_payout_auto_retries_enabled,
                 always_request_extended_authorization: item.always_request_extended_authorization,
                 is_click_to_pay_enabled: item.is_click_to_pay_enabled,
                 authentication_product_ids: item.authentication_product_ids,
@@ -887,6 +969,8 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: Some(self.is_auto_retries_enabled),
             max_auto_retries_enabled: self.max_auto_retries_enabled,
+            is_payout_auto_retries_enabled: Some(self.is_payout_auto_retries_enabled),
+            max_payout_auto_retries_enabled: self.max_payout_auto_retries_enabled,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
             authentication_product_ids: self.authentication_product_ids,
             card_testing_guard_config: self.card_testing_guard_config,
@@ -1167,6 +1251,9 @@ pub enum ProfileUpdate {
     NetworkTokenizationUpdate {
         is_network_tokenization_enabled: bool,
     },
+    WebhookDetailsUpdate {
+        webhook_details: WebhookDetails,
+    },
     CollectCvvDuringPaymentUpdate {
         should_collect_cvv_during_payment: primitive_wrappers::ShouldCollectCvvDuringPayment,
     },
@@ -1261,6 +1348,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_network_tokenization_enabled,
                     is_auto_retries_enabled: None,
                     max_auto_retries_enabled: None,
+                    is_payout_auto_retries_enabled: None,
+                    max_payout_auto_retries_enabled: None,
                     is_click_to_pay_enabled,
                     authentication_product_ids,
                     three_ds_decision_manager_config,
@@ -1315,6 +1404,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1367,6 +1458,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1419,6 +1512,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1471,6 +1566,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1523,6 +1620,60 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: Some(is_network_tokenization_enabled),
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                three_ds_decision_manager_config: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                revenue_recovery_retry_algorithm_type: None,
+                revenue_recovery_retry_algorithm_data: None,
+                is_iframe_redirection_enabled: None,
+                is_external_vault_enabled: None,
+                external_vault_connector_details: None,
+            },
+            ProfileUpdate::WebhookDetailsUpdate { webhook_details } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: Some(webhook_details),
+                metadata: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                routing_algorithm_id: None,
+                payout_routing_algorithm_id: None,
+                order_fulfillment_time: None,
+                order_fulfillment_time_origin: None,
+                frm_routing_algorithm_id: None,
+                default_fallback_routing: None,
+                should_collect_cvv_during_payment: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1575,6 +1726,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1627,6 +1780,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: Some(three_ds_decision_manager_config),
@@ -1679,6 +1834,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1732,6 +1889,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_network_tokenization_enabled: None,
                 is_auto_retries_enabled: None,
                 max_auto_retries_enabled: None,
+                is_payout_auto_retries_enabled: None,
+                max_payout_auto_retries_enabled: None,
                 is_click_to_pay_enabled: None,
                 authentication_product_ids: None,
                 three_ds_decision_manager_config: None,
@@ -1804,6 +1963,8 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: None,
             max_auto_retries_enabled: None,
+            is_payout_auto_retries_enabled: None,
+            max_payout_auto_retries_enabled: None,
             always_request_extended_authorization: None,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
             authentication_product_ids: self.authentication_product_ids,
@@ -1969,6 +2130,8 @@ impl super::behaviour::Conversion for Profile {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: None,
             max_auto_retries_enabled: None,
+            is_payout_auto_retries_enabled: None,
+            max_payout_auto_retries_enabled: None,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
             authentication_product_ids: self.authentication_product_ids,
             three_ds_decision_manager_config: self.three_ds_decision_manager_config,
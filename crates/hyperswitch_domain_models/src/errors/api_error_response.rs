@@ -285,6 +285,8 @@ pub enum ApiErrorResponse {
     InvalidPlatformOperation,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "External vault failed during processing with connector")]
     ExternalVaultFailed,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_46", message = "This action requires approval, approval request {approval_request_id} was created")]
+    ApprovalRequired { approval_request_id: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -643,6 +645,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExternalVaultFailed => {
                 AER::BadRequest(ApiError::new("IR", 45, "External Vault failed while processing with connector.", None))
             },
+            Self::ApprovalRequired { approval_request_id } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 46, format!("This action requires approval, approval request {approval_request_id} was created"), Some(Extra { data: Some(serde_json::json!({ "approval_request_id": approval_request_id })), ..Default::default()})))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
@@ -1927,6 +1927,9 @@ pub struct NetworkTokenDetailsPaymentMethod {
     pub card_type: Option<String>,
     #[serde(default = "saved_in_locker_default")]
     pub saved_to_locker: bool,
+    /// Set while the token is suspended by the card network, payments use the card meanwhile
+    #[serde(default)]
+    pub is_suspended: bool,
 }
 
 fn saved_in_locker_default() -> bool {
@@ -1984,6 +1987,7 @@ impl From<NetworkTokenDetails> for NetworkTokenDetailsPaymentMethod {
             card_network: item.card_network,
             card_type: item.card_type.map(|card| card.to_string()),
             saved_to_locker: true,
+            is_suspended: false,
         }
     }
 }
@@ -158,6 +158,41 @@ impl PaymentMethod {
             })
     }
 
+    /// Whether the network token of the payment method is currently suspended by the card network
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    pub fn is_network_token_suspended(&self) -> bool {
+        self.network_token_payment_method_data
+            .clone()
+            .map(|value| value.into_inner().expose())
+            .and_then(|value| {
+                serde_json::from_value::<domain_payment_method_data::PaymentMethodsData>(value).ok()
+            })
+            .is_some_and(|data| {
+                matches!(
+                    data,
+                    domain_payment_method_data::PaymentMethodsData::NetworkToken(token)
+                        if token.is_suspended
+                )
+            })
+    }
+
+    /// Whether the network token of the payment method is currently suspended by the card network
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    pub fn is_network_token_suspended(&self) -> bool {
+        self.network_token_payment_method_data
+            .as_ref()
+            .is_some_and(|data| {
+                matches!(
+                    data.get_inner(),
+                    domain_payment_method_data::PaymentMethodsData::NetworkToken(token)
+                        if token.is_suspended
+                )
+            })
+    }
+
     #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
     pub fn get_id(&self) -> &id_type::GlobalPaymentMethodId {
         &self.id
@@ -769,6 +804,19 @@ pub trait PaymentMethodInterface {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentMethod, Self::Error>;
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_network_token_requestor_reference_id(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<PaymentMethod, Self::Error>;
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -838,6 +886,20 @@ pub trait PaymentMethodInterface {
         status: common_enums::PaymentMethodStatus,
     ) -> CustomResult<i64, Self::Error>;
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_list_by_merchant_id_status_after_key(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        after_key: Option<(PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
     async fn insert_payment_method(
         &self,
         state: &keymanager::KeyManagerState,
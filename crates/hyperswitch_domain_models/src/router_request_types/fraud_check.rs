@@ -48,6 +48,8 @@ pub struct FraudCheckRecordReturnData {
     pub currency: Option<common_enums::Currency>,
     pub refund_method: RefundMethod,
     pub refund_transaction_id: Option<String>,
+    /// Set when the return is reported as feedback on an earlier fraud decision
+    pub feedback_type: Option<common_enums::FrmFeedbackType>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
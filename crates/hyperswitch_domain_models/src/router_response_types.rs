@@ -542,6 +542,8 @@ pub enum AuthenticationResponseData {
         trans_status: common_enums::TransactionStatus,
         connector_metadata: Option<serde_json::Value>,
         ds_trans_id: Option<String>,
+        /// Whether the cardholder has whitelisted the merchant with the issuer, when reported by the ACS
+        is_merchant_whitelisted: Option<bool>,
     },
     PostAuthNResponse {
         trans_status: common_enums::TransactionStatus,
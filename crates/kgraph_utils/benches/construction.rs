@@ -0,0 +1,197 @@
+#![allow(unused, clippy::expect_used)]
+
+use std::collections::HashMap;
+
+use api_models::{
+    admin as admin_api, enums as api_enums, payment_methods::RequestPaymentMethodTypes,
+};
+use common_utils::types::MinorUnit;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use euclid::{
+    dirval,
+    dssa::graph::{self, CgraphExt},
+    frontend::dir,
+};
+use hyperswitch_constraint_graph::{
+    ConstraintGraph, CycleCheck, Memoization, SerializedConstraintGraph,
+};
+use kgraph_utils::{mca, types::CountryCurrencyFilter};
+
+const CONNECTORS: [&str; 4] = ["stripe", "adyen", "checkout", "bluesnap"];
+
+#[cfg(feature = "v1")]
+fn build_accounts(
+    total_accounts: usize,
+    total_pm_types: usize,
+) -> Vec<admin_api::MerchantConnectorResponse> {
+    use api_models::{admin::*, payment_methods::*};
+
+    let profile_id = common_utils::generate_profile_id_of_default_length();
+
+    CONNECTORS
+        .iter()
+        .cycle()
+        .take(total_accounts)
+        .map(|connector_name| {
+            let pm_types = (0..total_pm_types)
+                .map(|_| RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Credit,
+                    payment_experience: None,
+                    card_networks: Some(vec![
+                        api_enums::CardNetwork::Visa,
+                        api_enums::CardNetwork::Mastercard,
+                    ]),
+                    accepted_currencies: Some(AcceptedCurrencies::EnableOnly(vec![
+                        api_enums::Currency::USD,
+                        api_enums::Currency::INR,
+                    ])),
+                    accepted_countries: None,
+                    minimum_amount: Some(MinorUnit::new(10)),
+                    maximum_amount: Some(MinorUnit::new(1000)),
+                    recurring_enabled: true,
+                    installment_payment_enabled: true,
+                })
+                .collect();
+
+            MerchantConnectorResponse {
+                connector_type: api_enums::ConnectorType::FizOperations,
+                connector_name: connector_name.to_string(),
+                merchant_connector_id:
+                    common_utils::generate_merchant_connector_account_id_of_default_length(),
+                connector_account_details: masking::Secret::new(serde_json::json!({})),
+                test_mode: None,
+                disabled: None,
+                metadata: None,
+                payment_methods_enabled: Some(vec![PaymentMethodsEnabled {
+                    payment_method: api_enums::PaymentMethod::Card,
+                    payment_method_types: Some(pm_types),
+                }]),
+                business_country: Some(api_enums::CountryAlpha2::US),
+                business_label: Some("hello".to_string()),
+                connector_label: Some("something".to_string()),
+                business_sub_label: Some("something".to_string()),
+                frm_configs: None,
+                connector_webhook_details: None,
+                profile_id: profile_id.clone(),
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                status: api_enums::ConnectorStatus::Inactive,
+                additional_merchant_data: None,
+                connector_wallets_details: None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "v1")]
+fn check_graph(graph: &ConstraintGraph<dir::DirValue>) {
+    let _ = graph.key_value_analysis(
+        dirval!(Connector = Stripe),
+        &graph::AnalysisContext::from_dir_values([
+            dirval!(Connector = Stripe),
+            dirval!(PaymentMethod = Card),
+            dirval!(CardType = Credit),
+            dirval!(CardNetwork = Visa),
+            dirval!(PaymentCurrency = USD),
+            dirval!(PaymentAmount = 100),
+        ]),
+        &mut Memoization::new(),
+        &mut CycleCheck::new(),
+        None,
+    );
+}
+
+#[cfg(feature = "v1")]
+fn construction(c: &mut Criterion) {
+    let config = CountryCurrencyFilter {
+        connector_configs: HashMap::new(),
+        default_configs: None,
+    };
+    let mut group = c.benchmark_group("MCA Graph Construction");
+
+    for total_accounts in [10, 100, 300] {
+        let accounts = build_accounts(total_accounts, 10);
+        let graph = mca::make_mca_graph(accounts.clone(), &config).expect("graph");
+        let subgraphs = accounts
+            .iter()
+            .cloned()
+            .map(|account| mca::make_mca_subgraph(account, &config))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("subgraphs");
+        let serialized = serde_json::to_vec(&graph.to_serialized()).expect("serialization");
+
+        group.bench_with_input(
+            BenchmarkId::new("Full Build", total_accounts),
+            &accounts,
+            |b, accounts| {
+                b.iter(|| mca::make_mca_graph(black_box(accounts.clone()), &config));
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Incremental Build", total_accounts),
+            &accounts,
+            |b, accounts| {
+                b.iter(|| {
+                    let updated_subgraph = accounts
+                        .first()
+                        .cloned()
+                        .map(|account| mca::make_mca_subgraph(account, &config))
+                        .transpose()
+                        .expect("subgraph");
+                    mca::merge_mca_graphs(
+                        updated_subgraph
+                            .iter()
+                            .chain(black_box(&subgraphs).iter().skip(1)),
+                    )
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Deserialization", total_accounts),
+            &serialized,
+            |b, serialized| {
+                b.iter(|| {
+                    let serialized_graph: SerializedConstraintGraph<dir::DirValue> =
+                        serde_json::from_slice(black_box(serialized)).expect("deserialization");
+                    ConstraintGraph::from_serialized(serialized_graph)
+                });
+            },
+        );
+
+        let merged_graph = mca::merge_mca_graphs(&subgraphs).expect("merged graph");
+        let deserialized_graph = ConstraintGraph::from_serialized(
+            serde_json::from_slice(&serialized).expect("deserialization"),
+        )
+        .expect("deserialized graph");
+
+        group.bench_with_input(
+            BenchmarkId::new("Check Built Graph", total_accounts),
+            &graph,
+            |b, graph| b.iter(|| check_graph(graph)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Check Merged Graph", total_accounts),
+            &merged_graph,
+            |b, graph| b.iter(|| check_graph(graph)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Check Deserialized Graph", total_accounts),
+            &deserialized_graph,
+            |b, graph| b.iter(|| check_graph(graph)),
+        );
+    }
+
+    group.finish();
+}
+
+#[cfg(feature = "v1")]
+criterion_group!(benches, construction);
+#[cfg(feature = "v1")]
+criterion_main!(benches);
+
+#[cfg(feature = "v2")]
+fn main() {}
@@ -0,0 +1,104 @@
+#[cfg(feature = "v1")]
+use api_models::admin as admin_api;
+use api_models::{
+    enums as api_enums,
+    routing::{PaymentMethodEligibilityRequest, PaymentMethodTypeChoice},
+};
+use euclid::{
+    dssa::graph::{AnalysisContext, CgraphExt},
+    frontend::{ast, dir},
+    types::NumValue,
+};
+use hyperswitch_constraint_graph as cgraph;
+
+use crate::{error::KgraphError, transformers::IntoDirValue};
+
+/// Eligibility of a connector for a payment method, along with the explanation from the
+/// constraint graph when the connector is not eligible
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectorEligibility {
+    pub connector: api_enums::RoutableConnectors,
+    pub payment_method: api_enums::PaymentMethod,
+    pub payment_method_type: api_enums::PaymentMethodType,
+    pub is_eligible: bool,
+    pub explanation: Option<cgraph::Explanation>,
+}
+
+/// Explain the eligibility of each of the connectors for each of the payment methods in the
+/// payment context described by the request
+pub fn explain_payment_method_eligibility(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    connectors: &[api_enums::RoutableConnectors],
+    payment_methods: &[PaymentMethodTypeChoice],
+    request: &PaymentMethodEligibilityRequest,
+) -> Result<Vec<ConnectorEligibility>, KgraphError> {
+    let mut context_values = vec![
+        dir::DirValue::PaymentAmount(NumValue {
+            number: request.amount,
+            refinement: None,
+        }),
+        request.currency.into_dir_value()?,
+    ];
+    if let Some(billing_country) = request.billing_country {
+        context_values.push(dir::DirValue::BillingCountry(
+            api_enums::Country::from_alpha2(billing_country),
+        ));
+    }
+    if let Some(card_network) = request.card_network.clone() {
+        context_values.push(card_network.into_dir_value()?);
+    }
+
+    let mut eligibility = Vec::new();
+    for choice in payment_methods {
+        let mut payment_context = context_values.clone();
+        payment_context.push(choice.payment_method.into_dir_value()?);
+        payment_context.push((choice.payment_method_type, choice.payment_method).into_dir_value()?);
+        let analysis_ctx = AnalysisContext::from_dir_values(payment_context);
+
+        for connector in connectors {
+            let explanation = graph
+                .explain_value_validity(
+                    ast::ConnectorChoice {
+                        connector: *connector,
+                    }
+                    .into_dir_value()?,
+                    &analysis_ctx,
+                    None,
+                )
+                .map_err(KgraphError::GraphAnalysisError)?;
+
+            eligibility.push(ConnectorEligibility {
+                connector: *connector,
+                payment_method: choice.payment_method,
+                payment_method_type: choice.payment_method_type,
+                is_eligible: explanation.is_none(),
+                explanation,
+            });
+        }
+    }
+
+    Ok(eligibility)
+}
+
+/// Collect the distinct payment methods enabled across the merchant connector accounts
+#[cfg(feature = "v1")]
+pub fn get_enabled_payment_methods(
+    mcas: &[admin_api::MerchantConnectorResponse],
+) -> Vec<PaymentMethodTypeChoice> {
+    let mut payment_methods = Vec::new();
+    for payment_method_enabled in mcas
+        .iter()
+        .flat_map(|mca| mca.payment_methods_enabled.iter().flatten())
+    {
+        for payment_method_type in payment_method_enabled.payment_method_types.iter().flatten() {
+            let choice = PaymentMethodTypeChoice {
+                payment_method: payment_method_enabled.payment_method,
+                payment_method_type: payment_method_type.payment_method_type,
+            };
+            if !payment_methods.contains(&choice) {
+                payment_methods.push(choice);
+            }
+        }
+    }
+    payment_methods
+}
@@ -1,3 +1,4 @@
+pub mod eligibility;
 pub mod error;
 pub mod mca;
 pub mod transformers;
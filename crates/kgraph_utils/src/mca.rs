@@ -1040,6 +1040,28 @@ pub fn make_mca_graph(
     Ok(builder.build())
 }
 
+/// Build the graph of a single merchant connector account.
+///
+/// The graphs of the individual accounts can be cached and combined using [`merge_mca_graphs`],
+/// so that only the graph of the account which changed has to be rebuilt.
+pub fn make_mca_subgraph(
+    acct: admin_api::MerchantConnectorResponse,
+    config: &kgraph_types::CountryCurrencyFilter,
+) -> Result<cgraph::ConstraintGraph<dir::DirValue>, KgraphError> {
+    make_mca_graph(vec![acct], config)
+}
+
+/// Combine the graphs of individual merchant connector accounts into the graph that
+/// [`make_mca_graph`] would have built for all of them
+pub fn merge_mca_graphs<'a>(
+    graphs: impl IntoIterator<Item = &'a cgraph::ConstraintGraph<dir::DirValue>>,
+) -> Result<cgraph::ConstraintGraph<dir::DirValue>, KgraphError> {
+    let base_graph = make_mca_graph(Vec::new(), &kgraph_types::CountryCurrencyFilter::default())?;
+
+    cgraph::ConstraintGraph::merge(std::iter::once(&base_graph).chain(graphs))
+        .map_err(KgraphError::GraphConstructionError)
+}
+
 #[cfg(feature = "v1")]
 #[cfg(test)]
 mod tests {
@@ -1050,7 +1072,7 @@ mod tests {
     use api_models::enums as api_enums;
     use euclid::{
         dirval,
-        dssa::graph::{AnalysisContext, CgraphExt},
+        dssa::graph::{self as euclid_graph, AnalysisContext, CgraphExt},
     };
     use hyperswitch_constraint_graph::{ConstraintGraph, CycleCheck, Memoization};
 
@@ -1355,8 +1377,7 @@ mod tests {
         assert!(result.is_err());
     }
 
-    #[test]
-    fn test_sandbox_applepay_bug_usecase() {
+    fn build_sandbox_accounts() -> Vec<admin_api::MerchantConnectorResponse> {
         let value = serde_json::json!([
             {
                 "connector_type": "payment_processor",
@@ -1527,8 +1548,12 @@ mod tests {
             }
         ]);
 
-        let data: Vec<admin_api::MerchantConnectorResponse> =
-            serde_json::from_value(value).expect("data");
+        serde_json::from_value(value).expect("data")
+    }
+
+    #[test]
+    fn test_sandbox_applepay_bug_usecase() {
+        let data = build_sandbox_accounts();
         let config = kgraph_types::CountryCurrencyFilter {
             connector_configs: HashMap::new(),
             default_configs: None,
@@ -1561,4 +1586,62 @@ mod tests {
         );
         assert!(result.is_err(), "bluesnap validation failed");
     }
+
+    #[test]
+    fn test_incremental_and_serialized_graph() {
+        let accounts = build_sandbox_accounts();
+        let config = kgraph_types::CountryCurrencyFilter::default();
+        let graph = make_mca_graph(accounts.clone(), &config).expect("graph");
+
+        let subgraphs = accounts
+            .iter()
+            .cloned()
+            .map(|account| make_mca_subgraph(account, &config))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("subgraphs");
+        let merged_graph = merge_mca_graphs(&subgraphs).expect("merged graph");
+        assert!(graph.diff(&merged_graph).expect("diff").is_empty());
+
+        let serialized =
+            serde_json::to_string(&euclid_graph::to_cached_graph(&graph)).expect("serialization");
+        let deserialized_graph = euclid_graph::from_cached_graph(
+            serde_json::from_str(&serialized).expect("deserialization"),
+        )
+        .expect("deserialized graph");
+        assert!(graph.diff(&deserialized_graph).expect("diff").is_empty());
+
+        let context = AnalysisContext::from_dir_values([
+            dirval!(Connector = Bluesnap),
+            dirval!(PaymentAmount = 212),
+            dirval!(PaymentCurrency = ILS),
+            dirval!(PaymentMethod = Wallet),
+            dirval!(WalletType = ApplePay),
+        ]);
+        let result = deserialized_graph.key_value_analysis(
+            dirval!(Connector = Bluesnap),
+            &context,
+            &mut Memoization::new(),
+            &mut CycleCheck::new(),
+            None,
+        );
+        assert!(result.is_err(), "bluesnap validation failed");
+
+        let mut updated_accounts = accounts;
+        let updated_account = updated_accounts.first_mut().expect("bluesnap account");
+        updated_account.payment_methods_enabled = None;
+        let updated_subgraph =
+            make_mca_subgraph(updated_account.clone(), &config).expect("updated subgraph");
+        let updated_graph =
+            merge_mca_graphs(std::iter::once(&updated_subgraph).chain(subgraphs.iter().skip(1)))
+                .expect("updated graph");
+
+        let diff = graph.diff(&updated_graph).expect("diff");
+        assert!(diff
+            .modified
+            .contains(&dirval!(Connector = Bluesnap).into()));
+        assert!(!diff.modified.contains(&dirval!(Connector = Stripe).into()));
+
+        let rebuilt_graph = make_mca_graph(updated_accounts, &config).expect("rebuilt graph");
+        assert!(rebuilt_graph.diff(&updated_graph).expect("diff").is_empty());
+    }
 }
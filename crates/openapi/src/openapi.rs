@@ -80,6 +80,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::upload_payment_link_template,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
@@ -164,6 +165,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_linked_config,
         routes::routing::routing_retrieve_default_config_for_profiles,
         routes::routing::routing_update_default_config_for_profile,
+        routes::routing::routing_explain_payment_method_eligibility,
         routes::routing::success_based_routing_update_configs,
         routes::routing::toggle_success_based_routing,
         routes::routing::toggle_elimination_routing,
@@ -186,6 +188,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payouts_batch_create,
+        routes::payouts::payouts_batch_retrieve,
+        routes::payouts::payouts_batch_results_download,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -199,6 +204,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::bulk_resend_webhook_events,
+        routes::webhook_events::retrieve_webhook_events_bulk_resend,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -248,6 +255,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::ThreeDsExemptionPolicy,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
         api_models::admin::BusinessCollectLinkConfig,
@@ -350,6 +358,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
         api_models::admin::BusinessPaymentLinkConfig,
+        api_models::admin::PaymentLinkUsagePolicy,
+        api_models::payments::PaymentLinkTemplateUploadRequest,
+        api_models::payments::PaymentLinkTemplateUploadResponse,
         api_models::admin::PaymentLinkBackgroundImageConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
@@ -603,6 +614,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutListFilters,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutBatchFileFormat,
+        api_models::payouts::PayoutBatchStatus,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchRowResult,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutMethodData,
         api_models::payouts::PayoutMethodDataResponse,
@@ -627,6 +642,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookEndpoint,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
@@ -656,6 +672,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
         api_models::routing::RoutingRetrieveResponse,
         api_models::routing::ProfileDefaultRoutingConfig,
+        api_models::routing::PaymentMethodEligibilityRequest,
+        api_models::routing::PaymentMethodTypeChoice,
+        api_models::routing::PaymentMethodEligibilityResponse,
+        api_models::routing::ConnectorPaymentMethodEligibility,
+        api_models::routing::EligibilityExplanation,
         api_models::routing::MerchantRoutingAlgorithm,
         api_models::routing::RoutingAlgorithmKind,
         api_models::routing::RoutingDictionary,
@@ -696,6 +717,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::WebhookEventsBulkResendRequest,
+        api_models::webhook_events::WebhookEventsBulkResendResponse,
+        api_models::webhook_events::WebhookEventsBulkResendStatus,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -154,9 +154,12 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for Revenue Recovery flow under Process Tracker
         routes::revenue_recovery::revenue_recovery_pt_retrieve_api,
+        routes::revenue_recovery::revenue_recovery_retry_timeline_api,
 
         // Routes for proxy
         routes::proxy::proxy_core,
+        routes::proxy::update_proxy_routes,
+        routes::proxy::retrieve_proxy_routes,
 
         // Route for tokenization
         routes::tokenization::create_token_vault_api,
@@ -212,6 +215,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::ThreeDsExemptionPolicy,
         api_models::admin::ExternalVaultConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
@@ -625,6 +629,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookEndpoint,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
@@ -753,10 +758,23 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::PaymentMethodSessionResponse,
         api_models::payment_methods::AuthenticationDetails,
         api_models::process_tracker::revenue_recovery::RevenueRecoveryResponse,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryTimelineResponse,
+        api_models::process_tracker::revenue_recovery::PlannedRetry,
+        api_models::process_tracker::revenue_recovery::RevenueRecoveryRetryStrategy,
+        api_models::process_tracker::revenue_recovery::CardNetworkRetryLimit,
+        api_models::process_tracker::revenue_recovery::PaydayAlignment,
         api_models::enums::ProcessTrackerStatus,
+        api_models::enums::RevenueRecoveryAlgorithmType,
         api_models::proxy::ProxyRequest,
         api_models::proxy::ProxyResponse,
         api_models::proxy::TokenType,
+        api_models::proxy::ProxyRoute,
+        api_models::proxy::ProxyBodyFormat,
+        api_models::proxy::ProxyDetokenizationRule,
+        api_models::proxy::ProxyDetokenizedData,
+        api_models::proxy::ProxyTokenizationRule,
+        api_models::proxy::ProxyRoutesRequest,
+        api_models::proxy::ProxyRoutesResponse,
         routes::payments::ForceSync,
         api_models::tokenization::GenericTokenizationRequest,
         api_models::tokenization::GenericTokenizationResponse,
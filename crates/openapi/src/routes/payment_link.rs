@@ -17,3 +17,20 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Payments Link - Upload Template
+///
+/// To upload a custom HTML template for rendering payment links. Templates may only reference the documented `{{ variable }}` placeholders. The returned `template_id` can be configured in the `payment_link_config` of a profile
+#[utoipa::path(
+    post,
+    path = "/payment_link/templates",
+    request_body = PaymentLinkTemplateUploadRequest,
+    responses(
+        (status = 200, description = "Payment link template uploaded", body = PaymentLinkTemplateUploadResponse),
+        (status = 400, description = "Invalid payment link template")
+    ),
+    tag = "Payments",
+    operation_id = "Upload a Payment Link Template",
+    security(("api_key" = []))
+)]
+pub async fn upload_payment_link_template() {}
@@ -190,3 +190,61 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+///
+/// Upload a CSV or JSON file of payouts to be created in bulk. The rows are validated on upload
+/// and payouts are created asynchronously; use the returned batch id to track the progress.
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body(
+        content = String,
+        content_type = "multipart/form-data",
+        description = "A form with the batch `file`, an optional `file_format` (`csv` or `json`) and an optional `concurrency`"
+    ),
+    responses(
+        (status = 200, description = "Payout batch scheduled", body = PayoutBatchResponse),
+        (status = 412, description = "Invalid payout batch file")
+    ),
+    tag = "Payouts",
+    operation_id = "Create Payouts in Bulk",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_create() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_retrieve() {}
+
+/// Payouts - Batch Results Download
+///
+/// Download the per-row results of a payout batch as a CSV file.
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}/results",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch results", content_type = "text/csv", body = String),
+        (status = 404, description = "Payout batch not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Download Payout Batch Results",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_results_download() {}
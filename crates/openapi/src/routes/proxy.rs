@@ -52,3 +52,64 @@
 )]
 
 pub async fn proxy_core() {}
+
+#[cfg(feature = "v2")]
+/// Proxy - Update Routes
+///
+/// Configure the proxy routes of a profile. Each route restricts the hosts requests can be forwarded to, and declares the fields of the request to be populated with data from the vault and the fields of the response to be tokenized
+#[utoipa::path(
+    put,
+    path = "/proxy/routes",
+    request_body(
+        content = ProxyRoutesRequest,
+        examples((
+            "Configure a proxy route" = (
+                value = json!({
+                    "routes": [{
+                        "route_id": "card_charge",
+                        "allowed_hosts": ["api.example.com"],
+                        "request_format": "json",
+                        "detokenize": [
+                            { "path": "$.source.number", "data": "card_number" },
+                            { "path": "$.source.expiry_month", "data": "card_expiry_month" },
+                            { "path": "$.source.expiry_year", "data": "card_expiry_year" }
+                        ],
+                        "tokenize_response": [
+                            { "path": "$.source.fingerprint" }
+                        ]
+                    }]
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Proxy routes updated", body = ProxyRoutesResponse),
+        (status = 400, description = "Invalid proxy routes")
+    ),
+    params(
+        ("X-Profile-Id" = String, Header, description = "Profile ID for authentication"),
+    ),
+    tag = "Proxy",
+    operation_id = "Update Proxy Routes",
+    security(("api_key" = []))
+)]
+pub async fn update_proxy_routes() {}
+
+#[cfg(feature = "v2")]
+/// Proxy - Retrieve Routes
+///
+/// Retrieve the proxy routes of a profile
+#[utoipa::path(
+    get,
+    path = "/proxy/routes",
+    responses(
+        (status = 200, description = "Proxy routes retrieved", body = ProxyRoutesResponse),
+    ),
+    params(
+        ("X-Profile-Id" = String, Header, description = "Profile ID for authentication"),
+    ),
+    tag = "Proxy",
+    operation_id = "Retrieve Proxy Routes",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_proxy_routes() {}
@@ -20,3 +20,25 @@
    security(("jwt_key" = []))
 )]
 pub async fn revenue_recovery_pt_retrieve_api() {}
+
+#[cfg(feature = "v2")]
+/// Revenue Recovery - Retry Timeline
+///
+/// Simulate the retries planned for a Revenue Recovery payment based on its retry algorithm
+#[utoipa::path(
+    get,
+    path = "/v2/process_tracker/revenue_recovery_workflow/{revenue_recovery_id}/retry_timeline",
+    params(
+        ("recovery_recovery_id" = String, Path, description = "The payment intent id"),
+    ),
+    responses(
+        (status = 200, description = "Revenue Recovery Retry Timeline Retrieved Successfully", body = RevenueRecoveryRetryTimelineResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Revenue Recovery",
+   operation_id = "Retrieve Revenue Recovery Retry Timeline",
+   security(("jwt_key" = []))
+)]
+pub async fn revenue_recovery_retry_timeline_api() {}
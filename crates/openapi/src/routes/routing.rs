@@ -254,6 +254,30 @@ pub async fn routing_retrieve_default_config_for_profiles() {}
 )]
 pub async fn routing_update_default_config_for_profile() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Explain Payment Method Eligibility For Profile
+///
+/// Explain which of the connectors of the profile are eligible for each payment method in a payment context, and why the others are not
+#[utoipa::path(
+    post,
+    path = "/routing/eligibility/profile/{profile_id}",
+    request_body = PaymentMethodEligibilityRequest,
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully explained payment method eligibility for profile", body = PaymentMethodEligibilityResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Explain payment method eligibility for profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_explain_payment_method_eligibility() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Toggle success based dynamic routing for profile
 ///
@@ -115,3 +115,47 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Bulk Resend
+///
+/// Resend all Events of a Business Profile created within the specified time range. The Events are
+/// resent in batches by a background job, at the rate configured for the server.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/bulk_resend",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = WebhookEventsBulkResendRequest,
+        description = "The constraints that the Events to be resent must satisfy",
+    ),
+    responses(
+        (status = 200, description = "The bulk resend job was scheduled successfully", body = WebhookEventsBulkResendResponse),
+        (status = 404, description = "No Events found matching the specified constraints"),
+    ),
+    tag = "Event",
+    operation_id = "Bulk resend Events",
+    security(("admin_api_key" = []))
+)]
+pub fn bulk_resend_webhook_events() {}
+
+/// Events - Retrieve Bulk Resend
+///
+/// Retrieve the progress of a bulk resend job.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/bulk_resend/{batch_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("batch_id" = String, Path, description = "The unique identifier for the bulk resend job"),
+    ),
+    responses(
+        (status = 200, description = "The bulk resend job was retrieved successfully", body = WebhookEventsBulkResendResponse),
+        (status = 404, description = "The bulk resend job was not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve a bulk resend job",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_events_bulk_resend() {}
@@ -71,7 +71,7 @@ pub struct PaymentMethodsMigrateForm {
         text::Text<Option<common_utils::id_type::MerchantConnectorAccountId>>,
 }
 
-fn parse_csv(data: &[u8]) -> csv::Result<Vec<pm_api::PaymentMethodRecord>> {
+pub fn parse_csv(data: &[u8]) -> csv::Result<Vec<pm_api::PaymentMethodRecord>> {
     let mut csv_reader = Reader::from_reader(data);
     let mut records = Vec::new();
     let mut id_counter = 0;
@@ -1,3 +1,8 @@
+pub mod gocardless_bank_data;
+pub mod mock;
 pub mod plaid;
+pub mod truelayer;
 
-pub use self::plaid::Plaid;
+pub use self::{
+    gocardless_bank_data::GocardlessBankData, mock::Mock, plaid::Plaid, truelayer::Truelayer,
+};
@@ -0,0 +1,595 @@
+pub mod transformers;
+
+use std::fmt::Debug;
+
+use common_utils::{
+    ext_traits::BytesExt,
+    request::{Method, Request, RequestBuilder, RequestContent},
+};
+use error_stack::ResultExt;
+use masking::{Mask, Maskable, PeekInterface};
+use transformers as gocardless_bank_data;
+
+use crate::{
+    core::errors,
+    types::{
+        self as auth_types,
+        api::{
+            auth_service::{
+                self, AccessToken, BankAccountCredentials, ExchangeToken, LinkToken,
+                RecipientCreate,
+            },
+            ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        },
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct GocardlessBankData;
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for GocardlessBankData
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &auth_types::PaymentAuthRouterData<Flow, Request, Response>,
+        _connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            "Content-Type".to_string(),
+            self.get_content_type().to_string().into(),
+        )];
+
+        if let Some(access_token) = &req.access_token {
+            header.push((
+                "Authorization".to_string(),
+                format!("Bearer {}", access_token.peek()).into_masked(),
+            ));
+        }
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for GocardlessBankData {
+    fn id(&self) -> &'static str {
+        "gocardless_bank_data"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str {
+        "https://bankaccountdata.gocardless.com"
+    }
+
+    fn build_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        let response: gocardless_bank_data::GocardlessBankDataErrorResponse = res
+            .response
+            .parse_struct("GocardlessBankDataErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::ErrorResponse {
+            status_code: res.status_code,
+            code: crate::consts::NO_ERROR_CODE.to_string(),
+            message: response.summary,
+            reason: response.detail,
+        })
+    }
+}
+
+impl auth_service::AuthService for GocardlessBankData {}
+impl auth_service::PaymentInitiationRecipientCreate for GocardlessBankData {}
+impl auth_service::PaymentInitiation for GocardlessBankData {}
+impl auth_service::AuthServiceAccessToken for GocardlessBankData {}
+
+impl
+    ConnectorIntegration<
+        AccessToken,
+        auth_types::AccessTokenRequest,
+        auth_types::AccessTokenResponse,
+    > for GocardlessBankData
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::AccessTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::AccessTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/api/v2/token/new/"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::AccessTokenRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = gocardless_bank_data::GocardlessBankDataAccessTokenRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::AccessTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthAccessTokenType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthAccessTokenType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(auth_types::PaymentAuthAccessTokenType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::AccessTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::AccessTokenRouterData, errors::ConnectorError> {
+        let response: gocardless_bank_data::GocardlessBankDataAccessTokenResponse = res
+            .response
+            .parse_struct("GocardlessBankDataAccessTokenResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::AccessTokenRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceLinkToken for GocardlessBankData {}
+
+impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
+    for GocardlessBankData
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::LinkTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::LinkTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/api/v2/requisitions/"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::LinkTokenRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = gocardless_bank_data::GocardlessBankDataRequisitionRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::LinkTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthLinkTokenType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthLinkTokenType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(auth_types::PaymentAuthLinkTokenType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::LinkTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::LinkTokenRouterData, errors::ConnectorError> {
+        let response: gocardless_bank_data::GocardlessBankDataRequisitionResponse = res
+            .response
+            .parse_struct("GocardlessBankDataRequisitionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::LinkTokenRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceExchangeToken for GocardlessBankData {}
+
+impl
+    ConnectorIntegration<
+        ExchangeToken,
+        auth_types::ExchangeTokenRequest,
+        auth_types::ExchangeTokenResponse,
+    > for GocardlessBankData
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    // The public token handed back by the client is the requisition id
+    fn get_url(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/api/v2/requisitions/{}/",
+            self.base_url(connectors),
+            req.request.public_token
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Get)
+                .url(&auth_types::PaymentAuthExchangeTokenType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthExchangeTokenType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::ExchangeTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ExchangeTokenRouterData, errors::ConnectorError> {
+        let response: gocardless_bank_data::GocardlessBankDataRequisitionResponse = res
+            .response
+            .parse_struct("GocardlessBankDataRequisitionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::ExchangeTokenRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceBankAccountCredentials for GocardlessBankData {}
+
+impl
+    ConnectorIntegration<
+        BankAccountCredentials,
+        auth_types::BankAccountCredentialsRequest,
+        auth_types::BankAccountCredentialsResponse,
+    > for GocardlessBankData
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    // Account details are only exposed one account at a time
+    fn get_url(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        let account_id = gocardless_bank_data::get_account_id(req)?;
+        Ok(format!(
+            "{}/api/v2/accounts/{}/details/",
+            self.base_url(connectors),
+            account_id.peek()
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Get)
+                .url(&auth_types::PaymentAuthBankAccountDetailsType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthBankAccountDetailsType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankDetailsRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankDetailsRouterData, errors::ConnectorError> {
+        let response: gocardless_bank_data::GocardlessBankDataAccountDetailsResponse = res
+            .response
+            .parse_struct("GocardlessBankDataAccountDetailsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::BankDetailsRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        RecipientCreate,
+        auth_types::RecipientCreateRequest,
+        auth_types::RecipientCreateResponse,
+    > for GocardlessBankData
+{
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::marker::PhantomData;
+
+    use common_utils::request::Method;
+    use masking::{PeekInterface, Secret};
+
+    use super::GocardlessBankData;
+    use crate::{
+        core::errors,
+        types::{self as auth_types, api::ConnectorIntegration},
+    };
+
+    fn get_router_data<F, Req, Resp>(
+        request: Req,
+    ) -> auth_types::PaymentAuthRouterData<F, Req, Resp> {
+        auth_types::PaymentAuthRouterData {
+            flow: PhantomData,
+            merchant_id: None,
+            connector: Some("gocardless_bank_data".to_string()),
+            request,
+            response: Err(auth_types::ErrorResponse {
+                code: "IR_00".to_string(),
+                message: "no response".to_string(),
+                reason: None,
+                status_code: 500,
+            }),
+            connector_auth_type: auth_types::ConnectorAuthType::BodyKey {
+                client_id: Secret::new("secret-id".to_string()),
+                secret: Secret::new("secret-key".to_string()),
+            },
+            connector_http_status_code: None,
+            access_token: Some(Secret::new("gocardless-access".to_string())),
+        }
+    }
+
+    fn get_link_token_request(institution_id: Option<&str>) -> auth_types::LinkTokenRequest {
+        auth_types::LinkTokenRequest {
+            client_name: "HyperSwitch".to_string(),
+            country_codes: None,
+            language: Some("de".to_string()),
+            user_info: None,
+            client_platform: None,
+            android_package_name: None,
+            redirect_uri: Some("https://merchant.example.com/callback".to_string()),
+            institution_id: institution_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_access_token_request() {
+        let router_data: auth_types::AccessTokenRouterData =
+            get_router_data(auth_types::AccessTokenRequest);
+
+        let request = auth_types::PaymentAuthAccessTokenType::build_request(
+            &GocardlessBankData,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::GocardlessBankData,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.url,
+            "https://bankaccountdata.gocardless.com/api/v2/token/new/"
+        );
+        assert_eq!(
+            request.body.unwrap().get_inner_value().peek(),
+            r#"{"secret_id":"secret-id","secret_key":"secret-key"}"#
+        );
+    }
+
+    #[test]
+    fn test_requisition_request() {
+        let router_data: auth_types::LinkTokenRouterData =
+            get_router_data(get_link_token_request(Some("SANDBOXFINANCE_SFIN0000")));
+
+        let request = auth_types::PaymentAuthLinkTokenType::build_request(
+            &GocardlessBankData,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::GocardlessBankData,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.url,
+            "https://bankaccountdata.gocardless.com/api/v2/requisitions/"
+        );
+        assert!(request
+            .headers
+            .iter()
+            .any(|(name, _)| name == "Authorization"));
+        assert_eq!(
+            request.body.unwrap().get_inner_value().peek(),
+            r#"{"redirect":"https://merchant.example.com/callback","institution_id":"SANDBOXFINANCE_SFIN0000","user_language":"DE"}"#
+        );
+    }
+
+    #[test]
+    fn test_requisition_request_requires_institution_id() {
+        let router_data: auth_types::LinkTokenRouterData =
+            get_router_data(get_link_token_request(None));
+
+        let error = auth_types::PaymentAuthLinkTokenType::get_request_body(
+            &GocardlessBankData,
+            &router_data,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "institution_id"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_exchange_token_request_reads_requisition() {
+        let router_data: auth_types::ExchangeTokenRouterData =
+            get_router_data(auth_types::ExchangeTokenRequest {
+                public_token: "requisition-id".to_string(),
+                redirect_uri: None,
+            });
+
+        let request = auth_types::PaymentAuthExchangeTokenType::build_request(
+            &GocardlessBankData,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::GocardlessBankData,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(
+            request.url,
+            "https://bankaccountdata.gocardless.com/api/v2/requisitions/requisition-id/"
+        );
+    }
+
+    #[test]
+    fn test_get_accounts_request_requires_account_id() {
+        let mut router_data: auth_types::BankDetailsRouterData =
+            get_router_data(auth_types::BankAccountCredentialsRequest {
+                access_token: Secret::new("gocardless-access".to_string()),
+                optional_ids: None,
+            });
+
+        assert!(auth_types::PaymentAuthBankAccountDetailsType::get_url(
+            &GocardlessBankData,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::GocardlessBankData,
+        )
+        .is_err());
+
+        router_data.request.optional_ids = Some(auth_types::BankAccountOptionalIDs {
+            ids: vec![Secret::new("account-1".to_string())],
+        });
+        let url = auth_types::PaymentAuthBankAccountDetailsType::get_url(
+            &GocardlessBankData,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::GocardlessBankData,
+        )
+        .unwrap();
+
+        assert_eq!(
+            url,
+            "https://bankaccountdata.gocardless.com/api/v2/accounts/account-1/details/"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use common_enums::{PaymentMethod, PaymentMethodType};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types};
+
+// Requisition status once the account holder has authorised access to their accounts
+const REQUISITION_STATUS_LINKED: &str = "LN";
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessBankDataAccessTokenRequest {
+    secret_id: Secret<String>,
+    secret_key: Secret<String>,
+}
+
+impl TryFrom<&types::AccessTokenRouterData> for GocardlessBankDataAccessTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::AccessTokenRouterData) -> Result<Self, Self::Error> {
+        let auth = GocardlessBankDataAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            secret_id: auth.secret_id,
+            secret_key: auth.secret_key,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessBankDataAccessTokenResponse {
+    pub access: Secret<String>,
+    pub access_expires: i64,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            GocardlessBankDataAccessTokenResponse,
+            T,
+            types::AccessTokenResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::AccessTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessBankDataAccessTokenResponse,
+            T,
+            types::AccessTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AccessTokenResponse {
+                access_token: item.response.access,
+                expires_in: item.response.access_expires,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GocardlessBankDataRequisitionRequest {
+    redirect: String,
+    institution_id: String,
+    user_language: Option<String>,
+}
+
+impl TryFrom<&types::LinkTokenRouterData> for GocardlessBankDataRequisitionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::LinkTokenRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            redirect: item.request.redirect_uri.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "redirect_uri",
+                },
+            )?,
+            institution_id: item.request.institution_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "institution_id",
+                },
+            )?,
+            user_language: item
+                .request
+                .language
+                .as_ref()
+                .map(|language| language.to_uppercase()),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessBankDataRequisitionResponse {
+    pub id: String,
+    pub status: String,
+    pub link: String,
+    #[serde(default)]
+    pub accounts: Vec<Secret<String>>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            GocardlessBankDataRequisitionResponse,
+            T,
+            types::LinkTokenResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::LinkTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessBankDataRequisitionResponse,
+            T,
+            types::LinkTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::LinkTokenResponse {
+                link_token: item.response.id,
+                redirect_url: Some(item.response.link),
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            GocardlessBankDataRequisitionResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::ExchangeTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessBankDataRequisitionResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = if item.response.status == REQUISITION_STATUS_LINKED {
+            Ok(types::ExchangeTokenResponse {
+                access_token: item.response.id,
+                account_ids: Some(item.response.accounts),
+            })
+        } else {
+            Err(types::ErrorResponse {
+                code: item.response.status,
+                message: "Requisition has not been linked by the account holder".to_string(),
+                reason: None,
+                status_code: item.http_code,
+            })
+        };
+
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+pub fn get_account_id(
+    item: &types::BankDetailsRouterData,
+) -> errors::CustomResult<&Secret<String>, errors::ConnectorError> {
+    item.request
+        .optional_ids
+        .as_ref()
+        .and_then(|optional_ids| optional_ids.ids.first())
+        .ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "account_ids",
+            }
+            .into(),
+        )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessBankDataAccountDetailsResponse {
+    pub account: GocardlessBankDataAccount,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GocardlessBankDataAccount {
+    pub iban: Option<Secret<String>>,
+    pub bic: Option<Secret<String>>,
+    pub name: Option<String>,
+    pub owner_name: Option<String>,
+    pub cash_account_type: Option<String>,
+    pub currency: Option<String>,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            GocardlessBankDataAccountDetailsResponse,
+            types::BankAccountCredentialsRequest,
+            types::BankAccountCredentialsResponse,
+        >,
+    >
+    for types::PaymentAuthRouterData<
+        F,
+        types::BankAccountCredentialsRequest,
+        types::BankAccountCredentialsResponse,
+    >
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            GocardlessBankDataAccountDetailsResponse,
+            types::BankAccountCredentialsRequest,
+            types::BankAccountCredentialsResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let account_id = item
+            .data
+            .request
+            .optional_ids
+            .as_ref()
+            .and_then(|optional_ids| optional_ids.ids.first())
+            .cloned()
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "account_ids",
+            })?;
+        let account = item.response.account;
+
+        let credentials = account
+            .iban
+            .map(|iban| {
+                let (account_details, payment_method_type) = match get_bacs_details(&iban) {
+                    Some(bacs) => (
+                        types::PaymentMethodTypeDetails::Bacs(bacs),
+                        PaymentMethodType::Bacs,
+                    ),
+                    None => (
+                        types::PaymentMethodTypeDetails::Sepa(types::BankAccountDetailsSepa {
+                            iban,
+                            bic: account.bic.unwrap_or_default(),
+                        }),
+                        PaymentMethodType::Sepa,
+                    ),
+                };
+
+                types::BankAccountDetails {
+                    account_name: account.name.or(account.owner_name),
+                    account_details,
+                    payment_method_type,
+                    payment_method: PaymentMethod::BankDebit,
+                    account_id,
+                    account_type: account
+                        .cash_account_type
+                        .as_deref()
+                        .and_then(get_bank_account_type),
+                    balance: None,
+                }
+            })
+            .into_iter()
+            .collect();
+
+        Ok(Self {
+            response: Ok(types::BankAccountCredentialsResponse { credentials }),
+            ..item.data
+        })
+    }
+}
+
+/// UK accounts are debited over Bacs, the sort code and account number are embedded in the IBAN
+fn get_bacs_details(iban: &Secret<String>) -> Option<types::BankAccountDetailsBacs> {
+    let iban = iban.peek();
+    match (iban.get(..2), iban.get(8..14), iban.get(14..)) {
+        (Some("GB"), Some(sort_code), Some(account_number)) if account_number.len() == 8 => {
+            Some(types::BankAccountDetailsBacs {
+                account_number: Secret::new(account_number.to_string()),
+                sort_code: Secret::new(sort_code.to_string()),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn get_bank_account_type(cash_account_type: &str) -> Option<String> {
+    match cash_account_type {
+        "CACC" => Some("checking".to_string()),
+        "SVGS" => Some("savings".to_string()),
+        _ => None,
+    }
+}
+
+pub struct GocardlessBankDataAuthType {
+    pub secret_id: Secret<String>,
+    pub secret_key: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for GocardlessBankDataAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { client_id, secret } => Ok(Self {
+                secret_id: client_id.to_owned(),
+                secret_key: secret.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GocardlessBankDataErrorResponse {
+    pub summary: String,
+    pub detail: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::{PeekInterface, Secret};
+
+    use super::get_bacs_details;
+
+    #[test]
+    fn test_bacs_details_from_uk_iban() {
+        let bacs = get_bacs_details(&Secret::new("GB33BUKB20201555555555".to_string())).unwrap();
+
+        assert_eq!(bacs.sort_code.peek(), "202015");
+        assert_eq!(bacs.account_number.peek(), "55555555");
+    }
+
+    #[test]
+    fn test_no_bacs_details_for_sepa_iban() {
+        assert!(get_bacs_details(&Secret::new("DE89370400440532013000".to_string())).is_none());
+    }
+}
@@ -0,0 +1,337 @@
+use std::fmt::Debug;
+
+use common_enums::{PaymentMethod, PaymentMethodType};
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    core::errors,
+    types::{
+        self as auth_types,
+        api::{
+            auth_service::{
+                self, AccessToken, BankAccountCredentials, ExchangeToken, LinkToken,
+                RecipientCreate,
+            },
+            ConnectorCommon, ConnectorIntegration,
+        },
+    },
+};
+
+/// Open banking connector that answers every flow locally with fixed sandbox accounts,
+/// without making any network calls
+#[derive(Debug, Clone)]
+pub struct Mock;
+
+impl ConnectorCommon for Mock {
+    fn id(&self) -> &'static str {
+        "mock"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str {
+        ""
+    }
+}
+
+impl auth_service::AuthService for Mock {}
+impl auth_service::PaymentInitiation for Mock {}
+impl auth_service::AuthServiceAccessToken for Mock {}
+
+impl
+    ConnectorIntegration<
+        AccessToken,
+        auth_types::AccessTokenRequest,
+        auth_types::AccessTokenResponse,
+    > for Mock
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::AccessTokenRouterData,
+    ) -> errors::CustomResult<auth_types::AccessTokenRouterData, errors::ConnectorError> {
+        Ok(auth_types::AccessTokenRouterData {
+            response: Ok(auth_types::AccessTokenResponse {
+                access_token: Secret::new("mock-access-token".to_string()),
+                expires_in: 3600,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceLinkToken for Mock {}
+
+impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
+    for Mock
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::LinkTokenRouterData,
+    ) -> errors::CustomResult<auth_types::LinkTokenRouterData, errors::ConnectorError> {
+        Ok(auth_types::LinkTokenRouterData {
+            response: Ok(auth_types::LinkTokenResponse {
+                link_token: "link-mock-token".to_string(),
+                redirect_url: None,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceExchangeToken for Mock {}
+
+impl
+    ConnectorIntegration<
+        ExchangeToken,
+        auth_types::ExchangeTokenRequest,
+        auth_types::ExchangeTokenResponse,
+    > for Mock
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::ExchangeTokenRouterData,
+    ) -> errors::CustomResult<auth_types::ExchangeTokenRouterData, errors::ConnectorError> {
+        Ok(auth_types::ExchangeTokenRouterData {
+            response: Ok(auth_types::ExchangeTokenResponse {
+                access_token: format!("access-mock-{}", data.request.public_token),
+                account_ids: None,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceBankAccountCredentials for Mock {}
+
+impl
+    ConnectorIntegration<
+        BankAccountCredentials,
+        auth_types::BankAccountCredentialsRequest,
+        auth_types::BankAccountCredentialsResponse,
+    > for Mock
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::BankDetailsRouterData,
+    ) -> errors::CustomResult<auth_types::BankDetailsRouterData, errors::ConnectorError> {
+        let credentials = get_mock_bank_accounts()
+            .into_iter()
+            .filter(|account| {
+                data.request
+                    .optional_ids
+                    .as_ref()
+                    .map_or(true, |optional_ids| {
+                        optional_ids
+                            .ids
+                            .iter()
+                            .any(|id| id.peek() == account.account_id.peek())
+                    })
+            })
+            .collect();
+
+        Ok(auth_types::BankDetailsRouterData {
+            response: Ok(auth_types::BankAccountCredentialsResponse { credentials }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationRecipientCreate for Mock {}
+
+impl
+    ConnectorIntegration<
+        RecipientCreate,
+        auth_types::RecipientCreateRequest,
+        auth_types::RecipientCreateResponse,
+    > for Mock
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::RecipientCreateRouterData,
+    ) -> errors::CustomResult<auth_types::RecipientCreateRouterData, errors::ConnectorError> {
+        Ok(auth_types::RecipientCreateRouterData {
+            response: Ok(auth_types::RecipientCreateResponse {
+                recipient_id: "recipient-mock".to_string(),
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+fn get_mock_bank_accounts() -> Vec<auth_types::BankAccountDetails> {
+    vec![
+        auth_types::BankAccountDetails {
+            account_name: Some("Mock Checking".to_string()),
+            account_details: auth_types::PaymentMethodTypeDetails::Ach(
+                auth_types::BankAccountDetailsAch {
+                    account_number: Secret::new("1111222233330000".to_string()),
+                    routing_number: Secret::new("011401533".to_string()),
+                },
+            ),
+            payment_method_type: PaymentMethodType::Ach,
+            payment_method: PaymentMethod::BankDebit,
+            account_id: Secret::new("mock-ach-account".to_string()),
+            account_type: Some("checking".to_string()),
+            balance: None,
+        },
+        auth_types::BankAccountDetails {
+            account_name: Some("Mock Current Account".to_string()),
+            account_details: auth_types::PaymentMethodTypeDetails::Bacs(
+                auth_types::BankAccountDetailsBacs {
+                    account_number: Secret::new("55779911".to_string()),
+                    sort_code: Secret::new("200000".to_string()),
+                },
+            ),
+            payment_method_type: PaymentMethodType::Bacs,
+            payment_method: PaymentMethod::BankDebit,
+            account_id: Secret::new("mock-bacs-account".to_string()),
+            account_type: Some("checking".to_string()),
+            balance: None,
+        },
+        auth_types::BankAccountDetails {
+            account_name: Some("Mock Girokonto".to_string()),
+            account_details: auth_types::PaymentMethodTypeDetails::Sepa(
+                auth_types::BankAccountDetailsSepa {
+                    iban: Secret::new("DE89370400440532013000".to_string()),
+                    bic: Secret::new("COBADEFFXXX".to_string()),
+                },
+            ),
+            payment_method_type: PaymentMethodType::Sepa,
+            payment_method: PaymentMethod::BankDebit,
+            account_id: Secret::new("mock-sepa-account".to_string()),
+            account_type: Some("checking".to_string()),
+            balance: None,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use std::marker::PhantomData;
+
+    use masking::{PeekInterface, Secret};
+
+    use super::Mock;
+    use crate::types::{self as auth_types, api::ConnectorIntegration};
+
+    fn get_router_data<F, Req, Resp>(
+        request: Req,
+    ) -> auth_types::PaymentAuthRouterData<F, Req, Resp> {
+        auth_types::PaymentAuthRouterData {
+            flow: PhantomData,
+            merchant_id: None,
+            connector: Some("mock".to_string()),
+            request,
+            response: Err(auth_types::ErrorResponse {
+                code: "IR_00".to_string(),
+                message: "no response".to_string(),
+                reason: None,
+                status_code: 500,
+            }),
+            connector_auth_type: auth_types::ConnectorAuthType::NoKey,
+            connector_http_status_code: None,
+            access_token: None,
+        }
+    }
+
+    #[test]
+    fn test_link_token_is_served_locally() {
+        let router_data: auth_types::LinkTokenRouterData =
+            get_router_data(auth_types::LinkTokenRequest {
+                client_name: "HyperSwitch".to_string(),
+                country_codes: None,
+                language: None,
+                user_info: None,
+                client_platform: None,
+                android_package_name: None,
+                redirect_uri: None,
+                institution_id: None,
+            });
+
+        assert!(auth_types::PaymentAuthLinkTokenType::build_request(
+            &Mock,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::Mock
+        )
+        .unwrap()
+        .is_none());
+
+        let response =
+            auth_types::PaymentAuthLinkTokenType::handle_local_response(&Mock, &router_data)
+                .unwrap()
+                .response
+                .unwrap();
+
+        assert_eq!(response.link_token, "link-mock-token");
+        assert!(response.redirect_url.is_none());
+    }
+
+    #[test]
+    fn test_exchange_token_derives_access_token_from_public_token() {
+        let router_data: auth_types::ExchangeTokenRouterData =
+            get_router_data(auth_types::ExchangeTokenRequest {
+                public_token: "public-token".to_string(),
+                redirect_uri: None,
+            });
+
+        let response =
+            auth_types::PaymentAuthExchangeTokenType::handle_local_response(&Mock, &router_data)
+                .unwrap()
+                .response
+                .unwrap();
+
+        assert_eq!(response.access_token, "access-mock-public-token");
+        assert!(response.account_ids.is_none());
+    }
+
+    #[test]
+    fn test_get_accounts_returns_all_accounts_without_ids() {
+        let router_data: auth_types::BankDetailsRouterData =
+            get_router_data(auth_types::BankAccountCredentialsRequest {
+                access_token: Secret::new("access-mock-public-token".to_string()),
+                optional_ids: None,
+            });
+
+        let response = auth_types::PaymentAuthBankAccountDetailsType::handle_local_response(
+            &Mock,
+            &router_data,
+        )
+        .unwrap()
+        .response
+        .unwrap();
+
+        let account_ids = response
+            .credentials
+            .iter()
+            .map(|account| account.account_id.peek().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            account_ids,
+            ["mock-ach-account", "mock-bacs-account", "mock-sepa-account"]
+        );
+    }
+
+    #[test]
+    fn test_get_accounts_filters_by_requested_ids() {
+        let router_data: auth_types::BankDetailsRouterData =
+            get_router_data(auth_types::BankAccountCredentialsRequest {
+                access_token: Secret::new("access-mock-public-token".to_string()),
+                optional_ids: Some(auth_types::BankAccountOptionalIDs {
+                    ids: vec![Secret::new("mock-sepa-account".to_string())],
+                }),
+            });
+
+        let response = auth_types::PaymentAuthBankAccountDetailsType::handle_local_response(
+            &Mock,
+            &router_data,
+        )
+        .unwrap()
+        .response
+        .unwrap();
+
+        assert_eq!(response.credentials.len(), 1);
+        assert!(matches!(
+            response.credentials[0].account_details,
+            auth_types::PaymentMethodTypeDetails::Sepa(_)
+        ));
+    }
+}
@@ -16,7 +16,8 @@ use crate::{
         self as auth_types,
         api::{
             auth_service::{
-                self, BankAccountCredentials, ExchangeToken, LinkToken, RecipientCreate,
+                self, AccessToken, BankAccountCredentials, ExchangeToken, LinkToken,
+                RecipientCreate,
             },
             ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
         },
@@ -93,8 +94,18 @@ impl ConnectorCommon for Plaid {
 impl auth_service::AuthService for Plaid {}
 impl auth_service::PaymentInitiationRecipientCreate for Plaid {}
 impl auth_service::PaymentInitiation for Plaid {}
+impl auth_service::AuthServiceAccessToken for Plaid {}
 impl auth_service::AuthServiceLinkToken for Plaid {}
 
+impl
+    ConnectorIntegration<
+        AccessToken,
+        auth_types::AccessTokenRequest,
+        auth_types::AccessTokenResponse,
+    > for Plaid
+{
+}
+
 impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
     for Plaid
 {
@@ -80,6 +80,7 @@ impl<F, T>
         Ok(Self {
             response: Ok(types::LinkTokenResponse {
                 link_token: item.response.link_token,
+                redirect_url: None,
             }),
             ..item.data
         })
@@ -114,6 +115,7 @@ impl<F, T>
         Ok(Self {
             response: Ok(types::ExchangeTokenResponse {
                 access_token: item.response.access_token,
+                account_ids: None,
             }),
             ..item.data
         })
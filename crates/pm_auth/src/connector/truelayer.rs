@@ -0,0 +1,461 @@
+pub mod transformers;
+
+use std::fmt::Debug;
+
+use common_utils::{
+    ext_traits::BytesExt,
+    request::{Method, Request, RequestBuilder, RequestContent},
+};
+use error_stack::ResultExt;
+use masking::{Mask, Maskable, PeekInterface};
+use transformers as truelayer;
+
+use crate::{
+    core::errors,
+    types::{
+        self as auth_types,
+        api::{
+            auth_service::{
+                self, AccessToken, BankAccountCredentials, ExchangeToken, LinkToken,
+                RecipientCreate,
+            },
+            ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        },
+    },
+};
+
+const TRUELAYER_AUTH_BASE_URL: &str = "https://auth.truelayer-sandbox.com";
+
+#[derive(Debug, Clone)]
+pub struct Truelayer;
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Truelayer
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        _req: &auth_types::PaymentAuthRouterData<Flow, Request, Response>,
+        _connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            "Content-Type".to_string(),
+            self.get_content_type().to_string().into(),
+        )])
+    }
+}
+
+impl ConnectorCommon for Truelayer {
+    fn id(&self) -> &'static str {
+        "truelayer"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str {
+        "https://api.truelayer-sandbox.com"
+    }
+
+    fn build_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        let response: truelayer::TruelayerErrorResponse = res
+            .response
+            .parse_struct("TruelayerErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error.clone(),
+            message: response.error,
+            reason: response.error_description,
+        })
+    }
+}
+
+impl auth_service::AuthService for Truelayer {}
+impl auth_service::PaymentInitiationRecipientCreate for Truelayer {}
+impl auth_service::PaymentInitiation for Truelayer {}
+impl auth_service::AuthServiceAccessToken for Truelayer {}
+impl auth_service::AuthServiceLinkToken for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        AccessToken,
+        auth_types::AccessTokenRequest,
+        auth_types::AccessTokenResponse,
+    > for Truelayer
+{
+}
+
+impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
+    for Truelayer
+{
+    // The auth link is built from the client credentials, no call to TrueLayer is needed
+    fn handle_local_response(
+        &self,
+        data: &auth_types::LinkTokenRouterData,
+    ) -> errors::CustomResult<auth_types::LinkTokenRouterData, errors::ConnectorError> {
+        let query = truelayer::TruelayerAuthLinkQuery::try_from(data)?;
+        let auth_link = format!("{TRUELAYER_AUTH_BASE_URL}/?{}", query.get_query_string()?);
+
+        Ok(auth_types::LinkTokenRouterData {
+            response: Ok(auth_types::LinkTokenResponse {
+                link_token: auth_link.clone(),
+                redirect_url: Some(auth_link),
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceExchangeToken for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        ExchangeToken,
+        auth_types::ExchangeTokenRequest,
+        auth_types::ExchangeTokenResponse,
+    > for Truelayer
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::ExchangeTokenRouterData,
+        _connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{TRUELAYER_AUTH_BASE_URL}/connect/token"))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = truelayer::TruelayerExchangeTokenRequest::try_from(req)?;
+        Ok(RequestContent::FormUrlEncoded(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthExchangeTokenType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthExchangeTokenType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(auth_types::PaymentAuthExchangeTokenType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::ExchangeTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ExchangeTokenRouterData, errors::ConnectorError> {
+        let response: truelayer::TruelayerExchangeTokenResponse = res
+            .response
+            .parse_struct("TruelayerExchangeTokenResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::ExchangeTokenRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceBankAccountCredentials for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        BankAccountCredentials,
+        auth_types::BankAccountCredentialsRequest,
+        auth_types::BankAccountCredentialsResponse,
+    > for Truelayer
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut headers = self.build_headers(req, connectors)?;
+        headers.push((
+            "Authorization".to_string(),
+            format!("Bearer {}", req.request.access_token.peek()).into_masked(),
+        ));
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        let account_id = req
+            .request
+            .optional_ids
+            .as_ref()
+            .and_then(|optional_ids| optional_ids.ids.first());
+
+        Ok(match account_id {
+            Some(account_id) => format!(
+                "{}/data/v1/accounts/{}",
+                self.base_url(connectors),
+                account_id.peek()
+            ),
+            None => format!("{}/data/v1/accounts", self.base_url(connectors)),
+        })
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Get)
+                .url(&auth_types::PaymentAuthBankAccountDetailsType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthBankAccountDetailsType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankDetailsRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankDetailsRouterData, errors::ConnectorError> {
+        let response: truelayer::TruelayerAccountsResponse = res
+            .response
+            .parse_struct("TruelayerAccountsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::BankDetailsRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    ConnectorIntegration<
+        RecipientCreate,
+        auth_types::RecipientCreateRequest,
+        auth_types::RecipientCreateResponse,
+    > for Truelayer
+{
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::marker::PhantomData;
+
+    use common_utils::request::Method;
+    use masking::{PeekInterface, Secret};
+
+    use super::Truelayer;
+    use crate::{
+        core::errors,
+        types::{self as auth_types, api::ConnectorIntegration},
+    };
+
+    const REDIRECT_URI: &str = "https://merchant.example.com/truelayer/callback";
+
+    fn get_router_data<F, Req, Resp>(
+        request: Req,
+    ) -> auth_types::PaymentAuthRouterData<F, Req, Resp> {
+        auth_types::PaymentAuthRouterData {
+            flow: PhantomData,
+            merchant_id: None,
+            connector: Some("truelayer".to_string()),
+            request,
+            response: Err(auth_types::ErrorResponse {
+                code: "IR_00".to_string(),
+                message: "no response".to_string(),
+                reason: None,
+                status_code: 500,
+            }),
+            connector_auth_type: auth_types::ConnectorAuthType::BodyKey {
+                client_id: Secret::new("sandbox-client".to_string()),
+                secret: Secret::new("sandbox-secret".to_string()),
+            },
+            connector_http_status_code: None,
+            access_token: None,
+        }
+    }
+
+    fn get_link_token_request(redirect_uri: Option<&str>) -> auth_types::LinkTokenRequest {
+        auth_types::LinkTokenRequest {
+            client_name: "HyperSwitch".to_string(),
+            country_codes: None,
+            language: None,
+            user_info: None,
+            client_platform: None,
+            android_package_name: None,
+            redirect_uri: redirect_uri.map(str::to_string),
+            institution_id: None,
+        }
+    }
+
+    #[test]
+    fn test_link_token_builds_auth_link() {
+        let router_data: auth_types::LinkTokenRouterData =
+            get_router_data(get_link_token_request(Some(REDIRECT_URI)));
+
+        let response =
+            auth_types::PaymentAuthLinkTokenType::handle_local_response(&Truelayer, &router_data)
+                .unwrap()
+                .response
+                .unwrap();
+
+        assert!(response
+            .link_token
+            .starts_with("https://auth.truelayer-sandbox.com/?response_type=code"));
+        assert!(response.link_token.contains("client_id=sandbox-client"));
+        assert!(response
+            .link_token
+            .contains("redirect_uri=https%3A%2F%2Fmerchant.example.com%2Ftruelayer%2Fcallback"));
+        assert!(response
+            .link_token
+            .contains("providers=uk-ob-all+uk-oauth-all"));
+        assert!(!response.link_token.contains("sandbox-secret"));
+        assert_eq!(response.redirect_url, Some(response.link_token));
+    }
+
+    #[test]
+    fn test_link_token_requires_redirect_uri() {
+        let router_data: auth_types::LinkTokenRouterData =
+            get_router_data(get_link_token_request(None));
+
+        let error =
+            auth_types::PaymentAuthLinkTokenType::handle_local_response(&Truelayer, &router_data)
+                .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "redirect_uri"
+            }
+        ));
+    }
+
+    #[test]
+    fn test_exchange_token_request() {
+        let router_data: auth_types::ExchangeTokenRouterData =
+            get_router_data(auth_types::ExchangeTokenRequest {
+                public_token: "auth-code".to_string(),
+                redirect_uri: Some(REDIRECT_URI.to_string()),
+            });
+
+        let request = auth_types::PaymentAuthExchangeTokenType::build_request(
+            &Truelayer,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::Truelayer,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.url,
+            "https://auth.truelayer-sandbox.com/connect/token"
+        );
+
+        let body = request.body.unwrap().get_inner_value();
+        assert_eq!(
+            body.peek(),
+            "grant_type=authorization_code&client_id=sandbox-client&client_secret=sandbox-secret\
+             &redirect_uri=https%3A%2F%2Fmerchant.example.com%2Ftruelayer%2Fcallback&code=auth-code"
+        );
+    }
+
+    #[test]
+    fn test_get_accounts_request() {
+        let mut router_data: auth_types::BankDetailsRouterData =
+            get_router_data(auth_types::BankAccountCredentialsRequest {
+                access_token: Secret::new("data-access-token".to_string()),
+                optional_ids: None,
+            });
+
+        let request = auth_types::PaymentAuthBankAccountDetailsType::build_request(
+            &Truelayer,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::Truelayer,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(request.method, Method::Get);
+        assert_eq!(
+            request.url,
+            "https://api.truelayer-sandbox.com/data/v1/accounts"
+        );
+        assert!(request
+            .headers
+            .iter()
+            .any(|(name, _)| name == "Authorization"));
+
+        router_data.request.optional_ids = Some(auth_types::BankAccountOptionalIDs {
+            ids: vec![Secret::new("account-1".to_string())],
+        });
+        let url = auth_types::PaymentAuthBankAccountDetailsType::get_url(
+            &Truelayer,
+            &router_data,
+            &auth_types::PaymentMethodAuthConnectors::Truelayer,
+        )
+        .unwrap();
+
+        assert_eq!(
+            url,
+            "https://api.truelayer-sandbox.com/data/v1/accounts/account-1"
+        );
+    }
+}
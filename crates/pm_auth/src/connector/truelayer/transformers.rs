@@ -0,0 +1,237 @@
+use common_enums::{PaymentMethod, PaymentMethodType};
+use common_utils::ext_traits::Encode;
+use error_stack::ResultExt;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types};
+
+const TRUELAYER_DATA_SCOPES: &str = "info accounts balance offline_access";
+const TRUELAYER_DEFAULT_PROVIDERS: &str = "uk-ob-all uk-oauth-all";
+
+#[derive(Debug, Serialize)]
+pub struct TruelayerAuthLinkQuery {
+    response_type: String,
+    client_id: Secret<String>,
+    scope: String,
+    redirect_uri: String,
+    providers: String,
+}
+
+impl TruelayerAuthLinkQuery {
+    pub fn get_query_string(&self) -> errors::CustomResult<String, errors::ConnectorError> {
+        self.url_encode()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+    }
+}
+
+impl TryFrom<&types::LinkTokenRouterData> for TruelayerAuthLinkQuery {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::LinkTokenRouterData) -> Result<Self, Self::Error> {
+        let auth = TruelayerAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            response_type: "code".to_string(),
+            client_id: auth.client_id,
+            scope: TRUELAYER_DATA_SCOPES.to_string(),
+            redirect_uri: item.request.redirect_uri.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "redirect_uri",
+                },
+            )?,
+            providers: item
+                .request
+                .institution_id
+                .clone()
+                .unwrap_or(TRUELAYER_DEFAULT_PROVIDERS.to_string()),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TruelayerExchangeTokenRequest {
+    grant_type: String,
+    client_id: Secret<String>,
+    client_secret: Secret<String>,
+    redirect_uri: String,
+    code: String,
+}
+
+impl TryFrom<&types::ExchangeTokenRouterData> for TruelayerExchangeTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ExchangeTokenRouterData) -> Result<Self, Self::Error> {
+        let auth = TruelayerAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            grant_type: "authorization_code".to_string(),
+            client_id: auth.client_id,
+            client_secret: auth.client_secret,
+            redirect_uri: item.request.redirect_uri.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "redirect_uri",
+                },
+            )?,
+            code: item.request.public_token.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerExchangeTokenResponse {
+    pub access_token: String,
+    pub expires_in: Option<i64>,
+    pub token_type: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            TruelayerExchangeTokenResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::ExchangeTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            TruelayerExchangeTokenResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::ExchangeTokenResponse {
+                access_token: item.response.access_token,
+                account_ids: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerAccountsResponse {
+    pub results: Vec<TruelayerAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerAccount {
+    pub account_id: String,
+    pub account_type: Option<String>,
+    pub display_name: Option<String>,
+    pub currency: Option<String>,
+    pub account_number: TruelayerAccountNumber,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerAccountNumber {
+    pub iban: Option<Secret<String>>,
+    pub swift_bic: Option<Secret<String>>,
+    pub number: Option<Secret<String>>,
+    pub sort_code: Option<Secret<String>>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            TruelayerAccountsResponse,
+            T,
+            types::BankAccountCredentialsResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::BankAccountCredentialsResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            TruelayerAccountsResponse,
+            T,
+            types::BankAccountCredentialsResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let credentials = item
+            .response
+            .results
+            .into_iter()
+            .filter_map(|account| {
+                let TruelayerAccountNumber {
+                    iban,
+                    swift_bic,
+                    number,
+                    sort_code,
+                } = account.account_number;
+
+                // UK accounts are debited over Bacs, every other account is treated as SEPA
+                let (account_details, payment_method_type) = match (number, sort_code, iban) {
+                    (Some(account_number), Some(sort_code), _) => (
+                        types::PaymentMethodTypeDetails::Bacs(types::BankAccountDetailsBacs {
+                            account_number,
+                            sort_code,
+                        }),
+                        PaymentMethodType::Bacs,
+                    ),
+                    (_, _, Some(iban)) => (
+                        types::PaymentMethodTypeDetails::Sepa(types::BankAccountDetailsSepa {
+                            iban,
+                            bic: swift_bic.unwrap_or_default(),
+                        }),
+                        PaymentMethodType::Sepa,
+                    ),
+                    _ => return None,
+                };
+
+                Some(types::BankAccountDetails {
+                    account_name: account.display_name,
+                    account_details,
+                    payment_method_type,
+                    payment_method: PaymentMethod::BankDebit,
+                    account_id: account.account_id.into(),
+                    account_type: account
+                        .account_type
+                        .as_deref()
+                        .and_then(get_bank_account_type),
+                    balance: None,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            response: Ok(types::BankAccountCredentialsResponse { credentials }),
+            ..item.data
+        })
+    }
+}
+
+fn get_bank_account_type(account_type: &str) -> Option<String> {
+    match account_type {
+        "TRANSACTION" => Some("checking".to_string()),
+        "SAVINGS" => Some("savings".to_string()),
+        _ => None,
+    }
+}
+
+pub struct TruelayerAuthType {
+    pub client_id: Secret<String>,
+    pub client_secret: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for TruelayerAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { client_id, secret } => Ok(Self {
+                client_id: client_id.to_owned(),
+                client_secret: secret.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
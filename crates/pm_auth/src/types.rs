@@ -2,7 +2,9 @@ pub mod api;
 
 use std::marker::PhantomData;
 
-use api::auth_service::{BankAccountCredentials, ExchangeToken, LinkToken, RecipientCreate};
+use api::auth_service::{
+    AccessToken, BankAccountCredentials, ExchangeToken, LinkToken, RecipientCreate,
+};
 use api_models::enums as api_enums;
 use common_enums::{CountryAlpha2, PaymentMethod, PaymentMethodType};
 use common_utils::{id_type, types};
@@ -17,8 +19,21 @@ pub struct PaymentAuthRouterData<F, Request, Response> {
     pub response: Result<Response, ErrorResponse>,
     pub connector_auth_type: ConnectorAuthType,
     pub connector_http_status_code: Option<u16>,
+    pub access_token: Option<Secret<String>>,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccessTokenRequest;
+
+#[derive(Debug, Clone)]
+pub struct AccessTokenResponse {
+    pub access_token: Secret<String>,
+    pub expires_in: i64,
+}
+
+pub type AccessTokenRouterData =
+    PaymentAuthRouterData<AccessToken, AccessTokenRequest, AccessTokenResponse>;
+
 #[derive(Debug, Clone)]
 pub struct LinkTokenRequest {
     pub client_name: String,
@@ -28,11 +43,14 @@ pub struct LinkTokenRequest {
     pub client_platform: Option<api_enums::ClientPlatform>,
     pub android_package_name: Option<String>,
     pub redirect_uri: Option<String>,
+    pub institution_id: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct LinkTokenResponse {
     pub link_token: String,
+    /// Hosted page the account holder is sent to, for providers that authorise through a redirect
+    pub redirect_url: Option<String>,
 }
 
 pub type LinkTokenRouterData =
@@ -41,11 +59,14 @@ pub type LinkTokenRouterData =
 #[derive(Debug, Clone)]
 pub struct ExchangeTokenRequest {
     pub public_token: String,
+    pub redirect_uri: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ExchangeTokenResponse {
     pub access_token: String,
+    /// Accounts linked during the exchange, for providers that only expose details per account
+    pub account_ids: Option<Vec<Secret<String>>>,
 }
 
 impl From<ExchangeTokenResponse> for api_models::pm_auth::ExchangeTokenCreateResponse {
@@ -148,6 +169,9 @@ pub struct RecipientCreateAddress {
 pub type RecipientCreateRouterData =
     PaymentAuthRouterData<RecipientCreate, RecipientCreateRequest, RecipientCreateResponse>;
 
+pub type PaymentAuthAccessTokenType =
+    dyn api::ConnectorIntegration<AccessToken, AccessTokenRequest, AccessTokenResponse>;
+
 pub type PaymentAuthLinkTokenType =
     dyn api::ConnectorIntegration<LinkToken, LinkTokenRequest, LinkTokenResponse>;
 
@@ -167,6 +191,17 @@ pub type PaymentInitiationRecipientCreateType =
 #[strum(serialize_all = "snake_case")]
 pub enum PaymentMethodAuthConnectors {
     Plaid,
+    Truelayer,
+    GocardlessBankData,
+    Mock,
+}
+
+impl PaymentMethodAuthConnectors {
+    /// Whether the connector needs a short lived access token, obtained with the account
+    /// credentials, before any other flow can be called
+    pub fn supports_access_token(&self) -> bool {
+        matches!(self, Self::GocardlessBankData)
+    }
 }
 
 #[derive(Debug, Clone)]
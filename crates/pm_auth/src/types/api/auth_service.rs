@@ -1,11 +1,12 @@
 use crate::types::{
-    BankAccountCredentialsRequest, BankAccountCredentialsResponse, ExchangeTokenRequest,
-    ExchangeTokenResponse, LinkTokenRequest, LinkTokenResponse, RecipientCreateRequest,
-    RecipientCreateResponse,
+    AccessTokenRequest, AccessTokenResponse, BankAccountCredentialsRequest,
+    BankAccountCredentialsResponse, ExchangeTokenRequest, ExchangeTokenResponse, LinkTokenRequest,
+    LinkTokenResponse, RecipientCreateRequest, RecipientCreateResponse,
 };
 
 pub trait AuthService:
     super::ConnectorCommon
+    + AuthServiceAccessToken
     + AuthServiceLinkToken
     + AuthServiceExchangeToken
     + AuthServiceBankAccountCredentials
@@ -14,6 +15,14 @@ pub trait AuthService:
 
 pub trait PaymentInitiation: super::ConnectorCommon + PaymentInitiationRecipientCreate {}
 
+#[derive(Debug, Clone)]
+pub struct AccessToken;
+
+pub trait AuthServiceAccessToken:
+    super::ConnectorIntegration<AccessToken, AccessTokenRequest, AccessTokenResponse>
+{
+}
+
 #[derive(Debug, Clone)]
 pub struct LinkToken;
 
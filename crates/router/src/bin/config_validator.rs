@@ -0,0 +1,162 @@
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use connector_configs::validation::{validate_connector_config, ValidationIssue};
+use hyperswitch_interfaces::secrets_interface::secret_state::SecuredSecret;
+use router::{
+    configs::{secrets_transformers, settings::Settings},
+    core::errors::{ApplicationError, ApplicationResult},
+};
+
+/// Validate router and connector configuration files, reporting invalid values, missing required
+/// fields and unknown keys
+#[derive(clap::Parser)]
+struct Args {
+    /// Router configuration file to validate against the application settings.
+    /// Can be specified multiple times.
+    #[arg(short = 'f', long = "config-path", value_name = "FILE")]
+    config_paths: Vec<PathBuf>,
+
+    /// Connector configuration file, in the format of the files in `crates/connector_configs/toml`.
+    /// Can be specified multiple times.
+    #[arg(short = 'c', long = "connector-config", value_name = "FILE")]
+    connector_config_paths: Vec<PathBuf>,
+
+    /// Decrypt the secrets in the router configuration files using the configured secrets
+    /// management client, to verify that the application can transition to raw secrets.
+    #[arg(long)]
+    check_secrets: bool,
+
+    /// Treat unknown keys as errors instead of warnings.
+    #[arg(long)]
+    deny_unknown_keys: bool,
+}
+
+enum Issue {
+    Error(String),
+    UnknownKey(String),
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(message) => write!(f, "error: {message}"),
+            Self::UnknownKey(key) => write!(f, "warning: unknown key `{key}`"),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> ApplicationResult<ExitCode> {
+    let args = <Args as clap::Parser>::parse();
+    let mut stdout = std::io::stdout().lock();
+    let mut valid = true;
+
+    for config_path in args.config_paths {
+        let issues = validate_router_config(&config_path, args.check_secrets).await;
+        valid &= report(&mut stdout, &config_path, &issues, args.deny_unknown_keys)?;
+    }
+
+    for connector_config_path in args.connector_config_paths {
+        let issues = validate_connector_config_file(&connector_config_path);
+        valid &= report(
+            &mut stdout,
+            &connector_config_path,
+            &issues,
+            args.deny_unknown_keys,
+        )?;
+    }
+
+    Ok(if valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+async fn validate_router_config(config_path: &Path, check_secrets: bool) -> Vec<Issue> {
+    // The application falls back to the default configuration if the file does not exist
+    if !config_path.is_file() {
+        return vec![Issue::Error("file does not exist".to_string())];
+    }
+
+    let (conf, unknown_keys) =
+        match Settings::with_config_path_and_unknown_keys(Some(config_path.to_path_buf())) {
+            Ok(loaded) => loaded,
+            Err(error) => return vec![Issue::Error(format!("{error:?}"))],
+        };
+
+    let mut issues = unknown_keys
+        .into_iter()
+        .map(Issue::UnknownKey)
+        .collect::<Vec<_>>();
+
+    if let Err(error) = conf.validate() {
+        issues.push(Issue::Error(format!("{error:?}")));
+    }
+
+    if check_secrets {
+        if let Err(message) = check_secrets_transition(conf).await {
+            issues.push(Issue::Error(message));
+        }
+    }
+
+    issues
+}
+
+/// Transition the configuration to raw secrets the same way as the application does at startup
+async fn check_secrets_transition(conf: Settings<SecuredSecret>) -> Result<(), String> {
+    let secret_management_client = conf
+        .secrets_management
+        .get_secret_management_client()
+        .await
+        .map_err(|error| format!("failed to create secret management client: {error:?}"))?;
+
+    Box::pin(secrets_transformers::try_fetch_raw_secrets(
+        conf,
+        &*secret_management_client,
+    ))
+    .await
+    .map(|_| ())
+    .map_err(|error| format!("failed to transition to raw secrets: {error:?}"))
+}
+
+fn validate_connector_config_file(path: &Path) -> Vec<Issue> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) => return vec![Issue::Error(format!("failed to read file: {error}"))],
+    };
+
+    validate_connector_config(&contents)
+        .into_iter()
+        .map(|issue| match issue {
+            ValidationIssue::UnknownKey(key) => Issue::UnknownKey(key),
+            issue => Issue::Error(issue.to_string()),
+        })
+        .collect()
+}
+
+/// Write the issues found in the file, returning whether the file is valid
+fn report(
+    writer: &mut impl Write,
+    path: &Path,
+    issues: &[Issue],
+    deny_unknown_keys: bool,
+) -> ApplicationResult<bool> {
+    let valid = issues.iter().all(|issue| match issue {
+        Issue::Error(_) => false,
+        Issue::UnknownKey(_) => !deny_unknown_keys,
+    });
+
+    let status = if valid { "valid" } else { "invalid" };
+    writeln!(writer, "{}: {status}", path.display()).map_err(ApplicationError::from)?;
+    for issue in issues {
+        writeln!(writer, "  {issue}").map_err(ApplicationError::from)?;
+    }
+
+    Ok(valid)
+}
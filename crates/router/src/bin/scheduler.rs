@@ -324,6 +324,92 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::WebhookEventsBulkResendWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::webhook_events_bulk_resend::WebhookEventsBulkResendWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run webhook events bulk resend workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(all(feature = "payouts", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PaymentMethodMigrationBatchWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::payment_method_migration_batch::PaymentMethodMigrationBatchWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payment method migration batch workflow when v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::CardVaultExportWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::card_vault_export::CardVaultExportWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run card vault export workflow when v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ReconSettlementWorkflow => {
+                    #[cfg(all(feature = "recon", feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::recon_settlement::ReconSettlementWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(feature = "recon", feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run recon settlement workflow when recon, olap or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::FrmFeedbackWorkflow => {
+                    #[cfg(all(feature = "frm", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::frm_feedback::FrmFeedbackWorkflow))
+                    }
+                    #[cfg(not(all(feature = "frm", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run frm feedback workflow when frm or v1 feature is disabled",
+                        )
+                    }
+                }
             }
         };
 
@@ -587,6 +587,13 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::PreconditionFailed { message } => {
                 Self::PreconditionFailed { message }
             }
+            errors::ApiErrorResponse::ApprovalRequired {
+                approval_request_id,
+            } => Self::PreconditionFailed {
+                message: format!(
+                    "This action requires approval, approval request {approval_request_id} was created"
+                ),
+            },
             errors::ApiErrorResponse::InvalidDataValue { field_name } => Self::ParameterMissing {
                 field_name: field_name.to_string(),
                 param: field_name.to_string(),
@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Flow, Tag};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
-    core::{api_locking, refunds},
+    core::{api_locking, approvals, refunds},
     db::domain,
     logger, routes,
     services::{api, authentication as auth},
@@ -35,6 +35,7 @@ pub async fn refund_create(
     let create_refund_req: refund_types::RefundRequest = payload.into();
 
     let flow = Flow::RefundsCreate;
+    let request_headers = req.headers();
 
     Box::pin(wrap::compatibility_api_wrap::<
         _,
@@ -50,11 +51,21 @@ pub async fn refund_create(
         state.into_inner(),
         &req,
         create_refund_req,
-        |state, auth: auth::AuthenticationData, req, _| {
+        |state, auth: auth::AuthenticationData, req, req_state| async move {
+            approvals::check_approval_required(
+                &state,
+                &req_state,
+                request_headers,
+                &auth.merchant_account,
+                &auth.key_store,
+                auth.profile_id.clone(),
+                approvals::ApprovalPayload::RefundCreate(req.clone()),
+            )
+            .await?;
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refunds::refund_create_core(state, merchant_context, None, req)
+            refunds::refund_create_core(state, merchant_context, None, req).await
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
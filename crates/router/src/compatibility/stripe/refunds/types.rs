@@ -48,6 +48,7 @@ impl From<StripeCreateRefundRequest> for refunds::RefundRequest {
             refund_id: req.refund_id,
             amount: req.amount.map(common_utils::types::MinorUnit::new),
             payment_id: req.payment_intent,
+            is_fraudulent: req.reason.as_deref().map(|reason| reason == "fraudulent"),
             reason: req.reason,
             refund_type: Some(refunds::RefundType::Instant),
             metadata: req.metadata,
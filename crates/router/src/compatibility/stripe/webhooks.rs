@@ -87,6 +87,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    PaymentMethod(Box<api_models::payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -296,6 +297,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
     }
 }
 
@@ -338,6 +340,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod(payment_method)
+            }
         }
     }
 }
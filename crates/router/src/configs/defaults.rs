@@ -135,6 +135,46 @@ impl Default for super::settings::GlobalTenant {
     }
 }
 
+impl Default for super::settings::WebhookBulkResendSettings {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            batch_interval_seconds: 60,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentMethodMigrationBatchSettings {
+    fn default() -> Self {
+        Self {
+            max_rows: 1_000_000,
+            chunk_size: 500,
+            // 7 days
+            fingerprint_ttl_in_secs: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::CardVaultExportSettings {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1000,
+            locker_read_concurrency: 10,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl Default for super::settings::PayoutBatchSettings {
+    fn default() -> Self {
+        Self {
+            max_rows: 10000,
+            chunk_size: 100,
+            max_concurrency: 10,
+        }
+    }
+}
+
 #[allow(clippy::derivable_impls)]
 impl Default for super::settings::ApiKeys {
     fn default() -> Self {
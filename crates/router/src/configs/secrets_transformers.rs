@@ -1,4 +1,5 @@
 use common_utils::{errors::CustomResult, ext_traits::AsyncExt};
+use error_stack::ResultExt;
 use hyperswitch_interfaces::secrets_interface::{
     secret_handler::SecretsHandler,
     secret_state::{RawSecret, SecretStateContainer, SecuredSecret},
@@ -314,11 +315,18 @@ impl SecretsHandler for settings::NetworkTokenizationService {
         let private_key = secret_management_client
             .get_secret(network_tokenization.private_key.clone())
             .await?;
+        let webhook_secret = match network_tokenization.webhook_secret.clone() {
+            Some(webhook_secret) => {
+                Some(secret_management_client.get_secret(webhook_secret).await?)
+            }
+            None => None,
+        };
 
         Ok(value.transition_state(|network_tokenization| Self {
             public_key,
             private_key,
             token_service_api_key,
+            webhook_secret,
             ..network_tokenization
         }))
     }
@@ -327,113 +335,110 @@ impl SecretsHandler for settings::NetworkTokenizationService {
 /// # Panics
 ///
 /// Will panic even if kms decryption fails for at least one field
+#[allow(clippy::expect_used)]
 pub(crate) async fn fetch_raw_secrets(
     conf: Settings<SecuredSecret>,
     secret_management_client: &dyn SecretManagementInterface,
 ) -> Settings<RawSecret> {
-    #[allow(clippy::expect_used)]
+    try_fetch_raw_secrets(conf, secret_management_client)
+        .await
+        .expect("Failed to decrypt the configuration")
+}
+
+/// Transition the configuration to raw secrets, failing if the secrets of any of the fields
+/// cannot be obtained
+pub async fn try_fetch_raw_secrets(
+    conf: Settings<SecuredSecret>,
+    secret_management_client: &dyn SecretManagementInterface,
+) -> CustomResult<Settings<RawSecret>, SecretsManagementError> {
     let master_database =
         settings::Database::convert_to_raw_secret(conf.master_database, secret_management_client)
             .await
-            .expect("Failed to decrypt master database configuration");
+            .attach_printable("Failed to decrypt master database configuration")?;
 
     #[cfg(feature = "olap")]
-    #[allow(clippy::expect_used)]
     let analytics =
         analytics::AnalyticsConfig::convert_to_raw_secret(conf.analytics, secret_management_client)
             .await
-            .expect("Failed to decrypt analytics configuration");
+            .attach_printable("Failed to decrypt analytics configuration")?;
 
     #[cfg(feature = "olap")]
-    #[allow(clippy::expect_used)]
     let replica_database =
         settings::Database::convert_to_raw_secret(conf.replica_database, secret_management_client)
             .await
-            .expect("Failed to decrypt replica database configuration");
+            .attach_printable("Failed to decrypt replica database configuration")?;
 
-    #[allow(clippy::expect_used)]
     let secrets = settings::Secrets::convert_to_raw_secret(conf.secrets, secret_management_client)
         .await
-        .expect("Failed to decrypt secrets");
+        .attach_printable("Failed to decrypt secrets")?;
 
-    #[allow(clippy::expect_used)]
     let forex_api =
         settings::ForexApi::convert_to_raw_secret(conf.forex_api, secret_management_client)
             .await
-            .expect("Failed to decrypt forex api configs");
+            .attach_printable("Failed to decrypt forex api configs")?;
 
-    #[allow(clippy::expect_used)]
     let jwekey = settings::Jwekey::convert_to_raw_secret(conf.jwekey, secret_management_client)
         .await
-        .expect("Failed to decrypt jwekey configs");
+        .attach_printable("Failed to decrypt jwekey configs")?;
 
-    #[allow(clippy::expect_used)]
     let api_keys =
         settings::ApiKeys::convert_to_raw_secret(conf.api_keys, secret_management_client)
             .await
-            .expect("Failed to decrypt api_keys configs");
+            .attach_printable("Failed to decrypt api_keys configs")?;
 
     #[cfg(feature = "olap")]
-    #[allow(clippy::expect_used)]
     let connector_onboarding = settings::ConnectorOnboarding::convert_to_raw_secret(
         conf.connector_onboarding,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt connector_onboarding configs");
+    .attach_printable("Failed to decrypt connector_onboarding configs")?;
 
-    #[allow(clippy::expect_used)]
     let applepay_decrypt_keys = settings::ApplePayDecryptConfig::convert_to_raw_secret(
         conf.applepay_decrypt_keys,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt applepay decrypt configs");
+    .attach_printable("Failed to decrypt applepay decrypt configs")?;
 
-    #[allow(clippy::expect_used)]
     let paze_decrypt_keys = if let Some(paze_keys) = conf.paze_decrypt_keys {
         Some(
             settings::PazeDecryptConfig::convert_to_raw_secret(paze_keys, secret_management_client)
                 .await
-                .expect("Failed to decrypt paze decrypt configs"),
+                .attach_printable("Failed to decrypt paze decrypt configs")?,
         )
     } else {
         None
     };
 
-    #[allow(clippy::expect_used)]
     let applepay_merchant_configs = settings::ApplepayMerchantConfigs::convert_to_raw_secret(
         conf.applepay_merchant_configs,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt applepay merchant configs");
+    .attach_printable("Failed to decrypt applepay merchant configs")?;
 
-    #[allow(clippy::expect_used)]
     let payment_method_auth = settings::PaymentMethodAuth::convert_to_raw_secret(
         conf.payment_method_auth,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt payment method auth configs");
+    .attach_printable("Failed to decrypt payment method auth configs")?;
 
-    #[allow(clippy::expect_used)]
     let key_manager = settings::KeyManagerConfig::convert_to_raw_secret(
         conf.key_manager,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt keymanager configs");
+    .attach_printable("Failed to decrypt keymanager configs")?;
 
-    #[allow(clippy::expect_used)]
     let user_auth_methods = settings::UserAuthMethodSettings::convert_to_raw_secret(
         conf.user_auth_methods,
         secret_management_client,
     )
     .await
-    .expect("Failed to decrypt user_auth_methods configs");
+    .attach_printable("Failed to decrypt user_auth_methods configs")?;
 
-    #[allow(clippy::expect_used)]
     let network_tokenization_service = conf
         .network_tokenization_service
         .async_map(|network_tokenization_service| async {
@@ -442,11 +447,12 @@ pub(crate) async fn fetch_raw_secrets(
                 secret_management_client,
             )
             .await
-            .expect("Failed to decrypt network tokenization service configs")
+            .attach_printable("Failed to decrypt network tokenization service configs")
         })
-        .await;
+        .await
+        .transpose()?;
 
-    Settings {
+    Ok(Settings {
         server: conf.server,
         master_database,
         redis: conf.redis,
@@ -463,6 +469,8 @@ pub(crate) async fn fetch_raw_secrets(
         secrets,
         fallback_merchant_ids_api_key_auth: conf.fallback_merchant_ids_api_key_auth,
         locker: conf.locker,
+        payment_method_migration_batch: conf.payment_method_migration_batch,
+        card_vault_export: conf.card_vault_export,
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
@@ -540,5 +548,5 @@ pub(crate) async fn fetch_raw_secrets(
         revenue_recovery: conf.revenue_recovery,
         debit_routing_config: conf.debit_routing_config,
         clone_connector_allowlist: conf.clone_connector_allowlist,
-    }
+    })
 }
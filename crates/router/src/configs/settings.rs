@@ -78,6 +78,8 @@ pub struct Settings<S: SecretState> {
     pub secrets: SecretStateContainer<Secrets, S>,
     pub fallback_merchant_ids_api_key_auth: Option<FallbackMerchantIds>,
     pub locker: Locker,
+    pub payment_method_migration_batch: PaymentMethodMigrationBatchSettings,
+    pub card_vault_export: CardVaultExportSettings,
     pub key_manager: SecretStateContainer<KeyManagerConfig, S>,
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
@@ -377,6 +379,16 @@ pub struct UnmaskedHeaders {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Frm {
     pub enabled: bool,
+    pub ip_geolocation: Option<IpGeolocationConfig>,
+}
+
+/// Service used to locate the IP addresses of shoppers for the IP distance rule of the
+/// `hyperswitch_frm` connector. It is called with `GET {base_url}/{ip_address}` and responds
+/// with the `latitude` and `longitude` of the address.
+#[cfg(feature = "frm")]
+#[derive(Debug, Deserialize, Clone)]
+pub struct IpGeolocationConfig {
+    pub base_url: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -523,6 +535,10 @@ pub struct DummyConnector {
     pub refund_retrieve_duration: u64,
     pub refund_retrieve_tolerance: u64,
     pub authorize_ttl: i64,
+    pub async_flow_duration: u64,
+    pub async_flow_tolerance: u64,
+    #[serde(default)]
+    pub webhook_secret: Secret<String>,
     pub assets_base_url: String,
     pub default_return_url: String,
     pub slack_invite_url: String,
@@ -587,6 +603,8 @@ pub struct NetworkTokenizationService {
     pub key_id: String,
     pub delete_token_url: url::Url,
     pub check_token_status_url: url::Url,
+    /// Secret used to verify the token lifecycle notifications sent by the token service
+    pub webhook_secret: Option<Secret<String>>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -713,6 +731,28 @@ pub struct Locker {
     pub decryption_scheme: DecryptionScheme,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentMethodMigrationBatchSettings {
+    /// Maximum number of rows allowed in a payment method migration file
+    pub max_rows: u32,
+    /// Number of rows processed each time a payment method migration job runs
+    pub chunk_size: u16,
+    /// Time since the last processed row for which the card fingerprints and row checkpoints of an
+    /// unfinished migration batch are retained
+    pub fingerprint_ttl_in_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardVaultExportSettings {
+    /// Number of payment methods exported each time a card vault export job runs, the cards of
+    /// each run are written to a separate encrypted file
+    pub chunk_size: u16,
+    /// Number of cards read from the locker concurrently
+    pub locker_read_concurrency: u8,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub enum DecryptionScheme {
     #[default]
@@ -811,6 +851,21 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    /// Number of consecutive delivery failures after which a profile webhook endpoint is disabled.
+    /// Endpoints are never disabled automatically if set to 0.
+    pub endpoint_failure_threshold: u32,
+    /// Window within which consecutive delivery failures to an endpoint are counted
+    pub endpoint_failure_window_seconds: u32,
+    pub bulk_resend: WebhookBulkResendSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookBulkResendSettings {
+    /// Maximum number of events resent each time a bulk resend job runs
+    pub batch_size: u16,
+    /// Delay between consecutive runs of a bulk resend job
+    pub batch_interval_seconds: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -913,6 +968,33 @@ impl Settings<SecuredSecret> {
     }
 
     pub fn with_config_path(config_path: Option<PathBuf>) -> ApplicationResult<Self> {
+        serde_path_to_error::deserialize(Self::build_config(config_path)?)
+            .attach_printable("Unable to deserialize application configuration")
+            .change_context(ApplicationError::ConfigurationError)
+    }
+
+    /// Construct the application configuration in the same manner as [`Self::with_config_path`],
+    /// additionally returning the keys which are not part of the configuration and are ignored.
+    #[cfg(feature = "config_validator")]
+    pub fn with_config_path_and_unknown_keys(
+        config_path: Option<PathBuf>,
+    ) -> ApplicationResult<(Self, Vec<String>)> {
+        let config = Self::build_config(config_path)?;
+        let mut unknown_keys = Vec::new();
+        let mut record_unknown_key =
+            |path: serde_ignored::Path<'_>| unknown_keys.push(format_config_key(&path));
+
+        let settings = serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+            config,
+            &mut record_unknown_key,
+        ))
+        .attach_printable("Unable to deserialize application configuration")
+        .change_context(ApplicationError::ConfigurationError)?;
+
+        Ok((settings, unknown_keys))
+    }
+
+    fn build_config(config_path: Option<PathBuf>) -> ApplicationResult<config::Config> {
         // Configuration values are picked up in the following priority order (1 being least
         // priority):
         // 1. Defaults from the implementation of the `Default` trait.
@@ -940,7 +1022,7 @@ impl Settings<SecuredSecret> {
             config.add_source(File::from(required_fields_config_file).required(false))
         };
 
-        let config = config
+        config
             .add_source(
                 Environment::with_prefix("ROUTER")
                     .try_parsing(true)
@@ -954,10 +1036,6 @@ impl Settings<SecuredSecret> {
 
             )
             .build()
-            .change_context(ApplicationError::ConfigurationError)?;
-
-        serde_path_to_error::deserialize(config)
-            .attach_printable("Unable to deserialize application configuration")
             .change_context(ApplicationError::ConfigurationError)
     }
 
@@ -1028,6 +1106,8 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
         self.generic_link.payment_method_collect.validate()?;
         self.generic_link.payout_link.validate()?;
+        #[cfg(feature = "dummy_connector")]
+        self.dummy_connector.validate()?;
 
         #[cfg(feature = "v2")]
         self.cell_information.validate()?;
@@ -1079,6 +1159,20 @@ pub struct Payouts {
     pub payout_eligibility: bool,
     #[serde(default)]
     pub required_fields: PayoutRequiredFields,
+    #[serde(default)]
+    pub batch: PayoutBatchSettings,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PayoutBatchSettings {
+    /// Maximum number of rows allowed in a payout batch file
+    pub max_rows: u32,
+    /// Number of rows processed each time a payout batch job runs
+    pub chunk_size: u16,
+    /// Maximum number of payouts created concurrently for a batch
+    pub max_concurrency: u8,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1164,6 +1258,25 @@ pub struct ThemeSettings {
     pub email_config: EmailThemeConfig,
 }
 
+/// Format the path to an ignored configuration value as a dotted key, leaving out the segments
+/// for options and newtypes which do not appear in the configuration files
+#[cfg(feature = "config_validator")]
+fn format_config_key(path: &serde_ignored::Path<'_>) -> String {
+    let (parent, segment) = match path {
+        serde_ignored::Path::Root => return String::new(),
+        serde_ignored::Path::Seq { parent, index } => (parent, index.to_string()),
+        serde_ignored::Path::Map { parent, key } => (parent, key.clone()),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => return format_config_key(parent),
+    };
+
+    match format_config_key(parent) {
+        parent if parent.is_empty() => segment,
+        parent => format!("{parent}.{segment}"),
+    }
+}
+
 fn deserialize_hashmap_inner<K, V>(
     value: HashMap<String, String>,
 ) -> Result<HashMap<K, HashSet<V>>, String>
@@ -305,3 +305,25 @@ impl super::settings::KeyManagerConfig {
         })
     }
 }
+
+#[cfg(feature = "dummy_connector")]
+impl super::settings::DummyConnector {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        /// Webhook secret which used to be shipped in the configuration files
+        const PLACEHOLDER_WEBHOOK_SECRET: &str = "dummy_connector_webhook_secret";
+
+        when(
+            self.enabled
+                && (self.webhook_secret.is_default_or_empty()
+                    || self.webhook_secret.peek() == PLACEHOLDER_WEBHOOK_SECRET),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "dummy connector webhook secret must be set when the dummy connector is enabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
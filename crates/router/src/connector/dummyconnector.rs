@@ -2,9 +2,9 @@ pub mod transformers;
 
 use std::fmt::Debug;
 
-use common_utils::{consts as common_consts, request::RequestContent};
+use common_utils::{consts as common_consts, crypto, request::RequestContent};
 use diesel_models::enums;
-use error_stack::{report, ResultExt};
+use error_stack::ResultExt;
 
 use super::utils::RefundsRequestData;
 use crate::{
@@ -381,18 +381,23 @@ impl<const T: u8>
 
     fn get_url(
         &self,
-        _req: &types::PaymentsCaptureRouterData,
-        _connectors: &settings::Connectors,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_url method".to_string()).into())
+        Ok(format!(
+            "{}/{}/capture",
+            self.base_url(connectors),
+            req.request.connector_transaction_id
+        ))
     }
 
     fn get_request_body(
         &self,
-        _req: &types::PaymentsCaptureRouterData,
+        req: &types::PaymentsCaptureRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_request_body method".to_string()).into())
+        let connector_req = transformers::DummyConnectorCaptureRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
     fn build_request(
@@ -408,6 +413,9 @@ impl<const T: u8>
                 .headers(types::PaymentsCaptureType::get_headers(
                     self, req, connectors,
                 )?)
+                .set_body(types::PaymentsCaptureType::get_request_body(
+                    self, req, connectors,
+                )?)
                 .build(),
         ))
     }
@@ -605,25 +613,148 @@ impl<const T: u8> ConnectorIntegration<api::RSync, types::RefundsData, types::Re
 
 #[async_trait::async_trait]
 impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            connector_utils::get_header_key_value("x-dummy-connector-signature", request.headers)
+                .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        hex::decode(signature).change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook_body: transformers::DummyConnectorWebhookBody<
+            transformers::DummyConnectorWebhookObjectReference,
+        > = request
+            .body
+            .parse_struct("DummyConnectorWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        match webhook_body.event_type {
+            transformers::DummyConnectorWebhookEventType::PaymentAuthorized
+            | transformers::DummyConnectorWebhookEventType::PaymentSucceeded
+            | transformers::DummyConnectorWebhookEventType::PaymentFailed => {
+                Ok(api::webhooks::ObjectReferenceId::PaymentId(
+                    api::PaymentIdType::ConnectorTransactionId(webhook_body.data.id),
+                ))
+            }
+            transformers::DummyConnectorWebhookEventType::RefundSucceeded
+            | transformers::DummyConnectorWebhookEventType::RefundFailed => {
+                Ok(api::webhooks::ObjectReferenceId::RefundId(
+                    api_models::webhooks::RefundIdType::ConnectorRefundId(webhook_body.data.id),
+                ))
+            }
+            transformers::DummyConnectorWebhookEventType::DisputeOpened => {
+                Ok(api::webhooks::ObjectReferenceId::PaymentId(
+                    api::PaymentIdType::ConnectorTransactionId(
+                        webhook_body
+                            .data
+                            .payment_id
+                            .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)?,
+                    ),
+                ))
+            }
+        }
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Ok(api::IncomingWebhookEvent::EventNotSupported)
+        let webhook_body: transformers::DummyConnectorWebhookEventTypeBody = request
+            .body
+            .parse_struct("DummyConnectorWebhookEventTypeBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(webhook_body.event_type))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook_body: transformers::DummyConnectorWebhookEventTypeBody = request
+            .body
+            .parse_struct("DummyConnectorWebhookEventTypeBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        match webhook_body.event_type {
+            transformers::DummyConnectorWebhookEventType::PaymentAuthorized
+            | transformers::DummyConnectorWebhookEventType::PaymentSucceeded
+            | transformers::DummyConnectorWebhookEventType::PaymentFailed => {
+                let webhook_body: transformers::DummyConnectorWebhookBody<
+                    transformers::PaymentsResponse,
+                > = request
+                    .body
+                    .parse_struct("DummyConnectorWebhookBody")
+                    .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+                Ok(Box::new(webhook_body.data))
+            }
+            transformers::DummyConnectorWebhookEventType::RefundSucceeded
+            | transformers::DummyConnectorWebhookEventType::RefundFailed => {
+                let webhook_body: transformers::DummyConnectorWebhookBody<
+                    transformers::RefundResponse,
+                > = request
+                    .body
+                    .parse_struct("DummyConnectorWebhookBody")
+                    .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+                Ok(Box::new(webhook_body.data))
+            }
+            transformers::DummyConnectorWebhookEventType::DisputeOpened => {
+                let webhook_body: transformers::DummyConnectorWebhookBody<
+                    transformers::DummyConnectorDisputeResponse,
+                > = request
+                    .body
+                    .parse_struct("DummyConnectorWebhookBody")
+                    .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+                Ok(Box::new(webhook_body.data))
+            }
+        }
+    }
+
+    fn get_dispute_details(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::disputes::DisputePayload, errors::ConnectorError> {
+        let webhook_body: transformers::DummyConnectorWebhookBody<
+            transformers::DummyConnectorDisputeResponse,
+        > = request
+            .body
+            .parse_struct("DummyConnectorWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let dispute = webhook_body.data;
+        Ok(api::disputes::DisputePayload {
+            amount: dispute.amount.to_string(),
+            currency: dispute.currency,
+            dispute_stage: enums::DisputeStage::Dispute,
+            connector_status: webhook_body.event_type.to_string(),
+            connector_dispute_id: dispute.id,
+            connector_reason: Some(dispute.reason),
+            connector_reason_code: None,
+            challenge_required_by: None,
+            created_at: Some(dispute.created),
+            updated_at: Some(dispute.created),
+        })
     }
 }
 
@@ -2,6 +2,7 @@ use common_utils::pii;
 use diesel_models::enums::Currency;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
@@ -66,6 +67,8 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: Option<enums::CaptureMethod>,
+    webhook_url: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -209,6 +212,8 @@ impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
             payment_method_data: payment_method_data?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method,
+            webhook_url: item.request.webhook_url.clone(),
         })
     }
 }
@@ -235,21 +240,12 @@ impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
 #[serde(rename_all = "lowercase")]
 pub enum DummyConnectorPaymentStatus {
     Succeeded,
+    Authorized,
     Failed,
     #[default]
     Processing,
 }
 
-impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
-    fn from(item: DummyConnectorPaymentStatus) -> Self {
-        match item {
-            DummyConnectorPaymentStatus::Succeeded => Self::Charged,
-            DummyConnectorPaymentStatus::Failed => Self::Failure,
-            DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
-        }
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PaymentsResponse {
     status: DummyConnectorPaymentStatus,
@@ -259,6 +255,27 @@ pub struct PaymentsResponse {
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    captured_amount: Option<i64>,
+}
+
+impl PaymentsResponse {
+    fn get_attempt_status(&self) -> enums::AttemptStatus {
+        match self.status {
+            DummyConnectorPaymentStatus::Succeeded => match self.captured_amount {
+                Some(captured_amount) if captured_amount < self.amount => {
+                    enums::AttemptStatus::PartialCharged
+                }
+                _ => enums::AttemptStatus::Charged,
+            },
+            DummyConnectorPaymentStatus::Authorized => enums::AttemptStatus::Authorized,
+            DummyConnectorPaymentStatus::Failed => enums::AttemptStatus::Failure,
+            // Payments without a redirection are completed asynchronously by the connector
+            DummyConnectorPaymentStatus::Processing => match self.next_action {
+                Some(_) => enums::AttemptStatus::AuthenticationPending,
+                None => enums::AttemptStatus::Pending,
+            },
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -282,6 +299,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
     fn try_from(
         item: types::ResponseRouterData<F, PaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let status = item.response.get_attempt_status();
         let redirection_data = item
             .response
             .next_action
@@ -290,7 +308,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data: Box::new(redirection_data),
@@ -320,11 +338,28 @@ impl DummyConnectorNextAction {
     }
 }
 
+// CAPTURE :
+// Type definition for CaptureRequest
+#[derive(Default, Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
+    pub webhook_url: Option<String>,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
@@ -332,6 +367,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
             amount: item.request.refund_amount,
+            webhook_url: item.request.webhook_url.clone(),
         })
     }
 }
@@ -414,3 +450,60 @@ pub struct ErrorData {
     pub message: String,
     pub reason: Option<String>,
 }
+
+// WEBHOOKS :
+#[derive(Debug, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentAuthorized,
+    PaymentSucceeded,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+}
+
+impl From<DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: DummyConnectorWebhookEventType) -> Self {
+        match event_type {
+            DummyConnectorWebhookEventType::PaymentAuthorized => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEventType::RefundSucceeded => Self::RefundSuccess,
+            DummyConnectorWebhookEventType::RefundFailed => Self::RefundFailure,
+            DummyConnectorWebhookEventType::DisputeOpened => Self::DisputeOpened,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookEventTypeBody {
+    pub event_type: DummyConnectorWebhookEventType,
+}
+
+/// Identifiers of the object the webhook is about, present in all the webhook payloads
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookObjectReference {
+    pub id: String,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookBody<T> {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: T,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorDisputeResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
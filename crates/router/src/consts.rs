@@ -42,6 +42,19 @@ pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
 pub const DEFAULT_LIST_API_LIMIT: u16 = 10;
 
+/// Maximum number of approval requests returned by a single list call
+pub const APPROVAL_REQUESTS_LIST_MAX_LIMIT: i64 = 100;
+
+/// Pending approval requests expire if they are not reviewed within this time (7 days)
+pub const APPROVAL_REQUEST_EXPIRY_IN_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Prefix of the redis key holding the result of an approved action until the requester
+/// retrieves it
+pub const APPROVAL_RESULT_REDIS_KEY_PREFIX: &str = "APPROVAL_RESULT_";
+
+/// Time for which the result of an approved action can be retrieved by the requester (1 day)
+pub const APPROVAL_RESULT_TTL_IN_SECS: i64 = 24 * 60 * 60;
+
 // String literals
 pub(crate) const UNSUPPORTED_ERROR_MESSAGE: &str = "Unsupported response type";
 
@@ -72,6 +85,8 @@ pub const USER_BLACKLIST_PREFIX: &str = "BU_";
 
 pub const ROLE_BLACKLIST_PREFIX: &str = "BR_";
 
+pub const USER_ORG_BLACKLIST_PREFIX: &str = "BUO_";
+
 #[cfg(feature = "email")]
 pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 
@@ -168,6 +183,57 @@ pub const DEFAULT_BACKGROUND_COLOR: &str = "#212E46";
 pub const DEFAULT_PRODUCT_IMG: &str =
     "https://live.hyperswitch.io/payment-link-assets/cart_placeholder.png";
 
+/// Maximum size of a custom payment link template, in bytes
+pub const MAX_PAYMENT_LINK_TEMPLATE_SIZE: usize = 64 * 1024;
+
+/// Variables which can be referenced in custom payment link templates
+pub const PAYMENT_LINK_TEMPLATE_VARIABLES: [&str; 8] = [
+    "rendered_meta_tag_html",
+    "preload_link_tags",
+    "rendered_css",
+    "logging_template",
+    "locale_template",
+    "rendered_js",
+    "payment_link_initiator",
+    "hyperloader_sdk_link",
+];
+
+/// Variables which must be referenced in custom payment link templates for the payment form to load
+pub const PAYMENT_LINK_TEMPLATE_REQUIRED_VARIABLES: [&str; 3] = [
+    "rendered_js",
+    "payment_link_initiator",
+    "hyperloader_sdk_link",
+];
+
+/// Localized strings available to custom payment link templates, as `i18n_{key}` variables
+pub const PAYMENT_LINK_TEMPLATE_I18N_KEYS: [&str; 10] = [
+    "expires_on",
+    "ref_id",
+    "requested_by",
+    "pay_now",
+    "your_cart",
+    "quantity",
+    "show_less",
+    "show_more",
+    "payment_link_expired",
+    "payment_link_expired_message",
+];
+
+/// Redis key prefix for tracking the payment attempts made through a payment link
+pub const PAYMENT_LINK_USAGE_KEY_PREFIX: &str = "PAYMENT_LINK_USAGE";
+
+/// Redis key prefix for the payment history used by the native rules based fraud checks
+pub const FRM_CUSTOMER_PROFILE_KEY_PREFIX: &str = "FRM_CUSTOMER_PROFILE";
+
+/// Time for which a customer's payment history is kept for fraud checks, in seconds (90 days)
+pub const FRM_CUSTOMER_PROFILE_TTL: i64 = 7_776_000;
+
+/// Redis key prefix for the authentication history used to decide 3DS exemptions
+pub const AUTHENTICATION_HISTORY_KEY_PREFIX: &str = "AUTHENTICATION_HISTORY";
+
+/// Time for which a customer's authentication history is kept, in seconds (180 days)
+pub const AUTHENTICATION_HISTORY_TTL: i64 = 15_552_000;
+
 /// Default SDK Layout
 pub const DEFAULT_SDK_LAYOUT: &str = "tabs";
 
@@ -243,3 +309,7 @@ pub const IRRELEVANT_PAYMENT_ATTEMPT_ID: &str = "irrelevant_payment_attempt_id";
 
 // Default payment method storing TTL in redis in seconds
 pub const DEFAULT_PAYMENT_METHOD_STORE_TTL: i64 = 86400; // 1 day
+
+/// Time for which the serialized constraint graph of a profile is cached in redis, in seconds,
+/// same as the time for which it is cached in memory
+pub const CGRAPH_REDIS_TTL_IN_SECS: i64 = 30 * 60; // 30 minutes
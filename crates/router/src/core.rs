@@ -3,6 +3,8 @@ pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
+#[cfg(feature = "v1")]
+pub mod approvals;
 pub mod authentication;
 #[cfg(feature = "v1")]
 pub mod blocklist;
@@ -31,6 +31,7 @@ use crate::{
     core::{
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        fraud_check::rules_engine::FrmRulesConfig,
         payment_methods::{cards, transformers},
         payments::helpers,
         pm_auth::helpers::PaymentAuthConnectorDataExt,
@@ -69,6 +70,94 @@ pub fn create_merchant_publishable_key() -> String {
     )
 }
 
+/// Validates the webhook details received in a request and converts them to the stored
+/// representation, encrypting the signing secrets of webhook endpoints with the merchant's key.
+///
+/// Signing secrets are never returned in responses, so an endpoint which does not provide a
+/// `signing_secret` keeps the secrets already stored for the same `endpoint_id`.
+pub(crate) async fn get_webhook_details_for_storage(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_details: admin_types::WebhookDetails,
+    existing_webhook_details: Option<&diesel_models::business_profile::WebhookDetails>,
+) -> RouterResult<diesel_models::business_profile::WebhookDetails> {
+    webhook_details.validate().map_err(|message| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string()
+        })
+    })?;
+
+    let mut webhook_details = webhook_details;
+    let webhook_endpoints = webhook_details.webhook_endpoints.take();
+    let has_webhook_endpoints = webhook_endpoints.is_some();
+    let existing_webhook_endpoints = existing_webhook_details
+        .and_then(|webhook_details| webhook_details.webhook_endpoints.as_ref());
+
+    let mut stored_webhook_endpoints = Vec::new();
+    for webhook_endpoint in webhook_endpoints.into_iter().flatten() {
+        let existing_webhook_endpoint = existing_webhook_endpoints.and_then(|endpoints| {
+            endpoints
+                .iter()
+                .find(|endpoint| endpoint.endpoint_id == webhook_endpoint.endpoint_id)
+        });
+
+        let (signing_secret, previous_signing_secret) =
+            match (webhook_endpoint.signing_secret, existing_webhook_endpoint) {
+                (Some(signing_secret), _) => (
+                    encrypt_webhook_endpoint_secret(state, key_store, signing_secret).await?,
+                    webhook_endpoint
+                        .previous_signing_secret
+                        .async_map(|secret| {
+                            encrypt_webhook_endpoint_secret(state, key_store, secret)
+                        })
+                        .await
+                        .transpose()?,
+                ),
+                (None, Some(existing_webhook_endpoint)) => (
+                    existing_webhook_endpoint.signing_secret.clone(),
+                    existing_webhook_endpoint.previous_signing_secret.clone(),
+                ),
+                (None, None) => Err(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "webhook_endpoints.signing_secret",
+                })?,
+            };
+
+        stored_webhook_endpoints.push(diesel_models::business_profile::WebhookEndpoint {
+            endpoint_id: webhook_endpoint.endpoint_id,
+            url: webhook_endpoint.url,
+            enabled_events: webhook_endpoint.enabled_events,
+            signing_secret,
+            previous_signing_secret,
+            previous_signing_secret_expires_at: webhook_endpoint.previous_signing_secret_expires_at,
+            disabled: webhook_endpoint.disabled,
+        });
+    }
+
+    Ok(diesel_models::business_profile::WebhookDetails {
+        webhook_endpoints: has_webhook_endpoints.then_some(stored_webhook_endpoints),
+        ..webhook_details.foreign_into()
+    })
+}
+
+async fn encrypt_webhook_endpoint_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secret: Secret<String>,
+) -> RouterResult<common_utils::encryption::Encryption> {
+    domain_types::crypto_operation(
+        &state.into(),
+        type_name!(domain::Profile),
+        domain_types::CryptoOperation::Encrypt(secret),
+        km_types::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|encrypted_secret| encrypted_secret.try_into_operation())
+    .map(common_utils::encryption::Encryption::from)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt webhook endpoint signing secret")
+}
+
 pub async fn insert_merchant_configs(
     db: &dyn StorageInterface,
     merchant_id: &id_type::MerchantId,
@@ -330,7 +419,14 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
             },
         )?;
 
-        let webhook_details = self.webhook_details.clone().map(ForeignInto::foreign_into);
+        let webhook_details = self
+            .webhook_details
+            .clone()
+            .async_map(|webhook_details| {
+                get_webhook_details_for_storage(state, &key_store, webhook_details, None)
+            })
+            .await
+            .transpose()?;
 
         let pm_collect_link_config = self.get_pm_link_config_as_value().change_context(
             errors::ApiErrorResponse::InvalidDataValue {
@@ -986,7 +1082,28 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
             },
         )?;
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        // Signing secrets of webhook endpoints are kept when an update does not provide them
+        let existing_webhook_details = match self.webhook_details {
+            Some(_) => {
+                db.find_merchant_account_by_merchant_id(key_manager_state, merchant_id, key_store)
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+                    .webhook_details
+            }
+            None => None,
+        };
+        let webhook_details = self
+            .webhook_details
+            .async_map(|webhook_details| {
+                get_webhook_details_for_storage(
+                    state,
+                    key_store,
+                    webhook_details,
+                    existing_webhook_details.as_ref(),
+                )
+            })
+            .await
+            .transpose()?;
 
         let parent_merchant_id = get_parent_merchant(
             state,
@@ -1774,10 +1891,21 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
                 riskified::transformers::RiskifiedAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::HyperswitchFrm => {
+                if !matches!(self.auth_type, types::ConnectorAuthType::NoKey) {
+                    Err(errors::ConnectorError::FailedToObtainAuthType)?
+                }
+                FrmRulesConfig::try_from(self.connector_meta_data)?;
+                Ok(())
+            }
             api_enums::Connector::Plaid => {
                 PlaidAuthType::foreign_try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Truelayer | api_enums::Connector::GocardlessBankData => {
+                pm_auth_types::ConnectorAuthType::foreign_try_from(self.auth_type.clone())?;
+                Ok(())
+            }
             api_enums::Connector::Threedsecureio => {
                 threedsecureio::transformers::ThreedsecureioAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -1979,6 +2107,14 @@ impl PMAuthConfigValidation<'_> {
                 id: self.merchant_id.get_string_repr().to_owned(),
             })?;
         for conn_choice in config.enabled_payment_methods {
+            pm_auth_types::PaymentMethodAuthConnectors::from_str(&conn_choice.connector_name)
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "invalid payment method auth connector {}",
+                        conn_choice.connector_name
+                    ),
+                })?;
+
             let pm_auth_mca = all_mcas
                 .iter()
                 .find(|mca| mca.get_id() == conn_choice.mca_id)
@@ -3849,7 +3985,18 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let current_time = date_time::now();
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        let webhook_details = self
+            .webhook_details
+            .async_map(|webhook_details| {
+                get_webhook_details_for_storage(
+                    state,
+                    merchant_context.get_merchant_key_store(),
+                    webhook_details,
+                    None,
+                )
+            })
+            .await
+            .transpose()?;
 
         let payment_response_hash_key = self
             .payment_response_hash_key
@@ -3985,6 +4132,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_network_tokenization_enabled: self.is_network_tokenization_enabled,
             is_auto_retries_enabled: self.is_auto_retries_enabled.unwrap_or_default(),
             max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
+            is_payout_auto_retries_enabled: self.is_payout_auto_retries_enabled.unwrap_or_default(),
+            max_payout_auto_retries_enabled: self.max_payout_auto_retries_enabled.map(i16::from),
             always_request_extended_authorization: self.always_request_extended_authorization,
             is_click_to_pay_enabled: self.is_click_to_pay_enabled,
             authentication_product_ids: self.authentication_product_ids,
@@ -4038,7 +4187,13 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let current_time = date_time::now();
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        let webhook_details = self
+            .webhook_details
+            .async_map(|webhook_details| {
+                get_webhook_details_for_storage(state, key_store, webhook_details, None)
+            })
+            .await
+            .transpose()?;
 
         let payment_response_hash_key = self
             .payment_response_hash_key
@@ -4330,7 +4485,18 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        let webhook_details = self
+            .webhook_details
+            .async_map(|webhook_details| {
+                get_webhook_details_for_storage(
+                    state,
+                    key_store,
+                    webhook_details,
+                    business_profile.webhook_details.as_ref(),
+                )
+            })
+            .await
+            .transpose()?;
 
         if let Some(ref routing_algorithm) = self.routing_algorithm {
             let _: api_models::routing::StaticRoutingAlgorithm = routing_algorithm
@@ -4455,6 +4621,10 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 is_network_tokenization_enabled: self.is_network_tokenization_enabled,
                 is_auto_retries_enabled: self.is_auto_retries_enabled,
                 max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
+                is_payout_auto_retries_enabled: self.is_payout_auto_retries_enabled,
+                max_payout_auto_retries_enabled: self
+                    .max_payout_auto_retries_enabled
+                    .map(i16::from),
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
                 authentication_product_ids: self.authentication_product_ids,
                 card_testing_guard_config: self
@@ -4485,7 +4655,18 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        let webhook_details = self
+            .webhook_details
+            .async_map(|webhook_details| {
+                get_webhook_details_for_storage(
+                    state,
+                    key_store,
+                    webhook_details,
+                    business_profile.webhook_details.as_ref(),
+                )
+            })
+            .await
+            .transpose()?;
 
         let payment_link_config = self
             .payment_link_config
@@ -4628,6 +4809,22 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    // Webhook endpoints which are updated without being disabled are re-enabled, if they were
+    // disabled after sustained delivery failures
+    #[cfg(feature = "v1")]
+    let enabled_webhook_endpoint_ids = request
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_endpoints.as_ref())
+        .map(|webhook_endpoints| {
+            webhook_endpoints
+                .iter()
+                .filter(|webhook_endpoint| webhook_endpoint.disabled != Some(true))
+                .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
     let profile_update = request
         .get_update_profile_object(&state, &key_store, &business_profile)
         .await?;
@@ -4644,6 +4841,16 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    #[cfg(feature = "v1")]
+    crate::core::webhooks::reset_webhook_endpoint_delivery_status(
+        &state,
+        profile_id,
+        &enabled_webhook_endpoint_ids,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to re-enable webhook endpoints")?;
+
     Ok(service_api::ApplicationResponse::Json(
         api_models::admin::ProfileResponse::foreign_try_from(updated_business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -5159,6 +5366,7 @@ async fn connector_recipient_create_call(
         }),
         connector_http_status_code: None,
         connector_auth_type: auth,
+        access_token: None,
     };
 
     let resp = payment_initiation_service::execute_connector_processing_step(
@@ -0,0 +1,1127 @@
+use actix_web::http::header::HeaderMap;
+use api_models::{
+    admin as admin_api, api_keys as api_keys_api, approvals as approvals_api,
+    payment_methods as pm_api, refunds as refunds_api,
+};
+use common_enums::{ApprovalAction, ApprovalRequestStatus, EntityType};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use redis_interface::{errors::RedisError, DelReply};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        admin, api_keys,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, vault_export},
+        refunds,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::{
+        self, authentication as auth,
+        authorization::{self, permissions::Permission, roles},
+    },
+    types::{domain, storage},
+};
+
+/// The request body of an action held back for approval, stored encrypted with the merchant key
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "action", content = "data", rename_all = "snake_case")]
+pub enum ApprovalPayload {
+    RefundCreate(refunds_api::RefundRequest),
+    ConnectorCredentialsUpdate {
+        merchant_connector_id: id_type::MerchantConnectorAccountId,
+        request: admin_api::MerchantConnectorUpdate,
+    },
+    ApiKeyCreate(api_keys_api::CreateApiKeyRequest),
+    ApiKeyUpdate {
+        key_id: id_type::ApiKeyId,
+        request: api_keys_api::UpdateApiKeyRequest,
+    },
+    CardVaultExport {
+        export_id: String,
+        request: pm_api::CardVaultExportRequest,
+    },
+}
+
+impl ApprovalPayload {
+    fn get_action(&self) -> ApprovalAction {
+        match self {
+            Self::RefundCreate(_) => ApprovalAction::RefundCreate,
+            Self::ConnectorCredentialsUpdate { .. } => ApprovalAction::ConnectorCredentialsUpdate,
+            Self::ApiKeyCreate(_) => ApprovalAction::ApiKeyCreate,
+            Self::ApiKeyUpdate { .. } => ApprovalAction::ApiKeyUpdate,
+            Self::CardVaultExport { .. } => ApprovalAction::CardVaultExport,
+        }
+    }
+
+    fn get_resource_id(&self) -> Option<String> {
+        match self {
+            Self::RefundCreate(request) => Some(request.payment_id.get_string_repr().to_owned()),
+            Self::ConnectorCredentialsUpdate {
+                merchant_connector_id,
+                ..
+            } => Some(merchant_connector_id.get_string_repr().to_owned()),
+            Self::ApiKeyCreate(_) => None,
+            Self::ApiKeyUpdate { key_id, .. } => Some(key_id.get_string_repr().to_owned()),
+            Self::CardVaultExport { export_id, .. } => Some(export_id.clone()),
+        }
+    }
+}
+
+/// The permission a reviewer needs to approve or reject an action, this is the same permission
+/// that is needed to perform the action directly
+fn get_reviewer_permission(action: ApprovalAction) -> Permission {
+    match action {
+        ApprovalAction::RefundCreate => Permission::ProfileRefundWrite,
+        ApprovalAction::ConnectorCredentialsUpdate => Permission::ProfileConnectorWrite,
+        ApprovalAction::ApiKeyCreate | ApprovalAction::ApiKeyUpdate => {
+            Permission::MerchantApiKeyWrite
+        }
+        ApprovalAction::CardVaultExport => Permission::MerchantAccountWrite,
+    }
+}
+
+/// Holds back an action when the approval policy of the merchant covers it. For requests from
+/// the dashboard a pending approval request is created and `ApprovalRequired` is returned to the
+/// caller. Approval requests need a user to raise them, so covered actions are rejected for
+/// requests authenticated with API keys.
+#[instrument(skip_all)]
+pub async fn check_approval_required(
+    state: &SessionState,
+    req_state: &ReqState,
+    request_headers: &HeaderMap,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    payload: ApprovalPayload,
+) -> RouterResult<()> {
+    let requested_by = if auth::is_jwt_auth(request_headers) {
+        Some(
+            auth::parse_jwt_payload::<_, auth::AuthToken>(request_headers, state)
+                .await?
+                .user_id,
+        )
+    } else {
+        None
+    };
+
+    check_approval_required_for_user(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        profile_id,
+        requested_by,
+        payload,
+    )
+    .await
+}
+
+/// Same as [`check_approval_required`], for flows which have already identified the dashboard
+/// user performing the action. `requested_by` is `None` for requests authenticated with API keys.
+#[instrument(skip_all)]
+pub async fn check_approval_required_for_user(
+    state: &SessionState,
+    req_state: &ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    requested_by: Option<String>,
+    payload: ApprovalPayload,
+) -> RouterResult<()> {
+    let Some((_, policy)) = get_effective_approval_policy(
+        state,
+        merchant_account.get_id(),
+        merchant_account.get_org_id(),
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+
+    let action = payload.get_action();
+    let Some(rule) = policy.find_rule(action) else {
+        return Ok(());
+    };
+
+    let refund_amount = match (&payload, rule.min_amount) {
+        (ApprovalPayload::RefundCreate(request), Some(_)) => {
+            Some(get_refund_amount(state, merchant_account, key_store, request).await?)
+        }
+        _ => None,
+    };
+    if !is_covered_by_rule(rule, &payload, refund_amount) {
+        return Ok(());
+    }
+
+    let requested_by = requested_by.ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "{action} requires approval and can only be requested from the dashboard"
+            ),
+        })
+    })?;
+
+    let approval_request = raise_approval_request(
+        state,
+        req_state,
+        merchant_account,
+        key_store,
+        profile_id,
+        requested_by,
+        payload,
+    )
+    .await?;
+
+    Err(report!(errors::ApiErrorResponse::ApprovalRequired {
+        approval_request_id: approval_request.approval_request_id,
+    }))
+}
+
+/// `refund_amount` is needed only for refunds covered by a rule with a minimum amount
+fn is_covered_by_rule(
+    rule: &approvals_api::ApprovalRule,
+    payload: &ApprovalPayload,
+    refund_amount: Option<MinorUnit>,
+) -> bool {
+    if rule.action != payload.get_action() {
+        return false;
+    }
+
+    match payload {
+        ApprovalPayload::RefundCreate(_) => rule.min_amount.map_or(true, |min_amount| {
+            refund_amount.is_some_and(|amount| amount >= min_amount)
+        }),
+        ApprovalPayload::ConnectorCredentialsUpdate { request, .. } => {
+            request.connector_account_details.is_some()
+        }
+        ApprovalPayload::ApiKeyCreate(_)
+        | ApprovalPayload::ApiKeyUpdate { .. }
+        | ApprovalPayload::CardVaultExport { .. } => true,
+    }
+}
+
+/// Creates a pending approval request for the action, irrespective of the approval policy of the
+/// merchant
+#[instrument(skip_all)]
+pub async fn raise_approval_request(
+    state: &SessionState,
+    req_state: &ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    requested_by: String,
+    payload: ApprovalPayload,
+) -> RouterResult<storage::ApprovalRequest> {
+    let action = payload.get_action();
+    let encrypted_payload = cards::create_encrypted_data(&state.into(), key_store, &payload)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt approval request payload")?;
+
+    let now = common_utils::date_time::now();
+    let approval_request = state
+        .store
+        .insert_approval_request(storage::ApprovalRequestNew {
+            approval_request_id: common_utils::generate_id(consts::ID_LENGTH, "apprq"),
+            merchant_id: merchant_account.get_id().to_owned(),
+            org_id: merchant_account.get_org_id().to_owned(),
+            profile_id,
+            action,
+            status: ApprovalRequestStatus::Pending,
+            resource_id: payload.get_resource_id(),
+            payload: encrypted_payload.into(),
+            requested_by,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert approval request")?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ApprovalRequested {
+            approval_request_id: approval_request.approval_request_id.clone(),
+            action,
+            resource_id: approval_request.resource_id.clone(),
+            requested_by: approval_request.requested_by.clone(),
+        }))
+        .emit();
+
+    Ok(approval_request)
+}
+
+async fn get_refund_amount(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    request: &refunds_api::RefundRequest,
+) -> RouterResult<MinorUnit> {
+    if let Some(amount) = request.amount {
+        return Ok(amount);
+    }
+
+    // Amount is not passed in the request, the refund defaults to the captured amount
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &request.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(payment_intent
+        .amount_captured
+        .unwrap_or(payment_intent.amount))
+}
+
+pub async fn retrieve_approval_policy(
+    state: SessionState,
+    user: auth::UserFromToken,
+) -> RouterResponse<approvals_api::ApprovalPolicyResponse> {
+    let policy = get_effective_approval_policy(&state, &user.merchant_id, &user.org_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        approvals_api::ApprovalPolicyResponse {
+            entity_type: policy.as_ref().map(|(entity_type, _)| *entity_type),
+            rules: policy.map(|(_, policy)| policy.rules).unwrap_or_default(),
+        },
+    ))
+}
+
+pub async fn update_merchant_approval_policy(
+    state: SessionState,
+    req_state: ReqState,
+    user: auth::UserFromToken,
+    policy: approvals_api::ApprovalPolicy,
+) -> RouterResponse<approvals_api::ApprovalPolicyResponse> {
+    update_approval_policy(
+        &state,
+        &req_state,
+        user.user_id,
+        EntityType::Merchant,
+        user.merchant_id.get_approval_policy_key(),
+        policy,
+    )
+    .await
+}
+
+pub async fn update_organization_approval_policy(
+    state: SessionState,
+    req_state: ReqState,
+    user: auth::UserFromToken,
+    policy: approvals_api::ApprovalPolicy,
+) -> RouterResponse<approvals_api::ApprovalPolicyResponse> {
+    update_approval_policy(
+        &state,
+        &req_state,
+        user.user_id,
+        EntityType::Organization,
+        user.org_id.get_approval_policy_key(),
+        policy,
+    )
+    .await
+}
+
+/// Removes the merchant level policy, after which the merchant inherits the organization policy
+pub async fn delete_merchant_approval_policy(
+    state: SessionState,
+    user: auth::UserFromToken,
+) -> RouterResponse<approvals_api::ApprovalPolicyResponse> {
+    state
+        .store
+        .delete_config_by_key(&user.merchant_id.get_approval_policy_key())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Approval policy not configured for the merchant".to_string(),
+        })?;
+
+    retrieve_approval_policy(state, user).await
+}
+
+async fn update_approval_policy(
+    state: &SessionState,
+    req_state: &ReqState,
+    updated_by: String,
+    entity_type: EntityType,
+    key: String,
+    policy: approvals_api::ApprovalPolicy,
+) -> RouterResponse<approvals_api::ApprovalPolicyResponse> {
+    validate_approval_policy(&policy)?;
+
+    let config = policy
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize approval policy")?;
+
+    let config_update = storage::ConfigUpdate::Update {
+        config: Some(config.clone()),
+    };
+    match state.store.update_config_by_key(&key, config_update).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert approval policy config"),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update approval policy config"),
+    }?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ApprovalPolicyUpdated {
+            entity_type,
+            updated_by,
+            rules: policy.rules.clone(),
+        }))
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(
+        approvals_api::ApprovalPolicyResponse {
+            entity_type: Some(entity_type),
+            rules: policy.rules,
+        },
+    ))
+}
+
+fn validate_approval_policy(policy: &approvals_api::ApprovalPolicy) -> RouterResult<()> {
+    for (index, rule) in policy.rules.iter().enumerate() {
+        if policy.rules[..index]
+            .iter()
+            .any(|previous| previous.action == rule.action)
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate approval rule for action {}", rule.action),
+            }));
+        }
+
+        match (rule.action, rule.min_amount) {
+            (ApprovalAction::RefundCreate, Some(min_amount)) if min_amount <= MinorUnit::new(0) => {
+                return Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "min_amount".to_string(),
+                    expected_format: "positive integer".to_string(),
+                }));
+            }
+            (ApprovalAction::RefundCreate, _) | (_, None) => {}
+            (_, Some(_)) => {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("min_amount is not applicable to action {}", rule.action),
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The merchant policy takes precedence over the organization policy as a whole, rules are not
+/// merged across the two levels
+async fn get_effective_approval_policy(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    org_id: &id_type::OrganizationId,
+) -> RouterResult<Option<(EntityType, approvals_api::ApprovalPolicy)>> {
+    if let Some(policy) =
+        find_approval_policy(state, &merchant_id.get_approval_policy_key()).await?
+    {
+        return Ok(Some((EntityType::Merchant, policy)));
+    }
+
+    Ok(
+        find_approval_policy(state, &org_id.get_approval_policy_key())
+            .await?
+            .map(|policy| (EntityType::Organization, policy)),
+    )
+}
+
+async fn find_approval_policy(
+    state: &SessionState,
+    key: &str,
+) -> RouterResult<Option<approvals_api::ApprovalPolicy>> {
+    match state.store.find_config_by_key(key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("ApprovalPolicy")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Approval policy config has invalid structure")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch approval policy config"),
+    }
+}
+
+pub async fn list_approval_requests(
+    state: SessionState,
+    user: auth::UserFromToken,
+    constraints: approvals_api::ApprovalRequestListConstraints,
+) -> RouterResponse<Vec<approvals_api::ApprovalRequestResponse>> {
+    let limit = constraints
+        .limit
+        .map(|limit| limit.clamp(1, consts::APPROVAL_REQUESTS_LIST_MAX_LIMIT))
+        .unwrap_or(consts::APPROVAL_REQUESTS_LIST_MAX_LIMIT);
+
+    let approval_requests = state
+        .store
+        .list_approval_requests_by_merchant_id(
+            &user.merchant_id,
+            constraints.status,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list approval requests")?;
+
+    Ok(services::ApplicationResponse::Json(
+        approval_requests
+            .into_iter()
+            .map(get_approval_request_response)
+            .collect(),
+    ))
+}
+
+pub async fn retrieve_approval_request(
+    state: SessionState,
+    user: auth::UserFromToken,
+    approval_request_id: String,
+) -> RouterResponse<approvals_api::ApprovalRequestResponse> {
+    let approval_request =
+        find_approval_request(&state, &user.merchant_id, &approval_request_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_approval_request_response(approval_request),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn approve_approval_request(
+    state: SessionState,
+    req_state: ReqState,
+    user: auth::UserFromToken,
+    approval_request_id: String,
+    decision: approvals_api::ApprovalDecisionRequest,
+) -> RouterResponse<approvals_api::ApprovalDecisionResponse> {
+    let approval_request =
+        find_approval_request(&state, &user.merchant_id, &approval_request_id).await?;
+    validate_reviewer(&state, &user, &approval_request).await?;
+
+    let approval_request = review_approval_request(
+        &state,
+        &user,
+        &approval_request,
+        ApprovalRequestStatus::Approved,
+        decision.comment,
+    )
+    .await?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ApprovalApproved {
+            approval_request_id: approval_request.approval_request_id.clone(),
+            action: approval_request.action,
+            requested_by: approval_request.requested_by.clone(),
+            reviewed_by: user.user_id.clone(),
+        }))
+        .emit();
+
+    match execute_approved_action(&state, &approval_request).await {
+        Ok(result) => {
+            // The plaintext API key must only be seen by the requester, it is held back from
+            // the reviewer and can be retrieved once by the requester
+            let result = if is_result_for_requester_only(approval_request.action) {
+                store_approval_result(&state, &approval_request, &result).await?;
+                None
+            } else {
+                Some(result)
+            };
+
+            Ok(services::ApplicationResponse::Json(
+                approvals_api::ApprovalDecisionResponse {
+                    approval_request: get_approval_request_response(approval_request),
+                    result,
+                },
+            ))
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to execute approved action");
+            state
+                .store
+                .update_approval_request_with_status(
+                    &approval_request.merchant_id,
+                    &approval_request.approval_request_id,
+                    ApprovalRequestStatus::Approved,
+                    storage::ApprovalRequestUpdate::ExecutionFailed {
+                        error_message: error.current_context().to_string(),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to mark approval request as failed")?;
+
+            Err(error)
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn reject_approval_request(
+    state: SessionState,
+    req_state: ReqState,
+    user: auth::UserFromToken,
+    approval_request_id: String,
+    decision: approvals_api::ApprovalDecisionRequest,
+) -> RouterResponse<approvals_api::ApprovalDecisionResponse> {
+    let approval_request =
+        find_approval_request(&state, &user.merchant_id, &approval_request_id).await?;
+    validate_reviewer(&state, &user, &approval_request).await?;
+
+    let approval_request = review_approval_request(
+        &state,
+        &user,
+        &approval_request,
+        ApprovalRequestStatus::Rejected,
+        decision.comment,
+    )
+    .await?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ApprovalRejected {
+            approval_request_id: approval_request.approval_request_id.clone(),
+            action: approval_request.action,
+            requested_by: approval_request.requested_by.clone(),
+            reviewed_by: user.user_id.clone(),
+            review_comment: approval_request.review_comment.clone(),
+        }))
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(
+        approvals_api::ApprovalDecisionResponse {
+            approval_request: get_approval_request_response(approval_request),
+            result: None,
+        },
+    ))
+}
+
+async fn find_approval_request(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    approval_request_id: &str,
+) -> RouterResult<storage::ApprovalRequest> {
+    state
+        .store
+        .find_approval_request_by_merchant_id_approval_request_id(merchant_id, approval_request_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Approval request {approval_request_id} not found"),
+        })
+}
+
+/// A reviewer must be a different user than the requester and must hold the permission needed to
+/// perform the action directly
+async fn validate_reviewer(
+    state: &SessionState,
+    user: &auth::UserFromToken,
+    approval_request: &storage::ApprovalRequest,
+) -> RouterResult<()> {
+    let now = common_utils::date_time::now();
+    if approval_request.status == ApprovalRequestStatus::Pending
+        && is_approval_request_expired(approval_request, now)
+    {
+        state
+            .store
+            .update_approval_request_with_status(
+                &approval_request.merchant_id,
+                &approval_request.approval_request_id,
+                ApprovalRequestStatus::Pending,
+                storage::ApprovalRequestUpdate::Expire,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Approval request was reviewed by another user".to_string(),
+            })?;
+    }
+
+    validate_review(approval_request, &user.user_id, now)?;
+
+    let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &user.role_id,
+        &user.org_id,
+        user.tenant_id.as_ref().unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch role info of the reviewer")?;
+
+    authorization::check_permission(get_reviewer_permission(approval_request.action), &role_info)?;
+
+    let is_other_profile = approval_request
+        .profile_id
+        .as_ref()
+        .is_some_and(|profile_id| *profile_id != user.profile_id);
+    if role_info.get_entity_type() == EntityType::Profile && is_other_profile {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "approval_request".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Checks that don't depend on the role of the reviewer: the request must be pending and not
+/// expired, and the reviewer must be a different user than the requester
+fn validate_review(
+    approval_request: &storage::ApprovalRequest,
+    reviewer_id: &str,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    if approval_request.status != ApprovalRequestStatus::Pending {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Approval request is already {}", approval_request.status),
+        }));
+    }
+
+    if is_approval_request_expired(approval_request, now) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Approval request has expired".to_string(),
+        }));
+    }
+
+    if approval_request.requested_by == reviewer_id {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Approval request cannot be reviewed by the user who raised it".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+fn is_approval_request_expired(
+    approval_request: &storage::ApprovalRequest,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    now - approval_request.created_at
+        > time::Duration::seconds(consts::APPROVAL_REQUEST_EXPIRY_IN_SECS)
+}
+
+/// Results of these actions contain secrets which must only be seen by the requester
+fn is_result_for_requester_only(action: ApprovalAction) -> bool {
+    match action {
+        ApprovalAction::ApiKeyCreate => true,
+        ApprovalAction::RefundCreate
+        | ApprovalAction::ConnectorCredentialsUpdate
+        | ApprovalAction::ApiKeyUpdate
+        | ApprovalAction::CardVaultExport => false,
+    }
+}
+
+fn get_approval_result_key(approval_request_id: &str) -> String {
+    format!(
+        "{}{approval_request_id}",
+        consts::APPROVAL_RESULT_REDIS_KEY_PREFIX
+    )
+}
+
+async fn store_approval_result(
+    state: &SessionState,
+    approval_request: &storage::ApprovalRequest,
+    result: &serde_json::Value,
+) -> RouterResult<()> {
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            &approval_request.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let encrypted_result: common_utils::encryption::Encryption =
+        cards::create_encrypted_data(&state.into(), &key_store, result)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt approval result")?
+            .into();
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .serialize_and_set_key_with_expiry(
+            &get_approval_result_key(&approval_request.approval_request_id).into(),
+            encrypted_result,
+            consts::APPROVAL_RESULT_TTL_IN_SECS,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store approval result")
+}
+
+/// Returns the result of an approved action to the user who requested it. The result is deleted
+/// once it is retrieved.
+#[instrument(skip_all)]
+pub async fn retrieve_approval_result(
+    state: SessionState,
+    user: auth::UserFromToken,
+    approval_request_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let approval_request =
+        find_approval_request(&state, &user.merchant_id, &approval_request_id).await?;
+    if approval_request.requested_by != user.user_id {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "approval_request_result".to_string(),
+        }));
+    }
+
+    let result_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Result of approval request {approval_request_id} is not available"),
+    };
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let key = get_approval_result_key(&approval_request_id);
+    let encrypted_result = redis_conn
+        .get_and_deserialize_key::<common_utils::encryption::Encryption>(
+            &key.as_str().into(),
+            "Encryption",
+        )
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(result_not_found()),
+            _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+        })?;
+
+    // Only the caller that deletes the result gets to see it
+    match redis_conn
+        .delete_key(&key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+    {
+        DelReply::KeyDeleted => Ok(()),
+        DelReply::KeyNotDeleted => Err(report!(result_not_found())),
+    }?;
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &(&state).into(),
+            &approval_request.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let result: serde_json::Value =
+        cards::decrypt_generic_data(&state, Some(encrypted_result), &key_store)
+            .await?
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Approval result is missing")?;
+
+    Ok(services::ApplicationResponse::Json(result))
+}
+
+async fn review_approval_request(
+    state: &SessionState,
+    user: &auth::UserFromToken,
+    approval_request: &storage::ApprovalRequest,
+    status: ApprovalRequestStatus,
+    review_comment: Option<String>,
+) -> RouterResult<storage::ApprovalRequest> {
+    state
+        .store
+        .update_approval_request_with_status(
+            &approval_request.merchant_id,
+            &approval_request.approval_request_id,
+            ApprovalRequestStatus::Pending,
+            storage::ApprovalRequestUpdate::Review {
+                status,
+                reviewed_by: user.user_id.clone(),
+                review_comment,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Approval request was reviewed by another user".to_string(),
+        })
+}
+
+async fn execute_approved_action(
+    state: &SessionState,
+    approval_request: &storage::ApprovalRequest,
+) -> RouterResult<serde_json::Value> {
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &approval_request.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &approval_request.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payload: ApprovalPayload =
+        cards::decrypt_generic_data(state, Some(approval_request.payload.clone()), &key_store)
+            .await?
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Approval request payload is missing")?;
+
+    match payload {
+        ApprovalPayload::RefundCreate(request) => {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(merchant_account, key_store),
+            ));
+            let response = refunds::refund_create_core(
+                state.clone(),
+                merchant_context,
+                approval_request.profile_id.clone(),
+                request,
+            )
+            .await?;
+            encode_action_response(response)
+        }
+        ApprovalPayload::ConnectorCredentialsUpdate {
+            merchant_connector_id,
+            request,
+        } => {
+            let response = admin::update_connector(
+                state.clone(),
+                &approval_request.merchant_id,
+                approval_request.profile_id.clone(),
+                &merchant_connector_id,
+                request,
+            )
+            .await?;
+            encode_action_response(response)
+        }
+        ApprovalPayload::ApiKeyCreate(request) => {
+            let response = api_keys::create_api_key(state.clone(), request, key_store).await?;
+            encode_action_response(response)
+        }
+        ApprovalPayload::ApiKeyUpdate {
+            key_id,
+            mut request,
+        } => {
+            request.key_id = key_id;
+            request.merchant_id = approval_request.merchant_id.clone();
+            let response = api_keys::update_api_key(state.clone(), request).await?;
+            encode_action_response(response)
+        }
+        ApprovalPayload::CardVaultExport { export_id, request } => {
+            let response = vault_export::schedule_card_vault_export(
+                state,
+                merchant_account.get_id(),
+                &approval_request.approval_request_id,
+                export_id,
+                request,
+            )
+            .await?;
+            encode_action_response(response)
+        }
+    }
+}
+
+fn encode_action_response<T: serde::Serialize>(
+    response: services::ApplicationResponse<T>,
+) -> RouterResult<serde_json::Value> {
+    response
+        .get_json_body()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Approved action did not return a json response")?
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the response of the approved action")
+}
+
+fn get_approval_request_response(
+    approval_request: storage::ApprovalRequest,
+) -> approvals_api::ApprovalRequestResponse {
+    approvals_api::ApprovalRequestResponse {
+        approval_request_id: approval_request.approval_request_id,
+        merchant_id: approval_request.merchant_id,
+        profile_id: approval_request.profile_id,
+        action: approval_request.action,
+        status: approval_request.status,
+        resource_id: approval_request.resource_id,
+        requested_by: approval_request.requested_by,
+        reviewed_by: approval_request.reviewed_by,
+        review_comment: approval_request.review_comment,
+        error_message: approval_request.error_message,
+        created_at: approval_request.created_at,
+        modified_at: approval_request.modified_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use masking::Secret;
+
+    use super::*;
+
+    fn rule(action: ApprovalAction, min_amount: Option<i64>) -> approvals_api::ApprovalRule {
+        approvals_api::ApprovalRule {
+            action,
+            min_amount: min_amount.map(MinorUnit::new),
+        }
+    }
+
+    fn refund_payload() -> ApprovalPayload {
+        ApprovalPayload::RefundCreate(
+            serde_json::from_value(serde_json::json!({ "payment_id": "pay_1" })).unwrap(),
+        )
+    }
+
+    fn connector_payload(connector_account_details: Option<serde_json::Value>) -> ApprovalPayload {
+        ApprovalPayload::ConnectorCredentialsUpdate {
+            merchant_connector_id: id_type::MerchantConnectorAccountId::wrap("mca_1".to_string())
+                .unwrap(),
+            request: serde_json::from_value(serde_json::json!({
+                "connector_type": "payment_processor",
+                "connector_account_details": connector_account_details,
+            }))
+            .unwrap(),
+        }
+    }
+
+    fn approval_request(
+        status: ApprovalRequestStatus,
+        created_at: time::PrimitiveDateTime,
+    ) -> storage::ApprovalRequest {
+        storage::ApprovalRequest {
+            approval_request_id: "apprq_1".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            org_id: id_type::OrganizationId::default(),
+            profile_id: None,
+            action: ApprovalAction::RefundCreate,
+            status,
+            resource_id: None,
+            payload: common_utils::encryption::Encryption::new(Secret::new(Vec::new())),
+            requested_by: "maker".to_string(),
+            reviewed_by: None,
+            review_comment: None,
+            error_message: None,
+            created_at,
+            modified_at: created_at,
+        }
+    }
+
+    #[test]
+    fn test_refund_coverage_depends_on_min_amount() {
+        let payload = refund_payload();
+
+        assert!(is_covered_by_rule(
+            &rule(ApprovalAction::RefundCreate, None),
+            &payload,
+            None
+        ));
+        assert!(is_covered_by_rule(
+            &rule(ApprovalAction::RefundCreate, Some(1000)),
+            &payload,
+            Some(MinorUnit::new(1000))
+        ));
+        assert!(!is_covered_by_rule(
+            &rule(ApprovalAction::RefundCreate, Some(1000)),
+            &payload,
+            Some(MinorUnit::new(999))
+        ));
+        assert!(!is_covered_by_rule(
+            &rule(ApprovalAction::ApiKeyCreate, None),
+            &payload,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_connector_update_is_covered_only_for_credential_changes() {
+        let rule = rule(ApprovalAction::ConnectorCredentialsUpdate, None);
+
+        assert!(is_covered_by_rule(
+            &rule,
+            &connector_payload(Some(serde_json::json!({ "auth_type": "HeaderKey" }))),
+            None
+        ));
+        assert!(!is_covered_by_rule(&rule, &connector_payload(None), None));
+    }
+
+    #[test]
+    fn test_review_by_requester_is_rejected() {
+        let now = common_utils::date_time::now();
+        let approval_request = approval_request(ApprovalRequestStatus::Pending, now);
+
+        assert!(validate_review(&approval_request, "maker", now).is_err());
+        assert!(validate_review(&approval_request, "checker", now).is_ok());
+    }
+
+    #[test]
+    fn test_expired_and_reviewed_requests_cannot_be_reviewed() {
+        let now = common_utils::date_time::now();
+        let created_at = now - time::Duration::seconds(consts::APPROVAL_REQUEST_EXPIRY_IN_SECS + 1);
+
+        let expired_request = approval_request(ApprovalRequestStatus::Pending, created_at);
+        assert!(is_approval_request_expired(&expired_request, now));
+        assert!(validate_review(&expired_request, "checker", now).is_err());
+
+        let approved_request = approval_request(ApprovalRequestStatus::Approved, now);
+        assert!(!is_approval_request_expired(&approved_request, now));
+        assert!(validate_review(&approved_request, "checker", now).is_err());
+    }
+
+    #[test]
+    fn test_payload_round_trip_for_execution() {
+        let payload = connector_payload(Some(serde_json::json!({ "auth_type": "HeaderKey" })));
+        let encoded = serde_json::to_value(&payload).unwrap();
+        assert_eq!(encoded["action"], "connector_credentials_update");
+
+        let decoded: ApprovalPayload = serde_json::from_value(encoded).unwrap();
+        assert_eq!(
+            decoded.get_action(),
+            ApprovalAction::ConnectorCredentialsUpdate
+        );
+        assert_eq!(decoded.get_resource_id().as_deref(), Some("mca_1"));
+    }
+
+    #[test]
+    fn test_api_key_result_is_held_back_for_requester() {
+        assert!(is_result_for_requester_only(ApprovalAction::ApiKeyCreate));
+        assert!(!is_result_for_requester_only(ApprovalAction::RefundCreate));
+        assert!(!is_result_for_requester_only(ApprovalAction::ApiKeyUpdate));
+        assert!(!is_result_for_requester_only(
+            ApprovalAction::ConnectorCredentialsUpdate
+        ));
+        assert!(!is_result_for_requester_only(
+            ApprovalAction::CardVaultExport
+        ));
+    }
+}
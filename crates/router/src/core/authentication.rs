@@ -1,5 +1,6 @@
 pub(crate) mod utils;
 
+pub mod exemption;
 pub mod transformers;
 pub mod types;
 
@@ -0,0 +1,405 @@
+use common_enums::{Currency, ScaExemptionType};
+use common_utils::types::MinorUnit;
+use diesel_models::business_profile::ThreeDsExemptionPolicy;
+use redis_interface::errors::RedisError;
+use router_env::logger;
+use serde::{Deserialize, Serialize};
+
+use crate::{consts, core::utils as core_utils, routes::SessionState, types::domain};
+
+/// Amount limits up to which transaction risk analysis may be applied under the PSD2 RTS, for
+/// the acquirer fraud rate (in percent) they are valid for, in minor units of EUR
+const TRA_AMOUNT_LIMITS: [(f64, i64); 3] = [(0.01, 50000), (0.06, 25000), (0.13, 10000)];
+
+/// Card subtypes in the card info table that are issued to companies rather than consumers
+const CORPORATE_CARD_SUBTYPES: [&str; 4] = ["CORPORATE", "COMMERCIAL", "BUSINESS", "PURCHASING"];
+
+/// Authentication history of a customer, kept in redis to decide exemptions for returning customers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuthenticationHistory {
+    pub successful_authentications: u32,
+    /// Whether the customer has whitelisted the merchant with their issuer, as reported in the
+    /// latest authentication that carried a whitelist status
+    #[serde(default)]
+    pub is_merchant_whitelisted: bool,
+}
+
+#[derive(Debug)]
+pub struct ExemptionInput<'a> {
+    pub amount: MinorUnit,
+    pub currency: Currency,
+    pub card_issuing_country: Option<&'a str>,
+    pub card_isin: Option<String>,
+    pub force_3ds_challenge: bool,
+    /// Exemption explicitly requested by the merchant for the payment
+    pub requested_exemption: Option<ScaExemptionType>,
+}
+
+impl<'a> ExemptionInput<'a> {
+    pub fn new(
+        amount: MinorUnit,
+        currency: Currency,
+        payment_method_data: &'a domain::PaymentMethodData,
+        force_3ds_challenge: bool,
+        requested_exemption: Option<ScaExemptionType>,
+    ) -> Self {
+        let (card_issuing_country, card_isin) = match payment_method_data {
+            domain::PaymentMethodData::Card(card) => (
+                card.card_issuing_country.as_deref(),
+                Some(card.card_number.get_card_isin()),
+            ),
+            _ => (None, None),
+        };
+        Self {
+            amount,
+            currency,
+            card_issuing_country,
+            card_isin,
+            force_3ds_challenge,
+            requested_exemption,
+        }
+    }
+}
+
+/// The amount limits are defined in EUR, so transaction risk analysis is only applied to EUR
+/// payments
+fn get_tra_amount_limit(currency: Currency, acquirer_fraud_rate: f64) -> Option<MinorUnit> {
+    if currency != Currency::EUR {
+        return None;
+    }
+    TRA_AMOUNT_LIMITS
+        .iter()
+        .find(|(max_fraud_rate, _)| acquirer_fraud_rate <= *max_fraud_rate)
+        .map(|(_, amount_limit)| MinorUnit::new(*amount_limit))
+}
+
+fn is_corporate_card_subtype(card_subtype: &str) -> bool {
+    CORPORATE_CARD_SUBTYPES
+        .iter()
+        .any(|subtype| card_subtype.trim().eq_ignore_ascii_case(subtype))
+}
+
+/// Picks the exemption to be requested for the payment. An exemption requested by the merchant
+/// for the payment takes precedence over the policy, a forced challenge disables exemptions.
+pub fn evaluate_exemption(
+    policy: Option<&ThreeDsExemptionPolicy>,
+    input: &ExemptionInput<'_>,
+    history: &AuthenticationHistory,
+    is_corporate_card: bool,
+) -> Option<ScaExemptionType> {
+    if input.force_3ds_challenge {
+        return None;
+    }
+    if input.requested_exemption.is_some() {
+        return input.requested_exemption;
+    }
+
+    let policy = policy.filter(|policy| policy.currency == input.currency)?;
+
+    let is_excluded_issuer = input
+        .card_issuing_country
+        .and_then(core_utils::get_card_issuing_country_code)
+        .zip(policy.excluded_issuer_countries.as_deref())
+        .is_some_and(|(issuing_country, excluded_countries)| {
+            excluded_countries.contains(&issuing_country)
+        });
+    let has_enough_prior_authentications = policy
+        .min_prior_authentications
+        .map_or(true, |min_authentications| {
+            history.successful_authentications >= min_authentications
+        });
+    if is_excluded_issuer || !has_enough_prior_authentications {
+        return None;
+    }
+
+    let is_within_tra_limit = policy
+        .acquirer_fraud_rate
+        .and_then(|fraud_rate| get_tra_amount_limit(input.currency, fraud_rate))
+        .is_some_and(|amount_limit| input.amount <= amount_limit);
+    let is_low_value = policy
+        .low_value_max_amount
+        .is_some_and(|max_amount| input.amount <= max_amount);
+
+    if policy.secure_corporate_payment && is_corporate_card {
+        Some(ScaExemptionType::SecureCorporatePayment)
+    } else if policy.trusted_beneficiary && history.is_merchant_whitelisted {
+        Some(ScaExemptionType::TrustedBeneficiary)
+    } else if is_within_tra_limit {
+        Some(ScaExemptionType::TransactionRiskAnalysis)
+    } else if is_low_value {
+        Some(ScaExemptionType::LowValue)
+    } else {
+        None
+    }
+}
+
+fn get_authentication_history_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: &common_utils::id_type::CustomerId,
+) -> String {
+    format!(
+        "{}_{}_{}",
+        consts::AUTHENTICATION_HISTORY_KEY_PREFIX,
+        merchant_id.get_string_repr(),
+        customer_id.get_string_repr()
+    )
+}
+
+/// Fetches the authentication history of the customer. Guest checkouts and redis failures are
+/// treated as a customer without any prior authentications.
+pub async fn get_authentication_history(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+) -> AuthenticationHistory {
+    let Some(customer_id) = customer_id else {
+        return AuthenticationHistory::default();
+    };
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return AuthenticationHistory::default();
+        }
+    };
+
+    redis_conn
+        .get_and_deserialize_key::<AuthenticationHistory>(
+            &get_authentication_history_key(merchant_id, customer_id)
+                .as_str()
+                .into(),
+            "AuthenticationHistory",
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context() != &RedisError::NotFound {
+                logger::error!(?error, "Failed to fetch authentication history");
+            }
+        })
+        .unwrap_or_default()
+}
+
+/// Records a successful authentication of the customer along with the whitelist status reported
+/// by the issuer, if any. Failures are only logged since the history is used to decide exemptions
+/// for later payments.
+pub async fn record_successful_authentication(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+    is_merchant_whitelisted: Option<bool>,
+) {
+    let Some(customer_id) = customer_id else {
+        return;
+    };
+    let mut history = get_authentication_history(state, merchant_id, Some(customer_id)).await;
+    history.successful_authentications = history.successful_authentications.saturating_add(1);
+    if let Some(is_merchant_whitelisted) = is_merchant_whitelisted {
+        history.is_merchant_whitelisted = is_merchant_whitelisted;
+    }
+
+    if let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    {
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                &get_authentication_history_key(merchant_id, customer_id)
+                    .as_str()
+                    .into(),
+                history,
+                consts::AUTHENTICATION_HISTORY_TTL,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to store authentication history"))
+            .ok();
+    }
+}
+
+/// Looks up the card subtype in the card info table, cards without card info are not treated as
+/// corporate cards
+async fn is_corporate_card(state: &SessionState, card_isin: Option<&str>) -> bool {
+    let Some(card_isin) = card_isin else {
+        return false;
+    };
+    state
+        .store
+        .get_card_info(card_isin)
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()
+        .and_then(|card_info| card_info.card_subtype)
+        .is_some_and(|card_subtype| is_corporate_card_subtype(&card_subtype))
+}
+
+/// Decides the exemption to be requested in the authentication request of the payment
+pub async fn decide_sca_exemption(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+    input: &ExemptionInput<'_>,
+) -> Option<ScaExemptionType> {
+    let policy = business_profile
+        .authentication_connector_details
+        .as_ref()
+        .and_then(|details| details.three_ds_exemption_policy.as_ref());
+    let history = match policy {
+        Some(_) => {
+            get_authentication_history(state, &business_profile.merchant_id, customer_id).await
+        }
+        None => AuthenticationHistory::default(),
+    };
+    let is_corporate_card = match policy {
+        Some(policy) if policy.secure_corporate_payment => {
+            is_corporate_card(state, input.card_isin.as_deref()).await
+        }
+        _ => false,
+    };
+    let exemption = evaluate_exemption(policy, input, &history, is_corporate_card);
+    logger::debug!(
+        ?exemption,
+        ?history,
+        is_corporate_card,
+        "3DS exemption decision"
+    );
+    exemption
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EUR_POLICY: ThreeDsExemptionPolicy = ThreeDsExemptionPolicy {
+        currency: Currency::EUR,
+        low_value_max_amount: None,
+        acquirer_fraud_rate: None,
+        trusted_beneficiary: false,
+        secure_corporate_payment: false,
+        min_prior_authentications: None,
+        excluded_issuer_countries: None,
+    };
+
+    fn eur_payment(amount: i64) -> ExemptionInput<'static> {
+        ExemptionInput {
+            amount: MinorUnit::new(amount),
+            currency: Currency::EUR,
+            card_issuing_country: None,
+            card_isin: None,
+            force_3ds_challenge: false,
+            requested_exemption: None,
+        }
+    }
+
+    #[test]
+    fn test_tra_is_only_applied_to_eur_payments() {
+        let history = AuthenticationHistory::default();
+        let eur_policy = ThreeDsExemptionPolicy {
+            acquirer_fraud_rate: Some(0.05),
+            ..EUR_POLICY
+        };
+        assert_eq!(
+            evaluate_exemption(Some(&eur_policy), &eur_payment(25000), &history, false),
+            Some(ScaExemptionType::TransactionRiskAnalysis)
+        );
+        assert_eq!(
+            evaluate_exemption(Some(&eur_policy), &eur_payment(25001), &history, false),
+            None
+        );
+
+        let gbp_policy = ThreeDsExemptionPolicy {
+            currency: Currency::GBP,
+            ..eur_policy
+        };
+        let gbp_payment = ExemptionInput {
+            currency: Currency::GBP,
+            ..eur_payment(100)
+        };
+        assert_eq!(
+            evaluate_exemption(Some(&gbp_policy), &gbp_payment, &history, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_secure_corporate_payment_requires_corporate_card() {
+        let policy = ThreeDsExemptionPolicy {
+            secure_corporate_payment: true,
+            ..EUR_POLICY
+        };
+        let history = AuthenticationHistory::default();
+
+        assert_eq!(
+            evaluate_exemption(Some(&policy), &eur_payment(90000), &history, true),
+            Some(ScaExemptionType::SecureCorporatePayment)
+        );
+        assert_eq!(
+            evaluate_exemption(Some(&policy), &eur_payment(90000), &history, false),
+            None
+        );
+        assert!(is_corporate_card_subtype("Commercial"));
+        assert!(!is_corporate_card_subtype("CONSUMER"));
+    }
+
+    #[test]
+    fn test_trusted_beneficiary_requires_issuer_whitelisting() {
+        let policy = ThreeDsExemptionPolicy {
+            trusted_beneficiary: true,
+            ..EUR_POLICY
+        };
+        let frequent_customer = AuthenticationHistory {
+            successful_authentications: 25,
+            is_merchant_whitelisted: false,
+        };
+        let whitelisting_customer = AuthenticationHistory {
+            successful_authentications: 1,
+            is_merchant_whitelisted: true,
+        };
+
+        assert_eq!(
+            evaluate_exemption(
+                Some(&policy),
+                &eur_payment(90000),
+                &frequent_customer,
+                false
+            ),
+            None
+        );
+        assert_eq!(
+            evaluate_exemption(
+                Some(&policy),
+                &eur_payment(90000),
+                &whitelisting_customer,
+                false
+            ),
+            Some(ScaExemptionType::TrustedBeneficiary)
+        );
+    }
+
+    #[test]
+    fn test_excluded_issuer_countries_match_exactly() {
+        let policy = ThreeDsExemptionPolicy {
+            low_value_max_amount: Some(MinorUnit::new(3000)),
+            excluded_issuer_countries: Some(vec![common_enums::CountryAlpha2::NE]),
+            ..EUR_POLICY
+        };
+        let history = AuthenticationHistory::default();
+        let payment_from = |card_issuing_country: &'static str| ExemptionInput {
+            card_issuing_country: Some(card_issuing_country),
+            ..eur_payment(2000)
+        };
+
+        assert_eq!(
+            evaluate_exemption(Some(&policy), &payment_from("NIGER"), &history, false),
+            None
+        );
+        assert_eq!(
+            evaluate_exemption(Some(&policy), &payment_from("NE"), &history, false),
+            None
+        );
+        assert_eq!(
+            evaluate_exemption(Some(&policy), &payment_from("NIGERIA"), &history, false),
+            Some(ScaExemptionType::LowValue)
+        );
+    }
+}
@@ -95,6 +95,7 @@ pub async fn update_trackers<F: Clone, Req>(
                 trans_status,
                 connector_metadata,
                 ds_trans_id,
+                is_merchant_whitelisted,
             } => {
                 authentication_value
                     .async_map(|auth_val| {
@@ -123,6 +124,8 @@ pub async fn update_trackers<F: Clone, Req>(
                     authentication_status,
                     connector_metadata,
                     ds_trans_id,
+                    psd2_sca_exemption_type: router_data.psd2_sca_exemption_type,
+                    is_merchant_whitelisted,
                 }
             }
             AuthenticationResponseData::PostAuthNResponse {
@@ -254,6 +257,8 @@ pub async fn create_new_authentication(
         acquirer_country_code: None,
         service_details: None,
         organization_id,
+        psd2_sca_exemption_type: None,
+        is_merchant_whitelisted: None,
     };
     state
         .store
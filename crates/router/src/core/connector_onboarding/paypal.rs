@@ -4,12 +4,15 @@ use error_stack::ResultExt;
 pub use external_services::http_client;
 use masking::{ExposeInterface, PeekInterface, Secret};
 
+#[cfg(feature = "v1")]
+use crate::core::{approvals, errors::StorageErrorExt};
 use crate::{
     core::{
         admin,
         errors::{ApiErrorResponse, RouterResult},
     },
-    services::{ApplicationResponse, Request},
+    routes::app::ReqState,
+    services::{authentication as auth, ApplicationResponse, Request},
     types::{self as oss_types, api as oss_api_types, api::connector_onboarding as types},
     utils::connector_onboarding as utils,
     SessionState,
@@ -143,10 +146,12 @@ async fn find_paypal_merchant_by_tracking_id(
 
 pub async fn update_mca(
     state: &SessionState,
-    merchant_id: common_utils::id_type::MerchantId,
+    req_state: &ReqState,
+    user_from_token: &auth::UserFromToken,
     connector_id: common_utils::id_type::MerchantConnectorAccountId,
     auth_details: oss_types::ConnectorAuthType,
 ) -> RouterResult<oss_api_types::MerchantConnectorResponse> {
+    let merchant_id = user_from_token.merchant_id.clone();
     let connector_auth_json = auth_details
         .encode_to_value()
         .change_context(ApiErrorResponse::InternalServerError)
@@ -184,6 +189,42 @@ pub async fn update_mca(
         connector_wallets_details: None,
         feature_metadata: None,
     };
+
+    #[cfg(feature = "v1")]
+    {
+        let key_manager_state = &state.into();
+        let key_store = state
+            .store
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(ApiErrorResponse::MerchantAccountNotFound)?;
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await
+            .to_not_found_response(ApiErrorResponse::MerchantAccountNotFound)?;
+
+        approvals::check_approval_required_for_user(
+            state,
+            req_state,
+            &merchant_account,
+            &key_store,
+            Some(user_from_token.profile_id.clone()),
+            Some(user_from_token.user_id.clone()),
+            approvals::ApprovalPayload::ConnectorCredentialsUpdate {
+                merchant_connector_id: connector_id.clone(),
+                request: request.clone(),
+            },
+        )
+        .await?;
+    }
+    #[cfg(feature = "v2")]
+    let _ = req_state;
+
     let mca_response =
         admin::update_connector(state.clone(), &merchant_id, None, &connector_id, request).await?;
 
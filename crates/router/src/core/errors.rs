@@ -156,6 +156,12 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Webhook endpoint is not configured for the profile")]
+    WebhookEndpointNotFound,
+    #[error("Webhook endpoint is disabled")]
+    WebhookEndpointDisabled,
+    #[error("Failed to update webhook endpoint delivery status")]
+    WebhookEndpointStatusUpdateFailed,
 }
 
 impl WebhooksFlowError {
@@ -164,7 +170,9 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointNotFound
+            | Self::WebhookEndpointDisabled => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -173,7 +181,8 @@ impl WebhooksFlowError {
             | Self::DisputeWebhookValidationFailed
             | Self::OutgoingWebhookEncodingFailed
             | Self::OutgoingWebhookProcessTrackerTaskUpdateFailed
-            | Self::OutgoingWebhookRetrySchedulingFailed => true,
+            | Self::OutgoingWebhookRetrySchedulingFailed
+            | Self::WebhookEndpointStatusUpdateFailed => true,
         }
     }
 }
@@ -114,6 +114,24 @@ pub enum UserErrors {
     InvalidCloneConnectorOperation(String),
     #[error("Error cloning connector: {0}")]
     ErrorCloningConnector(String),
+    #[error("PasskeyNotSetup")]
+    PasskeyNotSetup,
+    #[error("InvalidPasskey")]
+    InvalidPasskey,
+    #[error("PasskeyNotFound")]
+    PasskeyNotFound,
+    #[error("PasskeyChallengeNotFound")]
+    PasskeyChallengeNotFound,
+    #[error("Maximum attempts reached for Passkey")]
+    MaxPasskeyAttemptsReached,
+    #[error("ScimUserNotFound")]
+    ScimUserNotFound,
+    #[error("ScimGroupNotFound")]
+    ScimGroupNotFound,
+    #[error("ScimResourceAlreadyExists")]
+    ScimResourceAlreadyExists,
+    #[error("Invalid SCIM request: {0}")]
+    InvalidScimRequest(String),
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -298,6 +316,33 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 self.get_error_message(),
                 None,
             )),
+            Self::PasskeyNotSetup => {
+                AER::BadRequest(ApiError::new(sub_code, 60, self.get_error_message(), None))
+            }
+            Self::InvalidPasskey => {
+                AER::BadRequest(ApiError::new(sub_code, 61, self.get_error_message(), None))
+            }
+            Self::PasskeyNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 62, self.get_error_message(), None))
+            }
+            Self::PasskeyChallengeNotFound => {
+                AER::BadRequest(ApiError::new(sub_code, 63, self.get_error_message(), None))
+            }
+            Self::MaxPasskeyAttemptsReached => {
+                AER::BadRequest(ApiError::new(sub_code, 64, self.get_error_message(), None))
+            }
+            Self::ScimUserNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 65, self.get_error_message(), None))
+            }
+            Self::ScimGroupNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 66, self.get_error_message(), None))
+            }
+            Self::ScimResourceAlreadyExists => {
+                AER::Conflict(ApiError::new(sub_code, 67, self.get_error_message(), None))
+            }
+            Self::InvalidScimRequest(_) => {
+                AER::BadRequest(ApiError::new(sub_code, 68, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -374,6 +419,15 @@ impl UserErrors {
             Self::ErrorCloningConnector(error_message) => {
                 format!("Error cloning connector: {}", error_message)
             }
+            Self::PasskeyNotSetup => "Passkey not setup".to_string(),
+            Self::InvalidPasskey => "Invalid Passkey".to_string(),
+            Self::PasskeyNotFound => "Passkey not found".to_string(),
+            Self::PasskeyChallengeNotFound => "Passkey challenge not found or expired".to_string(),
+            Self::MaxPasskeyAttemptsReached => "Maximum attempts reached for Passkey".to_string(),
+            Self::ScimUserNotFound => "SCIM user not found".to_string(),
+            Self::ScimGroupNotFound => "SCIM group not found".to_string(),
+            Self::ScimResourceAlreadyExists => "SCIM resource already exists".to_string(),
+            Self::InvalidScimRequest(message) => format!("Invalid SCIM request: {}", message),
         }
     }
 }
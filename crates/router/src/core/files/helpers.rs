@@ -1,12 +1,17 @@
 use actix_multipart::Field;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    errors::CustomResult,
+    ext_traits::{ByteSliceExt, Encode},
+};
 use error_stack::ResultExt;
 use futures::TryStreamExt;
 use hyperswitch_domain_models::router_response_types::disputes::FileInfo;
+use masking::PeekInterface;
 
 use crate::{
     core::{
-        errors::{self, StorageErrorExt},
+        errors::{self, RouterResult, StorageErrorExt},
         payments, utils,
     },
     routes::SessionState,
@@ -380,3 +385,54 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
         }
     }
 }
+
+/// Serializes the data and uploads it to file storage, encrypted with the merchant key
+pub async fn upload_encrypted_file<T: serde::Serialize + std::fmt::Debug>(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    file_key: &str,
+    data: &T,
+) -> RouterResult<()> {
+    let serialized_data = data
+        .encode_to_vec()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize file")?;
+    let encrypted_data = GcmAes256
+        .encode_message(key_store.key.get_inner().peek(), &serialized_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt file")?;
+
+    state
+        .file_storage_client
+        .upload_file(file_key, encrypted_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to upload file `{file_key}`"))
+}
+
+/// Retrieves a file uploaded using [`upload_encrypted_file`] and deserializes its contents
+pub async fn retrieve_encrypted_file<T: serde::de::DeserializeOwned>(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    file_key: &str,
+) -> RouterResult<T> {
+    let encrypted_data = state
+        .file_storage_client
+        .retrieve_file(file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to retrieve file `{file_key}`"))?;
+    let decrypted_data = GcmAes256
+        .decode_message(
+            key_store.key.get_inner().peek(),
+            masking::Secret::new(encrypted_data),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt file")?;
+
+    decrypted_data
+        .as_slice()
+        .parse_struct(std::any::type_name::<T>())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse file")
+}
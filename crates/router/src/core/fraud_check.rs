@@ -29,13 +29,14 @@ use crate::{
         self as oss_types,
         api::{
             fraud_check as frm_api, routing::FrmRoutingAlgorithm, Connector,
-            FraudCheckConnectorData, Fulfillment,
+            FraudCheckConnectorData, Fulfillment, RecordReturn,
         },
         domain, fraud_check as frm_types,
         storage::{
+            self,
             enums::{
-                AttemptStatus, FraudCheckLastStep, FraudCheckStatus, FraudCheckType, FrmSuggestion,
-                IntentStatus,
+                AttemptStatus, FraudCheckLastStep, FraudCheckStatus, FraudCheckType,
+                FrmFeedbackType, FrmSuggestion, IntentStatus,
             },
             fraud_check::{FraudCheck, FraudCheckUpdate},
             PaymentIntent,
@@ -45,6 +46,7 @@ use crate::{
 };
 pub mod flows;
 pub mod operation;
+pub mod rules_engine;
 pub mod types;
 
 #[cfg(feature = "v2")]
@@ -130,6 +132,20 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
+    if frm_data.connector_details.connector_name
+        == api_enums::FrmConnectors::HyperswitchFrm.to_string()
+    {
+        let rules_config =
+            rules_engine::FrmRulesConfig::try_from(&merchant_connector_account.get_metadata())
+                .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "metadata".to_string(),
+                    expected_format: "hyperswitch_frm rules configuration".to_string(),
+                })?;
+        return router_data
+            .decide_native_frm_flow(state, frm_data, customer, &rules_config)
+            .await;
+    }
+
     let connector =
         FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
     let router_data_res = router_data
@@ -842,31 +858,45 @@ pub async fn make_fulfillment_api_call(
         )
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
-    let connector_data = FraudCheckConnectorData::get_connector_by_name(&fraud_check.frm_name)?;
-    let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
-        Fulfillment,
-        frm_types::FraudCheckFulfillmentData,
-        frm_types::FraudCheckResponseData,
-    > = connector_data.connector.get_connector_integration();
-    let router_data = frm_flows::fulfillment_flow::construct_fulfillment_router_data(
-        &state,
-        &payment_intent,
-        &payment_attempt,
-        &merchant_context,
-        fraud_check.frm_name.clone(),
-        req,
-    )
-    .await?;
-    let response = services::execute_connector_processing_step(
-        &state,
-        connector_integration,
-        &router_data,
-        payments::CallConnectorAction::Trigger,
-        None,
-        None,
-    )
-    .await
-    .to_payment_failed_response()?;
+    let frm_name = fraud_check.frm_name.clone();
+    let response = if frm_name == api_enums::FrmConnectors::HyperswitchFrm.to_string() {
+        // Rules based fraud checks are evaluated natively, there is no provider to notify
+        Ok(frm_types::FraudCheckResponseData::FulfillmentResponse {
+            order_id: req.order_id,
+            shipment_ids: req
+                .fulfillments
+                .into_iter()
+                .map(|fulfillment| fulfillment.shipment_id)
+                .collect(),
+        })
+    } else {
+        let connector_data = FraudCheckConnectorData::get_connector_by_name(&frm_name)?;
+        let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
+            Fulfillment,
+            frm_types::FraudCheckFulfillmentData,
+            frm_types::FraudCheckResponseData,
+        > = connector_data.connector.get_connector_integration();
+        let router_data = frm_flows::fulfillment_flow::construct_fulfillment_router_data(
+            &state,
+            &payment_intent,
+            &payment_attempt,
+            &merchant_context,
+            frm_name.clone(),
+            req,
+        )
+        .await?;
+        services::execute_connector_processing_step(
+            &state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+            None,
+            None,
+        )
+        .await
+        .to_payment_failed_response()?
+        .response
+    };
     let fraud_check_copy = fraud_check.clone();
     let fraud_check_update = FraudCheckUpdate::ResponseUpdate {
         frm_status: fraud_check.frm_status,
@@ -883,14 +913,284 @@ pub async fn make_fulfillment_api_call(
         .await
         .map_err(|error| error.change_context(errors::ApiErrorResponse::PaymentNotFound))?;
     let fulfillment_response =
-        response
-            .response
-            .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
-                code: err.code,
-                message: err.message,
-                connector: connector_data.connector_name.clone().to_string(),
-                status_code: err.status_code,
-                reason: err.reason,
-            })?;
+        response.map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: frm_name,
+            status_code: err.status_code,
+            reason: err.reason,
+        })?;
     Ok(services::ApplicationResponse::Json(fulfillment_response))
 }
+
+pub const FRM_FEEDBACK_TASK: &str = "FRM_FEEDBACK";
+pub const FRM_FEEDBACK_TAG: &str = "FRM";
+
+#[cfg(feature = "v2")]
+pub async fn add_frm_feedback_task(
+    _state: &SessionState,
+    _payment_attempt: &storage::PaymentAttempt,
+    _feedback: frm_core_types::FrmFeedbackData,
+) -> RouterResult<()> {
+    todo!()
+}
+
+/// Schedules the chargeback or fraudulent refund to be reported to the frm connector, so that
+/// refunds and dispute webhooks don't wait on the frm connector
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn add_frm_feedback_task(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    feedback: frm_core_types::FrmFeedbackData,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::FrmFeedbackWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        FRM_FEEDBACK_TASK,
+        &get_feedback_reference_id(&feedback, &payment_attempt.attempt_id),
+        &payment_attempt.merchant_id,
+    );
+    let tracking_data = frm_core_types::FrmFeedbackTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        feedback,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        FRM_FEEDBACK_TASK,
+        runner,
+        [FRM_FEEDBACK_TAG],
+        &tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct frm feedback process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "FrmFeedback"));
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes))
+        .inspect_err(|_| crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert frm feedback process tracker task")?;
+
+    Ok(())
+}
+
+/// Identifies the feedback within the payment, disputes are reported again whenever their
+/// status changes
+fn get_feedback_reference_id(
+    feedback: &frm_core_types::FrmFeedbackData,
+    attempt_id: &str,
+) -> String {
+    feedback
+        .refund_id
+        .clone()
+        .or_else(|| {
+            feedback
+                .dispute_id
+                .as_ref()
+                .zip(feedback.dispute_status)
+                .map(|(dispute_id, dispute_status)| format!("{dispute_id}_{dispute_status}"))
+        })
+        .unwrap_or_else(|| attempt_id.to_owned())
+}
+
+/// A chargeback is the stronger signal, a later fraudulent refund does not replace it
+fn get_feedback_type(
+    recorded_feedback_type: Option<FrmFeedbackType>,
+    feedback_type: FrmFeedbackType,
+) -> FrmFeedbackType {
+    match recorded_feedback_type {
+        Some(FrmFeedbackType::Chargeback) => FrmFeedbackType::Chargeback,
+        _ => feedback_type,
+    }
+}
+
+#[cfg(feature = "v2")]
+pub async fn send_frm_feedback(
+    _state: &SessionState,
+    _merchant_context: &domain::MerchantContext,
+    _payment_attempt: &storage::PaymentAttempt,
+    _feedback: frm_core_types::FrmFeedbackData,
+) -> RouterResult<()> {
+    todo!()
+}
+
+/// Reports a chargeback or fraudulent refund to the frm connector that scored the payment, and
+/// records the outcome against the fraud check so that decisions can be correlated with it
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn send_frm_feedback(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_attempt: &storage::PaymentAttempt,
+    feedback: frm_core_types::FrmFeedbackData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let Some(fraud_check) = db
+        .find_fraud_check_by_payment_id_if_present(
+            payment_attempt.payment_id.clone(),
+            merchant_id.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch fraud check for the payment")?
+    else {
+        return Ok(());
+    };
+
+    // Payments which were never scored have no decision to report the outcome against
+    if matches!(
+        fraud_check.frm_status,
+        FraudCheckStatus::Pending | FraudCheckStatus::TransactionFailure
+    ) {
+        return Ok(());
+    }
+
+    let feedback_type = get_feedback_type(fraud_check.feedback_type, feedback.feedback_type);
+    let mut last_step = fraud_check.last_step;
+
+    // Subsequent dispute webhooks only track the dispute outcome, the connector is notified once
+    if fraud_check.feedback_type != Some(feedback_type)
+        && fraud_check.frm_name != api_enums::FrmConnectors::HyperswitchFrm.to_string()
+    {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &state.into(),
+                &payment_attempt.payment_id,
+                merchant_id,
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+        let connector_data = FraudCheckConnectorData::get_connector_by_name(&fraud_check.frm_name)?;
+        let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
+            RecordReturn,
+            frm_types::FraudCheckRecordReturnData,
+            frm_types::FraudCheckResponseData,
+        > = connector_data.connector.get_connector_integration();
+        let router_data = frm_flows::record_return::construct_feedback_router_data(
+            state,
+            &payment_intent,
+            payment_attempt,
+            merchant_context,
+            fraud_check.frm_name.clone(),
+            &feedback,
+        )
+        .await?;
+        let response = services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+            None,
+            None,
+        )
+        .await
+        .to_payment_failed_response()?
+        .response;
+
+        if let Err(err) = response {
+            Err(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Frm connector {} rejected the {feedback_type} feedback: {err:?}",
+                fraud_check.frm_name
+            ))?
+        }
+        last_step = FraudCheckLastStep::TransactionOrRecordRefund;
+    }
+
+    let fraud_check_update = FraudCheckUpdate::FeedbackUpdate {
+        feedback_type: Some(feedback_type),
+        dispute_status: feedback.dispute_status.or(fraud_check.dispute_status),
+        last_step,
+    };
+    db.update_fraud_check_response_with_attempt_id(fraud_check, fraud_check_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the fraud feedback")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_feedback(
+        feedback_type: FrmFeedbackType,
+        refund_id: Option<&str>,
+        dispute_id: Option<&str>,
+        dispute_status: Option<storage::enums::DisputeStatus>,
+    ) -> frm_core_types::FrmFeedbackData {
+        frm_core_types::FrmFeedbackData {
+            feedback_type,
+            amount: None,
+            refund_id: refund_id.map(str::to_owned),
+            dispute_id: dispute_id.map(str::to_owned),
+            dispute_status,
+        }
+    }
+
+    #[test]
+    fn test_chargeback_is_not_replaced_by_fraudulent_refund() {
+        assert_eq!(
+            get_feedback_type(
+                Some(FrmFeedbackType::Chargeback),
+                FrmFeedbackType::FraudulentRefund
+            ),
+            FrmFeedbackType::Chargeback
+        );
+        assert_eq!(
+            get_feedback_type(
+                Some(FrmFeedbackType::FraudulentRefund),
+                FrmFeedbackType::Chargeback
+            ),
+            FrmFeedbackType::Chargeback
+        );
+        assert_eq!(
+            get_feedback_type(None, FrmFeedbackType::FraudulentRefund),
+            FrmFeedbackType::FraudulentRefund
+        );
+    }
+
+    #[test]
+    fn test_feedback_reference_id() {
+        let refund_feedback = get_feedback(
+            FrmFeedbackType::FraudulentRefund,
+            Some("ref_123"),
+            None,
+            None,
+        );
+        assert_eq!(
+            get_feedback_reference_id(&refund_feedback, "pay_123_1"),
+            "ref_123"
+        );
+
+        let opened_dispute = get_feedback(
+            FrmFeedbackType::Chargeback,
+            None,
+            Some("dp_123"),
+            Some(storage::enums::DisputeStatus::DisputeOpened),
+        );
+        let lost_dispute = get_feedback(
+            FrmFeedbackType::Chargeback,
+            None,
+            Some("dp_123"),
+            Some(storage::enums::DisputeStatus::DisputeLost),
+        );
+        assert_ne!(
+            get_feedback_reference_id(&opened_dispute, "pay_123_1"),
+            get_feedback_reference_id(&lost_dispute, "pay_123_1")
+        );
+    }
+}
@@ -9,6 +9,7 @@ use async_trait::async_trait;
 use crate::{
     core::{
         errors::RouterResult,
+        fraud_check::{rules_engine::FrmRulesConfig, types::FrmData},
         payments::{self, flows::ConstructFlowSpecificData},
     },
     routes::SessionState,
@@ -33,4 +34,16 @@ pub trait FeatureFrm<F, T> {
         Self: Sized,
         F: Clone,
         dyn Connector: services::ConnectorIntegration<F, T, FraudCheckResponseData>;
+
+    /// Answers the flow with the native rules engine, used in place of an external FRM connector
+    /// when the `hyperswitch_frm` connector is configured
+    async fn decide_native_frm_flow<'a>(
+        self,
+        state: &SessionState,
+        frm_data: &FrmData,
+        customer: &Option<domain::Customer>,
+        rules_config: &FrmRulesConfig,
+    ) -> RouterResult<Self>
+    where
+        Self: Sized;
 }
@@ -7,7 +7,10 @@ use super::{ConstructFlowSpecificData, FeatureFrm};
 use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
-        fraud_check::types::FrmData,
+        fraud_check::{
+            rules_engine::{self, FrmRulesConfig},
+            types::FrmData,
+        },
         payments::{self, helpers},
     },
     errors, services,
@@ -196,6 +199,18 @@ impl FeatureFrm<frm_api::Checkout, FraudCheckCheckoutData> for FrmCheckoutRouter
         )
         .await
     }
+
+    async fn decide_native_frm_flow<'a>(
+        mut self,
+        state: &SessionState,
+        frm_data: &FrmData,
+        customer: &Option<domain::Customer>,
+        rules_config: &FrmRulesConfig,
+    ) -> RouterResult<Self> {
+        self.response =
+            Ok(rules_engine::screen_payment(state, frm_data, customer, rules_config).await?);
+        Ok(self)
+    }
 }
 
 pub async fn decide_frm_flow(
@@ -1,13 +1,18 @@
 use async_trait::async_trait;
-use common_utils::ext_traits::ValueExt;
+use common_utils::ext_traits::{OptionExt, ValueExt};
 use error_stack::ResultExt;
+use router_env::tracing::{self, instrument};
 
 use crate::{
     connector::signifyd::transformers::RefundMethod,
     core::{
         errors::{ConnectorErrorExt, RouterResult},
-        fraud_check::{FeatureFrm, FraudCheckConnectorData, FrmData},
+        fraud_check::{
+            rules_engine::FrmRulesConfig, types::FrmFeedbackData, FeatureFrm,
+            FraudCheckConnectorData, FrmData,
+        },
         payments::{self, flows::ConstructFlowSpecificData, helpers},
+        utils as core_utils,
     },
     errors, services,
     types::{
@@ -16,8 +21,9 @@ use crate::{
         fraud_check::{
             FraudCheckRecordReturnData, FraudCheckResponseData, FrmRecordReturnRouterData,
         },
-        storage::enums as storage_enums,
-        ConnectorAuthType, MerchantRecipientData, ResponseId, RouterData,
+        storage::{self, enums as storage_enums},
+        ConnectorAuthType, ErrorResponse, MerchantRecipientData, PaymentAddress, ResponseId,
+        RouterData,
     },
     utils, SessionState,
 };
@@ -94,6 +100,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
                 refund_method: RefundMethod::OriginalPaymentInstrument, //we dont consume this data now in payments...hence hardcoded
                 currency,
                 refund_transaction_id: self.refund.clone().map(|refund| refund.refund_id),
+                feedback_type: None,
             }, // self.order_details
             response: Ok(FraudCheckResponseData::RecordReturnResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
@@ -164,6 +171,25 @@ impl FeatureFrm<RecordReturn, FraudCheckRecordReturnData> for FrmRecordReturnRou
         )
         .await
     }
+
+    async fn decide_native_frm_flow<'a>(
+        mut self,
+        _state: &SessionState,
+        frm_data: &FrmData,
+        _customer: &Option<domain::Customer>,
+        _rules_config: &FrmRulesConfig,
+    ) -> RouterResult<Self> {
+        self.response = Ok(FraudCheckResponseData::RecordReturnResponse {
+            resource_id: frm_data
+                .fraud_check
+                .frm_transaction_id
+                .clone()
+                .map_or(ResponseId::NoResponseId, ResponseId::ConnectorTransactionId),
+            connector_metadata: None,
+            return_id: None,
+        });
+        Ok(self)
+    }
 }
 
 pub async fn decide_frm_flow(
@@ -191,3 +217,124 @@ pub async fn decide_frm_flow(
 
     Ok(resp)
 }
+
+#[cfg(feature = "v2")]
+pub async fn construct_feedback_router_data<'a>(
+    _state: &'a SessionState,
+    _payment_intent: &'a storage::PaymentIntent,
+    _payment_attempt: &storage::PaymentAttempt,
+    _merchant_context: &domain::MerchantContext,
+    _connector: String,
+    _feedback: &FrmFeedbackData,
+) -> RouterResult<FrmRecordReturnRouterData> {
+    todo!()
+}
+
+/// Builds the record return request used to report a chargeback or fraudulent refund
+/// back to the frm connector that scored the payment
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn construct_feedback_router_data<'a>(
+    state: &'a SessionState,
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    merchant_context: &domain::MerchantContext,
+    connector: String,
+    feedback: &FrmFeedbackData,
+) -> RouterResult<FrmRecordReturnRouterData> {
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?
+        .clone();
+
+    let merchant_connector_account = helpers::get_merchant_connector_account(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        None,
+        merchant_context.get_merchant_key_store(),
+        &profile_id,
+        &connector,
+        None,
+    )
+    .await?;
+
+    let test_mode: Option<bool> = merchant_connector_account.is_test_mode_on();
+    let auth_type: ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let payment_method = utils::OptionExt::get_required_value(
+        payment_attempt.payment_method,
+        "payment_method_type",
+    )?;
+    let router_data = RouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        tenant_id: state.tenant.tenant_id.clone(),
+        connector,
+        payment_id: payment_attempt.payment_id.get_string_repr().to_owned(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        amount_captured: payment_intent
+            .amount_captured
+            .map(|amt| amt.get_amount_as_i64()),
+        minor_amount_captured: payment_intent.amount_captured,
+        payment_method_status: None,
+        request: FraudCheckRecordReturnData {
+            amount: feedback
+                .amount
+                .unwrap_or(payment_attempt.net_amount.get_total_amount())
+                .get_amount_as_i64(),
+            refund_method: RefundMethod::OriginalPaymentInstrument,
+            currency: payment_attempt.currency,
+            refund_transaction_id: feedback.refund_id.clone(),
+            feedback_type: Some(feedback.feedback_type),
+        },
+        response: Err(ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        customer_id: payment_intent.customer_id.clone(),
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_request_reference_id: core_utils::get_connector_request_reference_id(
+            &state.conf,
+            merchant_context.get_merchant_account().get_id(),
+            payment_attempt,
+        ),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: feedback.refund_id.clone(),
+        dispute_id: feedback.dispute_id.clone(),
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+        authentication_id: None,
+        psd2_sca_exemption_type: None,
+        whole_connector_response: None,
+    };
+    Ok(router_data)
+}
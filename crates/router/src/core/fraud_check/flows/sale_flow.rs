@@ -6,7 +6,10 @@ use masking::ExposeInterface;
 use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
-        fraud_check::{FeatureFrm, FraudCheckConnectorData, FrmData},
+        fraud_check::{
+            rules_engine::{self, FrmRulesConfig},
+            FeatureFrm, FraudCheckConnectorData, FrmData,
+        },
         payments::{self, flows::ConstructFlowSpecificData, helpers},
     },
     errors, services,
@@ -172,6 +175,18 @@ impl FeatureFrm<frm_api::Sale, FraudCheckSaleData> for FrmSaleRouterData {
         )
         .await
     }
+
+    async fn decide_native_frm_flow<'a>(
+        mut self,
+        state: &SessionState,
+        frm_data: &FrmData,
+        customer: &Option<domain::Customer>,
+        rules_config: &FrmRulesConfig,
+    ) -> RouterResult<Self> {
+        self.response =
+            Ok(rules_engine::screen_payment(state, frm_data, customer, rules_config).await?);
+        Ok(self)
+    }
 }
 
 pub async fn decide_frm_flow(
@@ -5,7 +5,10 @@ use error_stack::ResultExt;
 use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
-        fraud_check::{FeatureFrm, FrmData},
+        fraud_check::{
+            rules_engine::{self, FrmRulesConfig},
+            FeatureFrm, FrmData,
+        },
         payments::{self, flows::ConstructFlowSpecificData, helpers},
     },
     errors, services,
@@ -181,6 +184,17 @@ impl FeatureFrm<frm_api::Transaction, FraudCheckTransactionData> for FrmTransact
         )
         .await
     }
+
+    async fn decide_native_frm_flow<'a>(
+        mut self,
+        _state: &SessionState,
+        frm_data: &FrmData,
+        _customer: &Option<domain::Customer>,
+        _rules_config: &FrmRulesConfig,
+    ) -> RouterResult<Self> {
+        self.response = Ok(rules_engine::get_recorded_decision(frm_data));
+        Ok(self)
+    }
 }
 
 pub async fn decide_frm_flow(
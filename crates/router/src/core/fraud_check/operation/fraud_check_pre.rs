@@ -125,6 +125,8 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPre {
                     modified_at: common_utils::date_time::now(),
                     last_step: FraudCheckLastStep::Processing,
                     payment_capture_method: payment_data.payment_attempt.capture_method,
+                    feedback_type: None,
+                    dispute_status: None,
                 })
                 .await
             }
@@ -0,0 +1,679 @@
+use std::net::IpAddr;
+
+use common_enums::{CountryAlpha2, Currency};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::ValueExt,
+    pii,
+    request::{Method, RequestBuilder},
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use external_services::http_client;
+use masking::ExposeInterface;
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisKey};
+use router_env::logger;
+use serde::{Deserialize, Serialize};
+
+use super::types::FrmData;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    types::{
+        domain, fraud_check::FraudCheckResponseData, storage::enums::FraudCheckStatus, ResponseId,
+    },
+};
+
+const EARTH_RADIUS_IN_KM: f64 = 6371.0;
+const MAX_FRAUD_SCORE: i32 = 100;
+
+/// Records the payment in the customer's payment history and returns the history as it was
+/// before the payment, in a single step so that concurrent payments of a customer are all
+/// counted. A payment that was already recorded is not added to the amounts again.
+///
+/// KEYS: payments sorted set, amounts hash, last location
+/// ARGV: created at, start of the velocity window, payment id, currency, amount, ttl, location
+const RECORD_PAYMENT_SCRIPT: &str = r#"
+local payments_key, amounts_key, location_key = KEYS[1], KEYS[2], KEYS[3]
+local created_at, window_start, payment_id = ARGV[1], ARGV[2], ARGV[3]
+local currency, amount, ttl, location = ARGV[4], ARGV[5], ARGV[6], ARGV[7]
+
+redis.call('ZREMRANGEBYSCORE', payments_key, '-inf', window_start)
+local payments_in_window = redis.call('ZCARD', payments_key)
+local is_new_payment = redis.call('ZADD', payments_key, created_at, payment_id) == 1
+redis.call('EXPIRE', payments_key, ttl)
+
+local count, total = 0, 0
+if currency ~= '' then
+    local count_field, total_field = currency .. '_count', currency .. '_total'
+    count = tonumber(redis.call('HGET', amounts_key, count_field) or '0')
+    total = tonumber(redis.call('HGET', amounts_key, total_field) or '0')
+    if is_new_payment then
+        redis.call('HINCRBY', amounts_key, count_field, 1)
+        redis.call('HINCRBY', amounts_key, total_field, amount)
+        redis.call('EXPIRE', amounts_key, ttl)
+    end
+end
+
+local last_location = redis.call('GET', location_key) or ''
+if location ~= '' then
+    redis.call('SET', location_key, location, 'EX', ttl)
+end
+
+return {tostring(payments_in_window), tostring(count), tostring(total), last_location}
+"#;
+
+/// Rules used by the `hyperswitch_frm` connector to score payments, read from the metadata of
+/// its merchant connector account. Rules that are left out fall back to their defaults and rules
+/// set to `null` are disabled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FrmRulesConfig {
+    /// Payments scoring at or above this threshold are sent for manual review
+    pub manual_review_threshold: i32,
+    /// Payments scoring at or above this threshold are declined as fraudulent
+    pub decline_threshold: i32,
+    pub velocity: Option<VelocityRule>,
+    pub bin_country_mismatch: Option<BinCountryMismatchRule>,
+    pub ip_distance: Option<IpDistanceRule>,
+    pub high_risk_email_domains: Option<HighRiskEmailDomainRule>,
+    pub amount_outlier: Option<AmountOutlierRule>,
+}
+
+impl Default for FrmRulesConfig {
+    fn default() -> Self {
+        Self {
+            manual_review_threshold: 50,
+            decline_threshold: 80,
+            velocity: Some(VelocityRule {
+                max_payments: 5,
+                window_in_secs: 3600,
+                score: 40,
+            }),
+            bin_country_mismatch: Some(BinCountryMismatchRule { score: 30 }),
+            ip_distance: Some(IpDistanceRule {
+                max_distance_in_km: 500.0,
+                score: 25,
+            }),
+            high_risk_email_domains: None,
+            amount_outlier: Some(AmountOutlierRule {
+                multiplier: 5,
+                min_payments: 3,
+                score: 30,
+            }),
+        }
+    }
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for FrmRulesConfig {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        let config = meta_data
+            .clone()
+            .map(|meta_data| {
+                meta_data
+                    .expose()
+                    .parse_value::<Self>("FrmRulesConfig")
+                    .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                        config: "metadata",
+                    })
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        if config.manual_review_threshold > config.decline_threshold {
+            Err(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.manual_review_threshold",
+            })?
+        }
+        Ok(config)
+    }
+}
+
+/// Flags customers making more than `max_payments` payments within the window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VelocityRule {
+    pub max_payments: usize,
+    pub window_in_secs: i64,
+    pub score: i32,
+}
+
+/// Flags cards issued in a different country than the billing address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinCountryMismatchRule {
+    pub score: i32,
+}
+
+/// Flags payments made from further than `max_distance_in_km` from the customer's previous
+/// payment. The IP address in the browser info of the payment is located through the
+/// `frm.ip_geolocation` service, the rule is skipped when that is not configured.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpDistanceRule {
+    pub max_distance_in_km: f64,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighRiskEmailDomainRule {
+    pub domains: Vec<String>,
+    pub score: i32,
+}
+
+/// Flags payments larger than `multiplier` times the customer's average payment in the same
+/// currency, once the customer has made at least `min_payments` payments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmountOutlierRule {
+    pub multiplier: u32,
+    pub min_payments: i64,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmRule {
+    Velocity,
+    BinCountryMismatch,
+    IpDistance,
+    HighRiskEmailDomain,
+    AmountOutlier,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GeoLocation {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl GeoLocation {
+    /// Great-circle distance between the two locations
+    fn distance_in_km(&self, other: &Self) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_IN_KM * a.sqrt().asin()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct AmountStats {
+    count: i64,
+    total: i64,
+}
+
+/// Payment history of a customer as it was before the payment being screened, kept in redis to
+/// evaluate the rules that look back at earlier payments
+#[derive(Debug, Clone, Default)]
+struct CustomerRiskProfile {
+    /// Number of the customer's payments within the velocity window
+    payments_in_window: usize,
+    /// Payments made by the customer in the currency of the payment
+    amount_stats: AmountStats,
+    /// Location of the customer's last located payment
+    last_location: Option<GeoLocation>,
+}
+
+#[derive(Debug, Clone)]
+struct FrmRulesInput {
+    payment_id: String,
+    amount: MinorUnit,
+    currency: Option<Currency>,
+    card_issuing_country: Option<String>,
+    billing_country: Option<CountryAlpha2>,
+    email: Option<String>,
+    ip_geolocation: Option<GeoLocation>,
+    created_at: i64,
+}
+
+impl FrmRulesInput {
+    #[cfg(feature = "v2")]
+    fn new(
+        _frm_data: &FrmData,
+        _customer: &Option<domain::Customer>,
+        _ip_geolocation: Option<GeoLocation>,
+    ) -> Self {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    fn new(
+        frm_data: &FrmData,
+        customer: &Option<domain::Customer>,
+        ip_geolocation: Option<GeoLocation>,
+    ) -> Self {
+        use api_models::payments::AdditionalPaymentData;
+        use masking::PeekInterface;
+
+        let card_issuing_country = frm_data
+            .payment_attempt
+            .payment_method_data
+            .clone()
+            .and_then(|pm_data| {
+                pm_data
+                    .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                    .ok()
+            })
+            .and_then(|pm_data| match pm_data {
+                AdditionalPaymentData::Card(card) => card.card_issuing_country,
+                _ => None,
+            });
+        let billing_address = frm_data
+            .address
+            .get_payment_method_billing()
+            .or(frm_data.address.get_payment_billing());
+        let email = customer
+            .as_ref()
+            .and_then(|customer| customer.email.clone())
+            .map(|email| email.into_inner().expose())
+            .or(billing_address
+                .and_then(|address| address.email.as_ref())
+                .map(|email| email.peek().to_owned()));
+
+        Self {
+            payment_id: frm_data
+                .payment_intent
+                .payment_id
+                .get_string_repr()
+                .to_owned(),
+            amount: frm_data.payment_attempt.net_amount.get_total_amount(),
+            currency: frm_data.payment_attempt.currency,
+            card_issuing_country,
+            billing_country: billing_address
+                .and_then(|address| address.address.as_ref())
+                .and_then(|address| address.country),
+            email,
+            ip_geolocation,
+            created_at: common_utils::date_time::now().assume_utc().unix_timestamp(),
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+fn get_ip_address(frm_data: &FrmData) -> Option<IpAddr> {
+    frm_data
+        .payment_attempt
+        .browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.get("ip_address"))
+        .and_then(|ip_address| ip_address.as_str())
+        .and_then(|ip_address| ip_address.parse().ok())
+}
+
+#[cfg(feature = "v2")]
+fn get_ip_address(frm_data: &FrmData) -> Option<IpAddr> {
+    frm_data
+        .payment_attempt
+        .browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+}
+
+/// Locates the IP address through the configured geolocation service. Lookup failures are logged
+/// and skip the IP distance rule rather than failing the payment.
+async fn locate_ip_address(state: &SessionState, ip_address: IpAddr) -> Option<GeoLocation> {
+    let geolocation_config = state.conf.frm.ip_geolocation.as_ref()?;
+    let request = RequestBuilder::new()
+        .method(Method::Get)
+        .url(&format!(
+            "{}/{ip_address}",
+            geolocation_config.base_url.trim_end_matches('/')
+        ))
+        .attach_default_headers()
+        .build();
+
+    let response = http_client::send_request(&state.conf.proxy, request, None)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to call the IP geolocation service"))
+        .ok()?;
+    response
+        .error_for_status()
+        .map_err(|error| logger::error!(?error, "IP geolocation service returned an error"))
+        .ok()?
+        .json::<GeoLocation>()
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to parse the IP geolocation response"))
+        .ok()
+}
+
+fn evaluate_rules(
+    config: &FrmRulesConfig,
+    input: &FrmRulesInput,
+    profile: Option<&CustomerRiskProfile>,
+) -> Vec<(FrmRule, i32)> {
+    let mut triggered_rules = Vec::new();
+
+    if let (Some(rule), Some(profile)) = (&config.velocity, profile) {
+        if profile.payments_in_window >= rule.max_payments {
+            triggered_rules.push((FrmRule::Velocity, rule.score));
+        }
+    }
+
+    if let (Some(rule), Some(card_issuing_country), Some(billing_country)) = (
+        &config.bin_country_mismatch,
+        &input.card_issuing_country,
+        input.billing_country,
+    ) {
+        // Issuing countries that can't be resolved are not treated as a mismatch
+        let is_mismatch = core_utils::get_card_issuing_country_code(card_issuing_country)
+            .is_some_and(|issuing_country| issuing_country != billing_country);
+        if is_mismatch {
+            triggered_rules.push((FrmRule::BinCountryMismatch, rule.score));
+        }
+    }
+
+    if let (Some(rule), Some(location), Some(last_location)) = (
+        &config.ip_distance,
+        input.ip_geolocation,
+        profile.and_then(|profile| profile.last_location),
+    ) {
+        if location.distance_in_km(&last_location) > rule.max_distance_in_km {
+            triggered_rules.push((FrmRule::IpDistance, rule.score));
+        }
+    }
+
+    if let (Some(rule), Some(email)) = (&config.high_risk_email_domains, &input.email) {
+        let is_high_risk_domain = email.rsplit_once('@').is_some_and(|(_, domain)| {
+            rule.domains
+                .iter()
+                .any(|high_risk_domain| high_risk_domain.eq_ignore_ascii_case(domain))
+        });
+        if is_high_risk_domain {
+            triggered_rules.push((FrmRule::HighRiskEmailDomain, rule.score));
+        }
+    }
+
+    if let (Some(rule), Some(profile)) = (&config.amount_outlier, profile) {
+        let stats = &profile.amount_stats;
+        // amount > multiplier * (total / count), compared without dividing
+        let is_outlier = stats.count >= rule.min_payments.max(1)
+            && input.amount.get_amount_as_i64().saturating_mul(stats.count)
+                > stats.total.saturating_mul(i64::from(rule.multiplier));
+        if is_outlier {
+            triggered_rules.push((FrmRule::AmountOutlier, rule.score));
+        }
+    }
+
+    triggered_rules
+}
+
+fn get_fraud_check_status(config: &FrmRulesConfig, score: i32) -> FraudCheckStatus {
+    if score >= config.decline_threshold {
+        FraudCheckStatus::Fraud
+    } else if score >= config.manual_review_threshold {
+        FraudCheckStatus::ManualReview
+    } else {
+        FraudCheckStatus::Legit
+    }
+}
+
+/// The customer's keys share a hash tag so that the script updating them runs on a single node
+fn get_customer_profile_key(
+    frm_data: &FrmData,
+    customer: &Option<domain::Customer>,
+) -> Option<String> {
+    customer.as_ref().map(|customer| {
+        format!(
+            "{{{}_{}_{}}}",
+            consts::FRM_CUSTOMER_PROFILE_KEY_PREFIX,
+            frm_data.merchant_account.get_id().get_string_repr(),
+            customer.get_id().get_string_repr()
+        )
+    })
+}
+
+async fn record_payment(
+    redis_conn: &RedisConnectionPool,
+    profile_key: &str,
+    input: &FrmRulesInput,
+    config: &FrmRulesConfig,
+) -> CustomResult<CustomerRiskProfile, RedisError> {
+    let window_in_secs = config
+        .velocity
+        .as_ref()
+        .map_or(0, |velocity| velocity.window_in_secs);
+    let keys = [
+        format!("{profile_key}_payments"),
+        format!("{profile_key}_amounts"),
+        format!("{profile_key}_location"),
+    ]
+    .iter()
+    .map(|key| RedisKey::from(key).tenant_aware_key(redis_conn))
+    .collect();
+    let values = vec![
+        input.created_at.to_string(),
+        (input.created_at - window_in_secs).to_string(),
+        input.payment_id.clone(),
+        input
+            .currency
+            .map(|currency| currency.to_string())
+            .unwrap_or_default(),
+        input.amount.get_amount_as_i64().to_string(),
+        consts::FRM_CUSTOMER_PROFILE_TTL.to_string(),
+        input
+            .ip_geolocation
+            .map(|location| serde_json::to_string(&location))
+            .transpose()
+            .change_context(RedisError::JsonSerializationFailed)?
+            .unwrap_or_default(),
+    ];
+
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<String>>(RECORD_PAYMENT_SCRIPT, keys, values)
+        .await?;
+    match result.as_slice() {
+        [payments_in_window, count, total, last_location] => Ok(CustomerRiskProfile {
+            payments_in_window: payments_in_window
+                .parse()
+                .change_context(RedisError::UnknownResult)?,
+            amount_stats: AmountStats {
+                count: count.parse().change_context(RedisError::UnknownResult)?,
+                total: total.parse().change_context(RedisError::UnknownResult)?,
+            },
+            last_location: (!last_location.is_empty())
+                .then(|| serde_json::from_str(last_location))
+                .transpose()
+                .change_context(RedisError::JsonDeserializationFailed)?,
+        }),
+        _ => Err(report!(RedisError::UnknownResult))
+            .attach_printable("Unexpected result from the record payment script"),
+    }
+}
+
+/// Records the payment in the customer's profile and scores it against the configured rules.
+/// Redis failures are logged rather than failing the payment, the customer based rules are
+/// skipped in that case.
+pub async fn screen_payment(
+    state: &SessionState,
+    frm_data: &FrmData,
+    customer: &Option<domain::Customer>,
+    config: &FrmRulesConfig,
+) -> RouterResult<FraudCheckResponseData> {
+    let ip_geolocation = match (&config.ip_distance, get_ip_address(frm_data)) {
+        (Some(_), Some(ip_address)) => locate_ip_address(state, ip_address).await,
+        _ => None,
+    };
+    let input = FrmRulesInput::new(frm_data, customer, ip_geolocation);
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok();
+
+    let profile = match (redis_conn, get_customer_profile_key(frm_data, customer)) {
+        (Some(redis_conn), Some(profile_key)) => {
+            record_payment(&redis_conn, &profile_key, &input, config)
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to record customer payment"))
+                .ok()
+        }
+        _ => None,
+    };
+
+    let triggered_rules = evaluate_rules(config, &input, profile.as_ref());
+    let score = triggered_rules
+        .iter()
+        .map(|(_, score)| *score)
+        .sum::<i32>()
+        .clamp(0, MAX_FRAUD_SCORE);
+    let status = get_fraud_check_status(config, score);
+    logger::debug!(?triggered_rules, score, ?status, "hyperswitch_frm decision");
+
+    Ok(FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::ConnectorTransactionId(frm_data.fraud_check.frm_id.clone()),
+        status,
+        connector_metadata: None,
+        reason: (!triggered_rules.is_empty()).then(|| {
+            serde_json::Value::from(
+                triggered_rules
+                    .iter()
+                    .map(|(rule, _)| rule.to_string())
+                    .collect::<Vec<_>>(),
+            )
+        }),
+        score: Some(score),
+    })
+}
+
+/// The payment is only scored once, later flows report the decision that was already made
+pub fn get_recorded_decision(frm_data: &FrmData) -> FraudCheckResponseData {
+    FraudCheckResponseData::TransactionResponse {
+        resource_id: frm_data
+            .fraud_check
+            .frm_transaction_id
+            .clone()
+            .map_or(ResponseId::NoResponseId, ResponseId::ConnectorTransactionId),
+        status: frm_data.fraud_check.frm_status,
+        connector_metadata: frm_data.fraud_check.metadata.clone(),
+        reason: frm_data.fraud_check.frm_reason.clone(),
+        score: frm_data.fraud_check.frm_score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> FrmRulesInput {
+        FrmRulesInput {
+            payment_id: "pay_123".to_string(),
+            amount: MinorUnit::new(1000),
+            currency: Some(Currency::USD),
+            card_issuing_country: Some("UNITED STATES OF AMERICA".to_string()),
+            billing_country: Some(CountryAlpha2::US),
+            email: Some("john@example.com".to_string()),
+            ip_geolocation: None,
+            created_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_no_rules_triggered_for_regular_payment() {
+        let config = FrmRulesConfig::default();
+        let triggered_rules = evaluate_rules(&config, &get_input(), None);
+
+        assert!(triggered_rules.is_empty());
+        assert_eq!(get_fraud_check_status(&config, 0), FraudCheckStatus::Legit);
+    }
+
+    #[test]
+    fn test_rules_triggered_for_risky_payment() {
+        let config = FrmRulesConfig {
+            high_risk_email_domains: Some(HighRiskEmailDomainRule {
+                domains: vec!["mailinator.com".to_string()],
+                score: 20,
+            }),
+            ..Default::default()
+        };
+        let input = FrmRulesInput {
+            amount: MinorUnit::new(100_000),
+            billing_country: Some(CountryAlpha2::DE),
+            email: Some("john@Mailinator.com".to_string()),
+            ..get_input()
+        };
+        let profile = CustomerRiskProfile {
+            payments_in_window: 5,
+            amount_stats: AmountStats {
+                count: 4,
+                total: 4000,
+            },
+            last_location: None,
+        };
+
+        let triggered_rules = evaluate_rules(&config, &input, Some(&profile))
+            .into_iter()
+            .map(|(rule, _)| rule)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            triggered_rules,
+            vec![
+                FrmRule::Velocity,
+                FrmRule::BinCountryMismatch,
+                FrmRule::HighRiskEmailDomain,
+                FrmRule::AmountOutlier,
+            ]
+        );
+        assert_eq!(
+            get_fraud_check_status(&config, 60),
+            FraudCheckStatus::ManualReview
+        );
+        assert_eq!(
+            get_fraud_check_status(&config, 100),
+            FraudCheckStatus::Fraud
+        );
+    }
+
+    #[test]
+    fn test_bin_country_mismatch_compares_country_codes_exactly() {
+        let config = FrmRulesConfig::default();
+        // Niger and Nigeria share a prefix but are different countries
+        let input = FrmRulesInput {
+            card_issuing_country: Some("NIGER".to_string()),
+            billing_country: Some(CountryAlpha2::NG),
+            ..get_input()
+        };
+
+        assert_eq!(
+            evaluate_rules(&config, &input, None),
+            vec![(FrmRule::BinCountryMismatch, 30)]
+        );
+    }
+
+    #[test]
+    fn test_ip_distance_from_last_location() {
+        let config = FrmRulesConfig::default();
+        let berlin = GeoLocation {
+            latitude: 52.52,
+            longitude: 13.405,
+        };
+        let hamburg = GeoLocation {
+            latitude: 53.551,
+            longitude: 9.993,
+        };
+        let new_york = GeoLocation {
+            latitude: 40.713,
+            longitude: -74.006,
+        };
+        let profile = CustomerRiskProfile {
+            last_location: Some(berlin),
+            ..Default::default()
+        };
+        let evaluate = |location| {
+            let input = FrmRulesInput {
+                ip_geolocation: Some(location),
+                ..get_input()
+            };
+            evaluate_rules(&config, &input, Some(&profile))
+        };
+
+        assert!(evaluate(hamburg).is_empty());
+        assert_eq!(evaluate(new_york), vec![(FrmRule::IpDistance, 25)]);
+    }
+}
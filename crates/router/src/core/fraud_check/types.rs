@@ -93,6 +93,24 @@ pub struct FrmConfigsObject {
     pub frm_preferred_flow_type: api_enums::FrmPreferredFlowTypes,
 }
 
+/// Post payment fraud signal that is reported back to the frm connector which scored the payment
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrmFeedbackData {
+    pub feedback_type: storage_enums::FrmFeedbackType,
+    pub amount: Option<common_utils::types::MinorUnit>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub dispute_status: Option<storage_enums::DisputeStatus>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrmFeedbackTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub feedback: FrmFeedbackData,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
 #[serde_with::skip_serializing_none]
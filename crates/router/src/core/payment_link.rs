@@ -11,7 +11,7 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use futures::future;
-use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
+use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData, PaymentLinkCustomTemplate};
 use masking::{PeekInterface, Secret};
 use router_env::logger;
 use time::PrimitiveDateTime;
@@ -69,7 +69,12 @@ pub async fn form_payment_link_data(
     merchant_context: domain::MerchantContext,
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
-) -> RouterResult<(PaymentLink, PaymentLinkData, PaymentLinkConfig)> {
+) -> RouterResult<(
+    PaymentLink,
+    PaymentLinkData,
+    PaymentLinkConfig,
+    Option<PaymentLinkCustomTemplate>,
+)> {
     todo!()
 }
 
@@ -79,7 +84,12 @@ pub async fn form_payment_link_data(
     merchant_context: domain::MerchantContext,
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
-) -> RouterResult<(PaymentLink, PaymentLinkData, PaymentLinkConfig)> {
+) -> RouterResult<(
+    PaymentLink,
+    PaymentLinkData,
+    PaymentLinkConfig,
+    Option<PaymentLinkCustomTemplate>,
+)> {
     let db = &*state.store;
     let key_manager_state = &state.into();
 
@@ -142,6 +152,7 @@ pub async fn form_payment_link_data(
                 payment_form_label_type: None,
                 show_card_terms: None,
                 is_setup_mandate_flow: None,
+                usage_policy: None,
             }
         };
 
@@ -197,6 +208,7 @@ pub async fn form_payment_link_data(
     // converting first letter of merchant name to upperCase
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
     let payment_link_status = check_payment_link_status(session_expiry);
+    let business_payment_link_config = business_profile.payment_link_config;
 
     let is_payment_link_terminal_state = check_payment_link_invalid_conditions(
         payment_intent.status,
@@ -212,6 +224,27 @@ pub async fn form_payment_link_data(
         ],
     );
 
+    let payment_link_status = match &payment_link_config.usage_policy {
+        Some(usage_policy)
+            if !is_payment_link_terminal_state
+                && payment_link_status == api_models::payments::PaymentLinkStatus::Active =>
+        {
+            if is_payment_link_usage_exhausted(
+                state,
+                &payment_link.payment_link_id,
+                usage_policy,
+                payment_intent.amount,
+            )
+            .await?
+            {
+                api_models::payments::PaymentLinkStatus::Expired
+            } else {
+                payment_link_status
+            }
+        }
+        _ => payment_link_status,
+    };
+
     let attempt_id = payment_intent.active_attempt.get_id().clone();
     let payment_attempt = db
         .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
@@ -280,6 +313,7 @@ pub async fn form_payment_link_data(
             payment_link,
             PaymentLinkData::PaymentLinkStatusDetails(Box::new(payment_details)),
             payment_link_config,
+            None,
         ));
     };
 
@@ -327,10 +361,247 @@ pub async fn form_payment_link_data(
         setup_future_usage_applied: payment_attempt.setup_future_usage_applied,
     };
 
+    let custom_template = get_payment_link_custom_template(
+        state,
+        &merchant_id,
+        business_payment_link_config.as_ref(),
+    )
+    .await;
+
     Ok((
         payment_link,
         PaymentLinkData::PaymentLinkDetails(Box::new(payment_link_details)),
         payment_link_config,
+        custom_template,
+    ))
+}
+
+#[cfg(feature = "v1")]
+fn get_payment_link_usage_key(payment_link_id: &str) -> String {
+    format!("{}_{payment_link_id}", consts::PAYMENT_LINK_USAGE_KEY_PREFIX)
+}
+
+#[cfg(feature = "v1")]
+const PAYMENT_LINK_USES_FIELD: &str = "uses";
+#[cfg(feature = "v1")]
+const PAYMENT_LINK_TOTAL_AMOUNT_FIELD: &str = "total_amount";
+
+/// Checks whether another payment attempt for the specified amount would exceed the limits of the
+/// link's usage policy, without recording a use.
+#[cfg(feature = "v1")]
+async fn is_payment_link_usage_exhausted(
+    state: &SessionState,
+    payment_link_id: &str,
+    usage_policy: &api_models::admin::PaymentLinkUsagePolicy,
+    amount: common_utils::types::MinorUnit,
+) -> RouterResult<bool> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let usage = redis_conn
+        .get_hash_fields::<std::collections::HashMap<String, i64>>(
+            &get_payment_link_usage_key(payment_link_id).as_str().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get payment link usage")?;
+
+    let uses = usage
+        .get(PAYMENT_LINK_USES_FIELD)
+        .copied()
+        .unwrap_or_default();
+    let total_amount = usage
+        .get(PAYMENT_LINK_TOTAL_AMOUNT_FIELD)
+        .copied()
+        .unwrap_or_default();
+
+    Ok(is_payment_link_usage_limit_exceeded(
+        usage_policy,
+        uses.saturating_add(1),
+        common_utils::types::MinorUnit::new(
+            total_amount.saturating_add(amount.get_amount_as_i64()),
+        ),
+    ))
+}
+
+/// Checks whether the payment attempts made through a payment link exceed the limits of the link's
+/// usage policy.
+#[cfg(feature = "v1")]
+fn is_payment_link_usage_limit_exceeded(
+    usage_policy: &api_models::admin::PaymentLinkUsagePolicy,
+    uses: i64,
+    total_amount: common_utils::types::MinorUnit,
+) -> bool {
+    usage_policy
+        .max_uses
+        .is_some_and(|max_uses| uses > i64::from(max_uses))
+        || usage_policy
+            .max_total_amount
+            .is_some_and(|max_total_amount| total_amount > max_total_amount)
+}
+
+/// Records a payment attempt made through a payment link against the link's usage policy, and
+/// rejects the attempt if it would exceed the maximum number of uses or the maximum total amount
+/// of the link.
+///
+/// The use is recorded with atomic increments, and reverted when the attempt is rejected, so that
+/// concurrent attempts cannot exceed the limits.
+#[cfg(feature = "v1")]
+pub async fn record_payment_link_usage(
+    state: &SessionState,
+    payment_link_id: &str,
+    amount: common_utils::types::MinorUnit,
+) -> RouterResult<()> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let Some(usage_policy) = payment_link
+        .payment_link_config
+        .map(extract_payment_link_config)
+        .transpose()?
+        .and_then(|payment_link_config| payment_link_config.usage_policy)
+    else {
+        return Ok(());
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let usage_key = get_payment_link_usage_key(payment_link_id);
+    let amount = amount.get_amount_as_i64();
+
+    let usage = redis_conn
+        .increment_fields_in_hash(
+            &usage_key.as_str().into(),
+            &[
+                (PAYMENT_LINK_USES_FIELD, 1),
+                (PAYMENT_LINK_TOTAL_AMOUNT_FIELD, amount),
+            ],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment payment link usage")?;
+    let mut usage = usage
+        .into_iter()
+        .map(|value| i64::try_from(value).unwrap_or(i64::MAX));
+    let uses = usage.next().unwrap_or_default();
+    let total_amount = usage.next().unwrap_or_default();
+
+    if let Some(fulfilment_time) = payment_link.fulfilment_time {
+        redis_conn
+            .set_expire_at(
+                &usage_key.as_str().into(),
+                fulfilment_time.assume_utc().unix_timestamp(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry for payment link usage")?;
+    }
+
+    if is_payment_link_usage_limit_exceeded(
+        &usage_policy,
+        uses,
+        common_utils::types::MinorUnit::new(total_amount),
+    ) {
+        redis_conn
+            .increment_fields_in_hash(
+                &usage_key.as_str().into(),
+                &[
+                    (PAYMENT_LINK_USES_FIELD, -1),
+                    (PAYMENT_LINK_TOTAL_AMOUNT_FIELD, -amount),
+                ],
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revert payment link usage")?;
+        logger::info!("payment link {payment_link_id} has reached the limits of its usage policy");
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The payment link has reached its usage limits".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Fetches the custom template configured for the profile, if any. The default template is used
+/// if the custom template cannot be retrieved, so that the payment link can still be rendered.
+#[cfg(feature = "v1")]
+async fn get_payment_link_custom_template(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    business_payment_link_config: Option<&diesel_models::business_profile::BusinessPaymentLinkConfig>,
+) -> Option<PaymentLinkCustomTemplate> {
+    let business_payment_link_config = business_payment_link_config?;
+    let template_id = business_payment_link_config.template_id.as_ref()?;
+    let file_key = get_payment_link_template_file_key(merchant_id, template_id);
+
+    let template = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payment link template")
+        .and_then(|template| {
+            String::from_utf8(template)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Payment link template is not valid UTF-8")
+        })
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to load payment link template {template_id}, using the default template"
+            )
+        })
+        .ok()?;
+
+    Some(PaymentLinkCustomTemplate {
+        template,
+        locale: state.locale.clone(),
+        locale_bundles: business_payment_link_config
+            .locale_bundles
+            .clone()
+            .unwrap_or_default(),
+    })
+}
+
+fn get_payment_link_template_file_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    template_id: &str,
+) -> String {
+    format!(
+        "payment_link/templates/{}/{template_id}.html",
+        merchant_id.get_string_repr()
+    )
+}
+
+pub async fn upload_payment_link_template(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: api_models::payments::PaymentLinkTemplateUploadRequest,
+) -> RouterResponse<api_models::payments::PaymentLinkTemplateUploadResponse> {
+    validator::validate_payment_link_template(&request.template)?;
+
+    let template_id = common_utils::generate_time_ordered_id("plt");
+    let file_key = get_payment_link_template_file_key(
+        merchant_context.get_merchant_account().get_id(),
+        &template_id,
+    );
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, request.template.into_bytes())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload payment link template")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::PaymentLinkTemplateUploadResponse { template_id },
     ))
 }
 
@@ -341,7 +612,7 @@ pub async fn initiate_secure_payment_link_flow(
     payment_id: common_utils::id_type::PaymentId,
     request_headers: &header::HeaderMap,
 ) -> RouterResponse<services::PaymentLinkFormData> {
-    let (payment_link, payment_link_details, payment_link_config) =
+    let (payment_link, payment_link_details, payment_link_config, custom_template) =
         form_payment_link_data(&state, merchant_context, merchant_id, payment_id).await?;
 
     validator::validate_secure_payment_link_render_request(
@@ -399,6 +670,7 @@ pub async fn initiate_secure_payment_link_flow(
                 sdk_url: state.conf.payment_link.sdk_url.clone(),
                 css_script,
                 html_meta_tags,
+                custom_template,
             };
             let allowed_domains = payment_link_config
                 .allowed_domains
@@ -444,7 +716,7 @@ pub async fn initiate_payment_link_flow(
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
 ) -> RouterResponse<services::PaymentLinkFormData> {
-    let (_, payment_details, payment_link_config) =
+    let (_, payment_details, payment_link_config, custom_template) =
         form_payment_link_data(&state, merchant_context, merchant_id, payment_id).await?;
 
     let css_script = get_color_scheme_css(&payment_link_config);
@@ -471,6 +743,7 @@ pub async fn initiate_payment_link_flow(
                 sdk_url: state.conf.payment_link.sdk_url.clone(),
                 css_script,
                 html_meta_tags,
+                custom_template,
             };
             logger::info!(
                 "payment link data, for building open payment link {:?}",
@@ -756,6 +1029,7 @@ pub fn get_payment_link_config_based_on_priority(
             payment_form_label_type,
             show_card_terms,
             is_setup_mandate_flow,
+            usage_policy: None,
         };
 
     Ok((payment_link_config, domain_name))
@@ -870,6 +1144,7 @@ pub async fn get_payment_link_status(
             payment_form_label_type: None,
             show_card_terms: None,
             is_setup_mandate_flow: None,
+            usage_policy: None,
         }
     };
 
@@ -970,3 +1245,55 @@ pub async fn get_payment_link_status(
         services::api::PaymentLinkAction::PaymentLinkStatus(payment_link_status_data),
     )))
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_payment_link_usage_limit_counts_uses() {
+        let usage_policy = api_models::admin::PaymentLinkUsagePolicy {
+            max_uses: Some(2),
+            max_total_amount: None,
+        };
+
+        assert!(!is_payment_link_usage_limit_exceeded(
+            &usage_policy,
+            2,
+            MinorUnit::new(1000)
+        ));
+        assert!(is_payment_link_usage_limit_exceeded(
+            &usage_policy,
+            3,
+            MinorUnit::new(1000)
+        ));
+    }
+
+    #[test]
+    fn test_payment_link_usage_limit_uses_running_total_amount() {
+        let usage_policy = api_models::admin::PaymentLinkUsagePolicy {
+            max_uses: None,
+            max_total_amount: Some(MinorUnit::new(1500)),
+        };
+
+        // A single attempt below the maximum total amount is allowed, the running total of the
+        // attempts made through the link is what is limited
+        assert!(!is_payment_link_usage_limit_exceeded(
+            &usage_policy,
+            1,
+            MinorUnit::new(1000)
+        ));
+        assert!(!is_payment_link_usage_limit_exceeded(
+            &usage_policy,
+            2,
+            MinorUnit::new(1500)
+        ));
+        assert!(is_payment_link_usage_limit_exceeded(
+            &usage_policy,
+            2,
+            MinorUnit::new(2000)
+        ));
+    }
+}
@@ -5,6 +5,7 @@ use error_stack::{report, ResultExt};
 use url::Url;
 
 use crate::{
+    consts,
     core::errors::{self, RouterResult},
     types::storage::PaymentLink,
 };
@@ -116,3 +117,104 @@ pub fn validate_secure_payment_link_render_request(
         })
     }
 }
+
+/// Validates a custom payment link template.
+///
+/// Templates are sandboxed by only allowing `{{ variable }}` placeholders referring to the
+/// variables provided by the server, so that templates cannot include other templates or run any
+/// logic when rendered.
+pub fn validate_payment_link_template(template: &str) -> RouterResult<()> {
+    let invalid_template = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData { message }))
+    };
+
+    if template.len() > consts::MAX_PAYMENT_LINK_TEMPLATE_SIZE {
+        return invalid_template(format!(
+            "Payment link template must not be larger than {} bytes",
+            consts::MAX_PAYMENT_LINK_TEMPLATE_SIZE
+        ));
+    }
+
+    if template.contains("{%") {
+        return invalid_template(
+            "Template tags are not allowed in payment link templates, only `{{ variable }}` placeholders are supported".to_string(),
+        );
+    }
+
+    let mut referenced_variables = Vec::new();
+    let mut remaining = template;
+    while let Some(start) = remaining.find("{{") {
+        let after_start = remaining.get(start + 2..).unwrap_or_default();
+        let Some(end) = after_start.find("}}") else {
+            return invalid_template("Unterminated placeholder in payment link template".to_string());
+        };
+        let variable = after_start.get(..end).unwrap_or_default().trim();
+
+        let is_allowed_variable = consts::PAYMENT_LINK_TEMPLATE_VARIABLES.contains(&variable)
+            || variable
+                .strip_prefix("i18n_")
+                .is_some_and(|key| consts::PAYMENT_LINK_TEMPLATE_I18N_KEYS.contains(&key));
+        if !is_allowed_variable {
+            return invalid_template(format!(
+                "Unsupported placeholder `{{{{ {variable} }}}}` in payment link template"
+            ));
+        }
+
+        referenced_variables.push(variable);
+        remaining = after_start.get(end + 2..).unwrap_or_default();
+    }
+
+    if let Some(missing_variable) = consts::PAYMENT_LINK_TEMPLATE_REQUIRED_VARIABLES
+        .iter()
+        .find(|variable| !referenced_variables.contains(variable))
+    {
+        return invalid_template(format!(
+            "Payment link template must include the `{{{{ {missing_variable} }}}}` placeholder"
+        ));
+    }
+
+    tera::Tera::default()
+        .add_raw_template("payment_link", template)
+        .map_err(|tera_error| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid payment link template: {tera_error}"),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUIRED_PLACEHOLDERS: &str =
+        "{{ rendered_js }}{{payment_link_initiator}}{{ hyperloader_sdk_link }}";
+
+    #[test]
+    fn test_valid_payment_link_template() {
+        let template = format!(
+            "<html><head>{{{{ rendered_css }}}}</head><body><h1>{{{{ i18n_pay_now }}}}</h1>{REQUIRED_PLACEHOLDERS}</body></html>"
+        );
+        assert!(validate_payment_link_template(&template).is_ok());
+    }
+
+    #[test]
+    fn test_payment_link_template_with_template_tags() {
+        let template = format!("{{% include \"other\" %}}{REQUIRED_PLACEHOLDERS}");
+        assert!(validate_payment_link_template(&template).is_err());
+    }
+
+    #[test]
+    fn test_payment_link_template_with_unknown_variables() {
+        let template = format!("{{{{ client_secret }}}}{REQUIRED_PLACEHOLDERS}");
+        assert!(validate_payment_link_template(&template).is_err());
+
+        let template = format!("{{{{ i18n_unknown }}}}{REQUIRED_PLACEHOLDERS}");
+        assert!(validate_payment_link_template(&template).is_err());
+    }
+
+    #[test]
+    fn test_payment_link_template_without_required_variables() {
+        let template = "<html>{{ rendered_js }}{{ payment_link_initiator }}</html>";
+        assert!(validate_payment_link_template(template).is_err());
+    }
+}
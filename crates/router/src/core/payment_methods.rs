@@ -1,4 +1,6 @@
 pub mod cards;
+#[cfg(feature = "v1")]
+pub mod migration_batch;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 #[cfg(all(
@@ -10,6 +12,8 @@ pub mod transformers;
 pub mod utils;
 mod validator;
 pub mod vault;
+#[cfg(feature = "v1")]
+pub mod vault_export;
 use std::borrow::Cow;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use std::collections::HashSet;
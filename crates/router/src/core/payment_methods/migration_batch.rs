@@ -0,0 +1,1074 @@
+//! Background migration of payment methods from a bulk file.
+//!
+//! The uploaded file is a CSV of payment method records, which may be sent as a compact JWE
+//! encrypted with the tunnel public key. The records are split into chunks which are stored
+//! encrypted in file storage (`payment_methods/migration_batches/{merchant_id}/{batch_id}`) and
+//! validated and migrated one chunk at a time by the payment method migration batch process
+//! tracker workflow. The results of each chunk are stored alongside the rows before the index of
+//! the next chunk is checkpointed in the tracking data of the task, so that a batch interrupted
+//! by a crash resumes from the first chunk which was not completed. Within a chunk, each row is
+//! checkpointed in redis before and after it is migrated, so that the rows of the resumed chunk
+//! are never migrated twice.
+//!
+//! Rows with the same card as a payment method already saved for the customer, including those
+//! migrated by earlier batches, are reported as duplicates of that payment method. Duplicates
+//! within a batch are found through the card fingerprints of its rows, which are kept in redis
+//! until the batch completes.
+
+use std::collections::HashMap;
+
+use ::payment_methods::core::migration;
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::payment_methods as pm_api;
+use common_utils::{
+    crypto::{self, SignMessage},
+    errors::ErrorSwitch,
+    ext_traits::{Encode, StringExt, ValueExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+use josekit::jwe;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        customers,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as files_helpers,
+        payment_methods::cards,
+    },
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage},
+};
+
+const PAYMENT_METHOD_MIGRATION_BATCH_FILES_PREFIX: &str = "payment_methods/migration_batches";
+/// Number of chunks of a batch which are uploaded to file storage concurrently
+const CHUNK_UPLOAD_CONCURRENCY: usize = 10;
+pub const PAYMENT_METHOD_MIGRATION_BATCH_TASK: &str = "PAYMENT_METHOD_MIGRATION_BATCH";
+pub const PAYMENT_METHOD_MIGRATION_BATCH_TAG: &str = "PAYMENT_METHOD";
+
+#[derive(Debug, MultipartForm)]
+pub struct PaymentMethodMigrationBatchForm {
+    #[multipart(limit = "200MB")]
+    pub file: Bytes,
+    pub merchant_id: Text<id_type::MerchantId>,
+    pub merchant_connector_id: Option<Text<id_type::MerchantConnectorAccountId>>,
+    /// Whether the file is a compact JWE encrypted with the tunnel public key
+    pub is_encrypted: Option<Text<bool>>,
+    /// Whether the rows are only validated, without migrating any payment method
+    pub dry_run: Option<Text<bool>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMethodMigrationBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    pub dry_run: bool,
+    pub total_rows: u32,
+    pub total_chunks: usize,
+    /// Index of the next chunk of rows to be processed
+    pub next_chunk_index: usize,
+    pub processed_rows: u32,
+    pub succeeded_rows: u32,
+    pub duplicate_rows: u32,
+    pub failed_rows: u32,
+}
+
+fn get_batch_files_prefix(merchant_id: &id_type::MerchantId, batch_id: &str) -> String {
+    format!(
+        "{PAYMENT_METHOD_MIGRATION_BATCH_FILES_PREFIX}/{}/{batch_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_rows_key(merchant_id: &id_type::MerchantId, batch_id: &str, chunk_index: usize) -> String {
+    format!(
+        "{}/rows/{chunk_index}.json",
+        get_batch_files_prefix(merchant_id, batch_id)
+    )
+}
+
+fn get_results_key(
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    chunk_index: usize,
+) -> String {
+    format!(
+        "{}/results/{chunk_index}.json",
+        get_batch_files_prefix(merchant_id, batch_id)
+    )
+}
+
+fn get_fingerprints_key(merchant_id: &id_type::MerchantId, batch_id: &str) -> String {
+    format!(
+        "pm_migration_batch_fingerprints_{}_{batch_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_row_checkpoints_key(
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    chunk_index: usize,
+) -> String {
+    format!(
+        "pm_migration_batch_row_checkpoints_{}_{batch_id}_{chunk_index}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Progress of a row of the chunk being processed, keyed by the index of the row in the chunk
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "state", content = "result", rename_all = "snake_case")]
+enum RowCheckpoint {
+    /// The customer and payment method of the row are being migrated, and may have been created
+    InProgress,
+    Completed(pm_api::PaymentMethodMigrationRowResult),
+}
+
+async fn set_row_checkpoint(
+    state: &SessionState,
+    row_checkpoints_key: &str,
+    row_index: usize,
+    checkpoint: &RowCheckpoint,
+) -> RouterResult<()> {
+    let checkpoint = checkpoint
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize migration batch row checkpoint")?;
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_hash_fields(
+            &row_checkpoints_key.into(),
+            vec![(row_index.to_string(), checkpoint)],
+            Some(
+                state
+                    .conf
+                    .payment_method_migration_batch
+                    .fingerprint_ttl_in_secs
+                    .into(),
+            ),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store migration batch row checkpoint")
+}
+
+async fn get_row_checkpoints(
+    state: &SessionState,
+    row_checkpoints_key: &str,
+) -> RouterResult<HashMap<usize, RowCheckpoint>> {
+    let checkpoints = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_hash_fields::<HashMap<String, String>>(&row_checkpoints_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve migration batch row checkpoints")?;
+
+    checkpoints
+        .into_iter()
+        .map(|(row_index, checkpoint)| {
+            let row_index = row_index
+                .parse::<usize>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid row index in migration batch row checkpoints")?;
+            let checkpoint = checkpoint
+                .parse_struct("RowCheckpoint")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((row_index, checkpoint))
+        })
+        .collect()
+}
+
+fn get_error_message(error: &errors::ApiErrorResponse) -> String {
+    let mut api_error: api_models::errors::types::ApiErrorResponse = error.switch();
+    api_error.get_internal_error_mut().error_message.clone()
+}
+
+async fn decrypt_migration_file(state: &SessionState, file: &[u8]) -> RouterResult<Vec<u8>> {
+    let jwe_body =
+        std::str::from_utf8(file).change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Encrypted payment method migration file must be a compact JWE".to_string(),
+        })?;
+    let private_key = state
+        .conf
+        .jwekey
+        .get_inner()
+        .tunnel_private_key
+        .peek()
+        .as_bytes();
+
+    services::decrypt_jwe(
+        jwe_body.trim(),
+        services::KeyIdCheck::SkipKeyIdCheck,
+        private_key,
+        jwe::RSA_OAEP_256,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::UnprocessableEntity {
+        message: "Failed to decrypt the payment method migration file".to_string(),
+    })
+    .map(String::into_bytes)
+}
+
+#[instrument(skip_all)]
+pub async fn create_payment_method_migration_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    file: Vec<u8>,
+    is_encrypted: bool,
+    merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    dry_run: bool,
+) -> RouterResponse<pm_api::PaymentMethodMigrationBatchResponse> {
+    let batch_config = &state.conf.payment_method_migration_batch;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    if let Some(merchant_connector_id) = &merchant_connector_id {
+        state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &(&state).into(),
+                &merchant_id,
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.get_string_repr().to_string(),
+            })?;
+    }
+
+    let file = if is_encrypted {
+        decrypt_migration_file(&state, &file).await?
+    } else {
+        file
+    };
+    let records = migration::parse_csv(&file).map_err(|error| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Invalid payment method migration file: {error}"),
+        })
+    })?;
+    let total_rows = u32::try_from(records.len())
+        .ok()
+        .filter(|total_rows| *total_rows <= batch_config.max_rows)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payment method migration file must not contain more than {} rows",
+                batch_config.max_rows
+            ),
+        })?;
+    if total_rows == 0 {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment method migration file must contain at least one row".to_string(),
+        }));
+    }
+
+    let batch_id = common_utils::generate_time_ordered_id("pmbatch");
+    let chunks = records
+        .chunks(usize::from(batch_config.chunk_size.max(1)))
+        .collect::<Vec<_>>();
+    let total_chunks = chunks.len();
+    futures::stream::iter(chunks.into_iter().enumerate())
+        .map(|(chunk_index, chunk)| {
+            let rows_key = get_rows_key(&merchant_id, &batch_id, chunk_index);
+            let state = &state;
+            async move {
+                files_helpers::upload_encrypted_file(state, key_store, &rows_key, &chunk).await
+            }
+        })
+        .buffer_unordered(CHUNK_UPLOAD_CONCURRENCY)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let tracking_data = PaymentMethodMigrationBatchTrackingData {
+        batch_id: batch_id.clone(),
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+        dry_run,
+        total_rows,
+        total_chunks,
+        next_chunk_index: 0,
+        processed_rows: 0,
+        succeeded_rows: 0,
+        duplicate_rows: 0,
+        failed_rows: 0,
+    };
+
+    let runner = storage::ProcessTrackerRunner::PaymentMethodMigrationBatchWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        PAYMENT_METHOD_MIGRATION_BATCH_TASK,
+        &batch_id,
+        &merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYMENT_METHOD_MIGRATION_BATCH_TASK,
+        runner,
+        [PAYMENT_METHOD_MIGRATION_BATCH_TAG],
+        &tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment method migration batch process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "PaymentMethodsMigrationBatchCreate"));
+    let process_tracker = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes))
+        .inspect_err(|_| crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment method migration batch process tracker task")?;
+
+    logger::info!(
+        %batch_id,
+        total_rows,
+        total_chunks,
+        dry_run,
+        "Scheduled payment method migration batch"
+    );
+
+    Ok(ApplicationResponse::Json(get_migration_batch_response(
+        &process_tracker,
+        tracking_data,
+    )))
+}
+
+async fn find_migration_batch(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<(
+    storage::ProcessTracker,
+    PaymentMethodMigrationBatchTrackingData,
+)> {
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PaymentMethodMigrationBatchWorkflow,
+        PAYMENT_METHOD_MIGRATION_BATCH_TASK,
+        batch_id,
+        merchant_id,
+    );
+
+    let process_tracker = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment method migration batch process tracker task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payment method migration batch `{batch_id}` not found"),
+        })?;
+
+    let tracking_data: PaymentMethodMigrationBatchTrackingData = process_tracker
+        .tracking_data
+        .clone()
+        .parse_value("PaymentMethodMigrationBatchTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment method migration batch tracking data")?;
+
+    Ok((process_tracker, tracking_data))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_payment_method_migration_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<pm_api::PaymentMethodMigrationBatchResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let (process_tracker, tracking_data) =
+        find_migration_batch(&state, merchant_id, &batch_id).await?;
+
+    Ok(ApplicationResponse::Json(get_migration_batch_response(
+        &process_tracker,
+        tracking_data,
+    )))
+}
+
+/// Downloads the per row results of the chunks of a migration batch processed so far as a CSV file
+#[instrument(skip(state, merchant_context))]
+pub async fn download_payment_method_migration_batch_results(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<Vec<u8>> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let (_, tracking_data) = find_migration_batch(&state, merchant_id, &batch_id).await?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for chunk_index in 0..tracking_data.next_chunk_index {
+        let results: Vec<pm_api::PaymentMethodMigrationRowResult> =
+            files_helpers::retrieve_encrypted_file(
+                &state,
+                merchant_context.get_merchant_key_store(),
+                &get_results_key(merchant_id, &batch_id, chunk_index),
+            )
+            .await?;
+        for result in results {
+            csv_writer
+                .serialize(result)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize payment method migration result")?;
+        }
+    }
+    let file_data = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write payment method migration results file")?;
+
+    Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)))
+}
+
+fn get_migration_batch_response(
+    process_tracker: &storage::ProcessTracker,
+    tracking_data: PaymentMethodMigrationBatchTrackingData,
+) -> pm_api::PaymentMethodMigrationBatchResponse {
+    let status = match process_tracker.status {
+        storage::enums::ProcessTrackerStatus::Finish
+            if process_tracker.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            pm_api::PaymentMethodMigrationBatchStatus::Completed
+        }
+        storage::enums::ProcessTrackerStatus::Finish => {
+            pm_api::PaymentMethodMigrationBatchStatus::Failed
+        }
+        _ if tracking_data.next_chunk_index == 0 => {
+            pm_api::PaymentMethodMigrationBatchStatus::Scheduled
+        }
+        _ => pm_api::PaymentMethodMigrationBatchStatus::InProgress,
+    };
+
+    pm_api::PaymentMethodMigrationBatchResponse {
+        batch_id: tracking_data.batch_id,
+        merchant_id: tracking_data.merchant_id,
+        merchant_connector_id: tracking_data.merchant_connector_id,
+        dry_run: tracking_data.dry_run,
+        status,
+        total_rows: tracking_data.total_rows,
+        processed_rows: tracking_data.processed_rows,
+        succeeded_rows: tracking_data.succeeded_rows,
+        duplicate_rows: tracking_data.duplicate_rows,
+        failed_rows: tracking_data.failed_rows,
+        created_at: process_tracker.created_at,
+    }
+}
+
+/// Validates the card details of a record, returning the data the card fingerprint is
+/// generated from.
+///
+/// The full card number must pass the Luhn check when present. Records without it can only be
+/// migrated using their connector mandate details or network token, and are fingerprinted using
+/// the masked card number, the expiry and the connector mandate id instead.
+fn validate_payment_method_record(record: &pm_api::PaymentMethodRecord) -> Result<String, String> {
+    let fingerprint_data = match &record.raw_card_number {
+        Some(raw_card_number) => {
+            let card_number = raw_card_number
+                .peek()
+                .split_whitespace()
+                .collect::<String>();
+            match ::cards::validate::sanitize_card_number(&card_number) {
+                Ok(true) => card_number,
+                Ok(false) => return Err("Card number failed the Luhn check".to_string()),
+                Err(error) => return Err(format!("Invalid card number: {error}")),
+            }
+        }
+        None => {
+            migration::payment_methods::get_card_bin_and_last4_digits_for_masked_card(
+                record.card_number_masked.peek(),
+            )
+            .map_err(|error| format!("Invalid masked card number: {error}"))?;
+            format!(
+                "{}|{}|{}|{}",
+                record.card_number_masked.peek(),
+                record.card_expiry_month.peek(),
+                record.card_expiry_year.peek(),
+                record
+                    .payment_instrument_id
+                    .as_ref()
+                    .map(|payment_instrument_id| payment_instrument_id.peek().as_str())
+                    .unwrap_or_default()
+            )
+        }
+    };
+
+    migration::validate_card_expiry(&record.card_expiry_month, &record.card_expiry_year)
+        .map_err(|error| get_error_message(error.current_context()))?;
+
+    if let Some(card_scheme) = record
+        .card_scheme
+        .as_deref()
+        .map(str::trim)
+        .filter(|card_scheme| !card_scheme.is_empty())
+    {
+        serde_json::Value::String(card_scheme.to_string())
+            .parse_value::<common_enums::CardNetwork>("CardNetwork")
+            .map_err(|_| format!("Unsupported card network `{card_scheme}`"))?;
+    }
+
+    if record.network_token_number.is_some() {
+        let (Some(expiry_month), Some(expiry_year)) = (
+            &record.network_token_expiry_month,
+            &record.network_token_expiry_year,
+        ) else {
+            return Err(
+                "Network token expiry is required along with the network token".to_string(),
+            );
+        };
+        migration::validate_card_expiry(expiry_month, expiry_year).map_err(|error| {
+            format!(
+                "Invalid network token expiry: {}",
+                get_error_message(error.current_context())
+            )
+        })?;
+    }
+
+    Ok(fingerprint_data)
+}
+
+/// Records the fingerprint of the card of a row, returning the line number of the earlier row of
+/// the batch with the same card for the same customer, if any.
+///
+/// A row processed again after a crash finds its own line number recorded against the
+/// fingerprint, and is not considered a duplicate. The expiry of the fingerprints is extended with
+/// every row, and they are deleted once the batch completes.
+async fn find_duplicate_row(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &PaymentMethodMigrationBatchTrackingData,
+    customer_id: &id_type::CustomerId,
+    line_number: i64,
+    fingerprint_data: &str,
+) -> RouterResult<Option<i64>> {
+    let fingerprint = crypto::HmacSha512
+        .sign_message(
+            key_store.key.get_inner().peek(),
+            fingerprint_data.as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate card fingerprint")?;
+    let field = format!(
+        "{}_{}",
+        customer_id.get_string_repr(),
+        hex::encode(fingerprint)
+    );
+    let fingerprints_key =
+        get_fingerprints_key(&tracking_data.merchant_id, &tracking_data.batch_id);
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let reply = redis_conn
+        .set_hash_field_if_not_exist(
+            &fingerprints_key.as_str().into(),
+            &field,
+            line_number,
+            Some(
+                state
+                    .conf
+                    .payment_method_migration_batch
+                    .fingerprint_ttl_in_secs,
+            ),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record card fingerprint")?;
+
+    match reply {
+        redis_interface::HsetnxReply::KeySet => Ok(None),
+        redis_interface::HsetnxReply::KeyNotSet => {
+            let first_line_number = redis_conn
+                .get_hash_field::<i64>(&fingerprints_key.as_str().into(), &field)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve card fingerprint")?;
+            Ok((first_line_number != line_number).then_some(first_line_number))
+        }
+    }
+}
+
+/// Card details identifying a card without its full number, as saved with payment methods
+#[derive(Debug, PartialEq, Eq)]
+struct SavedCardDetails {
+    card_isin: String,
+    last4_digits: String,
+    expiry_month: u8,
+    /// The last two digits of the expiry year
+    expiry_year: u16,
+}
+
+impl SavedCardDetails {
+    fn new(card_number: &str, expiry_month: &str, expiry_year: &str) -> Option<Self> {
+        let (card_isin, last4_digits) =
+            migration::payment_methods::get_card_bin_and_last4_digits_for_masked_card(card_number)
+                .ok()?;
+        Some(Self {
+            card_isin,
+            last4_digits,
+            expiry_month: expiry_month.trim().parse().ok()?,
+            expiry_year: expiry_year.trim().parse::<u16>().ok()? % 100,
+        })
+    }
+
+    fn from_record(record: &pm_api::PaymentMethodRecord) -> Option<Self> {
+        let card_number = record.raw_card_number.as_ref().map_or_else(
+            || record.card_number_masked.peek().clone(),
+            |raw_card_number| {
+                raw_card_number
+                    .peek()
+                    .split_whitespace()
+                    .collect::<String>()
+            },
+        );
+        Self::new(
+            &card_number,
+            record.card_expiry_month.peek(),
+            record.card_expiry_year.peek(),
+        )
+    }
+
+    fn from_payment_method(payment_method: &domain::PaymentMethod) -> Option<Self> {
+        let card = payment_method
+            .payment_method_data
+            .clone()
+            .map(|payment_method_data| payment_method_data.into_inner().expose())
+            .and_then(|payment_method_data| {
+                payment_method_data
+                    .parse_value::<pm_api::PaymentMethodsData>("PaymentMethodsData")
+                    .ok()
+            })
+            .and_then(|payment_method_data| match payment_method_data {
+                pm_api::PaymentMethodsData::Card(card) => Some(card),
+                _ => None,
+            })?;
+        Some(Self {
+            card_isin: card.card_isin?,
+            last4_digits: card.last4_digits?,
+            expiry_month: card.expiry_month?.peek().trim().parse().ok()?,
+            expiry_year: card.expiry_year?.peek().trim().parse::<u16>().ok()? % 100,
+        })
+    }
+}
+
+/// Returns the payment method already saved for the customer with the same card as the row, if
+/// any, so that cards in the vault, including those migrated by earlier batches, are not migrated
+/// again. Saved cards are matched on the card bin, the last four digits and the expiry.
+async fn find_saved_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    record: &pm_api::PaymentMethodRecord,
+) -> RouterResult<Option<String>> {
+    let Some(card_details) = SavedCardDetails::from_record(record) else {
+        return Ok(None);
+    };
+    let payment_methods = match state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &record.customer_id,
+            merchant_context.get_merchant_account().get_id(),
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve the payment methods of the customer")
+        }
+    };
+
+    Ok(payment_methods
+        .into_iter()
+        .find(|payment_method| {
+            SavedCardDetails::from_payment_method(payment_method).as_ref() == Some(&card_details)
+        })
+        .map(|payment_method| payment_method.payment_method_id))
+}
+
+fn get_row_result(
+    record: &pm_api::PaymentMethodRecord,
+    status: pm_api::PaymentMethodMigrationRowStatus,
+) -> pm_api::PaymentMethodMigrationRowResult {
+    pm_api::PaymentMethodMigrationRowResult {
+        line_number: record.line_number.unwrap_or_default(),
+        customer_id: record.customer_id.clone(),
+        card_number_masked: Some(record.card_number_masked.peek().clone()),
+        status,
+        payment_method_id: None,
+        duplicate_of_line_number: None,
+        card_migrated: None,
+        network_token_migrated: None,
+        connector_mandate_details_migrated: None,
+        network_transaction_id_migrated: None,
+        error_message: None,
+    }
+}
+
+async fn migrate_payment_method_record(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &PaymentMethodMigrationBatchTrackingData,
+    record: pm_api::PaymentMethodRecord,
+    row_checkpoints_key: &str,
+    row_index: usize,
+) -> RouterResult<pm_api::PaymentMethodMigrationRowResult> {
+    let line_number = record.line_number.unwrap_or_default();
+    let mut result = get_row_result(&record, pm_api::PaymentMethodMigrationRowStatus::Invalid);
+
+    let validation_result = validate_payment_method_record(&record).and_then(|fingerprint_data| {
+        pm_api::PaymentMethodMigrate::try_from((
+            record.clone(),
+            tracking_data.merchant_id.clone(),
+            tracking_data.merchant_connector_id.clone(),
+        ))
+        .map(|migrate_request| (fingerprint_data, migrate_request))
+        .map_err(|error| format!("Invalid record: {}", error.current_context()))
+    });
+    let (fingerprint_data, migrate_request) = match validation_result {
+        Ok(validated) => validated,
+        Err(error_message) => {
+            result.error_message = Some(error_message);
+            return Ok(result);
+        }
+    };
+
+    if let Some(first_line_number) = find_duplicate_row(
+        state,
+        merchant_context.get_merchant_key_store(),
+        tracking_data,
+        &record.customer_id,
+        line_number,
+        &fingerprint_data,
+    )
+    .await?
+    {
+        result.status = pm_api::PaymentMethodMigrationRowStatus::Duplicate;
+        result.duplicate_of_line_number = Some(first_line_number);
+        return Ok(result);
+    }
+
+    if let Some(payment_method_id) =
+        find_saved_payment_method(state, merchant_context, &record).await?
+    {
+        result.status = pm_api::PaymentMethodMigrationRowStatus::Duplicate;
+        result.payment_method_id = Some(payment_method_id);
+        return Ok(result);
+    }
+
+    if tracking_data.dry_run {
+        result.status = pm_api::PaymentMethodMigrationRowStatus::Valid;
+        return Ok(result);
+    }
+
+    set_row_checkpoint(
+        state,
+        row_checkpoints_key,
+        row_index,
+        &RowCheckpoint::InProgress,
+    )
+    .await?;
+    result.status = pm_api::PaymentMethodMigrationRowStatus::Failed;
+    let customer =
+        api::customers::CustomerRequest::from((record.clone(), tracking_data.merchant_id.clone()));
+    if let Err(error) =
+        customers::migrate_customers(state.clone(), vec![customer], merchant_context.clone()).await
+    {
+        logger::error!(?error, line_number, "Failed to migrate customer");
+        result.error_message = Some(error.current_context().to_string());
+        return Ok(result);
+    }
+
+    let response = Box::pin(migration::migrate_payment_method(
+        &state.into(),
+        migrate_request,
+        &tracking_data.merchant_id,
+        merchant_context,
+        &cards::PmCards {
+            state,
+            merchant_context,
+        },
+    ))
+    .await;
+    match response {
+        Ok(ApplicationResponse::Json(response)) => {
+            result.status = pm_api::PaymentMethodMigrationRowStatus::Migrated;
+            result.payment_method_id = Some(response.payment_method_response.payment_method_id);
+            result.card_migrated = response.card_migrated;
+            result.network_token_migrated = response.network_token_migrated;
+            result.connector_mandate_details_migrated = response.connector_mandate_details_migrated;
+            result.network_transaction_id_migrated = response.network_transaction_id_migrated;
+        }
+        Ok(_) => {
+            result.error_message = Some("Failed to migrate payment method".to_string());
+        }
+        Err(error) => {
+            logger::error!(?error, line_number, "Failed to migrate payment method");
+            result.error_message = Some(get_error_message(error.current_context()));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Validates and migrates the rows of the next chunk of a migration batch and records their
+/// results. Returns `true` once all chunks of the batch have been processed.
+#[instrument(skip_all, fields(batch_id = %tracking_data.batch_id))]
+pub async fn process_payment_method_migration_batch_chunk(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &mut PaymentMethodMigrationBatchTrackingData,
+) -> RouterResult<bool> {
+    let chunk_index = tracking_data.next_chunk_index;
+    if chunk_index >= tracking_data.total_chunks {
+        return Ok(true);
+    }
+    let key_store = merchant_context.get_merchant_key_store();
+    let results_key = get_results_key(
+        &tracking_data.merchant_id,
+        &tracking_data.batch_id,
+        chunk_index,
+    );
+
+    // The results of the chunk are present if the task was interrupted after they were stored,
+    // but before the checkpoint was updated
+    let results = match files_helpers::retrieve_encrypted_file::<
+        Vec<pm_api::PaymentMethodMigrationRowResult>,
+    >(state, key_store, &results_key)
+    .await
+    {
+        Ok(results) => {
+            logger::info!(chunk_index, "Using stored results of migration batch chunk");
+            results
+        }
+        Err(_) => {
+            let rows: Vec<pm_api::PaymentMethodRecord> = files_helpers::retrieve_encrypted_file(
+                state,
+                key_store,
+                &get_rows_key(
+                    &tracking_data.merchant_id,
+                    &tracking_data.batch_id,
+                    chunk_index,
+                ),
+            )
+            .await?;
+
+            let row_checkpoints_key = get_row_checkpoints_key(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+                chunk_index,
+            );
+            let mut row_checkpoints = get_row_checkpoints(state, &row_checkpoints_key).await?;
+
+            let mut results = Vec::with_capacity(rows.len());
+            for (row_index, record) in rows.into_iter().enumerate() {
+                let result = match row_checkpoints.remove(&row_index) {
+                    Some(RowCheckpoint::Completed(result)) => result,
+                    // Whether the payment method was created cannot be told, so the row is not
+                    // migrated again to avoid duplicating the payment method
+                    Some(RowCheckpoint::InProgress) => {
+                        let mut result = get_row_result(
+                            &record,
+                            pm_api::PaymentMethodMigrationRowStatus::Failed,
+                        );
+                        result.error_message = Some(
+                            "Migration of the row was interrupted, check whether the payment \
+                             method was migrated before migrating the row again"
+                                .to_string(),
+                        );
+                        result
+                    }
+                    None => {
+                        let result = migrate_payment_method_record(
+                            state,
+                            merchant_context,
+                            tracking_data,
+                            record,
+                            &row_checkpoints_key,
+                            row_index,
+                        )
+                        .await?;
+                        set_row_checkpoint(
+                            state,
+                            &row_checkpoints_key,
+                            row_index,
+                            &RowCheckpoint::Completed(result.clone()),
+                        )
+                        .await?;
+                        result
+                    }
+                };
+                results.push(result);
+            }
+            files_helpers::upload_encrypted_file(state, key_store, &results_key, &results).await?;
+
+            // The checkpoints of the rows are superseded by the stored results of the chunk
+            let redis_conn = state
+                .store
+                .get_redis_conn()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get redis connection")?;
+            if let Err(error) = redis_conn
+                .delete_key(&row_checkpoints_key.as_str().into())
+                .await
+            {
+                logger::warn!(
+                    ?error,
+                    chunk_index,
+                    "Failed to delete migration batch row checkpoints"
+                );
+            }
+            results
+        }
+    };
+
+    for result in &results {
+        tracking_data.processed_rows += 1;
+        match result.status {
+            pm_api::PaymentMethodMigrationRowStatus::Valid
+            | pm_api::PaymentMethodMigrationRowStatus::Migrated => {
+                tracking_data.succeeded_rows += 1
+            }
+            pm_api::PaymentMethodMigrationRowStatus::Duplicate => tracking_data.duplicate_rows += 1,
+            pm_api::PaymentMethodMigrationRowStatus::Invalid
+            | pm_api::PaymentMethodMigrationRowStatus::Failed => tracking_data.failed_rows += 1,
+        }
+    }
+
+    tracking_data.next_chunk_index += 1;
+    let is_completed = tracking_data.next_chunk_index >= tracking_data.total_chunks;
+    if is_completed {
+        let fingerprints_key =
+            get_fingerprints_key(&tracking_data.merchant_id, &tracking_data.batch_id);
+        let delete_result = match state.store.get_redis_conn() {
+            Ok(redis_conn) => redis_conn
+                .delete_key(&fingerprints_key.as_str().into())
+                .await
+                .map(|_| ()),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = delete_result {
+            logger::warn!(?error, "Failed to delete migration batch card fingerprints");
+        }
+    }
+    Ok(is_completed)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_record(
+        raw_card_number: Option<&str>,
+        card_scheme: Option<&str>,
+    ) -> pm_api::PaymentMethodRecord {
+        serde_json::from_value(serde_json::json!({
+            "customer_id": "cus_123",
+            "nick_name": "card",
+            "card_number_masked": "424242XXXXXX4242",
+            "card_expiry_month": "12",
+            "card_expiry_year": "2040",
+            "card_scheme": card_scheme,
+            "billing_address_zip": "94122",
+            "billing_address_state": "California",
+            "billing_address_first_name": "John",
+            "billing_address_last_name": "Doe",
+            "billing_address_city": "San Francisco",
+            "billing_address_line1": "1467 Harrison Street",
+            "raw_card_number": raw_card_number,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate_payment_method_record() {
+        assert_eq!(
+            validate_payment_method_record(&get_record(Some("4242 4242 4242 4242"), Some("VISA"))),
+            Ok("4242424242424242".to_string())
+        );
+        assert!(
+            validate_payment_method_record(&get_record(Some("4242424242424241"), None)).is_err()
+        );
+        assert!(validate_payment_method_record(&get_record(
+            Some("4242424242424242"),
+            Some("Unknown")
+        ))
+        .is_err());
+        assert_eq!(
+            validate_payment_method_record(&get_record(None, None)),
+            Ok("424242XXXXXX4242|12|2040|".to_string())
+        );
+    }
+
+    #[test]
+    #[allow(clippy::panic)]
+    fn test_row_checkpoint_serialization() {
+        let in_progress = RowCheckpoint::InProgress
+            .encode_to_string_of_json()
+            .unwrap();
+        assert_eq!(in_progress, r#"{"state":"in_progress"}"#);
+        assert!(matches!(
+            in_progress.parse_struct("RowCheckpoint").unwrap(),
+            RowCheckpoint::InProgress
+        ));
+
+        let mut result = get_row_result(
+            &get_record(None, None),
+            pm_api::PaymentMethodMigrationRowStatus::Migrated,
+        );
+        result.payment_method_id = Some("pm_123".to_string());
+        let completed = RowCheckpoint::Completed(result)
+            .encode_to_string_of_json()
+            .unwrap();
+        let RowCheckpoint::Completed(result) = completed.parse_struct("RowCheckpoint").unwrap()
+        else {
+            panic!("Expected a completed row checkpoint");
+        };
+        assert_eq!(result.payment_method_id.as_deref(), Some("pm_123"));
+        assert_eq!(
+            result.status,
+            pm_api::PaymentMethodMigrationRowStatus::Migrated
+        );
+    }
+
+    #[test]
+    fn test_saved_card_details_of_record() {
+        let saved_card = SavedCardDetails::new("4242424242424242", "12", "40");
+
+        assert!(saved_card.is_some());
+        assert_eq!(
+            SavedCardDetails::from_record(&get_record(Some("4242 4242 4242 4242"), None)),
+            saved_card
+        );
+        assert_eq!(
+            SavedCardDetails::from_record(&get_record(None, None)),
+            saved_card
+        );
+        assert_ne!(
+            SavedCardDetails::new("4242424242424242", "11", "2040"),
+            saved_card
+        );
+    }
+}
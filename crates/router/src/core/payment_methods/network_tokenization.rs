@@ -1,3 +1,6 @@
+#[cfg(feature = "v1")]
+pub mod lifecycle;
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use std::fmt::Debug;
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
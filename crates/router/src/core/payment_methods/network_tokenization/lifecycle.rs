@@ -0,0 +1,424 @@
+use ::payment_methods::controller::PaymentMethodsController;
+use actix_web::http::header::HeaderMap;
+use api_models::payment_methods as api_payment_methods;
+use common_utils::{
+    crypto::{self, OptionalEncryptableValue, VerifySignature},
+    ext_traits::BytesExt,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        webhooks,
+    },
+    headers,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain, payment_methods as pm_types,
+        storage::{self, enums},
+    },
+};
+
+/// Verifies the HMAC-SHA512 signature of a notification sent by the network tokenization service
+fn verify_notification_signature(
+    state: &SessionState,
+    request_headers: &HeaderMap,
+    body: &[u8],
+) -> RouterResult<()> {
+    let webhook_secret = state
+        .conf
+        .network_tokenization_service
+        .as_ref()
+        .and_then(|service| service.get_inner().webhook_secret.clone())
+        .ok_or(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Webhook secret of the network tokenization service is not configured")?;
+
+    let signature = request_headers
+        .get(headers::X_WEBHOOK_SIGNATURE)
+        .and_then(|value| value.to_str().ok())
+        .map(hex::decode)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Failed to decode the network token webhook signature")?
+        .ok_or(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Missing the network token webhook signature")?;
+
+    let is_verified = crypto::HmacSha512
+        .verify_signature(webhook_secret.peek().as_bytes(), &signature, body)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    if is_verified {
+        Ok(())
+    } else {
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network token webhook signature verification failed")
+    }
+}
+
+fn get_payment_methods_data(data: &OptionalEncryptableValue) -> Option<domain::PaymentMethodsData> {
+    data.clone()
+        .map(|value| value.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<domain::PaymentMethodsData>(value).ok())
+}
+
+/// Applies the new last four digits and expiry to the card or network token details stored
+/// against the payment method, any other payment method data is left untouched
+fn apply_updated_details(
+    payment_method_data: domain::PaymentMethodsData,
+    last_four: Option<String>,
+    expiry_month: Option<Secret<String>>,
+    expiry_year: Option<Secret<String>>,
+) -> domain::PaymentMethodsData {
+    match payment_method_data {
+        domain::PaymentMethodsData::Card(card) => {
+            domain::PaymentMethodsData::Card(domain::CardDetailsPaymentMethod {
+                last4_digits: last_four.or(card.last4_digits),
+                expiry_month: expiry_month.or(card.expiry_month),
+                expiry_year: expiry_year.or(card.expiry_year),
+                ..card
+            })
+        }
+        domain::PaymentMethodsData::NetworkToken(token) => {
+            domain::PaymentMethodsData::NetworkToken(domain::NetworkTokenDetailsPaymentMethod {
+                last4_digits: last_four.or(token.last4_digits),
+                network_token_expiry_month: expiry_month.or(token.network_token_expiry_month),
+                network_token_expiry_year: expiry_year.or(token.network_token_expiry_year),
+                ..token
+            })
+        }
+        data @ (domain::PaymentMethodsData::BankDetails(_)
+        | domain::PaymentMethodsData::WalletDetails(_)) => data,
+    }
+}
+
+async fn get_card_updated_payment_method_update(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+    token_details: pm_types::UpdatedNetworkTokenDetails,
+) -> RouterResult<storage::PaymentMethodUpdate> {
+    let key_manager_state = &state.into();
+
+    let payment_method_data =
+        get_payment_methods_data(&payment_method.payment_method_data).map(|data| {
+            apply_updated_details(
+                data,
+                token_details.card_last_four,
+                token_details.card_expiry_month,
+                token_details.card_expiry_year,
+            )
+        });
+    let network_token_payment_method_data =
+        get_payment_methods_data(&payment_method.network_token_payment_method_data).map(|data| {
+            apply_updated_details(
+                data,
+                token_details.token_last_four,
+                token_details.token_expiry_month,
+                token_details.token_expiry_year,
+            )
+        });
+
+    let payment_method_data_encrypted = match payment_method_data {
+        Some(data) => Some(
+            cards::create_encrypted_data(key_manager_state, key_store, data)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to encrypt payment method data")?,
+        ),
+        None => None,
+    };
+    let network_token_payment_method_data_encrypted = match network_token_payment_method_data {
+        Some(data) => Some(
+            cards::create_encrypted_data(key_manager_state, key_store, data)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to encrypt network token payment method data")?,
+        ),
+        None => None,
+    };
+
+    Ok(storage::PaymentMethodUpdate::AdditionalDataUpdate {
+        payment_method_data: payment_method_data_encrypted.map(Into::into),
+        status: None,
+        locker_id: None,
+        payment_method: None,
+        payment_method_type: None,
+        payment_method_issuer: None,
+        network_token_requestor_reference_id: None,
+        network_token_locker_id: None,
+        network_token_payment_method_data: network_token_payment_method_data_encrypted
+            .map(Into::into),
+    })
+}
+
+/// Marks the network token of the payment method as suspended or resumed. The payment method
+/// stays active, payments use the card while the token is suspended.
+async fn get_token_suspension_payment_method_update(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+    is_suspended: bool,
+) -> RouterResult<storage::PaymentMethodUpdate> {
+    let network_token_details =
+        get_payment_methods_data(&payment_method.network_token_payment_method_data)
+            .and_then(|data| match data {
+                domain::PaymentMethodsData::NetworkToken(token) => Some(token),
+                domain::PaymentMethodsData::Card(_)
+                | domain::PaymentMethodsData::BankDetails(_)
+                | domain::PaymentMethodsData::WalletDetails(_) => None,
+            })
+            .ok_or(errors::ApiErrorResponse::WebhookUnprocessableEntity)
+            .attach_printable("Network token details not found for the payment method")?;
+
+    let network_token_payment_method_data = cards::create_encrypted_data(
+        &state.into(),
+        key_store,
+        domain::PaymentMethodsData::NetworkToken(domain::NetworkTokenDetailsPaymentMethod {
+            is_suspended,
+            ..network_token_details
+        }),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt network token payment method data")?;
+
+    Ok(storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+        network_token_requestor_reference_id: None,
+        network_token_locker_id: None,
+        network_token_payment_method_data: Some(network_token_payment_method_data.into()),
+    })
+}
+
+/// Builds the payment method details sent in outgoing webhooks from the stored payment method,
+/// the card is never fetched from the locker so that the card number is not sent to the merchant
+pub fn get_payment_method_webhook_response(
+    payment_method: domain::PaymentMethod,
+) -> api::PaymentMethodResponse {
+    let card = payment_method
+        .payment_method_data
+        .clone()
+        .map(|value| value.into_inner().expose())
+        .and_then(|value| {
+            serde_json::from_value::<api_payment_methods::PaymentMethodsData>(value).ok()
+        })
+        .and_then(|data| match data {
+            api_payment_methods::PaymentMethodsData::Card(card) => {
+                Some(api::CardDetailFromLocker::from(card))
+            }
+            _ => None,
+        })
+        .map(|mut card| {
+            card.scheme.clone_from(&payment_method.scheme);
+            card
+        });
+
+    api::PaymentMethodResponse {
+        merchant_id: payment_method.merchant_id.clone(),
+        customer_id: Some(payment_method.customer_id.clone()),
+        payment_method_id: payment_method.payment_method_id.clone(),
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: payment_method.metadata,
+        created: Some(payment_method.created_at),
+        recurring_enabled: false,
+        installment_payment_enabled: false,
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+        last_used_at: Some(payment_method.last_used_at),
+        client_secret: payment_method.client_secret,
+    }
+}
+
+/// Sends the payment method updated webhook through the default profile of the merchant, since
+/// payment methods are not tied to a profile
+async fn trigger_payment_method_updated_webhook(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+) -> RouterResult<()> {
+    let Some(profile_id) = merchant_context
+        .get_merchant_account()
+        .default_profile
+        .clone()
+    else {
+        logger::info!("Skipping payment method webhook as the merchant has no default profile");
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let payment_method_id = payment_method.payment_method_id.clone();
+    let created_at = payment_method.created_at;
+    let content = api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+        get_payment_method_webhook_response(payment_method),
+    ));
+
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_context,
+        business_profile,
+        enums::EventType::PaymentMethodUpdated,
+        enums::EventClass::PaymentMethods,
+        payment_method_id,
+        enums::EventObjectType::PaymentMethodDetails,
+        content,
+        Some(created_at),
+    ))
+    .await
+}
+
+/// Consumes a token lifecycle notification from the network tokenization service and keeps the
+/// payment method the token belongs to in sync with it
+#[instrument(skip_all)]
+pub async fn handle_network_token_lifecycle_notification(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request_headers: &HeaderMap,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<()> {
+    verify_notification_signature(&state, request_headers, &body)?;
+
+    let notification: pm_types::NetworkTokenLifecycleNotification = body
+        .parse_struct("NetworkTokenLifecycleNotification")
+        .change_context(errors::ApiErrorResponse::WebhookUnprocessableEntity)?;
+    logger::info!(event_type = %notification.event_type, "Received network token lifecycle notification");
+
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_method = db
+        .find_payment_method_by_network_token_requestor_reference_id(
+            key_manager_state,
+            key_store,
+            merchant_context.get_merchant_account().get_id(),
+            &notification.card_reference,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+        .attach_printable("No payment method found for the network token reference")?;
+    if payment_method.customer_id != notification.customer_id {
+        return Err(report!(errors::ApiErrorResponse::WebhookResourceNotFound))
+            .attach_printable("Network token reference belongs to a different customer");
+    }
+
+    let payment_method_update = match notification.event_type {
+        pm_types::NetworkTokenLifecycleEvent::CardUpdated => {
+            let token_details = notification
+                .token_details
+                .ok_or(errors::ApiErrorResponse::WebhookBadRequest)
+                .attach_printable("Missing token details in the card updated notification")?;
+            get_card_updated_payment_method_update(
+                &state,
+                key_store,
+                &payment_method,
+                token_details,
+            )
+            .await?
+        }
+        pm_types::NetworkTokenLifecycleEvent::TokenSuspended => {
+            get_token_suspension_payment_method_update(&state, key_store, &payment_method, true)
+                .await?
+        }
+        pm_types::NetworkTokenLifecycleEvent::TokenResumed => {
+            get_token_suspension_payment_method_update(&state, key_store, &payment_method, false)
+                .await?
+        }
+        pm_types::NetworkTokenLifecycleEvent::TokenDeleted => {
+            // The token no longer exists with the network, failing to clean up the locker must
+            // not block the token from being unlinked from the payment method
+            if let Some(network_token_locker_id) = &payment_method.network_token_locker_id {
+                cards::PmCards {
+                    state: &state,
+                    merchant_context: &merchant_context,
+                }
+                .delete_card_from_locker(
+                    &payment_method.customer_id,
+                    &payment_method.merchant_id,
+                    network_token_locker_id,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to delete network token from locker")
+                })
+                .ok();
+            }
+            storage::PaymentMethodUpdate::NetworkTokenDataClear
+        }
+    };
+
+    let updated_payment_method = db
+        .update_payment_method(
+            key_manager_state,
+            key_store,
+            payment_method,
+            payment_method_update,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    trigger_payment_method_updated_webhook(state, merchant_context, updated_payment_method).await?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_updated_details_keeps_missing_fields() {
+        let token = domain::NetworkTokenDetailsPaymentMethod {
+            last4_digits: Some("4242".to_string()),
+            issuer_country: None,
+            network_token_expiry_month: Some(Secret::new("01".to_string())),
+            network_token_expiry_year: Some(Secret::new("2026".to_string())),
+            nick_name: None,
+            card_holder_name: None,
+            card_isin: None,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            saved_to_locker: true,
+            is_suspended: false,
+        };
+
+        let updated = apply_updated_details(
+            domain::PaymentMethodsData::NetworkToken(token.clone()),
+            None,
+            Some(Secret::new("12".to_string())),
+            Some(Secret::new("2030".to_string())),
+        );
+
+        assert_eq!(
+            updated,
+            domain::PaymentMethodsData::NetworkToken(domain::NetworkTokenDetailsPaymentMethod {
+                network_token_expiry_month: Some(Secret::new("12".to_string())),
+                network_token_expiry_year: Some(Secret::new("2030".to_string())),
+                ..token
+            })
+        );
+    }
+}
@@ -458,13 +458,15 @@ fn get_surcharge_details_from_surcharge_output(
     surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     payment_attempt: &storage::PaymentAttempt,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
-    let surcharge_amount = match surcharge_details.surcharge.clone() {
-        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => amount,
-        surcharge_decision_configs::SurchargeOutput::Rate(percentage) => percentage
-            .apply_and_ceil_result(payment_attempt.net_amount.get_total_amount())
-            .change_context(ConfigError::DslExecutionError)
-            .attach_printable("Failed to Calculate surcharge amount by applying percentage")?,
-    };
+    let calculated_surcharge_amount = calculate_surcharge_amount(
+        &surcharge_details.surcharge,
+        payment_attempt.net_amount.get_total_amount(),
+    )?;
+    let surcharge_amount = apply_surcharge_bounds(
+        calculated_surcharge_amount,
+        surcharge_details.min_surcharge_amount,
+        surcharge_details.max_surcharge_amount,
+    );
     let tax_on_surcharge_amount = surcharge_details
         .tax_on_surcharge
         .clone()
@@ -478,13 +480,20 @@ fn get_surcharge_details_from_surcharge_output(
         .unwrap_or_default();
     Ok(types::SurchargeDetails {
         original_amount: payment_attempt.net_amount.get_order_amount(),
+        // Surcharges which are banded, combined or bounded are represented by the
+        // resulting amount, as that is what gets applied to the payment
         surcharge: match surcharge_details.surcharge {
-            surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
+            surcharge_decision_configs::SurchargeOutput::Fixed { amount }
+                if amount == surcharge_amount =>
+            {
                 common_utils_types::Surcharge::Fixed(amount)
             }
-            surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
+            surcharge_decision_configs::SurchargeOutput::Rate(percentage)
+                if calculated_surcharge_amount == Some(surcharge_amount) =>
+            {
                 common_utils_types::Surcharge::Rate(percentage)
             }
+            _ => common_utils_types::Surcharge::Fixed(surcharge_amount),
         },
         tax_on_surcharge: surcharge_details.tax_on_surcharge,
         surcharge_amount,
@@ -492,6 +501,71 @@ fn get_surcharge_details_from_surcharge_output(
     })
 }
 
+/// Returns `None` when no surcharge applies to the payment amount, which is the case when the
+/// amount does not fall in any of the surcharge tiers
+#[cfg(feature = "v1")]
+fn calculate_surcharge_amount(
+    surcharge: &surcharge_decision_configs::SurchargeOutput,
+    payment_amount: common_utils_types::MinorUnit,
+) -> ConditionalConfigResult<Option<common_utils_types::MinorUnit>> {
+    let apply_percentage = |percentage: &common_utils_types::Percentage<
+        { common_utils::consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH },
+    >| {
+        percentage
+            .apply_and_ceil_result(payment_amount)
+            .change_context(ConfigError::DslExecutionError)
+            .attach_printable("Failed to Calculate surcharge amount by applying percentage")
+    };
+
+    match surcharge {
+        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => Ok(Some(*amount)),
+        surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
+            apply_percentage(percentage).map(Some)
+        }
+        surcharge_decision_configs::SurchargeOutput::FixedAndRate { amount, percentage } => {
+            Ok(Some(*amount + apply_percentage(percentage)?))
+        }
+        surcharge_decision_configs::SurchargeOutput::Tiered(tiers) => {
+            let Some(tier) = tiers.iter().find(|tier| tier.contains(payment_amount)) else {
+                logger::debug!("Payment amount does not fall in any of the surcharge tiers");
+                return Ok(None);
+            };
+            let percentage_amount = tier
+                .percentage
+                .as_ref()
+                .map(apply_percentage)
+                .transpose()?
+                .unwrap_or_default();
+            Ok(Some(tier.amount.unwrap_or_default() + percentage_amount))
+        }
+    }
+}
+
+/// The bounds only apply to a calculated surcharge, a payment which is not surcharged is not
+/// raised to the minimum surcharge amount
+#[cfg(feature = "v1")]
+fn apply_surcharge_bounds(
+    surcharge_amount: Option<common_utils_types::MinorUnit>,
+    min_surcharge_amount: Option<common_utils_types::MinorUnit>,
+    max_surcharge_amount: Option<common_utils_types::MinorUnit>,
+) -> common_utils_types::MinorUnit {
+    let Some(surcharge_amount) = surcharge_amount else {
+        return common_utils_types::MinorUnit::zero();
+    };
+    let surcharge_amount = match min_surcharge_amount {
+        Some(min_surcharge_amount) if surcharge_amount < min_surcharge_amount => {
+            min_surcharge_amount
+        }
+        _ => surcharge_amount,
+    };
+    match max_surcharge_amount {
+        Some(max_surcharge_amount) if surcharge_amount > max_surcharge_amount => {
+            max_surcharge_amount
+        }
+        _ => surcharge_amount,
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn ensure_algorithm_cached(
     store: &dyn StorageInterface,
@@ -533,3 +607,78 @@ pub fn execute_dsl_and_get_conditional_config(
         .change_context(ConfigError::DslExecutionError)?;
     Ok(routing_output)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn tiered_surcharge() -> surcharge_decision_configs::SurchargeOutput {
+        surcharge_decision_configs::SurchargeOutput::Tiered(vec![
+            surcharge_decision_configs::SurchargeTier {
+                min_amount: common_utils_types::MinorUnit::new(1000),
+                max_amount: Some(common_utils_types::MinorUnit::new(5000)),
+                amount: Some(common_utils_types::MinorUnit::new(20)),
+                percentage: None,
+            },
+            surcharge_decision_configs::SurchargeTier {
+                min_amount: common_utils_types::MinorUnit::new(5000),
+                max_amount: None,
+                amount: Some(common_utils_types::MinorUnit::new(500)),
+                percentage: None,
+            },
+        ])
+    }
+
+    #[test]
+    fn test_payment_amount_outside_tiers_is_not_surcharged() {
+        let calculated_surcharge_amount = calculate_surcharge_amount(
+            &tiered_surcharge(),
+            common_utils_types::MinorUnit::new(500),
+        )
+        .unwrap();
+        assert_eq!(calculated_surcharge_amount, None);
+
+        // The minimum surcharge amount does not apply to a payment which is not surcharged
+        let surcharge_amount = apply_surcharge_bounds(
+            calculated_surcharge_amount,
+            Some(common_utils_types::MinorUnit::new(50)),
+            Some(common_utils_types::MinorUnit::new(200)),
+        );
+        assert_eq!(surcharge_amount, common_utils_types::MinorUnit::zero());
+    }
+
+    #[test]
+    fn test_tiered_surcharge_is_bounded() {
+        let min_surcharge_amount = Some(common_utils_types::MinorUnit::new(50));
+        let max_surcharge_amount = Some(common_utils_types::MinorUnit::new(200));
+
+        let lower_tier_surcharge = calculate_surcharge_amount(
+            &tiered_surcharge(),
+            common_utils_types::MinorUnit::new(2000),
+        )
+        .unwrap();
+        assert_eq!(
+            apply_surcharge_bounds(
+                lower_tier_surcharge,
+                min_surcharge_amount,
+                max_surcharge_amount
+            ),
+            common_utils_types::MinorUnit::new(50)
+        );
+
+        let upper_tier_surcharge = calculate_surcharge_amount(
+            &tiered_surcharge(),
+            common_utils_types::MinorUnit::new(10000),
+        )
+        .unwrap();
+        assert_eq!(
+            apply_surcharge_bounds(
+                upper_tier_surcharge,
+                min_surcharge_amount,
+                max_surcharge_amount
+            ),
+            common_utils_types::MinorUnit::new(200)
+        );
+    }
+}
@@ -1016,6 +1016,28 @@ impl Vault {
     }
 }
 
+/// Retrieves the card of a saved payment method from the card vault
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all)]
+pub async fn retrieve_card_from_vault(
+    state: &routes::SessionState,
+    pm: &domain::PaymentMethod,
+) -> RouterResult<api_models::payment_methods::Card> {
+    let locker_id = pm
+        .locker_id
+        .as_deref()
+        .ok_or(errors::VaultError::MissingRequiredField {
+            field_name: "locker_id",
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing locker_id of the payment method")?;
+
+    super::cards::get_card_from_locker(state, &pm.customer_id, &pm.merchant_id, locker_id).await
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 
 #[inline(always)]
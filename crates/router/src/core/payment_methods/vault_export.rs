@@ -0,0 +1,772 @@
+//! Export of the cards saved in the locker, for moving them to another provider.
+//!
+//! An export is always held back for approval by a second user. Once approved, the card vault
+//! export process tracker workflow pages through the active payment methods of the merchant,
+//! reads the cards from the vault and writes the cards of each page to a separate file in file
+//! storage (`payment_methods/vault_exports/{merchant_id}/{export_id}`). Every file is a compact
+//! JWE encrypted with the public key supplied by the recipient, the format of the files is
+//! described by [`pm_api::CardVaultExportManifest`]. The manifest is written once all the
+//! payment methods have been exported. Every file written and downloaded is audit logged. Only
+//! the users who requested and approved an export can retrieve it and download its files.
+
+use api_models::payment_methods as pm_api;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::{Encode, ValueExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use josekit::jwe;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        approvals,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::vault,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::{self, authentication as auth, ApplicationResponse},
+    types::{domain, storage},
+};
+
+const CARD_VAULT_EXPORT_FILES_PREFIX: &str = "payment_methods/vault_exports";
+const CARD_VAULT_EXPORT_MANIFEST_FILE_NAME: &str = "manifest.json";
+const CARD_VAULT_EXPORT_FORMAT_VERSION: u8 = 1;
+pub const CARD_VAULT_EXPORT_TASK: &str = "CARD_VAULT_EXPORT";
+pub const CARD_VAULT_EXPORT_TAG: &str = "PAYMENT_METHOD";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardVaultExportTrackingData {
+    pub export_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub approval_request_id: String,
+    pub recipient_public_key: masking::Secret<String>,
+    pub recipient_key_id: Option<String>,
+    /// The `(created_at, payment_method_id)` key of the last active payment method of the merchant
+    /// paged through so far
+    pub last_exported_key: Option<(time::PrimitiveDateTime, String)>,
+    pub processed_payment_methods: u32,
+    pub exported_cards: u32,
+    pub failed_payment_methods: u32,
+    pub files: Vec<pm_api::CardVaultExportFile>,
+}
+
+fn get_export_file_key(
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+    file_name: &str,
+) -> String {
+    format!(
+        "{CARD_VAULT_EXPORT_FILES_PREFIX}/{}/{export_id}/{file_name}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_part_file_name(file_index: usize) -> String {
+    format!("part-{file_index:05}.jwe")
+}
+
+fn validate_recipient_public_key(request: &pm_api::CardVaultExportRequest) -> RouterResult<()> {
+    jwe::RSA_OAEP_256
+        .encrypter_from_pem(request.recipient_public_key.peek())
+        .map(|_| ())
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "recipient_public_key".to_string(),
+            expected_format: "PEM encoded RSA public key".to_string(),
+        })
+}
+
+/// Raises an approval request for exporting the cards of the merchant, the export is scheduled
+/// only after a second user approves it
+#[instrument(skip_all)]
+pub async fn create_card_vault_export(
+    state: SessionState,
+    req_state: ReqState,
+    user: auth::UserFromToken,
+    request: pm_api::CardVaultExportRequest,
+) -> RouterResponse<pm_api::CardVaultExportResponse> {
+    validate_recipient_public_key(&request)?;
+
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &user.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &user.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let export_id = common_utils::generate_time_ordered_id("vexp");
+    let recipient_key_id = request.recipient_key_id.clone();
+    let approval_request = approvals::raise_approval_request(
+        &state,
+        &req_state,
+        &merchant_account,
+        &key_store,
+        None,
+        user.user_id,
+        approvals::ApprovalPayload::CardVaultExport {
+            export_id: export_id.clone(),
+            request,
+        },
+    )
+    .await?;
+
+    logger::info!(%export_id, "Raised approval request for card vault export");
+
+    Ok(ApplicationResponse::Json(pm_api::CardVaultExportResponse {
+        export_id,
+        merchant_id: user.merchant_id,
+        status: pm_api::CardVaultExportStatus::PendingApproval,
+        approval_request_id: approval_request.approval_request_id,
+        recipient_key_id,
+        processed_payment_methods: 0,
+        exported_cards: 0,
+        failed_payment_methods: 0,
+        files: Vec::new(),
+    }))
+}
+
+/// Schedules an approved card vault export
+#[instrument(skip(state, request))]
+pub async fn schedule_card_vault_export(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    approval_request_id: &str,
+    export_id: String,
+    request: pm_api::CardVaultExportRequest,
+) -> RouterResponse<pm_api::CardVaultExportResponse> {
+    let tracking_data = CardVaultExportTrackingData {
+        export_id: export_id.clone(),
+        merchant_id: merchant_id.clone(),
+        approval_request_id: approval_request_id.to_string(),
+        recipient_public_key: request.recipient_public_key,
+        recipient_key_id: request.recipient_key_id,
+        last_exported_key: None,
+        processed_payment_methods: 0,
+        exported_cards: 0,
+        failed_payment_methods: 0,
+        files: Vec::new(),
+    };
+
+    let runner = storage::ProcessTrackerRunner::CardVaultExportWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        CARD_VAULT_EXPORT_TASK,
+        &export_id,
+        merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CARD_VAULT_EXPORT_TASK,
+        runner,
+        [CARD_VAULT_EXPORT_TAG],
+        &tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct card vault export process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "CardVaultExportCreate"));
+    let process_tracker = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes))
+        .inspect_err(|_| crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert card vault export process tracker task")?;
+
+    logger::info!(%export_id, "Scheduled card vault export");
+
+    Ok(ApplicationResponse::Json(get_card_vault_export_response(
+        &process_tracker,
+        tracking_data,
+    )))
+}
+
+/// Finds the export of the merchant of the user, provided that the user requested or approved it
+async fn find_card_vault_export(
+    state: &SessionState,
+    user: &auth::UserFromToken,
+    export_id: &str,
+) -> RouterResult<(storage::ProcessTracker, CardVaultExportTrackingData)> {
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::CardVaultExportWorkflow,
+        CARD_VAULT_EXPORT_TASK,
+        export_id,
+        &user.merchant_id,
+    );
+
+    let process_tracker = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find card vault export process tracker task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Card vault export `{export_id}` not found or not approved yet"),
+        })?;
+
+    let tracking_data: CardVaultExportTrackingData = process_tracker
+        .tracking_data
+        .clone()
+        .parse_value("CardVaultExportTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse card vault export tracking data")?;
+
+    let approval_request = state
+        .store
+        .find_approval_request_by_merchant_id_approval_request_id(
+            &user.merchant_id,
+            &tracking_data.approval_request_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the approval request of the card vault export")?;
+    let is_requester_or_reviewer = approval_request.requested_by == user.user_id
+        || approval_request.reviewed_by.as_deref() == Some(user.user_id.as_str());
+    if !is_requester_or_reviewer {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "card_vault_export".to_string(),
+        }));
+    }
+
+    Ok((process_tracker, tracking_data))
+}
+
+#[instrument(skip(state, user))]
+pub async fn retrieve_card_vault_export(
+    state: SessionState,
+    user: auth::UserFromToken,
+    export_id: String,
+) -> RouterResponse<pm_api::CardVaultExportResponse> {
+    let (process_tracker, tracking_data) =
+        find_card_vault_export(&state, &user, &export_id).await?;
+
+    Ok(ApplicationResponse::Json(get_card_vault_export_response(
+        &process_tracker,
+        tracking_data,
+    )))
+}
+
+/// Downloads a file of a completed card vault export, either the manifest or one of the
+/// encrypted files listed in it
+#[instrument(skip(state, req_state, user))]
+pub async fn download_card_vault_export_file(
+    state: SessionState,
+    req_state: ReqState,
+    user: auth::UserFromToken,
+    export_id: String,
+    file_name: String,
+) -> RouterResponse<Vec<u8>> {
+    let (process_tracker, tracking_data) =
+        find_card_vault_export(&state, &user, &export_id).await?;
+
+    if get_card_vault_export_status(&process_tracker, &tracking_data)
+        != pm_api::CardVaultExportStatus::Completed
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Card vault export files can be downloaded once it is completed".to_string(),
+        }));
+    }
+
+    let mime_type = if file_name == CARD_VAULT_EXPORT_MANIFEST_FILE_NAME {
+        mime::APPLICATION_JSON
+    } else if tracking_data
+        .files
+        .iter()
+        .any(|file| file.file_name == file_name)
+    {
+        mime::APPLICATION_OCTET_STREAM
+    } else {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("File `{file_name}` not found in card vault export `{export_id}`"),
+        }));
+    };
+
+    let file_key = get_export_file_key(&user.merchant_id, &export_id, &file_name);
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to retrieve file `{file_key}`"))?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::CardVaultExportFileDownloaded {
+                export_id,
+                merchant_id: user.merchant_id,
+                file_name,
+                downloaded_by: user.user_id,
+            },
+        ))
+        .emit();
+
+    Ok(ApplicationResponse::FileData((file_data, mime_type)))
+}
+
+fn get_card_vault_export_status(
+    process_tracker: &storage::ProcessTracker,
+    tracking_data: &CardVaultExportTrackingData,
+) -> pm_api::CardVaultExportStatus {
+    match process_tracker.status {
+        storage::enums::ProcessTrackerStatus::Finish
+            if process_tracker.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            pm_api::CardVaultExportStatus::Completed
+        }
+        storage::enums::ProcessTrackerStatus::Finish => pm_api::CardVaultExportStatus::Failed,
+        _ if tracking_data.last_exported_key.is_none() => pm_api::CardVaultExportStatus::Scheduled,
+        _ => pm_api::CardVaultExportStatus::InProgress,
+    }
+}
+
+fn get_card_vault_export_response(
+    process_tracker: &storage::ProcessTracker,
+    tracking_data: CardVaultExportTrackingData,
+) -> pm_api::CardVaultExportResponse {
+    pm_api::CardVaultExportResponse {
+        status: get_card_vault_export_status(process_tracker, &tracking_data),
+        export_id: tracking_data.export_id,
+        merchant_id: tracking_data.merchant_id,
+        approval_request_id: tracking_data.approval_request_id,
+        recipient_key_id: tracking_data.recipient_key_id,
+        processed_payment_methods: tracking_data.processed_payment_methods,
+        exported_cards: tracking_data.exported_cards,
+        failed_payment_methods: tracking_data.failed_payment_methods,
+        files: tracking_data.files,
+    }
+}
+
+async fn read_card_from_vault(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+) -> Result<pm_api::CardVaultExportRecord, pm_api::CardVaultExportFailure> {
+    let to_failure = |error_message: &str| pm_api::CardVaultExportFailure {
+        payment_method_id: payment_method.payment_method_id.clone(),
+        customer_id: payment_method.customer_id.clone(),
+        error_message: error_message.to_string(),
+    };
+
+    if payment_method.locker_id.is_none() {
+        return Err(to_failure("Card is not saved in the vault"));
+    }
+
+    let card = vault::retrieve_card_from_vault(state, payment_method)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to read card from the vault for card vault export"
+            );
+            to_failure("Failed to read card from the vault")
+        })?;
+
+    Ok(pm_api::CardVaultExportRecord {
+        payment_method_id: payment_method.payment_method_id.clone(),
+        customer_id: payment_method.customer_id.clone(),
+        card_number: card.card_number,
+        card_exp_month: card.card_exp_month,
+        card_exp_year: card.card_exp_year,
+        card_holder_name: card.name_on_card,
+        card_network: card.card_brand.or_else(|| payment_method.scheme.clone()),
+        card_isin: card.card_isin,
+        nick_name: card.nick_name,
+        network_transaction_id: payment_method.network_transaction_id.clone(),
+        created_at: payment_method.created_at,
+    })
+}
+
+/// Encrypts the contents with the recipient public key and writes them to file storage, returning
+/// the hex encoded SHA-256 digest of the encrypted file
+async fn write_encrypted_file(
+    state: &SessionState,
+    tracking_data: &CardVaultExportTrackingData,
+    file_name: &str,
+    contents: &pm_api::CardVaultExportFileContents,
+) -> RouterResult<String> {
+    let serialized_contents = contents
+        .encode_to_vec()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize card vault export file")?;
+    let encrypted_contents = services::encrypt_jwe(
+        &serialized_contents,
+        tracking_data.recipient_public_key.peek(),
+        services::EncryptionAlgorithm::A256GCM,
+        tracking_data.recipient_key_id.as_deref(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt card vault export file")?
+    .into_bytes();
+
+    let digest = crypto::Sha256
+        .generate_digest(&encrypted_contents)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate digest of card vault export file")?;
+
+    let file_key = get_export_file_key(
+        &tracking_data.merchant_id,
+        &tracking_data.export_id,
+        file_name,
+    );
+    state
+        .file_storage_client
+        .upload_file(&file_key, encrypted_contents)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to upload file `{file_key}`"))?;
+
+    Ok(hex::encode(digest))
+}
+
+fn get_file_contents(
+    results: Vec<Result<pm_api::CardVaultExportRecord, pm_api::CardVaultExportFailure>>,
+) -> pm_api::CardVaultExportFileContents {
+    let (records, failures): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+
+    pm_api::CardVaultExportFileContents {
+        records: records.into_iter().filter_map(Result::ok).collect(),
+        failures: failures.into_iter().filter_map(Result::err).collect(),
+    }
+}
+
+fn get_export_file(
+    file_name: String,
+    contents: &pm_api::CardVaultExportFileContents,
+    sha256: String,
+) -> RouterResult<pm_api::CardVaultExportFile> {
+    Ok(pm_api::CardVaultExportFile {
+        file_name,
+        record_count: u32::try_from(contents.records.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        failure_count: u32::try_from(contents.failures.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        sha256,
+    })
+}
+
+/// Adds a written file to the tracking data, along with the counts of the payment methods in it
+fn add_export_file(
+    tracking_data: &mut CardVaultExportTrackingData,
+    file: pm_api::CardVaultExportFile,
+) {
+    tracking_data.processed_payment_methods += file.record_count + file.failure_count;
+    tracking_data.exported_cards += file.record_count;
+    tracking_data.failed_payment_methods += file.failure_count;
+    tracking_data.files.push(file);
+}
+
+fn get_manifest(
+    tracking_data: &CardVaultExportTrackingData,
+    completed_at: time::PrimitiveDateTime,
+) -> pm_api::CardVaultExportManifest {
+    pm_api::CardVaultExportManifest {
+        format_version: CARD_VAULT_EXPORT_FORMAT_VERSION,
+        export_id: tracking_data.export_id.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        recipient_key_id: tracking_data.recipient_key_id.clone(),
+        key_management_algorithm: "RSA-OAEP-256".to_string(),
+        content_encryption_algorithm: services::EncryptionAlgorithm::A256GCM.to_string(),
+        exported_cards: tracking_data.exported_cards,
+        failed_payment_methods: tracking_data.failed_payment_methods,
+        files: tracking_data.files.clone(),
+        completed_at,
+    }
+}
+
+async fn write_manifest(
+    state: &SessionState,
+    tracking_data: &CardVaultExportTrackingData,
+) -> RouterResult<()> {
+    let manifest = get_manifest(tracking_data, common_utils::date_time::now());
+    let serialized_manifest = manifest
+        .encode_to_vec()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize card vault export manifest")?;
+
+    let file_key = get_export_file_key(
+        &tracking_data.merchant_id,
+        &tracking_data.export_id,
+        CARD_VAULT_EXPORT_MANIFEST_FILE_NAME,
+    );
+    state
+        .file_storage_client
+        .upload_file(&file_key, serialized_manifest)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to upload file `{file_key}`"))
+}
+
+/// Exports the cards of the next page of active payment methods of the merchant, returning
+/// whether the export is completed.
+///
+/// Payment methods are paged by their `(created_at, payment_method_id)` key, so that payment
+/// methods being saved, deactivated or deleted during the export do not shift the remaining
+/// pages. The key of the last payment method of a page is checkpointed in the tracking data only
+/// after the file of the page is written, a page interrupted by a crash is exported again to the
+/// same file.
+#[instrument(skip_all, fields(export_id = %tracking_data.export_id))]
+pub async fn process_card_vault_export_chunk(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &mut CardVaultExportTrackingData,
+) -> RouterResult<bool> {
+    let export_config = &state.conf.card_vault_export;
+    let chunk_size = export_config.chunk_size.max(1);
+    let req_state = state.get_req_state();
+
+    if tracking_data.last_exported_key.is_none() {
+        req_state
+            .event_context
+            .event(AuditEvent::new(AuditEventType::CardVaultExportStarted {
+                export_id: tracking_data.export_id.clone(),
+                merchant_id: tracking_data.merchant_id.clone(),
+                approval_request_id: tracking_data.approval_request_id.clone(),
+                recipient_key_id: tracking_data.recipient_key_id.clone(),
+            }))
+            .emit();
+    }
+
+    let payment_methods = state
+        .store
+        .find_payment_method_list_by_merchant_id_status_after_key(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            common_enums::PaymentMethodStatus::Active,
+            tracking_data.last_exported_key.clone(),
+            i64::from(chunk_size),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment methods for card vault export")?;
+    let page_size = payment_methods.len();
+
+    let results = futures::stream::iter(
+        payment_methods
+            .iter()
+            .filter(|payment_method| {
+                payment_method.get_payment_method_type() == Some(common_enums::PaymentMethod::Card)
+            })
+            .map(|payment_method| read_card_from_vault(state, payment_method)),
+    )
+    .buffered(usize::from(export_config.locker_read_concurrency.max(1)))
+    .collect::<Vec<_>>()
+    .await;
+
+    if !results.is_empty() {
+        let contents = get_file_contents(results);
+        let file_name = get_part_file_name(tracking_data.files.len());
+        let sha256 = write_encrypted_file(state, tracking_data, &file_name, &contents).await?;
+        let file = get_export_file(file_name, &contents, sha256)?;
+
+        req_state
+            .event_context
+            .event(AuditEvent::new(
+                AuditEventType::CardVaultExportFileWritten {
+                    export_id: tracking_data.export_id.clone(),
+                    merchant_id: tracking_data.merchant_id.clone(),
+                    file_name: file.file_name.clone(),
+                    record_count: file.record_count,
+                    failure_count: file.failure_count,
+                },
+            ))
+            .emit();
+
+        add_export_file(tracking_data, file);
+    }
+
+    if let Some(last_payment_method) = payment_methods.last() {
+        tracking_data.last_exported_key = Some((
+            last_payment_method.created_at,
+            last_payment_method.payment_method_id.clone(),
+        ));
+    }
+
+    if page_size == usize::from(chunk_size) {
+        return Ok(false);
+    }
+
+    write_manifest(state, tracking_data).await?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CardVaultExportCompleted {
+            export_id: tracking_data.export_id.clone(),
+            merchant_id: tracking_data.merchant_id.clone(),
+            exported_cards: tracking_data.exported_cards,
+            failed_payment_methods: tracking_data.failed_payment_methods,
+            file_count: tracking_data.files.len(),
+        }))
+        .emit();
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use std::{borrow::Cow, str::FromStr};
+
+    use super::*;
+
+    fn get_tracking_data() -> CardVaultExportTrackingData {
+        CardVaultExportTrackingData {
+            export_id: "vexp_123".to_string(),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap(),
+            approval_request_id: "apr_123".to_string(),
+            recipient_public_key: masking::Secret::new("public key".to_string()),
+            recipient_key_id: Some("recipient_key_1".to_string()),
+            last_exported_key: None,
+            processed_payment_methods: 0,
+            exported_cards: 0,
+            failed_payment_methods: 0,
+            files: Vec::new(),
+        }
+    }
+
+    fn get_record(payment_method_id: &str) -> pm_api::CardVaultExportRecord {
+        pm_api::CardVaultExportRecord {
+            payment_method_id: payment_method_id.to_string(),
+            customer_id: id_type::CustomerId::try_from(Cow::from("cus_123")).unwrap(),
+            card_number: cards::CardNumber::from_str("4111111111111111").unwrap(),
+            card_exp_month: masking::Secret::new("03".to_string()),
+            card_exp_year: masking::Secret::new("2030".to_string()),
+            card_holder_name: None,
+            card_network: Some("Visa".to_string()),
+            card_isin: None,
+            nick_name: None,
+            network_transaction_id: None,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    fn get_failure(payment_method_id: &str) -> pm_api::CardVaultExportFailure {
+        pm_api::CardVaultExportFailure {
+            payment_method_id: payment_method_id.to_string(),
+            customer_id: id_type::CustomerId::try_from(Cow::from("cus_123")).unwrap(),
+            error_message: "Failed to read card from the vault".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_file_contents_keep_the_order_of_the_page() {
+        let contents = get_file_contents(vec![
+            Ok(get_record("pm_1")),
+            Err(get_failure("pm_2")),
+            Ok(get_record("pm_3")),
+        ]);
+
+        let record_ids = contents
+            .records
+            .iter()
+            .map(|record| record.payment_method_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(record_ids, ["pm_1", "pm_3"]);
+        assert_eq!(contents.failures.len(), 1);
+        assert_eq!(contents.failures[0].payment_method_id, "pm_2");
+    }
+
+    #[test]
+    fn test_export_files_are_counted_in_the_tracking_data() {
+        let mut tracking_data = get_tracking_data();
+        let first_contents =
+            get_file_contents(vec![Ok(get_record("pm_1")), Err(get_failure("pm_2"))]);
+        let second_contents = get_file_contents(vec![Ok(get_record("pm_3"))]);
+
+        let first_file =
+            get_export_file(get_part_file_name(0), &first_contents, "abc".to_string()).unwrap();
+        assert_eq!(first_file.record_count, 1);
+        assert_eq!(first_file.failure_count, 1);
+        add_export_file(&mut tracking_data, first_file);
+        let second_file =
+            get_export_file(get_part_file_name(1), &second_contents, "def".to_string()).unwrap();
+        add_export_file(&mut tracking_data, second_file);
+
+        assert_eq!(tracking_data.processed_payment_methods, 3);
+        assert_eq!(tracking_data.exported_cards, 2);
+        assert_eq!(tracking_data.failed_payment_methods, 1);
+        assert_eq!(tracking_data.files.len(), 2);
+        assert_eq!(tracking_data.files[1].file_name, "part-00001.jwe");
+        assert_eq!(tracking_data.files[1].sha256, "def");
+    }
+
+    #[test]
+    fn test_manifest_lists_the_files_of_the_export() {
+        let mut tracking_data = get_tracking_data();
+        let contents = get_file_contents(vec![Ok(get_record("pm_1")), Err(get_failure("pm_2"))]);
+        let file = get_export_file(get_part_file_name(0), &contents, "abc".to_string()).unwrap();
+        add_export_file(&mut tracking_data, file);
+        let completed_at = common_utils::date_time::now();
+
+        let manifest = get_manifest(&tracking_data, completed_at);
+
+        assert_eq!(manifest.format_version, CARD_VAULT_EXPORT_FORMAT_VERSION);
+        assert_eq!(manifest.export_id, "vexp_123");
+        assert_eq!(
+            manifest.recipient_key_id.as_deref(),
+            Some("recipient_key_1")
+        );
+        assert_eq!(manifest.key_management_algorithm, "RSA-OAEP-256");
+        assert_eq!(manifest.content_encryption_algorithm, "A256GCM");
+        assert_eq!(manifest.exported_cards, 1);
+        assert_eq!(manifest.failed_payment_methods, 1);
+        assert_eq!(manifest.files.len(), 1);
+        assert_eq!(manifest.files[0].file_name, "part-00000.jwe");
+        assert_eq!(manifest.completed_at, completed_at);
+    }
+
+    #[test]
+    fn test_last_exported_key_is_checkpointed_in_tracking_data() {
+        let mut tracking_data = get_tracking_data();
+        let created_at = common_utils::date_time::now();
+        tracking_data.last_exported_key = Some((created_at, "pm_1".to_string()));
+
+        let checkpointed: CardVaultExportTrackingData = tracking_data
+            .encode_to_value()
+            .unwrap()
+            .parse_value("CardVaultExportTrackingData")
+            .unwrap();
+
+        assert_eq!(
+            checkpointed.last_exported_key,
+            Some((created_at, "pm_1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_part_file_names_are_ordered() {
+        assert_eq!(get_part_file_name(0), "part-00000.jwe");
+        assert_eq!(get_part_file_name(42), "part-00042.jwe");
+        assert!(get_part_file_name(9) < get_part_file_name(10));
+    }
+
+    #[test]
+    fn test_recipient_public_key_must_be_rsa_pem() {
+        let request = pm_api::CardVaultExportRequest {
+            recipient_public_key: masking::Secret::new("not a public key".to_string()),
+            recipient_key_id: None,
+        };
+
+        assert!(validate_recipient_public_key(&request).is_err());
+    }
+}
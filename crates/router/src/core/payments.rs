@@ -7288,7 +7288,8 @@ pub fn is_network_token_with_network_transaction_id_flow(
         payment_method_info.network_token_locker_id.is_some(),
         payment_method_info
             .network_token_requestor_reference_id
-            .is_some(),
+            .is_some()
+            && !payment_method_info.is_network_token_suspended(),
     ) {
         (
             Some(true),
@@ -7650,7 +7651,7 @@ pub async fn route_connector_v1_for_payouts(
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
-            api::ConnectorData::get_connector_by_name(
+            api::ConnectorData::get_payout_connector_by_name(
                 &state.conf.connectors,
                 &conn.connector.to_string(),
                 api::GetToken::Connector,
@@ -7867,6 +7868,21 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
         .get_required_value("authentication_connector_details")
         .attach_printable("authentication_connector_details not configured by the merchant")?;
 
+    let force_3ds_challenge = payment_intent.force_3ds_challenge_trigger.unwrap_or(false);
+    let psd2_sca_exemption_type = authentication_core::exemption::decide_sca_exemption(
+        &state,
+        &business_profile,
+        payment_intent.customer_id.as_ref(),
+        &authentication_core::exemption::ExemptionInput::new(
+            amount,
+            currency,
+            &payment_method_details.0,
+            force_3ds_challenge,
+            payment_intent.psd2_sca_exemption_type,
+        ),
+    )
+    .await;
+
     let authentication_response = if helpers::is_merchant_eligible_authentication_service(
         merchant_context.get_merchant_account().get_id(),
         &state,
@@ -7901,6 +7917,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
                 &merchant_connector_account,
                 &authentication_connector,
                 payment_intent.payment_id,
+                psd2_sca_exemption_type,
             )
             .await?;
         let authentication = external_authentication_update_trackers(
@@ -7939,9 +7956,9 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
             optional_customer.and_then(|customer| customer.email.map(pii::Email::from)),
             webhook_url,
             authentication_details.three_ds_requestor_url.clone(),
-            payment_intent.psd2_sca_exemption_type,
+            psd2_sca_exemption_type,
             payment_intent.payment_id,
-            payment_intent.force_3ds_challenge_trigger.unwrap_or(false),
+            force_3ds_challenge,
             merchant_context.get_merchant_key_store(),
         ))
         .await?
@@ -2120,7 +2120,8 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
         should_retry_with_pan,
         payment_method_info
             .network_token_requestor_reference_id
-            .clone(),
+            .clone()
+            .filter(|_| !payment_method_info.is_network_token_suspended()),
     );
 
     let co_badged_card_data = payment_method_info
@@ -151,6 +151,16 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
 
+        // Usage limits of payment links are applied to the payment attempts made through the link
+        if let Some(payment_link_id) = &payment_intent.payment_link_id {
+            crate::core::payment_link::record_payment_link_usage(
+                state,
+                payment_link_id,
+                payment_intent.amount,
+            )
+            .await?;
+        }
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
         // Stage 2
@@ -1087,6 +1097,14 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     != api_models::enums::AuthenticationStatus::Success
                 {
                     *should_continue_confirm_transaction = false;
+                } else {
+                    authentication::exemption::record_successful_authentication(
+                        state,
+                        &business_profile.merchant_id,
+                        payment_data.payment_intent.customer_id.as_ref(),
+                        authentication_store.authentication.is_merchant_whitelisted,
+                    )
+                    .await;
                 }
                 Some(authentication_store)
             }
@@ -1418,6 +1436,14 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     != api_models::enums::AuthenticationStatus::Success
                 {
                     *should_continue_confirm_transaction = false;
+                } else {
+                    authentication::exemption::record_successful_authentication(
+                        state,
+                        &business_profile.merchant_id,
+                        payment_data.payment_intent.customer_id.as_ref(),
+                        authentication_store.authentication.is_merchant_whitelisted,
+                    )
+                    .await;
                 }
             },
                 }
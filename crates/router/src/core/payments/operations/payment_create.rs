@@ -266,6 +266,18 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
                 let default_domain_name = state.base_url.clone();
 
+                let usage_policy = request
+                    .payment_link_config
+                    .as_ref()
+                    .and_then(|config| config.usage_policy.clone());
+                if let Some(usage_policy) = &usage_policy {
+                    usage_policy.validate().map_err(|message| {
+                        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                            message: message.to_string()
+                        })
+                    })?;
+                }
+
                 let (payment_link_config, domain_name) =
                     payment_link::get_payment_link_config_based_on_priority(
                         request.payment_link_config.clone(),
@@ -274,6 +286,10 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                         default_domain_name,
                         request.payment_link_config_id.clone(),
                     )?;
+                let payment_link_config = api_models::admin::PaymentLinkConfig {
+                    usage_policy,
+                    ..payment_link_config
+                };
 
                 create_payment_link(
                     request,
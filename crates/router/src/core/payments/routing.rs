@@ -44,10 +44,11 @@ use masking::{PeekInterface, Secret};
 use rand::distributions::{self, Distribution};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use rand::SeedableRng;
+use redis_interface::RedisKey;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
-use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+use storage_impl::redis::cache::{self, CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
 use utils::perform_decision_euclid_routing;
 
 #[cfg(feature = "v2")]
@@ -59,6 +60,7 @@ use crate::core::routing::transformers::OpenRouterDecideGatewayRequestExt;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::headers;
 use crate::{
+    consts,
     core::{
         errors, errors as oss_errors, payments::routing::utils::DecisionEngineApiHandler, routing,
     },
@@ -173,6 +175,9 @@ pub fn make_dsl_input_for_payouts(
             .as_ref()
             .and_then(|bic| bic.country)
             .map(api_enums::Country::from_alpha2),
+        destination_country: payout_data
+            .get_destination_country()
+            .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
     };
@@ -287,6 +292,7 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: None,
         setup_future_usage: Some(payments_dsl_input.payment_intent.setup_future_usage),
+        destination_country: None,
     };
 
     let metadata = payments_dsl_input
@@ -401,6 +407,7 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
+        destination_country: None,
     };
 
     let metadata = payments_dsl_input
@@ -736,6 +743,17 @@ pub async fn get_merchant_cgraph(
 
     let cgraph = if let Some(graph) = cached_cgraph {
         graph
+    } else if let Some(graph) = get_cgraph_from_redis(state, &key).await {
+        CGRAPH_CACHE
+            .push(
+                CacheKey {
+                    key,
+                    prefix: state.tenant.redis_key_prefix.clone(),
+                },
+                Arc::clone(&graph),
+            )
+            .await;
+        graph
     } else {
         refresh_cgraph_cache(state, key_store, key.clone(), profile_id, transaction_type).await?
     };
@@ -743,6 +761,155 @@ pub async fn get_merchant_cgraph(
     Ok(cgraph)
 }
 
+/// Set the serialized graph of a profile in redis, unless the graph has been invalidated since
+/// the version was read, as the graph may then have been built from outdated accounts.
+///
+/// KEYS: graph key, version key
+/// ARGV: version read before building the graph, serialized graph, ttl
+const SET_CGRAPH_SCRIPT: &str = r#"
+local version = redis.call('GET', KEYS[2]) or '0'
+if version ~= ARGV[1] then
+    return 0
+end
+redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+return 1
+"#;
+
+/// Fetch the serialized graph cached in redis by another instance. The graph is removed from
+/// redis along with the in-memory caches whenever a merchant connector account is updated.
+async fn get_cgraph_from_redis(
+    state: &SessionState,
+    key: &str,
+) -> Option<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    let cached_cgraph = redis_conn
+        .get_and_deserialize_key::<hyperswitch_constraint_graph::SerializedConstraintGraph<
+            euclid_graph::CachedDirValue,
+        >>(&key.into(), "SerializedConstraintGraph")
+        .await
+        .map_err(|error| {
+            if !matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) {
+                logger::error!(?error, "Failed to fetch cgraph from redis");
+            }
+        })
+        .ok()?;
+
+    euclid_graph::from_cached_graph(cached_cgraph)
+        .map_err(|error| logger::error!(?error, "Failed to rebuild cgraph cached in redis"))
+        .ok()
+        .map(Arc::new)
+}
+
+/// Read the version guarding the graph cached in redis, which has to be read before the
+/// merchant connector accounts are fetched to build the graph
+async fn get_cgraph_version(state: &SessionState, key: &str) -> Option<String> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    redis_conn
+        .get_key::<Option<String>>(&cache::get_cache_version_key(key).into())
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch cgraph version from redis"))
+        .ok()
+        .map(|version| version.unwrap_or_else(|| "0".to_string()))
+}
+
+async fn set_cgraph_in_redis(
+    state: &SessionState,
+    key: &str,
+    version: &str,
+    cgraph: &hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>,
+) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let cached_cgraph = match serde_json::to_string(&euclid_graph::to_cached_graph(cgraph)) {
+        Ok(cached_cgraph) => cached_cgraph,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize cgraph");
+            return;
+        }
+    };
+
+    match redis_conn
+        .evaluate_redis_script::<_, i64>(
+            SET_CGRAPH_SCRIPT,
+            vec![
+                RedisKey::from(key).tenant_aware_key(&redis_conn),
+                RedisKey::from(cache::get_cache_version_key(key)).tenant_aware_key(&redis_conn),
+            ],
+            vec![
+                version.to_string(),
+                cached_cgraph,
+                consts::CGRAPH_REDIS_TTL_IN_SECS.to_string(),
+            ],
+        )
+        .await
+    {
+        Ok(0) => logger::debug!("Skipped caching cgraph in redis as it was invalidated"),
+        Ok(_) => {}
+        Err(error) => logger::error!(?error, "Failed to cache cgraph in redis"),
+    }
+}
+
+/// Get the graph of a single merchant connector account, which is only built again when the
+/// account has been modified since the graph was last built
+async fn get_mca_cgraph(
+    state: &SessionState,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    config_pm_filters: &CountryCurrencyFilter,
+) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+    let cache_key = CacheKey {
+        key: format!(
+            "cgraph_mca_{}_{}",
+            merchant_connector_account.get_id().get_string_repr(),
+            merchant_connector_account
+                .modified_at
+                .assume_utc()
+                .unix_timestamp_nanos()
+        ),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
+
+    if let Some(cgraph) = CGRAPH_CACHE
+        .get_val::<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>>(
+            cache_key.clone(),
+        )
+        .await
+    {
+        return Ok(cgraph);
+    }
+
+    let api_mca =
+        admin_api::MerchantConnectorResponse::foreign_try_from(merchant_connector_account)
+            .change_context(errors::RoutingError::KgraphCacheRefreshFailed)?;
+    let cgraph = Arc::new(
+        mca_graph::make_mca_subgraph(api_mca, config_pm_filters)
+            .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
+            .attach_printable("when constructing cgraph of merchant connector account")?,
+    );
+
+    CGRAPH_CACHE.push(cache_key, Arc::clone(&cgraph)).await;
+
+    Ok(cgraph)
+}
+
 // #[cfg(feature = "v1")]
 pub async fn refresh_cgraph_cache(
     state: &SessionState,
@@ -751,6 +918,8 @@ pub async fn refresh_cgraph_cache(
     profile_id: &common_utils::id_type::ProfileId,
     transaction_type: &api_enums::TransactionType,
 ) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+    let cgraph_version = get_cgraph_version(state, &key).await;
+
     let mut merchant_connector_accounts = state
         .store
         .find_merchant_connector_account_by_merchant_id_and_disabled_list(
@@ -787,11 +956,6 @@ pub async fn refresh_cgraph_cache(
     let merchant_connector_accounts = merchant_connector_accounts
         .filter_based_on_profile_and_connector_type(profile_id, connector_type);
 
-    let api_mcas = merchant_connector_accounts
-        .into_iter()
-        .map(admin_api::MerchantConnectorResponse::foreign_try_from)
-        .collect::<Result<Vec<_>, _>>()
-        .change_context(errors::RoutingError::KgraphCacheRefreshFailed)?;
     let connector_configs = state
         .conf
         .pm_filters
@@ -817,12 +981,23 @@ pub async fn refresh_cgraph_cache(
         connector_configs,
         default_configs,
     };
+
+    let mut mca_cgraphs = Vec::with_capacity(merchant_connector_accounts.len());
+    for merchant_connector_account in merchant_connector_accounts {
+        mca_cgraphs
+            .push(get_mca_cgraph(state, merchant_connector_account, &config_pm_filters).await?);
+    }
+
     let cgraph = Arc::new(
-        mca_graph::make_mca_graph(api_mcas, &config_pm_filters)
+        mca_graph::merge_mca_graphs(mca_cgraphs.iter().map(AsRef::as_ref))
             .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
             .attach_printable("when construction cgraph")?,
     );
 
+    if let Some(cgraph_version) = cgraph_version {
+        set_cgraph_in_redis(state, &key, &cgraph_version, &cgraph).await;
+    }
+
     CGRAPH_CACHE
         .push(
             CacheKey {
@@ -1045,6 +1220,7 @@ pub async fn perform_session_flow_routing<'a>(
         // business_label not available in payment_intent anymore
         business_label: None,
         setup_future_usage: Some(session_input.payment_intent.setup_future_usage),
+        destination_country: None,
     };
 
     let metadata = session_input
@@ -1189,6 +1365,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        destination_country: None,
     };
 
     let metadata = session_input
@@ -1492,6 +1669,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        destination_country: None,
     };
 
     let metadata = payment_intent
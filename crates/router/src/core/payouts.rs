@@ -1,4 +1,6 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -80,6 +82,30 @@ pub struct PayoutData {
     pub payment_method: Option<PaymentMethod>,
 }
 
+impl PayoutData {
+    /// Country of the account receiving the payout. This is the country of the bank for bank
+    /// transfers, falling back to the country of the billing address.
+    pub fn get_destination_country(&self) -> Option<api_enums::CountryAlpha2> {
+        let bank_country = match self.payout_method_data.as_ref() {
+            Some(payouts::PayoutMethodData::Bank(bank)) => match bank {
+                payouts::BankPayout::Ach(ach) => ach.bank_country_code,
+                payouts::BankPayout::Bacs(bacs) => bacs.bank_country_code,
+                payouts::BankPayout::Sepa(sepa) => sepa.bank_country_code,
+                payouts::BankPayout::Pix(_) => Some(api_enums::CountryAlpha2::BR),
+            },
+            Some(payouts::PayoutMethodData::Card(_))
+            | Some(payouts::PayoutMethodData::Wallet(_))
+            | None => None,
+        };
+
+        bank_country.or_else(|| {
+            self.billing_address
+                .as_ref()
+                .and_then(|address| address.country)
+        })
+    }
+}
+
 // ********************************************** CORE FLOWS **********************************************
 pub fn get_next_connector(
     connectors: &mut IntoIter<api::ConnectorRoutingData>,
@@ -184,6 +210,7 @@ pub async fn make_connector_decision(
                 let config_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -219,6 +246,7 @@ pub async fn make_connector_decision(
                 let config_multiple_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::MultiConnector,
                 )
                 .await;
@@ -237,6 +265,7 @@ pub async fn make_connector_decision(
                 let config_single_connector_bool = retry::config_should_call_gsm_payout(
                     &*state.store,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -0,0 +1,704 @@
+//! Bulk creation of payouts from an uploaded batch file.
+//!
+//! Rows are validated when the file is uploaded, stored encrypted in file storage
+//! (`payouts/batches/{merchant_id}/{batch_id}`) and processed in chunks by the payout batch
+//! process tracker workflow, with the per-row results written back alongside the rows.
+
+use std::collections::HashSet;
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::{payments, payouts as payout_api};
+use common_utils::{
+    errors::ErrorSwitch,
+    ext_traits::{ByteSliceExt, ValueExt},
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::validator;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        files::helpers as files_helpers,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+    utils,
+};
+
+const PAYOUT_BATCH_FILES_PREFIX: &str = "payouts/batches";
+pub const PAYOUT_BATCH_TASK: &str = "PAYOUT_BATCH";
+pub const PAYOUT_BATCH_TAG: &str = "PAYOUTS";
+
+#[derive(Debug, MultipartForm)]
+pub struct PayoutBatchUploadForm {
+    #[multipart(limit = "20MB")]
+    pub file: Bytes,
+    pub file_format: Option<Text<payout_api::PayoutBatchFileFormat>>,
+    pub concurrency: Option<Text<u8>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub total_rows: u32,
+    /// Index of the next stored row to be processed
+    pub next_row_index: usize,
+    pub processed_rows: u32,
+    pub succeeded_rows: u32,
+    pub failed_rows: u32,
+    pub concurrency: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PayoutBatchRow {
+    row_number: u32,
+    request: payout_api::PayoutCreateRequest,
+}
+
+fn get_payout_batch_rows_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    batch_id: &str,
+) -> String {
+    format!(
+        "{PAYOUT_BATCH_FILES_PREFIX}/{}/{batch_id}/rows.json",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_payout_batch_results_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    batch_id: &str,
+) -> String {
+    format!(
+        "{PAYOUT_BATCH_FILES_PREFIX}/{}/{batch_id}/results.json",
+        merchant_id.get_string_repr()
+    )
+}
+
+impl From<payout_api::PayoutBatchRecord> for payout_api::PayoutCreateRequest {
+    fn from(record: payout_api::PayoutBatchRecord) -> Self {
+        let card = record.card_number.map(|card_number| {
+            payout_api::PayoutMethodData::Card(payout_api::CardPayout {
+                card_number,
+                expiry_month: record.card_expiry_month.unwrap_or_default(),
+                expiry_year: record.card_expiry_year.unwrap_or_default(),
+                card_holder_name: record.card_holder_name,
+            })
+        });
+        let sepa = record.iban.map(|iban| {
+            payout_api::PayoutMethodData::Bank(payout_api::Bank::Sepa(
+                payout_api::SepaBankTransfer {
+                    bank_name: record.bank_name.clone(),
+                    bank_country_code: record.bank_country_code,
+                    bank_city: None,
+                    iban,
+                    bic: record.bic,
+                },
+            ))
+        });
+        let ach = record
+            .bank_account_number
+            .zip(record.bank_routing_number)
+            .map(|(bank_account_number, bank_routing_number)| {
+                payout_api::PayoutMethodData::Bank(payout_api::Bank::Ach(
+                    payout_api::AchBankTransfer {
+                        bank_name: record.bank_name,
+                        bank_country_code: record.bank_country_code,
+                        bank_city: None,
+                        bank_account_number,
+                        bank_routing_number,
+                    },
+                ))
+            });
+
+        Self {
+            payout_id: record.payout_id,
+            amount: Some(payments::Amount::from(MinorUnit::new(record.amount))),
+            currency: Some(record.currency),
+            connector: record.connector.map(|connector| vec![connector]),
+            confirm: record.confirm,
+            payout_type: record.payout_type,
+            payout_method_data: card.or(sepa).or(ach),
+            auto_fulfill: record.auto_fulfill,
+            customer_id: record.customer_id,
+            description: record.description,
+            payout_token: record.payout_token,
+            profile_id: record.profile_id,
+            priority: record.priority,
+            email: record.email,
+            name: record.name,
+            payout_method_id: record.payout_method_id,
+            ..Default::default()
+        }
+    }
+}
+
+fn parse_payout_batch_file(
+    file: &[u8],
+    file_format: payout_api::PayoutBatchFileFormat,
+) -> RouterResult<Vec<payout_api::PayoutCreateRequest>> {
+    match file_format {
+        payout_api::PayoutBatchFileFormat::Csv => csv::Reader::from_reader(file)
+            .deserialize::<payout_api::PayoutBatchRecord>()
+            .enumerate()
+            .map(|(index, record)| {
+                record
+                    .map(payout_api::PayoutCreateRequest::from)
+                    .map_err(|error| {
+                        report!(errors::ApiErrorResponse::PreconditionFailed {
+                            message: format!("Invalid record at row {}: {error}", index + 1),
+                        })
+                    })
+            })
+            .collect(),
+        payout_api::PayoutBatchFileFormat::Json => file
+            .parse_struct("Vec<PayoutCreateRequest>")
+            .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                message: "File must contain a JSON array of payout create requests".to_string(),
+            }),
+    }
+}
+
+fn get_error_details(error: &errors::ApiErrorResponse) -> (String, String) {
+    let mut api_error: api_models::errors::types::ApiErrorResponse = error.switch();
+    let api_error = api_error.get_internal_error_mut();
+    (
+        format!("{}_{:02}", api_error.sub_code, api_error.error_identifier),
+        api_error.error_message.clone(),
+    )
+}
+
+fn get_failed_row_result(
+    row_number: u32,
+    payout_id: Option<String>,
+    error: &errors::ApiErrorResponse,
+) -> payout_api::PayoutBatchRowResult {
+    let (error_code, error_message) = get_error_details(error);
+    payout_api::PayoutBatchRowResult {
+        row_number,
+        payout_id,
+        status: None,
+        error_code: Some(error_code),
+        error_message: Some(error_message),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    file: Vec<u8>,
+    file_format: payout_api::PayoutBatchFileFormat,
+    concurrency: Option<u8>,
+) -> RouterResponse<payout_api::PayoutBatchResponse> {
+    let batch_config = &state.conf.payouts.batch;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let requests = parse_payout_batch_file(&file, file_format)?;
+    let total_rows = u32::try_from(requests.len())
+        .ok()
+        .filter(|total_rows| *total_rows <= batch_config.max_rows)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Payout batch must not contain more than {} rows",
+                batch_config.max_rows
+            ),
+        })?;
+    if total_rows == 0 {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payout batch must contain at least one row".to_string(),
+        }));
+    }
+    let concurrency = concurrency
+        .unwrap_or(batch_config.max_concurrency)
+        .clamp(1, batch_config.max_concurrency.max(1));
+
+    let batch_id = common_utils::generate_time_ordered_id("pobatch");
+    let mut payout_ids = HashSet::new();
+    let mut rows = Vec::new();
+    let mut results = Vec::new();
+    for (row_number, mut request) in (1..=total_rows).zip(requests) {
+        // The payout_id is stored along with the row, which keeps payout creation idempotent
+        // when a chunk is processed again after a failure
+        let payout_id = request
+            .payout_id
+            .get_or_insert_with(utils::generate_uuid)
+            .clone();
+        let validation_result =
+            validator::validate_payout_batch_row(&*state.store, &merchant_context, &request)
+                .await
+                .and_then(|()| {
+                    payout_ids
+                        .insert(payout_id.clone())
+                        .then_some(())
+                        .ok_or(report!(errors::ApiErrorResponse::DuplicatePayout {
+                            payout_id: payout_id.clone(),
+                        }))
+                });
+
+        match validation_result {
+            Ok(()) => rows.push(PayoutBatchRow {
+                row_number,
+                request,
+            }),
+            Err(error) => results.push(get_failed_row_result(
+                row_number,
+                Some(payout_id),
+                error.current_context(),
+            )),
+        }
+    }
+
+    let failed_rows = u32::try_from(results.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert number of failed rows")?;
+    files_helpers::upload_encrypted_file(
+        &state,
+        key_store,
+        &get_payout_batch_rows_key(&merchant_id, &batch_id),
+        &rows,
+    )
+    .await?;
+    files_helpers::upload_encrypted_file(
+        &state,
+        key_store,
+        &get_payout_batch_results_key(&merchant_id, &batch_id),
+        &results,
+    )
+    .await?;
+
+    let tracking_data = PayoutBatchTrackingData {
+        batch_id: batch_id.clone(),
+        merchant_id: merchant_id.clone(),
+        total_rows,
+        next_row_index: 0,
+        processed_rows: failed_rows,
+        succeeded_rows: 0,
+        failed_rows,
+        concurrency,
+    };
+
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        PAYOUT_BATCH_TASK,
+        &batch_id,
+        &merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_BATCH_TASK,
+        runner,
+        [PAYOUT_BATCH_TAG],
+        &tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "PayoutsBatchCreate"));
+    let process_tracker = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes))
+        .inspect_err(|_| crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch process tracker task")?;
+
+    logger::info!(%batch_id, total_rows, failed_rows, "Scheduled payout batch");
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        &process_tracker,
+        tracking_data,
+        None,
+    )))
+}
+
+async fn find_payout_batch(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<(storage::ProcessTracker, PayoutBatchTrackingData)> {
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutBatchWorkflow,
+        PAYOUT_BATCH_TASK,
+        batch_id,
+        merchant_id,
+    );
+
+    let process_tracker = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payout batch process tracker task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch `{batch_id}` not found"),
+        })?;
+
+    let tracking_data: PayoutBatchTrackingData = process_tracker
+        .tracking_data
+        .clone()
+        .parse_value("PayoutBatchTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payout batch tracking data")?;
+
+    Ok((process_tracker, tracking_data))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<payout_api::PayoutBatchResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let (process_tracker, tracking_data) =
+        find_payout_batch(&state, merchant_id, &batch_id).await?;
+    let results = files_helpers::retrieve_encrypted_file(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &get_payout_batch_results_key(merchant_id, &batch_id),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        &process_tracker,
+        tracking_data,
+        Some(results),
+    )))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn download_payout_batch_results(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    batch_id: String,
+) -> RouterResponse<Vec<u8>> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    find_payout_batch(&state, merchant_id, &batch_id).await?;
+    let results: Vec<payout_api::PayoutBatchRowResult> = files_helpers::retrieve_encrypted_file(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &get_payout_batch_results_key(merchant_id, &batch_id),
+    )
+    .await?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for result in results {
+        csv_writer
+            .serialize(result)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize payout batch result")?;
+    }
+    let file_data = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write payout batch results file")?;
+
+    Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)))
+}
+
+fn get_payout_batch_response(
+    process_tracker: &storage::ProcessTracker,
+    tracking_data: PayoutBatchTrackingData,
+    results: Option<Vec<payout_api::PayoutBatchRowResult>>,
+) -> payout_api::PayoutBatchResponse {
+    let status = match process_tracker.status {
+        storage::enums::ProcessTrackerStatus::Finish
+            if process_tracker.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            payout_api::PayoutBatchStatus::Completed
+        }
+        storage::enums::ProcessTrackerStatus::Finish => payout_api::PayoutBatchStatus::Failed,
+        _ if tracking_data.next_row_index == 0 => payout_api::PayoutBatchStatus::Scheduled,
+        _ => payout_api::PayoutBatchStatus::InProgress,
+    };
+
+    payout_api::PayoutBatchResponse {
+        batch_id: tracking_data.batch_id,
+        merchant_id: tracking_data.merchant_id,
+        status,
+        total_rows: tracking_data.total_rows,
+        processed_rows: tracking_data.processed_rows,
+        succeeded_rows: tracking_data.succeeded_rows,
+        failed_rows: tracking_data.failed_rows,
+        created_at: process_tracker.created_at,
+        results,
+    }
+}
+
+/// Creates payouts for the next chunk of rows of a payout batch and records their results.
+/// Returns `true` once all rows of the batch have been processed.
+#[instrument(skip_all, fields(batch_id = %tracking_data.batch_id))]
+pub async fn process_payout_batch_chunk(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &mut PayoutBatchTrackingData,
+) -> RouterResult<bool> {
+    let merchant_id = &tracking_data.merchant_id;
+    let key_store = merchant_context.get_merchant_key_store();
+    let rows: Vec<PayoutBatchRow> = files_helpers::retrieve_encrypted_file(
+        state,
+        key_store,
+        &get_payout_batch_rows_key(merchant_id, &tracking_data.batch_id),
+    )
+    .await?;
+    let total_stored_rows = rows.len();
+    let chunk = rows
+        .into_iter()
+        .skip(tracking_data.next_row_index)
+        .take(usize::from(state.conf.payouts.batch.chunk_size.max(1)))
+        .collect::<Vec<_>>();
+    let chunk_len = chunk.len();
+
+    let mut chunk_results = futures::stream::iter(chunk)
+        .map(|row| create_payout_for_batch_row(state, merchant_context, row))
+        .buffer_unordered(usize::from(tracking_data.concurrency.max(1)))
+        .collect::<Vec<_>>()
+        .await;
+    chunk_results.sort_by_key(|result| result.row_number);
+
+    let results_key = get_payout_batch_results_key(merchant_id, &tracking_data.batch_id);
+    let mut results: Vec<payout_api::PayoutBatchRowResult> =
+        files_helpers::retrieve_encrypted_file(state, key_store, &results_key).await?;
+    merge_payout_batch_results(&mut results, chunk_results);
+    files_helpers::upload_encrypted_file(state, key_store, &results_key, &results).await?;
+
+    update_payout_batch_counts(tracking_data, &results)?;
+    tracking_data.next_row_index += chunk_len;
+    Ok(tracking_data.next_row_index >= total_stored_rows)
+}
+
+/// Creates the payout for a row of a payout batch, or returns the result of the payout created
+/// for it by an earlier run of the same chunk.
+async fn create_payout_for_batch_row(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    row: PayoutBatchRow,
+) -> payout_api::PayoutBatchRowResult {
+    let payout_id = row.request.payout_id.clone();
+    let merchant_account = merchant_context.get_merchant_account();
+
+    let existing_payout = match payout_id.as_ref() {
+        Some(payout_id) => validator::validate_uniqueness_of_payout_id_against_merchant_id(
+            &*state.store,
+            payout_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, row_number = row.row_number, "Failed to find payout");
+            get_failed_row_result(
+                row.row_number,
+                Some(payout_id.clone()),
+                error.current_context(),
+            )
+        }),
+        None => Ok(None),
+    };
+
+    let response = match existing_payout {
+        Ok(Some(payout)) => {
+            return payout_api::PayoutBatchRowResult {
+                row_number: row.row_number,
+                payout_id: Some(payout.payout_id),
+                status: Some(payout.status),
+                error_code: None,
+                error_message: None,
+            }
+        }
+        Err(result) => return result,
+        Ok(None) => {
+            super::payouts_create_core(state.clone(), merchant_context.clone(), row.request)
+                .await
+                .and_then(|response| {
+                    response
+                        .get_json_body()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                })
+        }
+    };
+
+    match response {
+        Ok(payout) => payout_api::PayoutBatchRowResult {
+            row_number: row.row_number,
+            payout_id: Some(payout.payout_id),
+            status: Some(payout.status),
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+        },
+        Err(error) => {
+            logger::error!(
+                ?error,
+                row_number = row.row_number,
+                "Failed to create payout"
+            );
+            get_failed_row_result(row.row_number, payout_id, error.current_context())
+        }
+    }
+}
+
+/// Merges the results of a chunk into the results of the batch, replacing any result recorded
+/// for the same row by an earlier run of the chunk.
+fn merge_payout_batch_results(
+    results: &mut Vec<payout_api::PayoutBatchRowResult>,
+    chunk_results: Vec<payout_api::PayoutBatchRowResult>,
+) {
+    let chunk_row_numbers = chunk_results
+        .iter()
+        .map(|result| result.row_number)
+        .collect::<HashSet<_>>();
+    results.retain(|result| !chunk_row_numbers.contains(&result.row_number));
+    results.extend(chunk_results);
+    results.sort_by_key(|result| result.row_number);
+}
+
+fn is_failed_row_result(result: &payout_api::PayoutBatchRowResult) -> bool {
+    result.error_code.is_some()
+        || matches!(
+            result.status,
+            None | Some(
+                api_models::enums::PayoutStatus::Failed
+                    | api_models::enums::PayoutStatus::Ineligible
+            )
+        )
+}
+
+fn update_payout_batch_counts(
+    tracking_data: &mut PayoutBatchTrackingData,
+    results: &[payout_api::PayoutBatchRowResult],
+) -> RouterResult<()> {
+    let failed_rows = results
+        .iter()
+        .filter(|result| is_failed_row_result(result))
+        .count();
+    let succeeded_rows = results.len().saturating_sub(failed_rows);
+
+    let to_u32 = |count: usize| {
+        u32::try_from(count)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert number of payout batch rows")
+    };
+    tracking_data.processed_rows = to_u32(results.len())?;
+    tracking_data.failed_rows = to_u32(failed_rows)?;
+    tracking_data.succeeded_rows = to_u32(succeeded_rows)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use super::*;
+
+    fn row_result(
+        row_number: u32,
+        status: Option<api_models::enums::PayoutStatus>,
+    ) -> payout_api::PayoutBatchRowResult {
+        payout_api::PayoutBatchRowResult {
+            row_number,
+            payout_id: Some(format!("payout_{row_number}")),
+            status,
+            error_code: None,
+            error_message: None,
+        }
+    }
+
+    fn tracking_data() -> PayoutBatchTrackingData {
+        PayoutBatchTrackingData {
+            batch_id: "pobatch_test".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            total_rows: 3,
+            next_row_index: 0,
+            processed_rows: 0,
+            succeeded_rows: 0,
+            failed_rows: 0,
+            concurrency: 1,
+        }
+    }
+
+    #[test]
+    fn test_merge_results_replaces_rows_of_a_reprocessed_chunk() {
+        let mut results = vec![
+            row_result(1, None),
+            row_result(2, Some(api_models::enums::PayoutStatus::Success)),
+        ];
+
+        merge_payout_batch_results(
+            &mut results,
+            vec![
+                row_result(3, Some(api_models::enums::PayoutStatus::Success)),
+                row_result(2, Some(api_models::enums::PayoutStatus::Success)),
+            ],
+        );
+
+        let row_numbers = results
+            .iter()
+            .map(|result| result.row_number)
+            .collect::<Vec<_>>();
+        assert_eq!(row_numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_counts_are_not_inflated_when_a_chunk_is_reprocessed() {
+        let mut tracking_data = tracking_data();
+        let mut results = vec![row_result(1, None)];
+        let chunk_results = vec![
+            row_result(2, Some(api_models::enums::PayoutStatus::Success)),
+            row_result(3, Some(api_models::enums::PayoutStatus::Failed)),
+        ];
+
+        merge_payout_batch_results(&mut results, chunk_results.clone());
+        update_payout_batch_counts(&mut tracking_data, &results).unwrap();
+        merge_payout_batch_results(&mut results, chunk_results);
+        update_payout_batch_counts(&mut tracking_data, &results).unwrap();
+
+        assert_eq!(tracking_data.processed_rows, 3);
+        assert_eq!(tracking_data.succeeded_rows, 1);
+        assert_eq!(tracking_data.failed_rows, 2);
+    }
+
+    #[test]
+    fn test_parse_csv_payout_batch_file() {
+        let file = b"amount,currency,payout_type,customer_id,iban,bic\n\
+            1000,EUR,bank,cus_1,DE89370400440532013000,COBADEFFXXX\n";
+
+        let requests =
+            parse_payout_batch_file(file, payout_api::PayoutBatchFileFormat::Csv).unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].currency, Some(api_models::enums::Currency::EUR));
+        assert!(matches!(
+            requests[0].payout_method_data,
+            Some(payout_api::PayoutMethodData::Bank(payout_api::Bank::Sepa(
+                _
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_parse_payout_batch_file_reports_invalid_row() {
+        let file = b"amount,currency\n1000,EUR\nabc,EUR\n";
+
+        let error =
+            parse_payout_batch_file(file, payout_api::PayoutBatchFileFormat::Csv).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { message }
+                if message.starts_with("Invalid record at row 2")
+        ));
+    }
+}
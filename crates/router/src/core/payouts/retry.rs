@@ -43,6 +43,7 @@ pub async fn do_gsm_multiple_connector_actions(
                     state,
                     retries,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::MultiConnector,
                 )
                 .await;
@@ -113,6 +114,7 @@ pub async fn do_gsm_single_connector_actions(
                     state,
                     retries,
                     merchant_context.get_merchant_account().get_id(),
+                    &payout_data.business_profile,
                     PayoutRetryType::SingleConnector,
                 )
                 .await;
@@ -151,6 +153,7 @@ pub async fn get_retries(
     state: &app::SessionState,
     retries: Option<i32>,
     merchant_id: &common_utils::id_type::MerchantId,
+    profile: &domain::Profile,
     retry_type: PayoutRetryType,
 ) -> Option<i32> {
     match retries {
@@ -173,6 +176,7 @@ pub async fn get_retries(
                     None::<i32>
                 })
                 .ok()
+                .or(profile.max_payout_auto_retries_enabled.map(i32::from))
         }
     }
 }
@@ -290,8 +294,8 @@ pub async fn modify_trackers(
         error_code: None,
         created_at: common_utils::date_time::now(),
         last_modified_at: common_utils::date_time::now(),
-        merchant_connector_id: None,
-        routing_info: None,
+        merchant_connector_id: connector.merchant_connector_id.clone(),
+        routing_info: payout_data.payout_attempt.routing_info.to_owned(),
         unified_code: None,
         unified_message: None,
         additional_payout_method_data: payout_data
@@ -317,19 +321,21 @@ pub async fn modify_trackers(
 pub async fn config_should_call_gsm_payout(
     db: &dyn StorageInterface,
     merchant_id: &common_utils::id_type::MerchantId,
+    profile: &domain::Profile,
     retry_type: PayoutRetryType,
 ) -> bool {
     let key = merchant_id.get_should_call_gsm_payout_key(retry_type);
     let config = db
         .find_config_by_key_unwrap_or(key.as_str(), Some("false".to_string()))
         .await;
-    match config {
+    let merchant_config_gsm = match config {
         Ok(conf) => conf.config == "true",
         Err(error) => {
             logger::error!(?error);
             false
         }
-    }
+    };
+    merchant_config_gsm || profile.is_payout_auto_retries_enabled
 }
 
 pub trait GsmValidation {
@@ -93,15 +93,7 @@ pub async fn validate_create_request(
         }
     };
 
-    // Merchant ID
-    let predicate = req.merchant_id.as_ref().map(|mid| mid != merchant_id);
-    utils::when(predicate.unwrap_or(false), || {
-        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "merchant_id".to_string(),
-            expected_format: "merchant_id from merchant account".to_string(),
-        })
-        .attach_printable("invalid merchant_id in request"))
-    })?;
+    validate_create_request_fields(req, merchant_id)?;
 
     // Payout ID
     let db: &dyn StorageInterface = &*state.store;
@@ -164,10 +156,6 @@ pub async fn validate_create_request(
 
     let payment_method: Option<PaymentMethod> =
         match (req.payout_token.as_ref(), req.payout_method_id.clone()) {
-            (Some(_), Some(_)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-                message: "Only one of payout_method_id or payout_token should be provided."
-                    .to_string(),
-            })),
             (None, Some(payment_method_id)) => match customer.as_ref() {
                 Some(customer) => {
                     let payment_method = db
@@ -281,6 +269,85 @@ pub fn validate_payout_link_request(
     Ok(())
 }
 
+/// Validates a row of a payout batch file before it is scheduled for processing.
+/// Checks requiring database lookups are performed when the payout is created.
+/// Validates the fields of a payout create request which can be checked without fetching or
+/// creating any customer or payout method details
+/// - merchant_id passed is same as the one in merchant_account table
+/// - only one of payout_token or payout_method_id is provided
+pub fn validate_create_request_fields(
+    req: &payouts::PayoutCreateRequest,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<()> {
+    // Merchant ID
+    let predicate = req.merchant_id.as_ref().map(|mid| mid != merchant_id);
+    utils::when(predicate.unwrap_or(false), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "merchant_id".to_string(),
+            expected_format: "merchant_id from merchant account".to_string(),
+        })
+        .attach_printable("invalid merchant_id in request"))
+    })?;
+
+    utils::when(
+        req.payout_token.is_some() && req.payout_method_id.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only one of payout_method_id or payout_token should be provided."
+                    .to_string(),
+            }))
+        },
+    )
+}
+
+/// Validates a row of a payout batch on upload, before any payout of the batch is created
+/// - amount and currency are provided
+/// - payout links are not requested
+/// - request fields pass [`validate_create_request_fields`]
+/// - payout_id is unique against merchant_id
+pub async fn validate_payout_batch_row(
+    db: &dyn StorageInterface,
+    merchant_context: &domain::MerchantContext,
+    req: &payouts::PayoutCreateRequest,
+) -> RouterResult<()> {
+    let merchant_account = merchant_context.get_merchant_account();
+
+    utils::when(req.amount.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "amount",
+        }))
+    })?;
+
+    utils::when(req.currency.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        }))
+    })?;
+
+    utils::when(req.payout_link.unwrap_or(false), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout links cannot be created through a payout batch".to_string(),
+        }))
+    })?;
+
+    validate_create_request_fields(req, merchant_account.get_id())?;
+
+    let payout_id = core_utils::get_or_generate_uuid("payout_id", req.payout_id.as_ref())?;
+    match validate_uniqueness_of_payout_id_against_merchant_id(
+        db,
+        &payout_id,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+    )
+    .await?
+    {
+        Some(_) => Err(report!(errors::ApiErrorResponse::DuplicatePayout {
+            payout_id
+        })),
+        None => Ok(()),
+    }
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payout_list_request(
     req: &payouts::PayoutListConstraints,
@@ -25,7 +25,7 @@ use pm_auth::{
     types::{
         self as pm_auth_types,
         api::{
-            auth_service::{BankAccountCredentials, ExchangeToken, LinkToken},
+            auth_service::{AccessToken, BankAccountCredentials, ExchangeToken, LinkToken},
             BoxedConnectorIntegration, PaymentAuthConnectorData,
         },
     },
@@ -155,6 +155,15 @@ pub async fn create_link_token(
 
     let auth_type = helpers::get_connector_auth_type(merchant_connector_account)?;
 
+    let connector_access_token = get_connector_access_token(
+        &state,
+        &connector,
+        merchant_context.get_merchant_account().get_id(),
+        &selected_config.mca_id,
+        &auth_type,
+    )
+    .await?;
+
     let router_data = pm_auth_types::LinkTokenRouterData {
         flow: std::marker::PhantomData,
         merchant_id: Some(merchant_context.get_merchant_account().get_id().clone()),
@@ -175,12 +184,15 @@ pub async fn create_link_token(
             redirect_uri: headers
                 .as_ref()
                 .and_then(|header| header.x_redirect_uri.clone()),
+            institution_id: payload.institution_id,
         },
         response: Ok(pm_auth_types::LinkTokenResponse {
             link_token: "".to_string(),
+            redirect_url: None,
         }),
         connector_http_status_code: None,
         connector_auth_type: auth_type,
+        access_token: connector_access_token,
     };
 
     let connector_resp = pm_auth_services::execute_connector_processing_step(
@@ -207,6 +219,7 @@ pub async fn create_link_token(
     let response = api_models::pm_auth::LinkTokenCreateResponse {
         link_token: link_token_resp.link_token,
         connector: connector.connector_name.to_string(),
+        redirect_url: link_token_resp.redirect_url,
     };
 
     Ok(ApplicationResponse::Json(response))
@@ -279,26 +292,63 @@ pub async fn exchange_token_core(
 
     let auth_type = helpers::get_connector_auth_type(merchant_connector_account.clone())?;
 
-    let access_token = get_access_token_from_exchange_api(
+    let connector_access_token = get_connector_access_token(
+        &state,
         &connector,
-        connector_name,
-        &payload,
+        merchant_context.get_merchant_account().get_id(),
+        &merchant_connector_account.get_id(),
         &auth_type,
-        &state,
     )
     .await?;
 
-    let bank_account_details_resp = get_bank_account_creds(
-        connector,
-        &merchant_context,
+    let exchange_token_resp = get_access_token_from_exchange_api(
+        &connector,
         connector_name,
-        &access_token,
-        auth_type,
+        &payload,
+        &auth_type,
+        connector_access_token.clone(),
         &state,
-        None,
     )
     .await?;
 
+    let access_token = Secret::new(exchange_token_resp.access_token);
+
+    // Some providers only expose account details one account at a time, for those the
+    // accounts linked during the exchange are fetched individually
+    let bank_account_details_resp = match exchange_token_resp.account_ids {
+        Some(account_ids) => {
+            let mut credentials = Vec::new();
+            for account_id in account_ids {
+                let account_details_resp = get_bank_account_creds(
+                    connector.clone(),
+                    &merchant_context,
+                    connector_name,
+                    &access_token,
+                    auth_type.clone(),
+                    connector_access_token.clone(),
+                    &state,
+                    Some(account_id),
+                )
+                .await?;
+                credentials.extend(account_details_resp.credentials);
+            }
+            pm_auth_types::BankAccountCredentialsResponse { credentials }
+        }
+        None => {
+            get_bank_account_creds(
+                connector,
+                &merchant_context,
+                connector_name,
+                &access_token,
+                auth_type,
+                connector_access_token,
+                &state,
+                None,
+            )
+            .await?
+        }
+    };
+
     Box::pin(store_bank_details_in_payment_methods(
         payload,
         merchant_context,
@@ -656,12 +706,14 @@ async fn store_in_db(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn get_bank_account_creds(
     connector: PaymentAuthConnectorData,
     merchant_context: &domain::MerchantContext,
     connector_name: &str,
     access_token: &Secret<String>,
     auth_type: pm_auth_types::ConnectorAuthType,
+    connector_access_token: Option<Secret<String>>,
     state: &SessionState,
     bank_account_id: Option<Secret<String>>,
 ) -> RouterResult<pm_auth_types::BankAccountCredentialsResponse> {
@@ -686,6 +738,7 @@ pub async fn get_bank_account_creds(
         }),
         connector_http_status_code: None,
         connector_auth_type: auth_type,
+        access_token: connector_access_token,
     };
 
     let bank_details_resp = pm_auth_services::execute_connector_processing_step(
@@ -717,8 +770,9 @@ async fn get_access_token_from_exchange_api(
     connector_name: &str,
     payload: &api_models::pm_auth::ExchangeTokenCreateRequest,
     auth_type: &pm_auth_types::ConnectorAuthType,
+    connector_access_token: Option<Secret<String>>,
     state: &SessionState,
-) -> RouterResult<Secret<String>> {
+) -> RouterResult<pm_auth_types::ExchangeTokenResponse> {
     let connector_integration: BoxedConnectorIntegration<
         '_,
         ExchangeToken,
@@ -732,12 +786,15 @@ async fn get_access_token_from_exchange_api(
         connector: Some(connector_name.to_string()),
         request: pm_auth_types::ExchangeTokenRequest {
             public_token: payload.public_token.clone(),
+            redirect_uri: payload.redirect_uri.clone(),
         },
         response: Ok(pm_auth_types::ExchangeTokenResponse {
             access_token: "".to_string(),
+            account_ids: None,
         }),
         connector_http_status_code: None,
         connector_auth_type: auth_type.clone(),
+        access_token: connector_access_token,
     };
 
     let resp = pm_auth_services::execute_connector_processing_step(
@@ -760,8 +817,90 @@ async fn get_access_token_from_exchange_api(
                 reason: err.reason,
             })?;
 
-    let access_token = exchange_token_resp.access_token;
-    Ok(Secret::new(access_token))
+    Ok(exchange_token_resp)
+}
+
+/// Returns the access token needed to call connectors that authenticate with short lived
+/// tokens, reusing the cached token for the connector account when it is still valid
+async fn get_connector_access_token(
+    state: &SessionState,
+    connector: &PaymentAuthConnectorData,
+    merchant_id: &common_utils::id_type::MerchantId,
+    mca_id: &common_utils::id_type::MerchantConnectorAccountId,
+    auth_type: &pm_auth_types::ConnectorAuthType,
+) -> RouterResult<Option<Secret<String>>> {
+    if !connector.connector_name.supports_access_token() {
+        return Ok(None);
+    }
+
+    let store = &*state.store;
+    let old_access_token = store
+        .get_access_token(merchant_id, mca_id.get_string_repr())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("DB error when accessing the access token")?;
+
+    if let Some(access_token) = old_access_token {
+        return Ok(Some(access_token.token));
+    }
+
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        AccessToken,
+        pm_auth_types::AccessTokenRequest,
+        pm_auth_types::AccessTokenResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::AccessTokenRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_id.clone()),
+        connector: Some(connector.connector_name.to_string()),
+        request: pm_auth_types::AccessTokenRequest,
+        response: Ok(pm_auth_types::AccessTokenResponse {
+            access_token: Secret::new("".to_string()),
+            expires_in: 0,
+        }),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type.clone(),
+        access_token: None,
+    };
+
+    let resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling access token connector api")?;
+
+    let access_token_resp =
+        resp.response
+            .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.connector_name.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    // Expire the cached token slightly early to account for network delays
+    let access_token = types::AccessToken {
+        token: access_token_resp.access_token,
+        expires: access_token_resp
+            .expires_in
+            .saturating_sub(crate::consts::REDUCE_ACCESS_TOKEN_EXPIRY_TIME.into()),
+    };
+
+    if let Err(access_token_set_error) = store
+        .set_access_token(merchant_id, mca_id.get_string_repr(), access_token.clone())
+        .await
+    {
+        logger::error!(?access_token_set_error);
+    }
+
+    Ok(Some(access_token.token))
 }
 
 async fn get_selected_config_from_redis(
@@ -877,12 +1016,22 @@ pub async fn retrieve_payment_method_from_auth_service(
         merchant_account.clone(),
         key_store.clone(),
     )));
+    let connector_access_token = get_connector_access_token(
+        state,
+        &connector,
+        &payment_intent.merchant_id,
+        &auth_token.connector_details.mca_id,
+        &auth_type,
+    )
+    .await?;
+
     let bank_account_creds = get_bank_account_creds(
         connector,
         &merchant_context,
         &auth_token.connector_details.connector,
         access_token,
         auth_type,
+        connector_access_token,
         state,
         Some(auth_token.connector_details.account_id.clone()),
     )
@@ -1,28 +1,43 @@
+use std::str::FromStr;
+
 use super::errors::{self, RouterResponse, RouterResult};
-use crate::{logger, routes::SessionState, services, types::domain};
+use crate::{
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    logger,
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{domain, storage},
+};
+pub mod rules;
 pub mod utils;
 use api_models::proxy as proxy_api_models;
 use common_utils::{
-    ext_traits::{BytesExt, Encode},
+    ext_traits::{BytesExt, Encode, StringExt},
     request::{self, RequestBuilder},
 };
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::vault::PaymentMethodVaultingData;
 use hyperswitch_interfaces::types::Response;
+use masking::{PeekInterface, Secret};
 use serde_json::Value;
 
 pub async fn proxy_core(
     state: SessionState,
     merchant_context: domain::MerchantContext,
+    profile: domain::Profile,
+    req_state: ReqState,
     req: proxy_api_models::ProxyRequest,
 ) -> RouterResponse<proxy_api_models::ProxyResponse> {
     let req_wrapper = utils::ProxyRequestWrapper(req.clone());
-    let vault_id = req_wrapper
-        .get_vault_id(
+    let payment_method = req_wrapper
+        .get_payment_method(
             &state,
             merchant_context.get_merchant_key_store(),
             merchant_context.get_merchant_account().storage_scheme,
         )
         .await?;
+    let vault_id = utils::ProxyRequestWrapper::get_vault_id(&payment_method)?;
 
     let vault_response =
         super::payment_methods::vault::retrieve_payment_method_from_vault_internal(
@@ -34,22 +49,432 @@ pub async fn proxy_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error while fetching data from vault")?;
 
-    let vault_data = vault_response
-        .data
-        .encode_to_value()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to serialize vault data")?;
+    let proxy_route = match &req.route_id {
+        Some(route_id) => Some(
+            get_proxy_routes(state.store.as_ref(), profile.get_id())
+                .await?
+                .into_iter()
+                .find(|route| &route.route_id == route_id)
+                .ok_or_else(|| {
+                    report!(errors::ApiErrorResponse::GenericNotFoundError {
+                        message: format!("Proxy route `{route_id}` not found"),
+                    })
+                })?,
+        ),
+        None => None,
+    };
+
+    let request_content = match &proxy_route {
+        Some(proxy_route) => {
+            apply_detokenization_rules(
+                &state,
+                &req_state,
+                &profile,
+                proxy_route,
+                &req,
+                &payment_method,
+                &vault_response.data,
+            )
+            .await?
+        }
+        None => {
+            let vault_data = vault_response
+                .data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize vault data")?;
+
+            let processed_body = interpolate_token_references_with_vault_data(
+                req.request_body.clone(),
+                &vault_data,
+            )?;
+            request::RequestContent::Json(Box::new(processed_body))
+        }
+    };
 
-    let processed_body =
-        interpolate_token_references_with_vault_data(req.request_body.clone(), &vault_data)?;
+    let res = execute_proxy_request(&state, &req_wrapper, request_content).await?;
 
-    let res = execute_proxy_request(&state, &req_wrapper, processed_body).await?;
+    let mut proxy_response = proxy_api_models::ProxyResponse::try_from(ProxyResponseWrapper(res))?;
 
-    let proxy_response = proxy_api_models::ProxyResponse::try_from(ProxyResponseWrapper(res))?;
+    if let Some(proxy_route) = proxy_route {
+        tokenize_response_fields(
+            &state,
+            &merchant_context,
+            &payment_method,
+            &proxy_route.tokenize_response,
+            &mut proxy_response.response,
+        )
+        .await?;
+    }
 
     Ok(services::ApplicationResponse::Json(proxy_response))
 }
 
+pub async fn update_proxy_routes(
+    state: SessionState,
+    profile: domain::Profile,
+    req: proxy_api_models::ProxyRoutesRequest,
+) -> RouterResponse<proxy_api_models::ProxyRoutesResponse> {
+    validate_proxy_routes(&req.routes)?;
+
+    let db = state.store.as_ref();
+    let key = profile.get_id().get_proxy_routes_key();
+    let config = req
+        .routes
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize proxy routes")?;
+
+    let config_update = storage::ConfigUpdate::Update {
+        config: Some(config.clone()),
+    };
+    match db.update_config_by_key(&key, config_update).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert proxy routes config"),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update proxy routes config"),
+    }?;
+
+    Ok(services::ApplicationResponse::Json(
+        proxy_api_models::ProxyRoutesResponse {
+            profile_id: profile.get_id().to_owned(),
+            routes: req.routes,
+        },
+    ))
+}
+
+pub async fn retrieve_proxy_routes(
+    state: SessionState,
+    profile: domain::Profile,
+) -> RouterResponse<proxy_api_models::ProxyRoutesResponse> {
+    let routes = get_proxy_routes(state.store.as_ref(), profile.get_id()).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        proxy_api_models::ProxyRoutesResponse {
+            profile_id: profile.get_id().to_owned(),
+            routes,
+        },
+    ))
+}
+
+async fn get_proxy_routes(
+    db: &dyn StorageInterface,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Vec<proxy_api_models::ProxyRoute>> {
+    match db
+        .find_config_by_key(&profile_id.get_proxy_routes_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("Vec<ProxyRoute>")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Proxy routes config has invalid structure"),
+        Err(error) if error.current_context().is_db_not_found() => Ok(Vec::new()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch proxy routes config"),
+    }
+}
+
+fn validate_proxy_routes(routes: &[proxy_api_models::ProxyRoute]) -> RouterResult<()> {
+    let mut route_ids = std::collections::HashSet::new();
+
+    for route in routes {
+        let invalid_route = |message: &str| {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid proxy route `{}`: {message}", route.route_id),
+            }))
+        };
+
+        if !route_ids.insert(route.route_id.as_str()) {
+            return invalid_route("route ids must be unique");
+        }
+        if route.allowed_hosts.is_empty()
+            || route
+                .allowed_hosts
+                .iter()
+                .any(|allowed_host| get_allowed_host_url(allowed_host).is_none())
+        {
+            return invalid_route(
+                "allowed_hosts must be a non-empty list of host names, optionally followed by a port",
+            );
+        }
+        if route.detokenize.is_empty() {
+            return invalid_route("at least one detokenization rule is required");
+        }
+
+        for rule in &route.detokenize {
+            rules::FieldLocation::parse(route.request_format, &rule.path)?;
+        }
+        for rule in &route.tokenize_response {
+            rules::JsonPath::from_str(&rule.path).map_err(|message| {
+                report!(errors::ApiErrorResponse::InvalidRequestData { message })
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses an entry of the allowed hosts of a proxy route, a host name optionally followed by a
+/// port, as the base URL of the destinations allowed by it
+fn get_allowed_host_url(allowed_host: &str) -> Option<url::Url> {
+    url::Url::parse(&format!("https://{allowed_host}"))
+        .ok()
+        .filter(|url| {
+            url.path() == "/"
+                && url.username().is_empty()
+                && url.password().is_none()
+                && url.query().is_none()
+                && url.fragment().is_none()
+        })
+}
+
+/// The destination must be an `https` URL on one of the allowed hosts. If the allowed host
+/// specifies a port, the destination must use that port, otherwise the default `https` port.
+fn is_destination_allowed(
+    proxy_route: &proxy_api_models::ProxyRoute,
+    destination: &url::Url,
+) -> bool {
+    destination.scheme() == "https"
+        && proxy_route
+            .allowed_hosts
+            .iter()
+            .filter_map(|allowed_host| get_allowed_host_url(allowed_host))
+            .any(|allowed_url| {
+                allowed_url.host_str() == destination.host_str()
+                    && allowed_url.port() == destination.port()
+            })
+}
+
+async fn apply_detokenization_rules(
+    state: &SessionState,
+    req_state: &ReqState,
+    profile: &domain::Profile,
+    proxy_route: &proxy_api_models::ProxyRoute,
+    req: &proxy_api_models::ProxyRequest,
+    payment_method: &domain::PaymentMethod,
+    vault_data: &PaymentMethodVaultingData,
+) -> RouterResult<request::RequestContent> {
+    let destination_host = req.destination_url.host_str().unwrap_or_default();
+    if !is_destination_allowed(proxy_route, &req.destination_url) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Destination `{}` is not allowed by the proxy route `{}`",
+                req.destination_url.origin().ascii_serialization(),
+                proxy_route.route_id
+            ),
+        }));
+    }
+
+    let requires_token_service = proxy_route.detokenize.iter().any(|rule| match rule.data {
+        proxy_api_models::ProxyDetokenizedData::NetworkTokenCryptogram => true,
+        proxy_api_models::ProxyDetokenizedData::NetworkToken
+        | proxy_api_models::ProxyDetokenizedData::NetworkTokenExpiryMonth
+        | proxy_api_models::ProxyDetokenizedData::NetworkTokenExpiryYear => {
+            !matches!(vault_data, PaymentMethodVaultingData::NetworkToken(_))
+        }
+        proxy_api_models::ProxyDetokenizedData::CardNumber
+        | proxy_api_models::ProxyDetokenizedData::CardExpiryMonth
+        | proxy_api_models::ProxyDetokenizedData::CardExpiryYear
+        | proxy_api_models::ProxyDetokenizedData::CardExpiryDate
+        | proxy_api_models::ProxyDetokenizedData::CardHolderName => false,
+    });
+    let network_token_data = if requires_token_service {
+        let network_token_requestor_ref_id = payment_method
+            .network_token_requestor_reference_id
+            .clone()
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The payment method does not have a network token".to_string(),
+                })
+            })?;
+        Some(
+            super::payment_methods::network_tokenization::get_token_from_tokenization_service(
+                state,
+                network_token_requestor_ref_id,
+                payment_method,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    let field_locations = proxy_route
+        .detokenize
+        .iter()
+        .map(|rule| rules::FieldLocation::parse(proxy_route.request_format, &rule.path))
+        .collect::<RouterResult<Vec<_>>>()?;
+    let fields = field_locations
+        .iter()
+        .zip(proxy_route.detokenize.iter())
+        .map(|(location, rule)| {
+            get_detokenized_value(rule.data, vault_data, network_token_data.as_ref())
+                .map(|value| (location, value))
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let request_content = match proxy_route.request_format {
+        proxy_api_models::ProxyBodyFormat::Json => {
+            let mut request_body = req.request_body.clone();
+            rules::populate_fields_in_json(&mut request_body, fields)?;
+            request::RequestContent::Json(Box::new(request_body))
+        }
+        proxy_api_models::ProxyBodyFormat::FormUrlEncoded => {
+            let mut request_body = req.request_body.clone();
+            rules::populate_fields_in_json(&mut request_body, fields)?;
+            request::RequestContent::FormUrlEncoded(Box::new(request_body))
+        }
+        proxy_api_models::ProxyBodyFormat::Xml => {
+            let request_body = req.request_body.as_str().ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "XML request body must be provided as a string".to_string(),
+                })
+            })?;
+            request::RequestContent::RawBytes(
+                rules::populate_fields_in_xml(request_body, fields)?.into_bytes(),
+            )
+        }
+    };
+
+    for rule in &proxy_route.detokenize {
+        req_state
+            .event_context
+            .event(AuditEvent::new(AuditEventType::ProxyDetokenization {
+                profile_id: profile.get_id().to_owned(),
+                route_id: proxy_route.route_id.clone(),
+                destination_host: destination_host.to_string(),
+                token: req.token.clone(),
+                path: rule.path.clone(),
+                data: rule.data,
+            }))
+            .emit();
+    }
+
+    Ok(request_content)
+}
+
+fn get_detokenized_value(
+    data: proxy_api_models::ProxyDetokenizedData,
+    vault_data: &PaymentMethodVaultingData,
+    network_token_data: Option<&domain::NetworkTokenData>,
+) -> RouterResult<String> {
+    let card = vault_data.get_card();
+    let vault_network_token = match vault_data {
+        PaymentMethodVaultingData::NetworkToken(network_token) => Some(network_token),
+        PaymentMethodVaultingData::Card(_) => None,
+    };
+
+    let value = match data {
+        proxy_api_models::ProxyDetokenizedData::CardNumber => {
+            card.map(|card| card.card_number.get_card_no())
+        }
+        proxy_api_models::ProxyDetokenizedData::CardExpiryMonth => {
+            card.map(|card| card.card_exp_month.peek().to_owned())
+        }
+        proxy_api_models::ProxyDetokenizedData::CardExpiryYear => {
+            card.map(|card| get_four_digit_year(card.card_exp_year.peek()))
+        }
+        proxy_api_models::ProxyDetokenizedData::CardExpiryDate => card.map(|card| {
+            format!(
+                "{:0>2}/{}",
+                card.card_exp_month.peek(),
+                get_two_digit_year(card.card_exp_year.peek())
+            )
+        }),
+        proxy_api_models::ProxyDetokenizedData::CardHolderName => card
+            .and_then(|card| card.card_holder_name.clone())
+            .or_else(|| vault_network_token.and_then(|token| token.card_holder_name.clone()))
+            .map(|name| name.peek().to_owned()),
+        proxy_api_models::ProxyDetokenizedData::NetworkToken => vault_network_token
+            .map(|token| token.network_token.get_card_no())
+            .or_else(|| network_token_data.map(|token| token.network_token.get_card_no())),
+        proxy_api_models::ProxyDetokenizedData::NetworkTokenExpiryMonth => vault_network_token
+            .map(|token| token.network_token_exp_month.peek().to_owned())
+            .or_else(|| {
+                network_token_data.map(|token| token.network_token_exp_month.peek().to_owned())
+            }),
+        proxy_api_models::ProxyDetokenizedData::NetworkTokenExpiryYear => vault_network_token
+            .map(|token| get_four_digit_year(token.network_token_exp_year.peek()))
+            .or_else(|| {
+                network_token_data
+                    .map(|token| get_four_digit_year(token.network_token_exp_year.peek()))
+            }),
+        proxy_api_models::ProxyDetokenizedData::NetworkTokenCryptogram => network_token_data
+            .and_then(|token| token.cryptogram.as_ref())
+            .map(|cryptogram| cryptogram.peek().to_owned()),
+    };
+
+    value.ok_or_else(|| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`{data}` is not available for the payment method"),
+        })
+    })
+}
+
+fn get_four_digit_year(year: &str) -> String {
+    if year.len() == 2 {
+        format!("20{year}")
+    } else {
+        year.to_string()
+    }
+}
+
+fn get_two_digit_year(year: &str) -> String {
+    year.get(year.len().saturating_sub(2)..)
+        .unwrap_or(year)
+        .to_string()
+}
+
+async fn tokenize_response_fields(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+    tokenization_rules: &[proxy_api_models::ProxyTokenizationRule],
+    response: &mut Value,
+) -> RouterResult<()> {
+    for rule in tokenization_rules {
+        let path = rules::JsonPath::from_str(&rule.path).map_err(|message| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(message)
+        })?;
+        let Some(value) = path.get(response).cloned() else {
+            logger::warn!("Field {} not found in proxy response", rule.path);
+            continue;
+        };
+
+        let tokenization_response = super::tokenization::create_vault_token_core(
+            state.clone(),
+            merchant_context.get_merchant_account(),
+            merchant_context.get_merchant_key_store(),
+            api_models::tokenization::GenericTokenizationRequest {
+                customer_id: payment_method.customer_id.clone(),
+                token_request: Secret::new(value),
+            },
+        )
+        .await?
+        .get_json_body()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to tokenize proxy response field")?;
+
+        path.set(
+            response,
+            Value::String(tokenization_response.id.get_string_repr().to_string()),
+        )
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to replace proxy response field with token")?;
+    }
+
+    Ok(())
+}
+
 fn interpolate_token_references_with_vault_data(
     value: Value,
     vault_data: &Value,
@@ -100,14 +525,14 @@ fn extract_field_from_vault_data(vault_data: &Value, field_name: &str) -> Router
 async fn execute_proxy_request(
     state: &SessionState,
     req_wrapper: &utils::ProxyRequestWrapper,
-    processed_body: Value,
+    request_content: request::RequestContent,
 ) -> RouterResult<Response> {
     let request = RequestBuilder::new()
         .method(req_wrapper.get_method())
         .attach_default_headers()
         .headers(req_wrapper.get_headers())
         .url(req_wrapper.get_destination_url())
-        .set_body(request::RequestContent::Json(Box::new(processed_body)))
+        .set_body(request_content)
         .build();
 
     let response = services::call_connector_api(state, request, "proxy")
@@ -150,3 +575,51 @@ impl TryFrom<ProxyResponseWrapper> for proxy_api_models::ProxyResponse {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_proxy_route(allowed_hosts: &[&str]) -> proxy_api_models::ProxyRoute {
+        proxy_api_models::ProxyRoute {
+            route_id: "card_charge".to_string(),
+            allowed_hosts: allowed_hosts.iter().map(ToString::to_string).collect(),
+            request_format: proxy_api_models::ProxyBodyFormat::Json,
+            detokenize: Vec::new(),
+            tokenize_response: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_destination_must_use_https() {
+        let route = get_proxy_route(&["api.example.com"]);
+
+        let destination = url::Url::parse("https://API.example.com/charges").unwrap();
+        assert!(is_destination_allowed(&route, &destination));
+        let destination = url::Url::parse("http://api.example.com/charges").unwrap();
+        assert!(!is_destination_allowed(&route, &destination));
+        let destination = url::Url::parse("https://api.example.com.evil.com/charges").unwrap();
+        assert!(!is_destination_allowed(&route, &destination));
+    }
+
+    #[test]
+    fn test_destination_must_use_the_allowed_port() {
+        let route = get_proxy_route(&["api.example.com"]);
+        let destination = url::Url::parse("https://api.example.com:8443/charges").unwrap();
+        assert!(!is_destination_allowed(&route, &destination));
+
+        let route = get_proxy_route(&["api.example.com:8443"]);
+        assert!(is_destination_allowed(&route, &destination));
+        let destination = url::Url::parse("https://api.example.com/charges").unwrap();
+        assert!(!is_destination_allowed(&route, &destination));
+    }
+
+    #[test]
+    fn test_allowed_hosts_must_be_host_names() {
+        assert!(get_allowed_host_url("api.example.com:8443").is_some());
+        assert!(get_allowed_host_url("api.example.com/charges").is_none());
+        assert!(get_allowed_host_url("user@api.example.com").is_none());
+    }
+}
@@ -0,0 +1,335 @@
+use std::str::FromStr;
+
+use api_models::proxy as proxy_api_models;
+use error_stack::{report, ResultExt};
+use quick_xml::events::{BytesEnd, BytesText, Event};
+use serde_json::Value;
+
+use crate::core::errors::{self, RouterResult};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// A JSONPath referring to a single field, such as `$.source.card.number` or `$.items[0]['id']`.
+/// Wildcards, filters and recursive descent are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath(Vec<JsonPathSegment>);
+
+impl FromStr for JsonPath {
+    type Err = String;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut remaining = path
+            .strip_prefix('$')
+            .ok_or_else(|| format!("JSONPath `{path}` must start with `$`"))?;
+        let mut segments = Vec::new();
+
+        while !remaining.is_empty() {
+            if let Some(rest) = remaining.strip_prefix("['") {
+                let (key, rest) = rest
+                    .split_once("']")
+                    .ok_or_else(|| format!("Unterminated key in JSONPath `{path}`"))?;
+                segments.push(JsonPathSegment::Key(key.to_string()));
+                remaining = rest;
+            } else if let Some(rest) = remaining.strip_prefix('[') {
+                let (index, rest) = rest
+                    .split_once(']')
+                    .ok_or_else(|| format!("Unterminated index in JSONPath `{path}`"))?;
+                let index = index
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid index `{index}` in JSONPath `{path}`"))?;
+                segments.push(JsonPathSegment::Index(index));
+                remaining = rest;
+            } else if let Some(rest) = remaining.strip_prefix('.') {
+                let (key, rest) = rest.split_at(rest.find(['.', '[']).unwrap_or(rest.len()));
+                if key.is_empty() || key == "*" {
+                    return Err(format!("Invalid key in JSONPath `{path}`"));
+                }
+                segments.push(JsonPathSegment::Key(key.to_string()));
+                remaining = rest;
+            } else {
+                return Err(format!("Invalid JSONPath `{path}`"));
+            }
+        }
+
+        if segments.is_empty() {
+            return Err(format!("JSONPath `{path}` must refer to a field"));
+        }
+
+        Ok(Self(segments))
+    }
+}
+
+impl JsonPath {
+    pub fn get<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.0
+            .iter()
+            .try_fold(value, |value, segment| match segment {
+                JsonPathSegment::Key(key) => value.get(key.as_str()),
+                JsonPathSegment::Index(index) => value.get(*index),
+            })
+    }
+
+    /// Sets the field referred to by the path. The field is added if its parent object exists but
+    /// the field itself does not.
+    pub fn set(&self, value: &mut Value, new_value: Value) -> Option<()> {
+        let (last, parents) = self.0.split_last()?;
+        let parent = parents
+            .iter()
+            .try_fold(value, |value, segment| match segment {
+                JsonPathSegment::Key(key) => value.get_mut(key.as_str()),
+                JsonPathSegment::Index(index) => value.get_mut(*index),
+            })?;
+
+        match last {
+            JsonPathSegment::Key(key) => {
+                parent.as_object_mut()?.insert(key.clone(), new_value);
+            }
+            JsonPathSegment::Index(index) => *parent.get_mut(*index)? = new_value,
+        }
+
+        Some(())
+    }
+}
+
+/// An absolute XPath referring to an element, such as `/payment/card/number`. Predicates,
+/// attributes and relative paths are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlPath(Vec<String>);
+
+impl FromStr for XmlPath {
+    type Err = String;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let elements = path
+            .strip_prefix('/')
+            .ok_or_else(|| format!("XPath `{path}` must be an absolute path"))?
+            .split('/')
+            .map(|element| {
+                let is_valid_element = !element.is_empty()
+                    && element
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'));
+                is_valid_element
+                    .then(|| element.to_string())
+                    .ok_or_else(|| format!("Unsupported element `{element}` in XPath `{path}`"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(elements))
+    }
+}
+
+/// The location of a field in a request body, parsed according to the format of the body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldLocation {
+    Json(JsonPath),
+    FormField(String),
+    Xml(XmlPath),
+}
+
+impl FieldLocation {
+    pub fn parse(format: proxy_api_models::ProxyBodyFormat, path: &str) -> RouterResult<Self> {
+        let location = match format {
+            proxy_api_models::ProxyBodyFormat::Json => JsonPath::from_str(path).map(Self::Json),
+            proxy_api_models::ProxyBodyFormat::FormUrlEncoded => (!path.is_empty())
+                .then(|| Self::FormField(path.to_string()))
+                .ok_or_else(|| "Form field name must not be empty".to_string()),
+            proxy_api_models::ProxyBodyFormat::Xml => XmlPath::from_str(path).map(Self::Xml),
+        };
+
+        location
+            .map_err(|message| report!(errors::ApiErrorResponse::InvalidRequestData { message }))
+    }
+}
+
+/// Sets the fields of a JSON or form request body to the provided values.
+pub fn populate_fields_in_json(
+    body: &mut Value,
+    fields: Vec<(&FieldLocation, String)>,
+) -> RouterResult<()> {
+    for (location, field_value) in fields {
+        let is_field_set = match location {
+            FieldLocation::Json(path) => path.set(body, Value::String(field_value)),
+            FieldLocation::FormField(name) => body
+                .as_object_mut()
+                .map(|fields| fields.insert(name.clone(), Value::String(field_value)))
+                .map(|_| ()),
+            FieldLocation::Xml(_) => None,
+        };
+
+        is_field_set.ok_or_else(|| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Unable to locate the field `{location:?}` in the request body"),
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Sets the text of the elements of an XML request body to the provided values.
+pub fn populate_fields_in_xml(
+    body: &str,
+    fields: Vec<(&FieldLocation, String)>,
+) -> RouterResult<String> {
+    let fields = fields
+        .into_iter()
+        .filter_map(|(location, field_value)| match location {
+            FieldLocation::Xml(path) => Some((path, field_value)),
+            FieldLocation::Json(_) | FieldLocation::FormField(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let mut populated_fields = vec![false; fields.len()];
+
+    let mut reader = quick_xml::Reader::from_str(body);
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    let mut current_path: Vec<String> = Vec::new();
+    let mut is_text_replaced = false;
+
+    loop {
+        let event =
+            reader
+                .read_event()
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Request body is not a valid XML document".to_string(),
+                })?;
+
+        let events_to_write = match event {
+            Event::Start(start) => {
+                current_path.push(String::from_utf8_lossy(start.name().as_ref()).into_owned());
+                let field_index = fields.iter().position(|(path, _)| path.0 == current_path);
+                is_text_replaced = field_index.is_some();
+                let mut events = vec![Event::Start(start)];
+                if let Some((index, (_, field_value))) =
+                    field_index.and_then(|index| fields.get(index).map(|field| (index, field)))
+                {
+                    events.push(Event::Text(BytesText::new(field_value)));
+                    if let Some(is_populated) = populated_fields.get_mut(index) {
+                        *is_populated = true;
+                    }
+                }
+                events
+            }
+            Event::Empty(start) => {
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                current_path.push(name.clone());
+                let field_index = fields.iter().position(|(path, _)| path.0 == current_path);
+                current_path.pop();
+                match field_index.and_then(|index| fields.get(index).map(|field| (index, field))) {
+                    Some((index, (_, field_value))) => {
+                        if let Some(is_populated) = populated_fields.get_mut(index) {
+                            *is_populated = true;
+                        }
+                        vec![
+                            Event::Start(start),
+                            Event::Text(BytesText::new(field_value)),
+                            Event::End(BytesEnd::new(name)),
+                        ]
+                    }
+                    None => vec![Event::Empty(start)],
+                }
+            }
+            Event::End(end) => {
+                current_path.pop();
+                is_text_replaced = false;
+                vec![Event::End(end)]
+            }
+            Event::Text(_) | Event::CData(_) if is_text_replaced => Vec::new(),
+            Event::Eof => break,
+            other => vec![other],
+        };
+
+        for event in events_to_write {
+            writer
+                .write_event(event)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write XML request body")?;
+        }
+    }
+
+    if let Some((path, _)) = fields
+        .iter()
+        .zip(populated_fields)
+        .find_map(|(field, is_populated)| (!is_populated).then_some(field))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Unable to locate the element `/{}` in the request body",
+                path.0.join("/")
+            ),
+        }));
+    }
+
+    String::from_utf8(writer.into_inner())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("XML request body is not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_json_path_set() {
+        let mut body = serde_json::json!({
+            "source": { "card": { "number": "{{card_number}}" } },
+            "items": [{ "id": "1" }]
+        });
+
+        let path = JsonPath::from_str("$.source.card.number").unwrap();
+        path.set(&mut body, Value::String("4111111111111111".to_string()))
+            .unwrap();
+        let path = JsonPath::from_str("$.items[0]['id']").unwrap();
+        path.set(&mut body, Value::String("2".to_string())).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "source": { "card": { "number": "4111111111111111" } },
+                "items": [{ "id": "2" }]
+            })
+        );
+        assert!(JsonPath::from_str("$.missing.number")
+            .unwrap()
+            .set(&mut body, Value::Null)
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_json_paths() {
+        assert!(JsonPath::from_str("source.number").is_err());
+        assert!(JsonPath::from_str("$").is_err());
+        assert!(JsonPath::from_str("$..number").is_err());
+        assert!(JsonPath::from_str("$.items[*]").is_err());
+    }
+
+    #[test]
+    fn test_populate_fields_in_xml() {
+        let body = "<payment><card><number>token</number><expiry/></card></payment>";
+        let number = FieldLocation::Xml(XmlPath::from_str("/payment/card/number").unwrap());
+        let expiry = FieldLocation::Xml(XmlPath::from_str("/payment/card/expiry").unwrap());
+
+        let populated = populate_fields_in_xml(
+            body,
+            vec![
+                (&number, "4111111111111111".to_string()),
+                (&expiry, "03/30".to_string()),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            populated,
+            "<payment><card><number>4111111111111111</number><expiry>03/30</expiry></card></payment>"
+        );
+
+        let missing = FieldLocation::Xml(XmlPath::from_str("/payment/cvc").unwrap());
+        assert!(populate_fields_in_xml(body, vec![(&missing, "123".to_string())]).is_err());
+    }
+}
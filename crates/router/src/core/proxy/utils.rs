@@ -21,12 +21,12 @@ use crate::{
 pub struct ProxyRequestWrapper(pub proxy_api_models::ProxyRequest);
 
 impl ProxyRequestWrapper {
-    pub async fn get_vault_id(
+    pub async fn get_payment_method(
         &self,
         state: &SessionState,
         key_store: &domain::MerchantKeyStore,
         storage_scheme: common_enums::enums::MerchantStorageScheme,
-    ) -> RouterResult<payment_methods::VaultId> {
+    ) -> RouterResult<domain::PaymentMethod> {
         let token = &self.0.token;
 
         match self.0.token_type {
@@ -43,11 +43,7 @@ impl ProxyRequestWrapper {
                     .store
                     .find_payment_method(&((state).into()), key_store, &pm_id, storage_scheme)
                     .await
-                    .change_context(errors::ApiErrorResponse::PaymentMethodNotFound)?
-                    .locker_id
-                    .get_required_value("vault_id")
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Locker id not present in Payment Method Entry")
+                    .change_context(errors::ApiErrorResponse::PaymentMethodNotFound)
             }
             proxy_api_models::TokenType::TokenizationId => {
                 Err(report!(errors::ApiErrorResponse::NotImplemented {
@@ -59,6 +55,17 @@ impl ProxyRequestWrapper {
         }
     }
 
+    pub fn get_vault_id(
+        payment_method: &domain::PaymentMethod,
+    ) -> RouterResult<payment_methods::VaultId> {
+        payment_method
+            .locker_id
+            .clone()
+            .get_required_value("vault_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Locker id not present in Payment Method Entry")
+    }
+
     pub fn get_headers(&self) -> Vec<(String, masking::Maskable<String>)> {
         self.0
             .headers
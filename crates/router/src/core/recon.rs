@@ -1,3 +1,6 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod settlement;
+
 use api_models::recon as recon_api;
 #[cfg(feature = "email")]
 use common_utils::{ext_traits::AsyncExt, types::user::ThemeLineage};
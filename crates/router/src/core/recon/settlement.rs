@@ -0,0 +1,977 @@
+//! Reconciliation of connector settlement files against payment attempts, refunds and bank
+//! statements.
+//!
+//! Settlement files are read from the connector's drop folder in file storage
+//! (`recon/settlements/{merchant_id}/{connector}/{file_name}`) and bank statements from
+//! `recon/bank_statements/{merchant_id}/{file_name}`. Both are uploaded through the API.
+//!
+//! A recon run is performed by the recon settlement process tracker workflow, which matches
+//! every settlement line against Hyperswitch, looks for payments captured through the connector
+//! during the settlement period which were not settled, and matches the payouts referenced by the
+//! settlement lines against the amounts credited by the bank. The report of the run is stored in
+//! file storage and every reconciled settlement line is published for recon analytics.
+
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::{enums, recon as recon_api};
+use common_utils::{
+    ext_traits::{ByteSliceExt, ValueExt},
+    id_type,
+    types::MinorUnit,
+};
+use csv::Reader;
+use error_stack::ResultExt;
+use futures::StreamExt;
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    events::recon_events::ReconRecordEvent,
+    routes::{app::SessionStateInfo, SessionState},
+    services::ApplicationResponse,
+    types::{domain, storage},
+    utils,
+};
+
+const SETTLEMENT_FILES_PREFIX: &str = "recon/settlements";
+const BANK_STATEMENT_FILES_PREFIX: &str = "recon/bank_statements";
+const RECON_REPORTS_PREFIX: &str = "recon/reports";
+/// Number of settlement lines looked up in Hyperswitch concurrently
+const SETTLEMENT_LOOKUP_CONCURRENCY: usize = 10;
+pub const RECON_SETTLEMENT_TASK: &str = "RECON_SETTLEMENT";
+pub const RECON_SETTLEMENT_TAG: &str = "RECON";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconSettlementTrackingData {
+    pub recon_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub request: recon_api::ReconSettlementRunRequest,
+}
+
+fn get_settlement_file_key(
+    merchant_id: &id_type::MerchantId,
+    connector: enums::Connector,
+    file_name: &str,
+) -> String {
+    format!(
+        "{SETTLEMENT_FILES_PREFIX}/{}/{connector}/{file_name}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_bank_statement_file_key(merchant_id: &id_type::MerchantId, file_name: &str) -> String {
+    format!(
+        "{BANK_STATEMENT_FILES_PREFIX}/{}/{file_name}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_recon_report_key(merchant_id: &id_type::MerchantId, recon_id: &str) -> String {
+    format!(
+        "{RECON_REPORTS_PREFIX}/{}/{recon_id}.json",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_recon_process_tracker_id(merchant_id: &id_type::MerchantId, recon_id: &str) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::ReconSettlementWorkflow,
+        RECON_SETTLEMENT_TASK,
+        recon_id,
+        merchant_id,
+    )
+}
+
+fn validate_file_name(file_name: &str) -> RouterResult<()> {
+    utils::when(
+        file_name.is_empty()
+            || file_name.contains('/')
+            || file_name.contains('\\')
+            || file_name.contains(".."),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "file_name",
+            })
+            .attach_printable("settlement file name must not contain path separators")
+        },
+    )
+}
+
+fn parse_csv_records<T: serde::de::DeserializeOwned>(
+    data: &[u8],
+    set_line_number: impl Fn(&mut T, u64),
+) -> csv::Result<Vec<T>> {
+    let mut csv_reader = Reader::from_reader(data);
+    let mut records = Vec::new();
+    let mut id_counter = 0;
+    for result in csv_reader.deserialize() {
+        let mut record: T = result?;
+        id_counter += 1;
+        set_line_number(&mut record, id_counter);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+fn parse_settlement_csv(data: &[u8]) -> csv::Result<Vec<recon_api::SettlementRecord>> {
+    parse_csv_records(data, |record: &mut recon_api::SettlementRecord, line| {
+        record.line_number = Some(line)
+    })
+}
+
+fn parse_bank_statement_csv(data: &[u8]) -> csv::Result<Vec<recon_api::BankStatementRecord>> {
+    parse_csv_records(data, |record: &mut recon_api::BankStatementRecord, line| {
+        record.line_number = Some(line)
+    })
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct SettlementFileUploadForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+    pub file_name: Text<String>,
+}
+
+#[instrument(skip_all)]
+pub async fn upload_settlement_file(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    connector: enums::Connector,
+    file_name: String,
+    file: Vec<u8>,
+) -> RouterResponse<recon_api::ReconSettlementFileUploadResponse> {
+    validate_file_name(&file_name)?;
+    parse_settlement_csv(&file).map_err(|error| errors::ApiErrorResponse::PreconditionFailed {
+        message: error.to_string(),
+    })?;
+
+    let file_key = get_settlement_file_key(
+        merchant_context.get_merchant_account().get_id(),
+        connector,
+        &file_name,
+    );
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload settlement file")?;
+
+    Ok(ApplicationResponse::Json(
+        recon_api::ReconSettlementFileUploadResponse {
+            connector,
+            file_name,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn upload_bank_statement(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    file_name: String,
+    file: Vec<u8>,
+) -> RouterResponse<recon_api::ReconBankStatementUploadResponse> {
+    validate_file_name(&file_name)?;
+    parse_bank_statement_csv(&file).map_err(|error| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: error.to_string(),
+        }
+    })?;
+
+    let file_key =
+        get_bank_statement_file_key(merchant_context.get_merchant_account().get_id(), &file_name);
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload bank statement")?;
+
+    Ok(ApplicationResponse::Json(
+        recon_api::ReconBankStatementUploadResponse { file_name },
+    ))
+}
+
+/// Builds the result of a settlement line which could be matched to a Hyperswitch record,
+/// comparing the settled amount, currency and fee against what was expected.
+fn classify_record(
+    record: &recon_api::SettlementRecord,
+    expected_amount: MinorUnit,
+    expected_currency: Option<enums::Currency>,
+    fee_config: Option<&recon_api::ReconFeeConfig>,
+) -> (recon_api::ReconRecordStatus, Option<MinorUnit>) {
+    let expected_fee = fee_config.map(|fee_config| {
+        let percentage_fee = expected_amount
+            .get_amount_as_i64()
+            .saturating_mul(i64::from(fee_config.fee_basis_points))
+            / 10_000;
+        fee_config.fixed_fee + MinorUnit::new(percentage_fee)
+    });
+
+    if expected_currency != Some(record.currency) {
+        return (recon_api::ReconRecordStatus::CurrencyMismatch, expected_fee);
+    }
+
+    if record.amount != expected_amount {
+        return (recon_api::ReconRecordStatus::AmountMismatch, expected_fee);
+    }
+
+    let fee_mismatch = fee_config.zip(expected_fee).zip(record.fee).is_some_and(
+        |((fee_config, expected_fee), settled_fee)| {
+            (settled_fee - expected_fee).get_amount_as_i64().abs()
+                > fee_config.tolerance.get_amount_as_i64()
+        },
+    );
+
+    if fee_mismatch {
+        (recon_api::ReconRecordStatus::FeeMismatch, expected_fee)
+    } else {
+        (recon_api::ReconRecordStatus::Matched, expected_fee)
+    }
+}
+
+fn get_unmatched_record_result(
+    record: recon_api::SettlementRecord,
+    status: recon_api::ReconRecordStatus,
+) -> recon_api::ReconRecordResult {
+    recon_api::ReconRecordResult {
+        line_number: record.line_number,
+        connector_transaction_id: record.connector_transaction_id,
+        transaction_type: record.transaction_type,
+        status,
+        payment_id: None,
+        attempt_id: None,
+        refund_id: None,
+        currency: record.currency,
+        settled_amount: Some(record.amount),
+        expected_amount: None,
+        settled_fee: record.fee,
+        expected_fee: None,
+    }
+}
+
+fn get_expected_payment_amount(attempt: &storage::PaymentAttempt) -> MinorUnit {
+    match attempt.status {
+        enums::AttemptStatus::PartialCharged
+        | enums::AttemptStatus::PartialChargedAndChargeable => attempt
+            .amount_to_capture
+            .unwrap_or_else(|| attempt.net_amount.get_total_amount()),
+        _ => attempt.net_amount.get_total_amount(),
+    }
+}
+
+async fn reconcile_payment_record(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: enums::Connector,
+    record: recon_api::SettlementRecord,
+    fee_config: Option<&recon_api::ReconFeeConfig>,
+) -> RouterResult<recon_api::ReconRecordResult> {
+    let attempt = match state
+        .store
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            merchant_account.get_id(),
+            &record.connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(attempt) => attempt,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(get_unmatched_record_result(
+                record,
+                recon_api::ReconRecordStatus::Missing,
+            ))
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment attempt for settlement record"))
+        }
+    };
+
+    if attempt.connector.as_deref() != Some(connector.to_string().as_str()) {
+        return Ok(get_unmatched_record_result(
+            record,
+            recon_api::ReconRecordStatus::Missing,
+        ));
+    }
+
+    let expected_amount = get_expected_payment_amount(&attempt);
+    let (status, expected_fee) =
+        classify_record(&record, expected_amount, attempt.currency, fee_config);
+
+    Ok(recon_api::ReconRecordResult {
+        line_number: record.line_number,
+        connector_transaction_id: record.connector_transaction_id,
+        transaction_type: record.transaction_type,
+        status,
+        payment_id: Some(attempt.payment_id),
+        attempt_id: Some(attempt.attempt_id),
+        refund_id: None,
+        currency: record.currency,
+        settled_amount: Some(record.amount),
+        expected_amount: Some(expected_amount),
+        settled_fee: record.fee,
+        expected_fee,
+    })
+}
+
+async fn reconcile_refund_record(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: enums::Connector,
+    record: recon_api::SettlementRecord,
+    fee_config: Option<&recon_api::ReconFeeConfig>,
+) -> RouterResult<recon_api::ReconRecordResult> {
+    let refund = match state
+        .store
+        .find_refund_by_merchant_id_connector_refund_id_connector(
+            merchant_account.get_id(),
+            &record.connector_transaction_id,
+            &connector.to_string(),
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => refund,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(get_unmatched_record_result(
+                record,
+                recon_api::ReconRecordStatus::Missing,
+            ))
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch refund for settlement record"))
+        }
+    };
+
+    let (status, expected_fee) = classify_record(
+        &record,
+        refund.refund_amount,
+        Some(refund.currency),
+        fee_config,
+    );
+
+    Ok(recon_api::ReconRecordResult {
+        line_number: record.line_number,
+        connector_transaction_id: record.connector_transaction_id,
+        transaction_type: record.transaction_type,
+        status,
+        payment_id: Some(refund.payment_id),
+        attempt_id: Some(refund.attempt_id),
+        refund_id: Some(refund.refund_id),
+        currency: record.currency,
+        settled_amount: Some(record.amount),
+        expected_amount: Some(refund.refund_amount),
+        settled_fee: record.fee,
+        expected_fee,
+    })
+}
+
+/// Finds the payments captured through the connector during the settlement period which are not
+/// part of the settlement file.
+async fn find_payments_missing_in_settlement(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    connector: enums::Connector,
+    settlement_period: common_utils::types::TimeRange,
+    settled_payment_ids: &HashSet<&str>,
+) -> RouterResult<Vec<recon_api::ReconRecordResult>> {
+    let merchant_account = merchant_context.get_merchant_account();
+    let limit = common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V2;
+    let mut offset = 0;
+    let mut results = Vec::new();
+
+    loop {
+        let constraints = PaymentIntentFetchConstraints::from(
+            api_models::payments::PaymentListFilterConstraints {
+                payment_id: None,
+                profile_id: None,
+                customer_id: None,
+                limit,
+                offset: Some(offset),
+                amount_filter: None,
+                time_range: Some(settlement_period),
+                connector: Some(vec![connector]),
+                currency: None,
+                status: Some(vec![
+                    enums::IntentStatus::Succeeded,
+                    enums::IntentStatus::PartiallyCaptured,
+                ]),
+                payment_method: None,
+                payment_method_type: None,
+                authentication_type: None,
+                merchant_connector_id: None,
+                order: api_models::payments::Order {
+                    on: api_models::payments::SortOn::Created,
+                    by: api_models::payments::SortBy::Asc,
+                },
+                card_network: None,
+                merchant_order_reference_id: None,
+                card_discovery: None,
+            },
+        );
+        let payments = state
+            .store
+            .get_filtered_payment_intents_attempt(
+                &state.into(),
+                merchant_account.get_id(),
+                &constraints,
+                merchant_context.get_merchant_key_store(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list payments of the settlement period")?;
+        let page_size = payments.len();
+
+        for (payment_intent, attempt) in payments {
+            let connector_transaction_id = attempt.get_connector_payment_id();
+            if connector_transaction_id.is_some_and(|id| settled_payment_ids.contains(id)) {
+                continue;
+            }
+            let Some(currency) = attempt.currency.or(payment_intent.currency) else {
+                continue;
+            };
+            results.push(recon_api::ReconRecordResult {
+                line_number: None,
+                connector_transaction_id: connector_transaction_id.unwrap_or_default().to_string(),
+                transaction_type: recon_api::SettlementTransactionType::Payment,
+                status: recon_api::ReconRecordStatus::MissingInSettlement,
+                payment_id: Some(attempt.payment_id.clone()),
+                attempt_id: Some(attempt.attempt_id.clone()),
+                refund_id: None,
+                currency,
+                settled_amount: None,
+                expected_amount: Some(get_expected_payment_amount(&attempt)),
+                settled_fee: None,
+                expected_fee: None,
+            });
+        }
+
+        if page_size < usize::try_from(limit).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(results)
+}
+
+/// Sums up amounts per payout, keeping the payouts in the order they were first seen
+fn sum_by_payout<'a>(
+    amounts: impl Iterator<Item = ((&'a str, enums::Currency), i64)>,
+) -> Vec<((&'a str, enums::Currency), i64)> {
+    let mut totals: Vec<((&str, enums::Currency), i64)> = Vec::new();
+    let mut positions = HashMap::new();
+    for (payout, amount) in amounts {
+        match positions
+            .get(&payout)
+            .and_then(|&index| totals.get_mut(index))
+        {
+            Some((_, total)) => *total = total.saturating_add(amount),
+            None => {
+                positions.insert(payout, totals.len());
+                totals.push((payout, amount));
+            }
+        }
+    }
+    totals
+}
+
+/// Matches the payouts referenced by the settlement lines against the amounts credited by the
+/// bank. The expected amount of a payout is the net of the settled payments less the settled
+/// refunds and the fees withheld by the connector.
+fn reconcile_bank_statement(
+    settlement_records: &[recon_api::SettlementRecord],
+    bank_records: &[recon_api::BankStatementRecord],
+) -> Vec<recon_api::BankReconResult> {
+    let expected_payouts = sum_by_payout(settlement_records.iter().filter_map(|record| {
+        let amount = match record.transaction_type {
+            recon_api::SettlementTransactionType::Payment => record.amount.get_amount_as_i64(),
+            recon_api::SettlementTransactionType::Refund => {
+                record.amount.get_amount_as_i64().saturating_neg()
+            }
+        };
+        let net_amount = amount.saturating_sub(record.fee.unwrap_or_default().get_amount_as_i64());
+        record
+            .payout_reference
+            .as_deref()
+            .map(|payout_reference| ((payout_reference, record.currency), net_amount))
+    }));
+    let credited_payouts = sum_by_payout(bank_records.iter().map(|record| {
+        (
+            (record.payout_reference.as_str(), record.currency),
+            record.amount.get_amount_as_i64(),
+        )
+    }));
+    let credited_amounts = credited_payouts.iter().copied().collect::<HashMap<_, _>>();
+
+    let mut results = Vec::new();
+    for &((payout_reference, currency), expected_amount) in &expected_payouts {
+        let credited_amount = credited_amounts.get(&(payout_reference, currency)).copied();
+        let status = match credited_amount {
+            Some(credited_amount) if credited_amount == expected_amount => {
+                recon_api::BankReconStatus::Matched
+            }
+            Some(_) => recon_api::BankReconStatus::AmountMismatch,
+            None => recon_api::BankReconStatus::MissingInBankStatement,
+        };
+        results.push(recon_api::BankReconResult {
+            payout_reference: payout_reference.to_string(),
+            currency,
+            status,
+            expected_amount: Some(MinorUnit::new(expected_amount)),
+            credited_amount: credited_amount.map(MinorUnit::new),
+        });
+    }
+
+    let expected_payout_keys = expected_payouts
+        .iter()
+        .map(|(payout, _)| *payout)
+        .collect::<HashSet<_>>();
+    results.extend(
+        credited_payouts
+            .into_iter()
+            .filter(|(payout, _)| !expected_payout_keys.contains(payout))
+            .map(
+                |((payout_reference, currency), credited_amount)| recon_api::BankReconResult {
+                    payout_reference: payout_reference.to_string(),
+                    currency,
+                    status: recon_api::BankReconStatus::MissingInSettlement,
+                    expected_amount: None,
+                    credited_amount: Some(MinorUnit::new(credited_amount)),
+                },
+            ),
+    );
+
+    results
+}
+
+fn get_recon_summary(
+    records: &[recon_api::ReconRecordResult],
+    bank_records: &[recon_api::BankReconResult],
+) -> recon_api::ReconSummary {
+    let summary = records.iter().fold(
+        recon_api::ReconSummary {
+            total_records: records.len(),
+            ..Default::default()
+        },
+        |mut summary, record| {
+            match record.status {
+                recon_api::ReconRecordStatus::Matched => summary.matched += 1,
+                recon_api::ReconRecordStatus::Missing => summary.missing += 1,
+                recon_api::ReconRecordStatus::MissingInSettlement => {
+                    summary.missing_in_settlement += 1
+                }
+                recon_api::ReconRecordStatus::AmountMismatch => summary.amount_mismatch += 1,
+                recon_api::ReconRecordStatus::CurrencyMismatch => summary.currency_mismatch += 1,
+                recon_api::ReconRecordStatus::FeeMismatch => summary.fee_mismatch += 1,
+                recon_api::ReconRecordStatus::Duplicate => summary.duplicate += 1,
+            }
+            summary
+        },
+    );
+
+    bank_records
+        .iter()
+        .fold(summary, |mut summary, bank_record| {
+            match bank_record.status {
+                recon_api::BankReconStatus::Matched => summary.bank_matched += 1,
+                recon_api::BankReconStatus::AmountMismatch => summary.bank_mismatch += 1,
+                recon_api::BankReconStatus::MissingInBankStatement
+                | recon_api::BankReconStatus::MissingInSettlement => summary.bank_missing += 1,
+            }
+            summary
+        })
+}
+
+/// Schedules a recon run of a settlement file, the report can be retrieved once the run is
+/// completed
+#[instrument(skip_all)]
+pub async fn run_settlement_recon(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: recon_api::ReconSettlementRunRequest,
+) -> RouterResponse<recon_api::ReconSettlementRunResponse> {
+    validate_file_name(&req.file_name)?;
+    req.bank_statement_file_name
+        .as_deref()
+        .map(validate_file_name)
+        .transpose()?;
+
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let recon_id = common_utils::generate_id(consts::ID_LENGTH, "recon");
+    let tracking_data = ReconSettlementTrackingData {
+        recon_id: recon_id.clone(),
+        merchant_id: merchant_id.clone(),
+        request: req,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_recon_process_tracker_id(merchant_id, &recon_id),
+        RECON_SETTLEMENT_TASK,
+        storage::ProcessTrackerRunner::ReconSettlementWorkflow,
+        [RECON_SETTLEMENT_TAG],
+        &tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct recon settlement process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "ReconSettlementRun"));
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes))
+        .inspect_err(|_| crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert recon settlement process tracker task")?;
+
+    logger::info!(%recon_id, "Scheduled settlement recon");
+
+    Ok(ApplicationResponse::Json(
+        recon_api::ReconSettlementRunResponse {
+            recon_id,
+            status: recon_api::ReconRunStatus::Scheduled,
+        },
+    ))
+}
+
+/// Reconciles a settlement file and stores the report, called by the recon settlement workflow
+#[instrument(skip_all, fields(recon_id = %tracking_data.recon_id))]
+pub async fn perform_settlement_recon(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &ReconSettlementTrackingData,
+) -> RouterResult<()> {
+    let req = &tracking_data.request;
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+
+    let file = state
+        .file_storage_client
+        .retrieve_file(&get_settlement_file_key(
+            merchant_id,
+            req.connector,
+            &req.file_name,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Failed to retrieve settlement file")?;
+    let settlement_records = parse_settlement_csv(&file).map_err(|error| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: error.to_string(),
+        }
+    })?;
+
+    let bank_records = match req.bank_statement_file_name.as_deref() {
+        Some(bank_statement_file_name) => {
+            let bank_statement = state
+                .file_storage_client
+                .retrieve_file(&get_bank_statement_file_key(
+                    merchant_id,
+                    bank_statement_file_name,
+                ))
+                .await
+                .change_context(errors::ApiErrorResponse::FileNotFound)
+                .attach_printable("Failed to retrieve bank statement")?;
+            let bank_statement_records =
+                parse_bank_statement_csv(&bank_statement).map_err(|error| {
+                    errors::ApiErrorResponse::PreconditionFailed {
+                        message: error.to_string(),
+                    }
+                })?;
+            reconcile_bank_statement(&settlement_records, &bank_statement_records)
+        }
+        None => Vec::new(),
+    };
+
+    let missing_in_settlement = match req.settlement_period {
+        Some(settlement_period) => {
+            let settled_payment_ids = settlement_records
+                .iter()
+                .filter(|record| {
+                    record.transaction_type == recon_api::SettlementTransactionType::Payment
+                })
+                .map(|record| record.connector_transaction_id.as_str())
+                .collect::<HashSet<_>>();
+            find_payments_missing_in_settlement(
+                state,
+                merchant_context,
+                req.connector,
+                settlement_period,
+                &settled_payment_ids,
+            )
+            .await?
+        }
+        None => Vec::new(),
+    };
+
+    let mut seen_transactions = HashMap::new();
+    let lookups = settlement_records.into_iter().map(|record| {
+        let occurrences = seen_transactions
+            .entry((
+                record.transaction_type,
+                record.connector_transaction_id.clone(),
+            ))
+            .and_modify(|count| *count += 1)
+            .or_insert(1_u32);
+        let is_duplicate = *occurrences > 1;
+
+        async move {
+            if is_duplicate {
+                return Ok(get_unmatched_record_result(
+                    record,
+                    recon_api::ReconRecordStatus::Duplicate,
+                ));
+            }
+            match record.transaction_type {
+                recon_api::SettlementTransactionType::Payment => {
+                    reconcile_payment_record(
+                        state,
+                        merchant_account,
+                        req.connector,
+                        record,
+                        req.fee_config.as_ref(),
+                    )
+                    .await
+                }
+                recon_api::SettlementTransactionType::Refund => {
+                    reconcile_refund_record(
+                        state,
+                        merchant_account,
+                        req.connector,
+                        record,
+                        req.fee_config.as_ref(),
+                    )
+                    .await
+                }
+            }
+        }
+    });
+    let mut records = futures::stream::iter(lookups)
+        .buffered(SETTLEMENT_LOOKUP_CONCURRENCY)
+        .collect::<Vec<RouterResult<_>>>()
+        .await
+        .into_iter()
+        .collect::<RouterResult<Vec<_>>>()?;
+    records.extend(missing_in_settlement);
+
+    let report = recon_api::ReconSettlementReport {
+        recon_id: tracking_data.recon_id.clone(),
+        merchant_id: merchant_id.clone(),
+        connector: req.connector,
+        file_name: req.file_name.clone(),
+        created_at: common_utils::date_time::now(),
+        summary: get_recon_summary(&records, &bank_records),
+        records,
+        bank_records,
+    };
+
+    let serialized_report = serde_json::to_vec(&report)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize recon report")?;
+    state
+        .file_storage_client
+        .upload_file(
+            &get_recon_report_key(merchant_id, &report.recon_id),
+            serialized_report,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store recon report")?;
+
+    for record in &report.records {
+        state
+            .event_handler()
+            .log_event(&ReconRecordEvent::new(&report, record));
+    }
+    logger::info!(recon_id = %report.recon_id, summary = ?report.summary, "recon completed");
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_recon_report(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    recon_id: String,
+) -> RouterResponse<recon_api::ReconSettlementReportResponse> {
+    validate_file_name(&recon_id)?;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let process_tracker = state
+        .store
+        .find_process_by_id(&get_recon_process_tracker_id(merchant_id, &recon_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find recon settlement process tracker task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Recon report not found".to_string(),
+        })?;
+    let tracking_data: ReconSettlementTrackingData = process_tracker
+        .tracking_data
+        .clone()
+        .parse_value("ReconSettlementTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse recon settlement tracking data")?;
+
+    let status = match process_tracker.status {
+        storage::enums::ProcessTrackerStatus::Finish
+            if process_tracker.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            recon_api::ReconRunStatus::Completed
+        }
+        storage::enums::ProcessTrackerStatus::Finish => recon_api::ReconRunStatus::Failed,
+        _ => recon_api::ReconRunStatus::Scheduled,
+    };
+
+    let report = if status == recon_api::ReconRunStatus::Completed {
+        Some(
+            state
+                .file_storage_client
+                .retrieve_file(&get_recon_report_key(merchant_id, &recon_id))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve recon report")?
+                .as_slice()
+                .parse_struct::<recon_api::ReconSettlementReport>("ReconSettlementReport")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize recon report")?,
+        )
+    } else {
+        None
+    };
+
+    Ok(ApplicationResponse::Json(
+        recon_api::ReconSettlementReportResponse {
+            recon_id: tracking_data.recon_id,
+            status,
+            report,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use super::*;
+
+    fn get_settlement_record(amount: i64, fee: Option<i64>) -> recon_api::SettlementRecord {
+        recon_api::SettlementRecord {
+            connector_transaction_id: "txn_123".to_string(),
+            transaction_type: recon_api::SettlementTransactionType::Payment,
+            amount: MinorUnit::new(amount),
+            currency: enums::Currency::USD,
+            fee: fee.map(MinorUnit::new),
+            settlement_date: None,
+            payout_reference: Some("po_1".to_string()),
+            line_number: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_parse_settlement_csv() {
+        let data =
+            b"connector_transaction_id,transaction_type,amount,currency,fee,settlement_date\n\
+            txn_1,payment,1000,USD,32,2024-01-01\n\
+            txn_2,refund,500,EUR,,\n";
+        let records = parse_settlement_csv(data).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].line_number, Some(2));
+        assert_eq!(
+            records[1].transaction_type,
+            recon_api::SettlementTransactionType::Refund
+        );
+        assert_eq!(records[1].fee, None);
+        assert_eq!(records[1].payout_reference, None);
+    }
+
+    #[test]
+    fn test_classify_record() {
+        let fee_config = recon_api::ReconFeeConfig {
+            fixed_fee: MinorUnit::new(30),
+            fee_basis_points: 290,
+            tolerance: MinorUnit::new(1),
+        };
+
+        let (status, expected_fee) = classify_record(
+            &get_settlement_record(1000, Some(59)),
+            MinorUnit::new(1000),
+            Some(enums::Currency::USD),
+            Some(&fee_config),
+        );
+        assert_eq!(status, recon_api::ReconRecordStatus::Matched);
+        assert_eq!(expected_fee, Some(MinorUnit::new(59)));
+
+        let (status, _) = classify_record(
+            &get_settlement_record(1000, Some(80)),
+            MinorUnit::new(1000),
+            Some(enums::Currency::USD),
+            Some(&fee_config),
+        );
+        assert_eq!(status, recon_api::ReconRecordStatus::FeeMismatch);
+
+        let (status, expected_fee) = classify_record(
+            &get_settlement_record(900, None),
+            MinorUnit::new(1000),
+            Some(enums::Currency::USD),
+            Some(&fee_config),
+        );
+        assert_eq!(status, recon_api::ReconRecordStatus::AmountMismatch);
+        assert_eq!(expected_fee, Some(MinorUnit::new(59)));
+
+        let (status, _) = classify_record(
+            &get_settlement_record(1000, None),
+            MinorUnit::new(1000),
+            Some(enums::Currency::EUR),
+            None,
+        );
+        assert_eq!(status, recon_api::ReconRecordStatus::CurrencyMismatch);
+    }
+
+    #[test]
+    fn test_reconcile_bank_statement() {
+        let mut refund = get_settlement_record(200, Some(0));
+        refund.transaction_type = recon_api::SettlementTransactionType::Refund;
+        let mut unpaid = get_settlement_record(500, None);
+        unpaid.payout_reference = Some("po_2".to_string());
+        let settlement_records = vec![get_settlement_record(1000, Some(59)), refund, unpaid];
+
+        let get_bank_record = |payout_reference: &str, amount| recon_api::BankStatementRecord {
+            payout_reference: payout_reference.to_string(),
+            amount: MinorUnit::new(amount),
+            currency: enums::Currency::USD,
+            value_date: None,
+            line_number: None,
+        };
+        let results = reconcile_bank_statement(
+            &settlement_records,
+            &[get_bank_record("po_1", 741), get_bank_record("po_3", 100)],
+        );
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].status, recon_api::BankReconStatus::Matched);
+        assert_eq!(
+            results[1].status,
+            recon_api::BankReconStatus::MissingInBankStatement
+        );
+        assert_eq!(results[1].expected_amount, Some(MinorUnit::new(500)));
+        assert_eq!(
+            results[2].status,
+            recon_api::BankReconStatus::MissingInSettlement
+        );
+    }
+}
@@ -959,6 +959,26 @@ pub async fn validate_and_create_refund(
             }
         }
     };
+
+    #[cfg(feature = "frm")]
+    if refund.refund_status != enums::RefundStatus::Failure && req.is_fraudulent == Some(true) {
+        let feedback = crate::core::fraud_check::types::FrmFeedbackData {
+            feedback_type: enums::FrmFeedbackType::FraudulentRefund,
+            amount: Some(refund.refund_amount),
+            refund_id: Some(refund.refund_id.clone()),
+            dispute_id: None,
+            dispute_status: None,
+        };
+        if let Err(error) =
+            crate::core::fraud_check::add_frm_feedback_task(state, payment_attempt, feedback).await
+        {
+            logger::error!(
+                ?error,
+                "Failed to schedule fraudulent refund feedback to frm connector"
+            );
+        }
+    }
+
     let unified_translated_message = if let (Some(unified_code), Some(unified_message)) =
         (refund.unified_code.clone(), refund.unified_message.clone())
     {
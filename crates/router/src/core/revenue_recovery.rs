@@ -1,4 +1,5 @@
 pub mod api;
+pub mod retry_strategy;
 pub mod transformers;
 pub mod types;
 use api_models::{enums, process_tracker::revenue_recovery};
@@ -22,6 +23,7 @@ use crate::{
         storage::{self, revenue_recovery as pcr},
         transformers::ForeignInto,
     },
+    workflows,
 };
 
 pub const EXECUTE_WORKFLOW: &str = "EXECUTE_WORKFLOW";
@@ -339,3 +341,114 @@ pub async fn retrieve_revenue_recovery_process_tracker(
     };
     Ok(ApplicationResponse::Json(response))
 }
+
+pub async fn retrieve_revenue_recovery_retry_timeline(
+    state: SessionState,
+    id: id_type::GlobalPaymentId,
+) -> RouterResponse<revenue_recovery::RevenueRecoveryRetryTimelineResponse> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::PassiveRecoveryWorkflow;
+    let process_tracker_id = id.get_execute_revenue_recovery_id(EXECUTE_WORKFLOW, runner);
+
+    let process_tracker = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("error retrieving the process tracker id")?
+        .get_required_value("Process Tracker")
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Entry For the following id doesn't exists".to_owned(),
+        })?;
+
+    let tracking_data = process_tracker
+        .tracking_data
+        .clone()
+        .parse_value::<pcr::RevenueRecoveryWorkflowTrackingData>("PCRWorkflowTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize  Pcr Workflow Tracking Data")?;
+
+    let revenue_recovery_payment_data =
+        workflows::revenue_recovery::extract_data_and_perform_action(&state, &tracking_data)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to fetch the Revenue Recovery payment data");
+                errors::ApiErrorResponse::InternalServerError
+            })?;
+
+    let payment_attempts = db
+        .find_payment_attempts_by_payment_intent_id(
+            &(&state).into(),
+            &id,
+            &revenue_recovery_payment_data.key_store,
+            revenue_recovery_payment_data
+                .merchant_account
+                .storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let now = common_utils::date_time::now();
+    let retry_count = process_tracker.retry_count;
+
+    let (is_hard_decline, planned_retries) = match tracking_data.revenue_recovery_retry {
+        enums::RevenueRecoveryAlgorithmType::Monitoring => (false, Vec::new()),
+        enums::RevenueRecoveryAlgorithmType::Cascading => {
+            let mapping = workflows::revenue_recovery::get_pcr_payments_retry_mapping(db).await;
+            let mut scheduled_at = now;
+            let planned_retries = (retry_count + 1..)
+                .map_while(|planned_retry_count| {
+                    scheduler::utils::get_pcr_payments_retry_schedule_time(
+                        mapping.clone(),
+                        &tracking_data.merchant_id,
+                        planned_retry_count,
+                    )
+                    .map(|time_delta| {
+                        scheduled_at =
+                            scheduled_at.saturating_add(time::Duration::seconds(time_delta.into()));
+                        revenue_recovery::PlannedRetry {
+                            retry_count: planned_retry_count,
+                            scheduled_at,
+                        }
+                    })
+                })
+                .collect();
+            (false, planned_retries)
+        }
+        enums::RevenueRecoveryAlgorithmType::Smart => {
+            let strategy = retry_strategy::get_retry_strategy(db, &tracking_data.profile_id).await;
+            let last_failed_attempt = payment_attempts
+                .iter()
+                .filter(|attempt| attempt.status == enums::AttemptStatus::Failure)
+                .max_by_key(|attempt| attempt.created_at);
+
+            let is_hard_decline = match last_failed_attempt {
+                Some(attempt) => retry_strategy::is_hard_decline(db, &strategy, attempt).await,
+                None => false,
+            };
+
+            let planned_retries = if is_hard_decline {
+                Vec::new()
+            } else {
+                retry_strategy::SmartRetryPlanner::new(
+                    &strategy,
+                    last_failed_attempt.and_then(retry_strategy::get_card_network),
+                    payment_attempts
+                        .iter()
+                        .map(|attempt| attempt.created_at)
+                        .collect(),
+                )
+                .plan_retry_timeline(retry_count, now)
+            };
+            (is_hard_decline, planned_retries)
+        }
+    };
+
+    let response = revenue_recovery::RevenueRecoveryRetryTimelineResponse {
+        id,
+        retry_algorithm: tracking_data.revenue_recovery_retry,
+        retry_count,
+        is_hard_decline,
+        planned_retries,
+    };
+    Ok(ApplicationResponse::Json(response))
+}
@@ -0,0 +1,366 @@
+use api_models::{
+    enums,
+    payments::AdditionalPaymentData,
+    process_tracker::revenue_recovery::{
+        PaydayAlignment, PlannedRetry, RevenueRecoveryRetryStrategy,
+    },
+};
+use common_utils::{
+    date_time,
+    ext_traits::{StringExt, ValueExt},
+    id_type,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
+use masking::ExposeInterface;
+use time::{Duration, PrimitiveDateTime, Time};
+
+use crate::{
+    db::StorageInterface, errors::StorageError, logger, routes::SessionState,
+    types::storage::revenue_recovery as pcr,
+};
+
+/// Flow against which the decline codes of recovery payments are looked up in the global status mapping
+const GSM_FLOW: &str = "Authorize";
+const GSM_SUB_FLOW: &str = "sub_flow";
+
+pub fn get_retry_strategy_config_key(profile_id: &id_type::ProfileId) -> String {
+    format!(
+        "revenue_recovery_retry_strategy_{}",
+        profile_id.get_string_repr()
+    )
+}
+
+/// Fetch the retry strategy configured for the profile, falling back to the default strategy
+pub async fn get_retry_strategy(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RevenueRecoveryRetryStrategy {
+    let key = get_retry_strategy_config_key(profile_id);
+    let result = db
+        .find_config_by_key(&key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("RevenueRecoveryRetryStrategy")
+                .change_context(StorageError::DeserializationFailed)
+        });
+
+    result.map_or_else(
+        |error| {
+            if error.current_context().is_db_not_found() {
+                logger::debug!("Revenue Recovery retry strategy `{key}` not found, using default");
+            } else {
+                logger::error!(
+                    ?error,
+                    "Failed to read Revenue Recovery retry strategy `{key}`"
+                );
+            }
+            RevenueRecoveryRetryStrategy::default()
+        },
+        |strategy| {
+            logger::debug!(?strategy, "Using custom Revenue Recovery retry strategy");
+            strategy
+        },
+    )
+}
+
+/// Check whether the decline of the attempt maps to an error category that should not be retried
+pub async fn is_hard_decline(
+    db: &dyn StorageInterface,
+    strategy: &RevenueRecoveryRetryStrategy,
+    payment_attempt: &PaymentAttempt,
+) -> bool {
+    let (Some(connector), Some(error)) = (
+        payment_attempt.connector.clone(),
+        payment_attempt.error.as_ref(),
+    ) else {
+        return false;
+    };
+
+    let error_category = db
+        .find_gsm_rule(
+            connector,
+            GSM_FLOW.to_string(),
+            GSM_SUB_FLOW.to_string(),
+            error.code.clone(),
+            error.message.clone(),
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                logger::debug!("GSM rule not found for the Revenue Recovery payment decline");
+            } else {
+                logger::error!(?error, "Failed to fetch GSM rule for Revenue Recovery");
+            }
+        })
+        .ok()
+        .and_then(|gsm| gsm.error_category);
+
+    error_category.is_some_and(|error_category| {
+        strategy
+            .hard_decline_error_categories
+            .contains(&error_category)
+    })
+}
+
+pub fn get_card_network(payment_attempt: &PaymentAttempt) -> Option<enums::CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|pm_data| {
+            pm_data
+                .expose()
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|pm_data| match pm_data {
+            AdditionalPaymentData::Card(card) => card.card_network,
+            _ => None,
+        })
+}
+
+/// Fetch the creation time of all the attempts made for the invoice
+pub async fn get_previous_attempt_times(
+    state: &SessionState,
+    revenue_recovery_payment_data: &pcr::RevenueRecoveryPaymentData,
+    payment_id: &id_type::GlobalPaymentId,
+) -> Vec<PrimitiveDateTime> {
+    state
+        .store
+        .find_payment_attempts_by_payment_intent_id(
+            &state.into(),
+            payment_id,
+            &revenue_recovery_payment_data.key_store,
+            revenue_recovery_payment_data
+                .merchant_account
+                .storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the attempts of the Revenue Recovery payment"
+            );
+        })
+        .map(|attempts| attempts.iter().map(|attempt| attempt.created_at).collect())
+        .unwrap_or_default()
+}
+
+/// Get the schedule time of the next retry as decided by the smart retry strategy of the profile
+pub async fn get_schedule_time_for_smart_retry(
+    state: &SessionState,
+    revenue_recovery_payment_data: &pcr::RevenueRecoveryPaymentData,
+    payment_attempt: &PaymentAttempt,
+    retry_count: i32,
+) -> Option<PrimitiveDateTime> {
+    let db = &*state.store;
+    let strategy = get_retry_strategy(db, revenue_recovery_payment_data.profile.get_id()).await;
+
+    if is_hard_decline(db, &strategy, payment_attempt).await {
+        logger::info!("Hard decline found for Revenue Recovery payment, not retrying");
+        return None;
+    }
+
+    let previous_attempt_times = get_previous_attempt_times(
+        state,
+        revenue_recovery_payment_data,
+        &payment_attempt.payment_id,
+    )
+    .await;
+
+    SmartRetryPlanner::new(
+        &strategy,
+        get_card_network(payment_attempt),
+        previous_attempt_times,
+    )
+    .get_next_retry_time(retry_count, date_time::now())
+}
+
+/// Plans retries of an invoice based on a retry strategy
+#[derive(Debug)]
+pub struct SmartRetryPlanner<'a> {
+    strategy: &'a RevenueRecoveryRetryStrategy,
+    card_network: Option<enums::CardNetwork>,
+    previous_attempt_times: Vec<PrimitiveDateTime>,
+}
+
+impl<'a> SmartRetryPlanner<'a> {
+    pub fn new(
+        strategy: &'a RevenueRecoveryRetryStrategy,
+        card_network: Option<enums::CardNetwork>,
+        mut previous_attempt_times: Vec<PrimitiveDateTime>,
+    ) -> Self {
+        previous_attempt_times.sort();
+        Self {
+            strategy,
+            card_network,
+            previous_attempt_times,
+        }
+    }
+
+    /// Get the time of the retry numbered `retry_count` (starting at 1) when the previous
+    /// attempt was made at `from`, `None` if the retry is not allowed
+    pub fn get_next_retry_time(
+        &self,
+        retry_count: i32,
+        from: PrimitiveDateTime,
+    ) -> Option<PrimitiveDateTime> {
+        if retry_count <= 0 || retry_count > i32::from(self.strategy.max_retry_attempts) {
+            return None;
+        }
+
+        let interval_index = usize::try_from(retry_count - 1).ok()?;
+        let interval_in_mins = self
+            .strategy
+            .retry_intervals_in_mins
+            .get(interval_index)
+            .or(self.strategy.retry_intervals_in_mins.last())?;
+
+        let schedule_time = from.saturating_add(Duration::minutes(i64::from(*interval_in_mins)));
+        let schedule_time = self.apply_card_network_retry_limit(schedule_time)?;
+
+        Some(
+            self.strategy
+                .payday_alignment
+                .as_ref()
+                .map_or(schedule_time, |payday_alignment| {
+                    align_to_payday(payday_alignment, schedule_time)
+                }),
+        )
+    }
+
+    /// Plan all the remaining retries of the invoice, the retries already made being `retry_count`
+    pub fn plan_retry_timeline(
+        mut self,
+        mut retry_count: i32,
+        mut from: PrimitiveDateTime,
+    ) -> Vec<PlannedRetry> {
+        let mut planned_retries = Vec::new();
+        while let Some(scheduled_at) = self.get_next_retry_time(retry_count + 1, from) {
+            retry_count += 1;
+            planned_retries.push(PlannedRetry {
+                retry_count,
+                scheduled_at,
+            });
+            self.previous_attempt_times.push(scheduled_at);
+            from = scheduled_at;
+        }
+        planned_retries
+    }
+
+    /// Postpone the retry until the card network allows another attempt on the card
+    fn apply_card_network_retry_limit(
+        &self,
+        schedule_time: PrimitiveDateTime,
+    ) -> Option<PrimitiveDateTime> {
+        let Some(retry_limit) = self.card_network.as_ref().and_then(|card_network| {
+            self.strategy
+                .card_network_retry_limits
+                .iter()
+                .find(|retry_limit| &retry_limit.card_network == card_network)
+        }) else {
+            return Some(schedule_time);
+        };
+
+        let max_retry_attempts = usize::from(retry_limit.max_retry_attempts);
+        if max_retry_attempts == 0 {
+            return None;
+        }
+
+        let window = Duration::days(i64::from(retry_limit.window_in_days));
+        let attempts_in_window = self
+            .previous_attempt_times
+            .iter()
+            .filter(|attempt_time| **attempt_time > schedule_time.saturating_sub(window))
+            .collect::<Vec<_>>();
+
+        // The oldest attempts have to move out of the window to make room for this one
+        match attempts_in_window
+            .len()
+            .checked_sub(max_retry_attempts)
+            .and_then(|index| attempts_in_window.get(index))
+        {
+            Some(attempt_time) => Some(attempt_time.saturating_add(window)),
+            None => Some(schedule_time),
+        }
+    }
+}
+
+/// Postpone the retry to the next preferred day of the month if it falls within the allowed shift
+fn align_to_payday(
+    payday_alignment: &PaydayAlignment,
+    schedule_time: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    let preferred_time =
+        Time::from_hms(payday_alignment.hour_of_day, 0, 0).unwrap_or(Time::MIDNIGHT);
+
+    (0..=payday_alignment.max_shift_in_days)
+        .filter_map(|shift| {
+            schedule_time
+                .date()
+                .checked_add(Duration::days(i64::from(shift)))
+        })
+        .find(|date| {
+            let last_day_of_month = time::util::days_in_year_month(date.year(), date.month());
+            payday_alignment
+                .days_of_month
+                .iter()
+                .any(|day| (*day).min(last_day_of_month) == date.day())
+        })
+        .map_or(schedule_time, |date| {
+            PrimitiveDateTime::new(date, preferred_time).max(schedule_time)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::process_tracker::revenue_recovery::CardNetworkRetryLimit;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_smart_retry_timeline() {
+        let strategy = RevenueRecoveryRetryStrategy {
+            max_retry_attempts: 3,
+            retry_intervals_in_mins: vec![60, 1440],
+            hard_decline_error_categories: Vec::new(),
+            card_network_retry_limits: vec![CardNetworkRetryLimit {
+                card_network: enums::CardNetwork::Visa,
+                max_retry_attempts: 2,
+                window_in_days: 2,
+            }],
+            payday_alignment: Some(PaydayAlignment {
+                days_of_month: vec![31],
+                max_shift_in_days: 1,
+                hour_of_day: 9,
+            }),
+        };
+        let planner = SmartRetryPlanner::new(
+            &strategy,
+            Some(enums::CardNetwork::Visa),
+            vec![datetime!(2025-04-27 10:00)],
+        );
+
+        let planned_retries = planner
+            .plan_retry_timeline(0, datetime!(2025-04-27 10:00))
+            .into_iter()
+            .map(|planned_retry| (planned_retry.retry_count, planned_retry.scheduled_at))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            planned_retries,
+            vec![
+                // Only the interval applies
+                (1, datetime!(2025-04-27 11:00)),
+                // The network limit postpones the retry to 2025-04-29 10:00, when the first
+                // attempt leaves the window, and it then moves to the last day of April
+                (2, datetime!(2025-04-30 09:00)),
+                // The last interval repeats as the retry intervals are exhausted
+                (3, datetime!(2025-05-01 09:00)),
+            ]
+        );
+    }
+}
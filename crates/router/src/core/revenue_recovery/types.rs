@@ -42,7 +42,7 @@ use crate::{
     types::{
         self, api as api_types, api::payments as payments_types, storage, transformers::ForeignInto,
     },
-    workflows::{payment_sync, revenue_recovery::get_schedule_time_to_retry_mit_payments},
+    workflows::payment_sync,
 };
 
 type RecoveryResult<T> = error_stack::Result<T, errors::RecoveryError>;
@@ -132,15 +132,14 @@ impl RevenueRecoveryPaymentsAttemptStatus {
                     .await?;
 
                 // get a reschedule time
-                let schedule_time = get_schedule_time_to_retry_mit_payments(
-                    db,
-                    &revenue_recovery_payment_data
-                        .merchant_account
-                        .get_id()
-                        .clone(),
-                    process_tracker.retry_count + 1,
-                )
-                .await;
+                let schedule_time = revenue_recovery_payment_data
+                    .get_schedule_time_based_on_retry_type(
+                        state,
+                        revenue_recovery_payment_data.merchant_account.get_id(),
+                        process_tracker.retry_count + 1,
+                        &payment_attempt,
+                    )
+                    .await;
 
                 // check if retry is possible
                 if let Some(schedule_time) = schedule_time {
@@ -291,7 +290,6 @@ impl Action {
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
         revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> RecoveryResult<Self> {
-        let db = &*state.store;
         let response = revenue_recovery_core::api::call_proxy_api(
             state,
             payment_intent,
@@ -307,7 +305,7 @@ impl Action {
                 )),
                 RevenueRecoveryPaymentsAttemptStatus::Failed => {
                     Self::decide_retry_failure_action(
-                        db,
+                        state,
                         merchant_id,
                         process.clone(),
                         revenue_recovery_payment_data,
@@ -491,7 +489,6 @@ impl Action {
             revenue_recovery_payment_data,
         )
         .await;
-        let db = &*state.store;
         match response {
             Ok(_payment_data) => match payment_attempt.status.foreign_into() {
                 RevenueRecoveryPaymentsAttemptStatus::Succeeded => {
@@ -499,7 +496,7 @@ impl Action {
                 }
                 RevenueRecoveryPaymentsAttemptStatus::Failed => {
                     Self::decide_retry_failure_action(
-                        db,
+                        state,
                         revenue_recovery_payment_data.merchant_account.get_id(),
                         process.clone(),
                         revenue_recovery_payment_data,
@@ -692,7 +689,7 @@ impl Action {
     }
 
     pub(crate) async fn decide_retry_failure_action(
-        db: &dyn StorageInterface,
+        state: &SessionState,
         merchant_id: &id_type::MerchantId,
         pt: storage::ProcessTracker,
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
@@ -700,7 +697,12 @@ impl Action {
     ) -> RecoveryResult<Self> {
         let next_retry_count = pt.retry_count + 1;
         let schedule_time = revenue_recovery_payment_data
-            .get_schedule_time_based_on_retry_type(db, merchant_id, next_retry_count)
+            .get_schedule_time_based_on_retry_type(
+                state,
+                merchant_id,
+                next_retry_count,
+                payment_attempt,
+            )
             .await;
 
         match schedule_time {
@@ -1418,6 +1418,98 @@ pub async fn update_default_routing_config_for_profile(
     ))
 }
 
+#[cfg(feature = "v1")]
+pub async fn explain_payment_method_eligibility(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: common_utils::id_type::ProfileId,
+    request: routing_types::PaymentMethodEligibilityRequest,
+) -> RouterResponse<routing_types::PaymentMethodEligibilityResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        key_store,
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?
+        .filter_based_on_profile_and_connector_type(
+            business_profile.get_id(),
+            common_enums::ConnectorType::PaymentProcessor,
+        )
+        .into_iter()
+        .map(api_models::admin::MerchantConnectorResponse::foreign_try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the merchant connector accounts")?;
+
+    let mut connectors = Vec::new();
+    for mca in &merchant_connector_accounts {
+        let connector = mca
+            .connector_name
+            .parse::<enums::RoutableConnectors>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Invalid connector name received: {}", mca.connector_name)
+            })?;
+        if !connectors.contains(&connector) {
+            connectors.push(connector);
+        }
+    }
+
+    let payment_methods = request.payment_methods.clone().unwrap_or_else(|| {
+        kgraph_utils::eligibility::get_enabled_payment_methods(&merchant_connector_accounts)
+    });
+
+    let cgraph = payments_routing::get_merchant_cgraph(
+        &state,
+        key_store,
+        business_profile.get_id(),
+        &enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the constraint graph of the profile")?;
+
+    let eligibility = kgraph_utils::eligibility::explain_payment_method_eligibility(
+        &cgraph,
+        &connectors,
+        &payment_methods,
+        &request,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to analyse the constraint graph of the profile")?
+    .into_iter()
+    .map(ForeignInto::foreign_into)
+    .collect::<Vec<routing_types::ConnectorPaymentMethodEligibility>>();
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::PaymentMethodEligibilityResponse {
+            profile_id: business_profile.get_id().to_owned(),
+            eligibility,
+        },
+    ))
+}
+
 // Toggle the specific routing type as well as add the default configs in RoutingAlgorithm table
 // and update the same in business profile table.
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
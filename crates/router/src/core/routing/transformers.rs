@@ -1,6 +1,7 @@
 use api_models::routing::{
-    DynamicRoutingAlgorithm, MerchantRoutingAlgorithm, RoutingAlgorithmKind,
-    RoutingAlgorithmWrapper, RoutingDictionaryRecord,
+    ConnectorPaymentMethodEligibility, DynamicRoutingAlgorithm, EligibilityExplanation,
+    MerchantRoutingAlgorithm, RoutingAlgorithmKind, RoutingAlgorithmWrapper,
+    RoutingDictionaryRecord,
 };
 #[cfg(feature = "v1")]
 use api_models::{
@@ -107,6 +108,30 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
     }
 }
 
+impl ForeignFrom<hyperswitch_constraint_graph::Explanation> for EligibilityExplanation {
+    fn foreign_from(value: hyperswitch_constraint_graph::Explanation) -> Self {
+        Self {
+            constraint: value.constraint,
+            reason: value.reason,
+            causes: value.causes.into_iter().map(Self::foreign_from).collect(),
+        }
+    }
+}
+
+impl ForeignFrom<kgraph_utils::eligibility::ConnectorEligibility>
+    for ConnectorPaymentMethodEligibility
+{
+    fn foreign_from(value: kgraph_utils::eligibility::ConnectorEligibility) -> Self {
+        Self {
+            connector: value.connector,
+            payment_method: value.payment_method,
+            payment_method_type: value.payment_method_type,
+            is_eligible: value.is_eligible,
+            explanation: value.explanation.map(ForeignFrom::foreign_from),
+        }
+    }
+}
+
 impl From<&routing::TransactionData<'_>> for storage_enums::TransactionType {
     fn from(value: &routing::TransactionData<'_>) -> Self {
         match value {
@@ -52,6 +52,7 @@ pub async fn upsert_surcharge_decision_config(
         .get_payment_method_surcharge_routing_id();
     let read_config_key = db.find_config_by_key(&key).await;
 
+    validate_surcharge_outputs(&program)?;
     euclid::frontend::ast::lowering::lower_program(program.clone())
         .change_context(errors::ApiErrorResponse::InvalidRequestData {
             message: "Invalid Request Data".to_string(),
@@ -153,6 +154,25 @@ pub async fn upsert_surcharge_decision_config(
     }
 }
 
+/// Ensures that the bounds and tiers of every surcharge output in the program are consistent
+#[cfg(feature = "v1")]
+fn validate_surcharge_outputs(
+    program: &euclid::frontend::ast::Program<
+        api_models::surcharge_decision_configs::SurchargeDecisionConfigs,
+    >,
+) -> errors::RouterResult<()> {
+    std::iter::once(&program.default_selection)
+        .chain(program.rules.iter().map(|rule| &rule.connector_selection))
+        .filter_map(|output| output.surcharge_details.as_ref())
+        .try_for_each(|surcharge_details| {
+            surcharge_details.validate().map_err(|message| {
+                error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: message.to_string(),
+                })
+            })
+        })
+}
+
 #[cfg(feature = "v2")]
 pub async fn upsert_surcharge_decision_config(
     _state: SessionState,
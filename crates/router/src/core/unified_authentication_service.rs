@@ -383,6 +383,7 @@ impl<F: Clone + Sync> UnifiedAuthenticationService<F> for ExternalAuthentication
         merchant_connector_account: &MerchantConnectorAccountType,
         connector_name: &str,
         payment_id: common_utils::id_type::PaymentId,
+        psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
     ) -> RouterResult<UasAuthenticationRouterData> {
         let authentication_data =
             <Self as UnifiedAuthenticationService<F>>::get_authentication_request_data(
@@ -402,7 +403,7 @@ impl<F: Clone + Sync> UnifiedAuthenticationService<F> for ExternalAuthentication
                 webhook_url,
                 three_ds_requestor_url,
             )?;
-        let auth_router_data: UasAuthenticationRouterData = utils::construct_uas_router_data(
+        let mut auth_router_data: UasAuthenticationRouterData = utils::construct_uas_router_data(
             state,
             connector_name.to_string(),
             payment_method,
@@ -413,6 +414,7 @@ impl<F: Clone + Sync> UnifiedAuthenticationService<F> for ExternalAuthentication
             Some(authentication.authentication_id.to_owned()),
             payment_id,
         )?;
+        auth_router_data.psd2_sca_exemption_type = psd2_sca_exemption_type;
 
         Box::pin(utils::do_auth_connector_call(
             state,
@@ -533,6 +535,8 @@ pub async fn create_new_authentication(
         acquirer_country_code: None,
         service_details: service_details_value,
         organization_id,
+        psd2_sca_exemption_type: None,
+        is_merchant_whitelisted: None,
     };
     state
         .store
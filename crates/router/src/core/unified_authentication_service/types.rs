@@ -113,6 +113,7 @@ pub trait UnifiedAuthenticationService<F: Clone + Sync> {
         _merchant_connector_account: &MerchantConnectorAccountType,
         _connector_name: &str,
         _payment_id: common_utils::id_type::PaymentId,
+        _psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
     ) -> RouterResult<hyperswitch_domain_models::types::UasAuthenticationRouterData> {
         Err(errors::ApiErrorResponse::NotImplemented {
             message: NotImplementedMessage::Reason("authentication".to_string()),
@@ -217,6 +217,8 @@ pub async fn external_authentication_update_trackers<F: Clone, Req>(
                         authentication_status,
                         connector_metadata: authentication_details.connector_metadata,
                         ds_trans_id: authentication_details.ds_trans_id,
+                        psd2_sca_exemption_type: router_data.psd2_sca_exemption_type,
+                        is_merchant_whitelisted: None,
                     },
                 )
             }
@@ -46,7 +46,7 @@ use crate::{
     types::{domain, transformers::ForeignInto},
     utils::{
         self,
-        user::{theme as theme_utils, two_factor_auth as tfa_utils},
+        user::{passkey as passkey_utils, theme as theme_utils, two_factor_auth as tfa_utils},
     },
 };
 #[cfg(feature = "email")]
@@ -55,6 +55,7 @@ use crate::{services::email::types as email_types, utils::user as user_utils};
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 pub mod theme;
 
 #[cfg(feature = "email")]
@@ -420,6 +421,7 @@ pub async fn signout(
 ) -> UserResponse<()> {
     tfa_utils::delete_totp_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_recovery_code_from_redis(&state, &user_from_token.user_id).await?;
+    tfa_utils::delete_passkey_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_totp_secret_from_redis(&state, &user_from_token.user_id).await?;
 
     auth::blacklist::insert_user_in_blacklist(&state, &user_from_token.user_id).await?;
@@ -2103,6 +2105,7 @@ pub async fn reset_totp(
 
     if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
         && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
+        && !tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?
     {
         return Err(UserErrors::TwoFactorAuthRequired.into());
     }
@@ -2382,29 +2385,37 @@ pub async fn terminate_two_factor_auth(
         .into();
 
     if state.conf.user.force_two_factor_auth || !skip_two_factor_auth {
-        if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
+        let is_passkey_verified =
+            tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?;
+
+        if !is_passkey_verified
+            && !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
             && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
         {
             return Err(UserErrors::TwoFactorAuthRequired.into());
         }
 
-        if user_from_db.get_recovery_codes().is_none() {
-            return Err(UserErrors::TwoFactorAuthNotSetup.into());
-        }
+        // Passkeys can only be registered after signing in, so a verified passkey means
+        // the user has already completed the TOTP setup or chose to skip it
+        if !is_passkey_verified {
+            if user_from_db.get_recovery_codes().is_none() {
+                return Err(UserErrors::TwoFactorAuthNotSetup.into());
+            }
 
-        if user_from_db.get_totp_status() != TotpStatus::Set {
-            state
-                .global_store
-                .update_user_by_user_id(
-                    user_from_db.get_user_id(),
-                    storage_user::UserUpdate::TotpUpdate {
-                        totp_status: Some(TotpStatus::Set),
-                        totp_secret: None,
-                        totp_recovery_codes: None,
-                    },
-                )
-                .await
-                .change_context(UserErrors::InternalServerError)?;
+            if user_from_db.get_totp_status() != TotpStatus::Set {
+                state
+                    .global_store
+                    .update_user_by_user_id(
+                        user_from_db.get_user_id(),
+                        storage_user::UserUpdate::TotpUpdate {
+                            totp_status: Some(TotpStatus::Set),
+                            totp_secret: None,
+                            totp_recovery_codes: None,
+                        },
+                    )
+                    .await
+                    .change_context(UserErrors::InternalServerError)?;
+            }
         }
     }
 
@@ -2418,6 +2429,9 @@ pub async fn terminate_two_factor_auth(
     let _ = tfa_utils::delete_recovery_code_attempts_from_redis(&state, &user_token.user_id)
         .await
         .inspect_err(|error| logger::error!(?error));
+    let _ = tfa_utils::delete_passkey_attempts_from_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
 
     auth::cookies::set_cookie_response(
         user_api::TokenResponse {
@@ -2437,6 +2451,7 @@ pub async fn check_two_factor_auth_status(
             totp: tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?,
             recovery_code: tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id)
                 .await?,
+            passkey: tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
         },
     ))
 }
@@ -2453,7 +2468,12 @@ pub async fn check_two_factor_auth_status_with_attempts(
         .into();
 
     let is_skippable = state.conf.user.force_two_factor_auth.not();
-    if user_from_db.get_totp_status() == TotpStatus::NotSet {
+    if user_from_db.get_totp_status() == TotpStatus::NotSet
+        && user_from_db
+            .decrypt_and_get_passkeys(&state)
+            .await?
+            .is_empty()
+    {
         return Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
             status: None,
             is_skippable,
@@ -2470,15 +2490,372 @@ pub async fn check_two_factor_auth_status_with_attempts(
         remaining_attempts: consts::user::RECOVERY_CODE_MAX_ATTEMPTS
             - tfa_utils::get_recovery_code_attempts_from_redis(&state, &user_token.user_id).await?,
     };
+    let passkey = user_api::TwoFactorAuthAttempts {
+        is_completed: tfa_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
+        remaining_attempts: consts::user::PASSKEY_MAX_ATTEMPTS
+            - tfa_utils::get_passkey_attempts_from_redis(&state, &user_token.user_id).await?,
+    };
     Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
         status: Some(user_api::TwoFactorAuthStatusResponseWithAttempts {
             totp,
             recovery_code,
+            passkey,
         }),
         is_skippable,
     }))
 }
 
+async fn check_two_factor_auth_for_passkey_management(
+    state: &SessionState,
+    user_from_db: &domain::UserFromStorage,
+    passkeys: &[passkey_utils::UserPasskey],
+) -> UserResult<()> {
+    let user_id = user_from_db.get_user_id();
+    let has_two_factor_auth =
+        user_from_db.get_totp_status() == TotpStatus::Set || !passkeys.is_empty();
+
+    if has_two_factor_auth
+        && !tfa_utils::check_totp_in_redis(state, user_id).await?
+        && !tfa_utils::check_recovery_code_in_redis(state, user_id).await?
+        && !tfa_utils::check_passkey_in_redis(state, user_id).await?
+    {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    Ok(())
+}
+
+pub async fn begin_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+) -> UserResponse<user_api::PasskeyOptionsResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let passkeys = user_from_db.decrypt_and_get_passkeys(&state).await?;
+    check_two_factor_auth_for_passkey_management(&state, &user_from_db, &passkeys).await?;
+
+    let webauthn = passkey_utils::get_webauthn(&state)?;
+    let (creation_challenge, registration) = passkey_utils::start_registration(
+        &webauthn,
+        user_from_db.get_user_id(),
+        &user_from_db.get_email().expose().expose(),
+        &user_from_db.get_name().expose(),
+        &passkeys,
+    )?;
+    passkey_utils::insert_passkey_registration_in_redis(
+        &state,
+        user_from_db.get_user_id(),
+        &registration,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyOptionsResponse {
+            options: serde_json::to_value(creation_challenge)
+                .change_context(UserErrors::InternalServerError)?,
+        },
+    ))
+}
+
+pub async fn finish_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    req: user_api::FinishPasskeyRegistrationRequest,
+) -> UserResponse<user_api::PasskeyResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let registration =
+        passkey_utils::get_passkey_registration_from_redis(&state, user_from_db.get_user_id())
+            .await?;
+    let credential = serde_json::from_value(req.credential)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to parse passkey registration credential")?;
+
+    let webauthn = passkey_utils::get_webauthn(&state)?;
+    let passkey = passkey_utils::finish_registration(&webauthn, &credential, &registration)?;
+
+    let mut passkeys = user_from_db.decrypt_and_get_passkeys(&state).await?;
+    let user_passkey = passkey_utils::UserPasskey {
+        passkey_id: common_utils::generate_id(consts::ID_LENGTH, "passkey"),
+        name: req
+            .name
+            .unwrap_or_else(|| format!("Passkey {}", passkeys.len() + 1)),
+        passkey,
+        created_at: common_utils::date_time::now(),
+        last_used_at: None,
+    };
+    let response = user_api::PasskeyResponse {
+        passkey_id: user_passkey.passkey_id.clone(),
+        name: user_passkey.name.clone(),
+        created_at: user_passkey.created_at,
+        last_used_at: user_passkey.last_used_at,
+    };
+    passkeys.push(user_passkey);
+
+    passkey_utils::update_passkeys_in_db(&state, &user_from_db, &passkeys).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn list_passkeys(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_api::PasskeyResponse>> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    Ok(ApplicationResponse::Json(
+        user_from_db
+            .decrypt_and_get_passkeys(&state)
+            .await?
+            .into_iter()
+            .map(|user_passkey| user_api::PasskeyResponse {
+                passkey_id: user_passkey.passkey_id,
+                name: user_passkey.name,
+                created_at: user_passkey.created_at,
+                last_used_at: user_passkey.last_used_at,
+            })
+            .collect(),
+    ))
+}
+
+pub async fn revoke_passkey(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    passkey_id: String,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let mut passkeys = user_from_db.decrypt_and_get_passkeys(&state).await?;
+    check_two_factor_auth_for_passkey_management(&state, &user_from_db, &passkeys).await?;
+
+    let passkeys_count = passkeys.len();
+    passkeys.retain(|user_passkey| user_passkey.passkey_id != passkey_id);
+    if passkeys.len() == passkeys_count {
+        return Err(UserErrors::PasskeyNotFound.into());
+    }
+
+    passkey_utils::update_passkeys_in_db(&state, &user_from_db, &passkeys).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_authentication(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyOptionsResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let passkeys = user_from_db.decrypt_and_get_passkeys(&state).await?;
+    let webauthn = passkey_utils::get_webauthn(&state)?;
+    let (request_challenge, authentication) =
+        passkey_utils::start_authentication(&webauthn, &passkeys)?;
+    passkey_utils::insert_passkey_authentication_in_redis(
+        &state,
+        user_from_db.get_user_id(),
+        &authentication,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyOptionsResponse {
+            options: serde_json::to_value(request_challenge)
+                .change_context(UserErrors::InternalServerError)?,
+        },
+    ))
+}
+
+pub async fn verify_passkey(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::VerifyPasskeyRequest,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let user_passkey_attempts =
+        tfa_utils::get_passkey_attempts_from_redis(&state, &user_token.user_id).await?;
+
+    if user_passkey_attempts >= consts::user::PASSKEY_MAX_ATTEMPTS {
+        return Err(UserErrors::MaxPasskeyAttemptsReached.into());
+    }
+
+    let authentication =
+        passkey_utils::get_passkey_authentication_from_redis(&state, &user_token.user_id).await?;
+    let credential = serde_json::from_value(req.credential)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to parse passkey authentication credential")?;
+
+    let mut passkeys = user_from_db.decrypt_and_get_passkeys(&state).await?;
+    let webauthn = passkey_utils::get_webauthn(&state)?;
+
+    if let Err(error) =
+        passkey_utils::finish_authentication(&webauthn, &credential, &authentication, &mut passkeys)
+    {
+        let _ = tfa_utils::insert_passkey_attempts_in_redis(
+            &state,
+            &user_token.user_id,
+            user_passkey_attempts + 1,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error));
+        return Err(error);
+    }
+
+    passkey_utils::update_passkeys_in_db(&state, &user_from_db, &passkeys).await?;
+    tfa_utils::insert_passkey_in_redis(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_sign_in(
+    state: SessionState,
+    req: user_api::BeginPasskeySignInRequest,
+) -> UserResponse<user_api::BeginPasskeySignInResponse> {
+    let user_email = domain::UserEmail::from_pii_email(req.email)?;
+
+    utils::user::validate_email_domain_auth_type_using_db(
+        &state,
+        &user_email,
+        UserAuthType::Password,
+    )
+    .await?;
+
+    let user_from_db = state
+        .global_store
+        .find_user_by_email(&user_email)
+        .await
+        .map(domain::UserFromStorage::from)
+        .map(Some)
+        .or_else(|error| {
+            if error.current_context().is_db_not_found() {
+                Ok(None)
+            } else {
+                Err(error.change_context(UserErrors::InternalServerError))
+            }
+        })?;
+    let passkeys = match user_from_db.as_ref() {
+        Some(user_from_db) => user_from_db.decrypt_and_get_passkeys(&state).await?,
+        None => Vec::new(),
+    };
+
+    // The challenge is the same for unknown emails and users without passkeys, so that it
+    // can't be used to find out which emails have passkeys set up
+    let webauthn = passkey_utils::get_webauthn(&state)?;
+    let (options, sign_in_state) = match user_from_db {
+        Some(user_from_db) if !passkeys.is_empty() => {
+            let (request_challenge, authentication) =
+                passkey_utils::start_authentication(&webauthn, &passkeys)?;
+            (
+                serde_json::to_value(request_challenge)
+                    .change_context(UserErrors::InternalServerError)?,
+                passkey_utils::PasskeySignInState::User {
+                    user_id: user_from_db.get_user_id().to_string(),
+                    authentication,
+                },
+            )
+        }
+        _ => (
+            passkey_utils::start_decoy_authentication(
+                &webauthn,
+                state.conf.secrets.get_inner().jwt_secret.peek().as_bytes(),
+                user_email.get_secret().peek(),
+            )?,
+            passkey_utils::PasskeySignInState::Decoy,
+        ),
+    };
+
+    let challenge_id = uuid::Uuid::new_v4().to_string();
+    passkey_utils::insert_passkey_sign_in_state_in_redis(&state, &challenge_id, &sign_in_state)
+        .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::BeginPasskeySignInResponse {
+            challenge_id,
+            options,
+        },
+    ))
+}
+
+pub async fn passkey_sign_in(
+    state: SessionState,
+    req: user_api::PasskeySignInRequest,
+) -> UserResponse<user_api::TokenResponse> {
+    let sign_in_state =
+        passkey_utils::get_passkey_sign_in_state_from_redis(&state, &req.challenge_id).await?;
+    let credential = serde_json::from_value(req.credential)
+        .change_context(UserErrors::InvalidCredentials)
+        .attach_printable("Failed to parse passkey authentication credential")?;
+
+    let (user_id, authentication) = match sign_in_state {
+        passkey_utils::PasskeySignInState::User {
+            user_id,
+            authentication,
+        } => (user_id, authentication),
+        passkey_utils::PasskeySignInState::Decoy => {
+            return Err(report!(UserErrors::InvalidCredentials))
+                .attach_printable("Passkey sign in attempted against a decoy challenge");
+        }
+    };
+
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidCredentials)?
+        .into();
+
+    let mut passkeys = user_from_db.decrypt_and_get_passkeys(&state).await?;
+    let webauthn = passkey_utils::get_webauthn(&state)?;
+    passkey_utils::finish_authentication(&webauthn, &credential, &authentication, &mut passkeys)
+        .change_context(UserErrors::InvalidCredentials)?;
+
+    passkey_utils::update_passkeys_in_db(&state, &user_from_db, &passkeys).await?;
+
+    // A passkey is both possession and user verification, so it also satisfies the two factor
+    // auth step that follows sign in
+    tfa_utils::insert_passkey_in_redis(&state, user_from_db.get_user_id()).await?;
+
+    let next_flow =
+        domain::NextFlow::from_origin(domain::Origin::SignIn, user_from_db.clone(), &state).await?;
+
+    let token = next_flow.get_token(&state).await?;
+
+    let response = user_api::TokenResponse {
+        token: token.clone(),
+        token_type: next_flow.get_flow().into(),
+    };
+    auth::cookies::set_cookie_response(response, token)
+}
+
 pub async fn create_user_authentication_method(
     state: SessionState,
     req: user_api::CreateUserAuthenticationMethodRequest,
@@ -0,0 +1,1371 @@
+use api_models::user::scim as scim_api;
+use common_enums::EntityType;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::{
+    enums::{UserRoleVersion, UserStatus},
+    user as storage_user,
+    user::scim_user::{ScimUser, ScimUserNew, ScimUserUpdate},
+    user_role::{UserRole, UserRoleUpdate},
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{
+        api_keys,
+        errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+    },
+    db::user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload},
+    routes::SessionState,
+    services::{authentication as auth, authorization::roles, ApplicationResponse},
+    types::{domain, storage},
+};
+
+pub async fn generate_scim_token(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+) -> UserResponse<scim_api::ScimTokenResponse> {
+    let hash_key = state
+        .conf
+        .api_keys
+        .get_inner()
+        .get_hash_key()
+        .change_context(UserErrors::InternalServerError)?;
+    let plaintext_token = api_keys::PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let hashed_token: storage::HashedApiKey = plaintext_token.keyed_hash(hash_key.peek()).into();
+
+    let key = org_id.get_scim_token_key();
+    let config = hashed_token.into_inner();
+    let config_update = storage::ConfigUpdate::Update {
+        config: Some(config.clone()),
+    };
+    match state.store.update_config_by_key(&key, config_update).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to insert SCIM token config"),
+        Err(error) => Err(error)
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to update SCIM token config"),
+    }?;
+
+    Ok(ApplicationResponse::Json(scim_api::ScimTokenResponse {
+        org_id,
+        token: plaintext_token.peek().to_owned().into(),
+    }))
+}
+
+pub async fn revoke_scim_token(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+) -> UserResponse<()> {
+    state
+        .store
+        .delete_config_by_key(&org_id.get_scim_token_key())
+        .await
+        .to_not_found_response(UserErrors::InvalidScimRequest(
+            "SCIM token is not configured for the organization".to_string(),
+        ))?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn create_scim_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let external_id = request.external_id.clone();
+    let active = request.active.unwrap_or(true);
+
+    let (user, is_user_created_by_scim) = match state
+        .global_store
+        .find_user_by_email(&domain::UserEmail::from_pii_email(
+            request.user_name.clone(),
+        )?)
+        .await
+    {
+        Ok(user) => {
+            // Existing users are linked only if they are already members of the organization, so
+            // that the identity provider of an organization cannot take over users of others
+            if !is_user_in_org(&state, &org_id, &user.user_id).await? {
+                return Err(report!(UserErrors::ScimResourceAlreadyExists)).attach_printable(
+                    "User with the same email is not a member of the organization",
+                );
+            }
+            (domain::UserFromStorage::from(user), false)
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let new_user = domain::NewUser::try_from(request)?;
+            let user = new_user
+                .insert_user_in_db(state.global_store.as_ref())
+                .await?;
+            // The identity provider has already verified the user's email
+            let user = state
+                .global_store
+                .update_user_by_user_id(user.get_user_id(), storage_user::UserUpdate::VerifyUser)
+                .await
+                .change_context(UserErrors::InternalServerError)?
+                .into();
+            (user, true)
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    let now = common_utils::date_time::now();
+    let scim_user = state
+        .global_store
+        .insert_scim_user(ScimUserNew {
+            org_id: org_id.clone(),
+            user_id: user.get_user_id().to_owned(),
+            tenant_id: state.tenant.tenant_id.clone(),
+            external_id,
+            active,
+            created_at: now,
+            last_modified_at: now,
+            is_user_created_by_scim,
+        })
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(UserErrors::ScimResourceAlreadyExists)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    let groups = get_scim_groups(&state, &org_id).await?;
+    let response = get_scim_user_response(&state, scim_user, user, &groups).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn get_scim_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    user_id: String,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let (scim_user, user) = get_scim_user_and_user(&state, &org_id, &user_id).await?;
+    let groups = get_scim_groups(&state, &org_id).await?;
+    let response = get_scim_user_response(&state, scim_user, user, &groups).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn list_scim_users(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimUserListResponse> {
+    let filter = query.filter.as_deref().map(parse_scim_filter).transpose()?;
+
+    let scim_users = match filter {
+        Some((attribute, value)) if attribute.eq_ignore_ascii_case("userName") => {
+            let user = state
+                .global_store
+                .find_user_by_email(&domain::UserEmail::new(value.into())?)
+                .await;
+            match user {
+                Ok(user) => state
+                    .global_store
+                    .find_scim_user_by_org_id_user_id(&org_id, &user.user_id)
+                    .await
+                    .map(|scim_user| vec![scim_user])
+                    .or_else(|error| {
+                        if error.current_context().is_db_not_found() {
+                            Ok(Vec::new())
+                        } else {
+                            Err(error)
+                        }
+                    })
+                    .change_context(UserErrors::InternalServerError)?,
+                Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+                Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+            }
+        }
+        Some((attribute, value)) if attribute.eq_ignore_ascii_case("externalId") => state
+            .global_store
+            .list_scim_users_by_org_id(&org_id, None, None)
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .into_iter()
+            .filter(|scim_user| scim_user.external_id.as_deref() == Some(value.as_str()))
+            .collect(),
+        Some((attribute, _)) => {
+            return Err(report!(UserErrors::InvalidScimRequest(format!(
+                "Filtering users by {attribute} is not supported"
+            ))))
+        }
+        None => state
+            .global_store
+            .list_scim_users_by_org_id(&org_id, None, None)
+            .await
+            .change_context(UserErrors::InternalServerError)?,
+    };
+
+    let groups = get_scim_groups(&state, &org_id).await?;
+    let (start_index, total_results, scim_users) = paginate(scim_users, &query);
+
+    let mut resources = Vec::with_capacity(scim_users.len());
+    for scim_user in scim_users {
+        let user = state
+            .global_store
+            .find_user_by_id(&scim_user.user_id)
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .into();
+        resources.push(get_scim_user_response(&state, scim_user, user, &groups).await?);
+    }
+
+    Ok(ApplicationResponse::Json(scim_api::ScimListResponse {
+        schemas: vec![scim_api::SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+        total_results,
+        start_index,
+        items_per_page: resources.len(),
+        resources,
+    }))
+}
+
+pub async fn replace_scim_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    user_id: String,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let (scim_user, user) = get_scim_user_and_user(&state, &org_id, &user_id).await?;
+
+    let user_name = domain::UserEmail::from_pii_email(request.user_name.clone())?;
+    if user_name.get_inner() != &user.get_email() {
+        return Err(report!(UserErrors::InvalidScimRequest(
+            "userName of a user cannot be changed".to_string()
+        )));
+    }
+
+    let display_name = request.display_name.clone();
+    let update = ScimUserAttributesUpdate {
+        active: request.active,
+        external_id: request.external_id,
+        display_name,
+    };
+    let (scim_user, user) = apply_scim_user_update(&state, scim_user, user, update).await?;
+
+    let groups = get_scim_groups(&state, &org_id).await?;
+    let response = get_scim_user_response(&state, scim_user, user, &groups).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn patch_scim_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    user_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let (scim_user, user) = get_scim_user_and_user(&state, &org_id, &user_id).await?;
+
+    let update = ScimUserAttributesUpdate::from_patch_operations(request.operations)?;
+    let (scim_user, user) = apply_scim_user_update(&state, scim_user, user, update).await?;
+
+    let groups = get_scim_groups(&state, &org_id).await?;
+    let response = get_scim_user_response(&state, scim_user, user, &groups).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn delete_scim_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    user_id: String,
+) -> UserResponse<()> {
+    let (scim_user, user) = get_scim_user_and_user(&state, &org_id, &user_id).await?;
+
+    deprovision_user(&state, &scim_user).await?;
+
+    state
+        .global_store
+        .delete_scim_user_by_org_id_user_id(&org_id, user.get_user_id())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if !scim_user.is_user_created_by_scim {
+        return Ok(ApplicationResponse::StatusOk);
+    }
+
+    // Users that were created through SCIM and are not part of any other entity are removed
+    let remaining_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id: user.get_user_id(),
+            tenant_id: &scim_user.tenant_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: Some(1),
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if remaining_roles.is_empty() {
+        state
+            .global_store
+            .delete_user_by_user_id(user.get_user_id())
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Error while deleting user entry")?;
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn create_scim_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    request: scim_api::ScimGroupRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    validate_group_role_mapping(&state, &org_id, &request.role_mapping).await?;
+
+    let mut groups = get_scim_groups(&state, &org_id).await?;
+    if groups.iter().any(|group| {
+        group.display_name == request.display_name || group.role_mapping == request.role_mapping
+    }) {
+        return Err(report!(UserErrors::ScimResourceAlreadyExists))
+            .attach_printable("Group with the same name or role mapping already exists");
+    }
+
+    let now = common_utils::date_time::now();
+    let group = scim_api::ScimGroup {
+        group_id: uuid::Uuid::new_v4().to_string(),
+        display_name: request.display_name,
+        external_id: request.external_id,
+        role_mapping: request.role_mapping,
+        created_at: now,
+        last_modified_at: now,
+    };
+
+    for member in request.members.unwrap_or_default() {
+        add_group_member(&state, &org_id, &group, &member.value).await?;
+    }
+
+    groups.push(group.clone());
+    update_scim_groups(&state, &org_id, &groups).await?;
+
+    let response = get_scim_group_response(&state, &org_id, group).await?;
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn get_scim_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    group_id: String,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let group = find_scim_group(&get_scim_groups(&state, &org_id).await?, &group_id)?;
+    let response = get_scim_group_response(&state, &org_id, group).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn list_scim_groups(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimGroupListResponse> {
+    let filter = query.filter.as_deref().map(parse_scim_filter).transpose()?;
+    let groups = get_scim_groups(&state, &org_id).await?;
+
+    let groups = match filter {
+        Some((attribute, value)) if attribute.eq_ignore_ascii_case("displayName") => groups
+            .into_iter()
+            .filter(|group| group.display_name == value)
+            .collect(),
+        Some((attribute, value)) if attribute.eq_ignore_ascii_case("externalId") => groups
+            .into_iter()
+            .filter(|group| group.external_id.as_deref() == Some(value.as_str()))
+            .collect(),
+        Some((attribute, _)) => {
+            return Err(report!(UserErrors::InvalidScimRequest(format!(
+                "Filtering groups by {attribute} is not supported"
+            ))))
+        }
+        None => groups,
+    };
+
+    let (start_index, total_results, groups) = paginate(groups, &query);
+
+    let mut resources = Vec::with_capacity(groups.len());
+    for group in groups {
+        resources.push(get_scim_group_response(&state, &org_id, group).await?);
+    }
+
+    Ok(ApplicationResponse::Json(scim_api::ScimListResponse {
+        schemas: vec![scim_api::SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+        total_results,
+        start_index,
+        items_per_page: resources.len(),
+        resources,
+    }))
+}
+
+pub async fn replace_scim_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    group_id: String,
+    request: scim_api::ScimGroupRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let mut groups = get_scim_groups(&state, &org_id).await?;
+    let group = find_scim_group_mut(&mut groups, &group_id)?;
+
+    if group.role_mapping != request.role_mapping {
+        return Err(report!(UserErrors::InvalidScimRequest(
+            "Role mapping of a group cannot be changed, create a new group instead".to_string()
+        )));
+    }
+
+    group.display_name = request.display_name;
+    group.external_id = request.external_id;
+    group.last_modified_at = common_utils::date_time::now();
+    let group = group.clone();
+
+    if let Some(members) = request.members {
+        let requested_members = members
+            .into_iter()
+            .map(|member| member.value)
+            .collect::<Vec<_>>();
+        replace_group_members(&state, &org_id, &group, &requested_members).await?;
+    }
+
+    update_scim_groups(&state, &org_id, &groups).await?;
+
+    let response = get_scim_group_response(&state, &org_id, group).await?;
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn patch_scim_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    group_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let mut groups = get_scim_groups(&state, &org_id).await?;
+    let group = find_scim_group_mut(&mut groups, &group_id)?;
+
+    for operation in request.operations {
+        let path = operation.path.unwrap_or_default();
+        match (operation.op, path.as_str()) {
+            (scim_api::ScimPatchOp::Remove, path) if path.starts_with("members") => {
+                let member_ids = match parse_member_path_filter(path)? {
+                    Some(member_id) => vec![member_id],
+                    None => match operation.value {
+                        Some(value) => parse_members(value)?,
+                        None => get_group_members(&state, &org_id, group)
+                            .await?
+                            .into_iter()
+                            .map(|user_role| user_role.user_id)
+                            .collect(),
+                    },
+                };
+                for member_id in member_ids {
+                    remove_group_member(&state, &org_id, group, &member_id).await?;
+                }
+            }
+            (scim_api::ScimPatchOp::Add, "members") => {
+                let value = operation.value.ok_or(UserErrors::InvalidScimRequest(
+                    "value is required for add operations".to_string(),
+                ))?;
+                for member_id in parse_members(value)? {
+                    add_group_member(&state, &org_id, group, &member_id).await?;
+                }
+            }
+            (scim_api::ScimPatchOp::Replace, "members") => {
+                let value = operation.value.ok_or(UserErrors::InvalidScimRequest(
+                    "value is required for replace operations".to_string(),
+                ))?;
+                replace_group_members(&state, &org_id, group, &parse_members(value)?).await?;
+            }
+            (op @ (scim_api::ScimPatchOp::Add | scim_api::ScimPatchOp::Replace), path) => {
+                let value = operation.value.ok_or(UserErrors::InvalidScimRequest(
+                    "value is required for add and replace operations".to_string(),
+                ))?;
+                let attributes = if path.is_empty() {
+                    value
+                        .as_object()
+                        .cloned()
+                        .ok_or(UserErrors::InvalidScimRequest(
+                            "value must be an object when path is not provided".to_string(),
+                        ))?
+                } else {
+                    serde_json::Map::from_iter([(path.to_string(), value)])
+                };
+
+                for (attribute, value) in attributes {
+                    match attribute.as_str() {
+                        "displayName" => {
+                            group.display_name = value
+                                .as_str()
+                                .ok_or(UserErrors::InvalidScimRequest(
+                                    "displayName must be a string".to_string(),
+                                ))?
+                                .to_string();
+                        }
+                        "externalId" => group.external_id = value.as_str().map(str::to_string),
+                        "members" if op == scim_api::ScimPatchOp::Replace => {
+                            replace_group_members(&state, &org_id, group, &parse_members(value)?)
+                                .await?;
+                        }
+                        "members" => {
+                            for member_id in parse_members(value)? {
+                                add_group_member(&state, &org_id, group, &member_id).await?;
+                            }
+                        }
+                        attribute => {
+                            logger::info!("Ignoring unsupported SCIM group attribute {attribute}")
+                        }
+                    }
+                }
+            }
+            (scim_api::ScimPatchOp::Remove, path) => {
+                logger::info!("Ignoring remove operation on SCIM group attribute {path}")
+            }
+        }
+    }
+
+    group.last_modified_at = common_utils::date_time::now();
+    let group = group.clone();
+    update_scim_groups(&state, &org_id, &groups).await?;
+
+    let response = get_scim_group_response(&state, &org_id, group).await?;
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn delete_scim_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    group_id: String,
+) -> UserResponse<()> {
+    let mut groups = get_scim_groups(&state, &org_id).await?;
+    let group = find_scim_group(&groups, &group_id)?;
+
+    for member in get_group_members(&state, &org_id, &group).await? {
+        remove_group_member(&state, &org_id, &group, &member.user_id).await?;
+    }
+
+    groups.retain(|group| group.group_id != group_id);
+    update_scim_groups(&state, &org_id, &groups).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+#[derive(Default)]
+struct ScimUserAttributesUpdate {
+    active: Option<bool>,
+    external_id: Option<String>,
+    display_name: Option<String>,
+}
+
+impl ScimUserAttributesUpdate {
+    fn from_patch_operations(operations: Vec<scim_api::ScimPatchOperation>) -> UserResult<Self> {
+        let mut update = Self::default();
+        for operation in operations {
+            if operation.op == scim_api::ScimPatchOp::Remove {
+                continue;
+            }
+            let value = operation.value.ok_or(UserErrors::InvalidScimRequest(
+                "value is required for add and replace operations".to_string(),
+            ))?;
+
+            match operation.path {
+                Some(path) => update.set_attribute(&path, value)?,
+                None => {
+                    let attributes = value.as_object().ok_or(UserErrors::InvalidScimRequest(
+                        "value must be an object when path is not provided".to_string(),
+                    ))?;
+                    for (path, value) in attributes {
+                        update.set_attribute(path, value.clone())?;
+                    }
+                }
+            }
+        }
+        Ok(update)
+    }
+
+    fn set_attribute(&mut self, path: &str, value: serde_json::Value) -> UserResult<()> {
+        match path {
+            "active" => {
+                // Some identity providers send booleans as strings
+                let active = match &value {
+                    serde_json::Value::Bool(active) => Some(*active),
+                    serde_json::Value::String(active) => active.to_lowercase().parse().ok(),
+                    _ => None,
+                }
+                .ok_or(UserErrors::InvalidScimRequest(
+                    "active must be a boolean".to_string(),
+                ))?;
+                self.active = Some(active);
+            }
+            "externalId" => self.external_id = value.as_str().map(str::to_string),
+            "displayName" => self.display_name = value.as_str().map(str::to_string),
+            path => logger::info!("Ignoring unsupported SCIM user attribute {path}"),
+        }
+        Ok(())
+    }
+}
+
+async fn apply_scim_user_update(
+    state: &SessionState,
+    scim_user: ScimUser,
+    user: domain::UserFromStorage,
+    update: ScimUserAttributesUpdate,
+) -> UserResult<(ScimUser, domain::UserFromStorage)> {
+    // The name of a user is shared across organizations, it is updated only for users created
+    // by the identity provider of the organization
+    let user = match update.display_name {
+        Some(display_name)
+            if scim_user.is_user_created_by_scim && display_name != user.get_name().expose() =>
+        {
+            let name = domain::UserName::new(display_name.into())?;
+            state
+                .global_store
+                .update_user_by_user_id(
+                    user.get_user_id(),
+                    storage_user::UserUpdate::AccountUpdate {
+                        name: Some(name.get_secret().expose()),
+                        is_verified: None,
+                    },
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?
+                .into()
+        }
+        _ => user,
+    };
+
+    let is_deactivated = scim_user.active && update.active == Some(false);
+    let scim_user = state
+        .global_store
+        .update_scim_user_by_org_id_user_id(
+            &scim_user.org_id,
+            &scim_user.user_id,
+            ScimUserUpdate::Update {
+                external_id: update.external_id,
+                active: update.active,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if is_deactivated {
+        deprovision_user(state, &scim_user).await?;
+    }
+
+    Ok((scim_user, user))
+}
+
+/// Revokes the roles granted to the user through SCIM groups and invalidates their sessions in
+/// the organization. Roles assigned to the user outside of SCIM and sessions in other
+/// organizations are left untouched.
+async fn deprovision_user(state: &SessionState, scim_user: &ScimUser) -> UserResult<()> {
+    for group in get_scim_groups(state, &scim_user.org_id).await? {
+        delete_group_role(state, &scim_user.org_id, &group, &scim_user.user_id)
+            .await
+            .attach_printable("Failed to revoke user roles of deprovisioned user")?;
+    }
+
+    auth::blacklist::insert_user_org_in_blacklist(state, &scim_user.user_id, &scim_user.org_id)
+        .await
+}
+
+async fn is_user_in_org(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    user_id: &String,
+) -> UserResult<bool> {
+    state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: Some(user_id),
+            tenant_id: &state.tenant.tenant_id,
+            org_id,
+            merchant_id: None,
+            profile_id: None,
+            version: None,
+            limit: Some(1),
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|user_roles| !user_roles.is_empty())
+}
+
+/// Deletes the role granted by the group, if the user holds it
+async fn delete_group_role(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    group: &scim_api::ScimGroup,
+    user_id: &str,
+) -> UserResult<bool> {
+    match state
+        .global_store
+        .delete_user_role_by_user_id_and_role_id(
+            user_id,
+            &state.tenant.tenant_id,
+            org_id,
+            group.role_mapping.merchant_id.as_ref(),
+            group.role_mapping.profile_id.as_ref(),
+            &group.role_mapping.role_id,
+        )
+        .await
+    {
+        Ok(is_deleted) => Ok(is_deleted),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error.change_context(UserErrors::InternalServerError)),
+    }
+}
+
+async fn get_scim_user_and_user(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    user_id: &str,
+) -> UserResult<(ScimUser, domain::UserFromStorage)> {
+    let scim_user = state
+        .global_store
+        .find_scim_user_by_org_id_user_id(org_id, user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimUserNotFound)?;
+
+    let user = state
+        .global_store
+        .find_user_by_id(user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimUserNotFound)?
+        .into();
+
+    Ok((scim_user, user))
+}
+
+async fn get_scim_user_response(
+    state: &SessionState,
+    scim_user: ScimUser,
+    user: domain::UserFromStorage,
+    groups: &[scim_api::ScimGroup],
+) -> UserResult<scim_api::ScimUserResponse> {
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: Some(&scim_user.user_id),
+            tenant_id: &scim_user.tenant_id,
+            org_id: &scim_user.org_id,
+            merchant_id: None,
+            profile_id: None,
+            version: Some(UserRoleVersion::V2),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let user_groups = groups
+        .iter()
+        .filter(|group| {
+            user_roles
+                .iter()
+                .any(|user_role| is_user_role_in_group(user_role, group))
+        })
+        .map(|group| scim_api::ScimMember {
+            value: group.group_id.clone(),
+            display: Some(group.display_name.clone()),
+        })
+        .collect();
+
+    let email = user.get_email();
+    Ok(scim_api::ScimUserResponse {
+        schemas: vec![scim_api::SCIM_USER_SCHEMA.to_string()],
+        id: scim_user.user_id,
+        external_id: scim_user.external_id,
+        user_name: email.clone(),
+        name: scim_api::ScimName {
+            formatted: Some(user.get_name().expose()),
+            ..Default::default()
+        },
+        display_name: user.get_name().expose(),
+        emails: vec![scim_api::ScimEmail {
+            value: email,
+            primary: Some(true),
+            email_type: Some("work".to_string()),
+        }],
+        active: scim_user.active,
+        groups: user_groups,
+        meta: scim_api::ScimMeta {
+            resource_type: "User".to_string(),
+            created: scim_user.created_at,
+            last_modified: scim_user.last_modified_at,
+        },
+    })
+}
+
+async fn get_scim_groups(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+) -> UserResult<Vec<scim_api::ScimGroup>> {
+    match state
+        .store
+        .find_config_by_key(&org_id.get_scim_groups_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("Vec<ScimGroup>")
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("SCIM groups config has invalid structure"),
+        Err(error) if error.current_context().is_db_not_found() => Ok(Vec::new()),
+        Err(error) => Err(error)
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to fetch SCIM groups config"),
+    }
+}
+
+async fn update_scim_groups(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    groups: &[scim_api::ScimGroup],
+) -> UserResult<()> {
+    let key = org_id.get_scim_groups_key();
+    let config = groups
+        .encode_to_string_of_json()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize SCIM groups")?;
+
+    let config_update = storage::ConfigUpdate::Update {
+        config: Some(config.clone()),
+    };
+    match state.store.update_config_by_key(&key, config_update).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to insert SCIM groups config"),
+        Err(error) => Err(error)
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to update SCIM groups config"),
+    }
+}
+
+fn find_scim_group(
+    groups: &[scim_api::ScimGroup],
+    group_id: &str,
+) -> UserResult<scim_api::ScimGroup> {
+    groups
+        .iter()
+        .find(|group| group.group_id == group_id)
+        .cloned()
+        .ok_or(report!(UserErrors::ScimGroupNotFound))
+}
+
+fn find_scim_group_mut<'a>(
+    groups: &'a mut [scim_api::ScimGroup],
+    group_id: &str,
+) -> UserResult<&'a mut scim_api::ScimGroup> {
+    groups
+        .iter_mut()
+        .find(|group| group.group_id == group_id)
+        .ok_or(report!(UserErrors::ScimGroupNotFound))
+}
+
+async fn get_scim_group_response(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    group: scim_api::ScimGroup,
+) -> UserResult<scim_api::ScimGroupResponse> {
+    let members = get_group_members(state, org_id, &group)
+        .await?
+        .into_iter()
+        .map(|user_role| scim_api::ScimMember {
+            value: user_role.user_id,
+            display: None,
+        })
+        .collect();
+
+    Ok(scim_api::ScimGroupResponse {
+        schemas: vec![
+            scim_api::SCIM_GROUP_SCHEMA.to_string(),
+            scim_api::SCIM_GROUP_ROLE_EXTENSION_SCHEMA.to_string(),
+        ],
+        id: group.group_id,
+        external_id: group.external_id,
+        display_name: group.display_name,
+        members,
+        role_mapping: group.role_mapping,
+        meta: scim_api::ScimMeta {
+            resource_type: "Group".to_string(),
+            created: group.created_at,
+            last_modified: group.last_modified_at,
+        },
+    })
+}
+
+/// Ensures the group maps to an invitable role whose entity matches the level of the mapping
+async fn validate_group_role_mapping(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    role_mapping: &scim_api::ScimGroupRoleMapping,
+) -> UserResult<()> {
+    let entity_type = match (&role_mapping.merchant_id, &role_mapping.profile_id) {
+        (None, None) => EntityType::Organization,
+        (Some(_), None) => EntityType::Merchant,
+        (Some(_), Some(_)) => EntityType::Profile,
+        (None, Some(_)) => {
+            return Err(report!(UserErrors::InvalidScimRequest(
+                "merchantId is required when profileId is provided".to_string()
+            )))
+        }
+    };
+
+    let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &role_mapping.role_id,
+        org_id,
+        &state.tenant.tenant_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if !role_info.is_invitable() || role_info.get_entity_type() != entity_type {
+        return Err(report!(UserErrors::InvalidScimRequest(format!(
+            "Role {} cannot be assigned at {} level",
+            role_mapping.role_id, entity_type
+        ))));
+    }
+
+    if let Some(merchant_id) = &role_mapping.merchant_id {
+        let key_manager_state = &state.into();
+        let merchant_belongs_to_org = state
+            .store
+            .list_merchant_accounts_by_organization_id(key_manager_state, org_id)
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .iter()
+            .any(|merchant_account| merchant_account.get_id() == merchant_id);
+        if !merchant_belongs_to_org {
+            return Err(report!(UserErrors::InvalidScimRequest(
+                "Merchant does not belong to the organization".to_string()
+            )));
+        }
+
+        if let Some(profile_id) = &role_mapping.profile_id {
+            let key_store = state
+                .store
+                .get_merchant_key_store_by_merchant_id(
+                    key_manager_state,
+                    merchant_id,
+                    &state.store.get_master_key().to_vec().into(),
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+
+            state
+                .store
+                .find_business_profile_by_merchant_id_profile_id(
+                    key_manager_state,
+                    &key_store,
+                    merchant_id,
+                    profile_id,
+                )
+                .await
+                .to_not_found_response(UserErrors::InvalidScimRequest(
+                    "Profile does not belong to the merchant".to_string(),
+                ))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_user_role_in_group(user_role: &UserRole, group: &scim_api::ScimGroup) -> bool {
+    user_role.role_id == group.role_mapping.role_id
+        && user_role.merchant_id == group.role_mapping.merchant_id
+        && user_role.profile_id == group.role_mapping.profile_id
+}
+
+/// Two entities overlap when one of them is in the lineage of the other
+fn is_overlapping_entity(
+    user_role: &UserRole,
+    role_mapping: &scim_api::ScimGroupRoleMapping,
+) -> bool {
+    match (&user_role.merchant_id, &role_mapping.merchant_id) {
+        (None, _) | (_, None) => true,
+        (Some(merchant_id), Some(mapped_merchant_id)) => {
+            merchant_id == mapped_merchant_id
+                && match (&user_role.profile_id, &role_mapping.profile_id) {
+                    (None, _) | (_, None) => true,
+                    (Some(profile_id), Some(mapped_profile_id)) => profile_id == mapped_profile_id,
+                }
+        }
+    }
+}
+
+async fn get_group_members(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    group: &scim_api::ScimGroup,
+) -> UserResult<Vec<UserRole>> {
+    Ok(state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: None,
+            tenant_id: &state.tenant.tenant_id,
+            org_id,
+            merchant_id: group.role_mapping.merchant_id.as_ref(),
+            profile_id: group.role_mapping.profile_id.as_ref(),
+            version: Some(UserRoleVersion::V2),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .filter(|user_role| is_user_role_in_group(user_role, group))
+        .collect())
+}
+
+async fn add_group_member(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    group: &scim_api::ScimGroup,
+    user_id: &str,
+) -> UserResult<()> {
+    let scim_user = state
+        .global_store
+        .find_scim_user_by_org_id_user_id(org_id, user_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidScimRequest(format!(
+            "Member {user_id} is not provisioned in the organization"
+        )))?;
+
+    if !scim_user.active {
+        return Err(report!(UserErrors::InvalidScimRequest(format!(
+            "Member {user_id} is deactivated"
+        ))));
+    }
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_org_id(ListUserRolesByOrgIdPayload {
+            user_id: Some(&scim_user.user_id),
+            tenant_id: &scim_user.tenant_id,
+            org_id,
+            merchant_id: None,
+            profile_id: None,
+            version: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let role_mapping = &group.role_mapping;
+    match user_roles
+        .iter()
+        .find(|user_role| is_overlapping_entity(user_role, role_mapping))
+    {
+        Some(user_role) if is_user_role_in_group(user_role, group) => Ok(()),
+        Some(user_role)
+            if user_role.version == UserRoleVersion::V2
+                && user_role.merchant_id == role_mapping.merchant_id
+                && user_role.profile_id == role_mapping.profile_id =>
+        {
+            state
+                .global_store
+                .update_user_role_by_user_id_and_lineage(
+                    &scim_user.user_id,
+                    &scim_user.tenant_id,
+                    org_id,
+                    role_mapping.merchant_id.as_ref(),
+                    role_mapping.profile_id.as_ref(),
+                    UserRoleUpdate::UpdateRole {
+                        role_id: role_mapping.role_id.clone(),
+                        modified_by: consts::user::SCIM_PROVISIONER.to_string(),
+                    },
+                    UserRoleVersion::V2,
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)
+                .map(|_| ())
+        }
+        Some(_) => Err(report!(UserErrors::InvalidScimRequest(format!(
+            "Member {user_id} already has a role in the lineage of the group"
+        )))),
+        None => {
+            let now = common_utils::date_time::now();
+            let new_user_role = domain::NewUserRole {
+                user_id: scim_user.user_id.clone(),
+                role_id: role_mapping.role_id.clone(),
+                status: UserStatus::Active,
+                created_by: consts::user::SCIM_PROVISIONER.to_string(),
+                last_modified_by: consts::user::SCIM_PROVISIONER.to_string(),
+                created_at: now,
+                last_modified: now,
+                entity: domain::NoLevel,
+            };
+            let tenant_id = scim_user.tenant_id.clone();
+            let org_id = org_id.clone();
+
+            match (&role_mapping.merchant_id, &role_mapping.profile_id) {
+                (Some(merchant_id), Some(profile_id)) => {
+                    new_user_role
+                        .add_entity(domain::ProfileLevel {
+                            tenant_id,
+                            org_id,
+                            merchant_id: merchant_id.clone(),
+                            profile_id: profile_id.clone(),
+                        })
+                        .insert_in_v2(state)
+                        .await
+                }
+                (Some(merchant_id), None) => {
+                    new_user_role
+                        .add_entity(domain::MerchantLevel {
+                            tenant_id,
+                            org_id,
+                            merchant_id: merchant_id.clone(),
+                        })
+                        .insert_in_v2(state)
+                        .await
+                }
+                (None, _) => {
+                    new_user_role
+                        .add_entity(domain::OrganizationLevel { tenant_id, org_id })
+                        .insert_in_v2(state)
+                        .await
+                }
+            }
+            .map(|_| ())
+        }
+    }
+}
+
+async fn remove_group_member(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    group: &scim_api::ScimGroup,
+    user_id: &str,
+) -> UserResult<()> {
+    if !delete_group_role(state, org_id, group, user_id).await? {
+        return Ok(());
+    }
+
+    auth::blacklist::insert_user_org_in_blacklist(state, user_id, org_id).await
+}
+
+async fn replace_group_members(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    group: &scim_api::ScimGroup,
+    member_ids: &[String],
+) -> UserResult<()> {
+    for member in get_group_members(state, org_id, group).await? {
+        if !member_ids.contains(&member.user_id) {
+            remove_group_member(state, org_id, group, &member.user_id).await?;
+        }
+    }
+
+    for member_id in member_ids {
+        add_group_member(state, org_id, group, member_id).await?;
+    }
+
+    Ok(())
+}
+
+fn parse_members(value: serde_json::Value) -> UserResult<Vec<String>> {
+    serde_json::from_value::<Vec<scim_api::ScimMember>>(value)
+        .map(|members| members.into_iter().map(|member| member.value).collect())
+        .change_context(UserErrors::InvalidScimRequest(
+            "members must be a list of objects with a value".to_string(),
+        ))
+}
+
+/// Parses paths of the form `members[value eq "<user_id>"]`
+fn parse_member_path_filter(path: &str) -> UserResult<Option<String>> {
+    match path
+        .strip_prefix("members[")
+        .and_then(|filter| filter.strip_suffix(']'))
+    {
+        Some(filter) => {
+            let (attribute, value) = parse_scim_filter(filter)?;
+            if attribute != "value" {
+                return Err(report!(UserErrors::InvalidScimRequest(format!(
+                    "Unsupported members filter on {attribute}"
+                ))));
+            }
+            Ok(Some(value))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Parses the `<attribute> eq "<value>"` filters sent by identity providers
+fn parse_scim_filter(filter: &str) -> UserResult<(String, String)> {
+    let mut parts = filter.trim().splitn(3, ' ');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(attribute), Some(operator), Some(value)) if operator.eq_ignore_ascii_case("eq") => {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            Ok((attribute.to_string(), value.to_string()))
+        }
+        _ => Err(report!(UserErrors::InvalidScimRequest(format!(
+            "Unsupported filter: {filter}"
+        )))),
+    }
+}
+
+/// Applies the 1-based `startIndex` and `count` of the SCIM list request
+fn paginate<T>(items: Vec<T>, query: &scim_api::ScimListQuery) -> (u32, usize, Vec<T>) {
+    let start_index = query.start_index.unwrap_or(1).max(1);
+    let count = query
+        .count
+        .unwrap_or(consts::user::SCIM_MAX_PAGE_SIZE)
+        .min(consts::user::SCIM_MAX_PAGE_SIZE);
+    let total_results = items.len();
+
+    let items = items
+        .into_iter()
+        .skip(usize::try_from(start_index - 1).unwrap_or(usize::MAX))
+        .take(usize::try_from(count).unwrap_or_default())
+        .collect();
+
+    (start_index, total_results, items)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn list_query(start_index: Option<u32>, count: Option<u32>) -> scim_api::ScimListQuery {
+        scim_api::ScimListQuery {
+            filter: None,
+            start_index,
+            count,
+        }
+    }
+
+    fn patch_operations(operations: serde_json::Value) -> Vec<scim_api::ScimPatchOperation> {
+        serde_json::from_value(operations).unwrap()
+    }
+
+    #[test]
+    fn test_parse_scim_filter() {
+        assert_eq!(
+            parse_scim_filter(r#"userName eq "user@example.com""#).unwrap(),
+            ("userName".to_string(), "user@example.com".to_string())
+        );
+        assert_eq!(
+            parse_scim_filter("externalId EQ 1234").unwrap(),
+            ("externalId".to_string(), "1234".to_string())
+        );
+        assert!(parse_scim_filter(r#"userName co "user""#).is_err());
+        assert!(parse_scim_filter("userName").is_err());
+    }
+
+    #[test]
+    fn test_parse_member_path_filter() {
+        assert_eq!(
+            parse_member_path_filter(r#"members[value eq "user_1"]"#).unwrap(),
+            Some("user_1".to_string())
+        );
+        assert_eq!(parse_member_path_filter("members").unwrap(), None);
+        assert!(parse_member_path_filter(r#"members[display eq "user"]"#).is_err());
+    }
+
+    #[test]
+    fn test_paginate() {
+        let items = (1..=5).collect::<Vec<_>>();
+
+        assert_eq!(
+            paginate(items.clone(), &list_query(None, None)),
+            (1, 5, vec![1, 2, 3, 4, 5])
+        );
+        assert_eq!(
+            paginate(items.clone(), &list_query(Some(2), Some(2))),
+            (2, 5, vec![2, 3])
+        );
+        assert_eq!(
+            paginate(items.clone(), &list_query(Some(0), Some(1))),
+            (1, 5, vec![1])
+        );
+        assert_eq!(
+            paginate(items, &list_query(Some(10), None)),
+            (10, 5, Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_paginate_caps_count_at_max_page_size() {
+        let items = (0..consts::user::SCIM_MAX_PAGE_SIZE + 10).collect::<Vec<_>>();
+        let (_, total_results, page) = paginate(items, &list_query(None, Some(u32::MAX)));
+
+        assert_eq!(
+            total_results,
+            usize::try_from(consts::user::SCIM_MAX_PAGE_SIZE + 10).unwrap()
+        );
+        assert_eq!(
+            page.len(),
+            usize::try_from(consts::user::SCIM_MAX_PAGE_SIZE).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_user_patch_operations() {
+        let update =
+            ScimUserAttributesUpdate::from_patch_operations(patch_operations(serde_json::json!([
+                { "op": "replace", "path": "active", "value": "False" },
+                { "op": "Add", "value": { "externalId": "ext_1", "displayName": "User" } },
+                { "op": "remove", "path": "displayName" }
+            ])))
+            .unwrap();
+
+        assert_eq!(update.active, Some(false));
+        assert_eq!(update.external_id.as_deref(), Some("ext_1"));
+        assert_eq!(update.display_name.as_deref(), Some("User"));
+    }
+
+    #[test]
+    fn test_user_patch_operations_reject_invalid_values() {
+        let missing_value = patch_operations(serde_json::json!([
+            { "op": "replace", "path": "active" }
+        ]));
+        assert!(ScimUserAttributesUpdate::from_patch_operations(missing_value).is_err());
+
+        let invalid_active = patch_operations(serde_json::json!([
+            { "op": "replace", "path": "active", "value": 1 }
+        ]));
+        assert!(ScimUserAttributesUpdate::from_patch_operations(invalid_active).is_err());
+
+        let non_object_value = patch_operations(serde_json::json!([
+            { "op": "replace", "value": true }
+        ]));
+        assert!(ScimUserAttributesUpdate::from_patch_operations(non_object_value).is_err());
+    }
+
+    #[test]
+    fn test_parse_members() {
+        assert_eq!(
+            parse_members(serde_json::json!([{ "value": "user_1" }, { "value": "user_2" }]))
+                .unwrap(),
+            vec!["user_1".to_string(), "user_2".to_string()]
+        );
+        assert!(parse_members(serde_json::json!({ "value": "user_1" })).is_err());
+    }
+}
@@ -6,7 +6,7 @@ use std::{collections::HashSet, marker::PhantomData, str::FromStr};
 use api_models::enums::{Connector, DisputeStage, DisputeStatus};
 #[cfg(feature = "payouts")]
 use api_models::payouts::PayoutVendorAccountDetails;
-use common_enums::{IntentStatus, RequestIncrementalAuthorization};
+use common_enums::{CountryAlpha2, IntentStatus, RequestIncrementalAuthorization};
 #[cfg(feature = "payouts")]
 use common_utils::{crypto::Encryptable, pii::Email};
 use common_utils::{
@@ -777,6 +777,28 @@ pub fn get_split_refunds(
         _ => Ok(None),
     }
 }
+
+/// Resolves the issuing country of a card to its alpha-2 code. The card info table stores
+/// issuing countries as ISO country names, so both alpha-2 codes and full names are accepted.
+pub fn get_card_issuing_country_code(card_issuing_country: &str) -> Option<CountryAlpha2> {
+    use strum::IntoEnumIterator;
+
+    let normalize = |country: &str| {
+        country
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_uppercase()
+    };
+    let issuing_country = normalize(card_issuing_country);
+
+    CountryAlpha2::from_str(&issuing_country).ok().or_else(|| {
+        common_enums::Country::iter()
+            .find(|country| normalize(&country.to_string()) == issuing_country)
+            .map(common_enums::Country::to_alpha2)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
@@ -801,6 +823,25 @@ mod tests {
         assert_eq!(result, payment_id);
     }
 
+    #[test]
+    fn test_get_card_issuing_country_code() {
+        assert_eq!(
+            get_card_issuing_country_code("UNITED STATES OF AMERICA"),
+            Some(CountryAlpha2::US)
+        );
+        assert_eq!(get_card_issuing_country_code("de"), Some(CountryAlpha2::DE));
+        assert_eq!(
+            get_card_issuing_country_code("INDIA"),
+            Some(CountryAlpha2::IN)
+        );
+        // Niger is a prefix of Nigeria, names must match exactly
+        assert_eq!(
+            get_card_issuing_country_code("NIGER"),
+            Some(CountryAlpha2::NE)
+        );
+        assert_eq!(get_card_issuing_country_code("UNITED"), None);
+    }
+
     #[test]
     fn test_generate_id() {
         let generated_id = generate_id(consts::ID_LENGTH, "ref");
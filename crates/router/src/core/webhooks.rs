@@ -18,7 +18,7 @@ pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        reset_webhook_endpoint_delivery_status, trigger_webhook_and_raise_event,
     },
 };
 #[cfg(feature = "v2")]
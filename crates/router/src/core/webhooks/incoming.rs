@@ -1647,6 +1647,27 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        #[cfg(feature = "frm")]
+        {
+            let feedback = crate::core::fraud_check::types::FrmFeedbackData {
+                feedback_type: common_enums::FrmFeedbackType::Chargeback,
+                amount: Some(common_utils::types::MinorUnit::new(
+                    dispute_object.dispute_amount,
+                )),
+                refund_id: None,
+                dispute_id: Some(dispute_object.dispute_id.clone()),
+                dispute_status: Some(dispute_object.dispute_status),
+            };
+            if let Err(error) =
+                crate::core::fraud_check::add_frm_feedback_task(&state, &payment_attempt, feedback)
+                    .await
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to schedule chargeback feedback to frm connector"
+                );
+            }
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -5,12 +5,13 @@ use api_models::{
     webhooks,
 };
 use common_utils::{
+    crypto::SignMessage,
     ext_traits::{Encode, StringExt},
     request::RequestContent,
     type_name,
     types::keymanager::{Identifier, KeyManagerState},
 };
-use diesel_models::process_tracker::business_status;
+use diesel_models::{business_profile::WebhookEndpoint, process_tracker::business_status};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
 use hyperswitch_interfaces::consts;
@@ -59,11 +60,76 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let webhook_endpoint_ids = get_subscribed_webhook_endpoint_ids(&business_profile, event_type);
+
+    let result = Box::pin(create_event_and_trigger_outgoing_webhook_for_endpoint(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile.clone(),
+        event_type,
+        event_class,
+        primary_object_id.clone(),
+        primary_object_type,
+        content.clone(),
+        primary_object_created_at,
+        None,
+    ))
+    .await;
+
+    for endpoint_id in webhook_endpoint_ids {
+        let _ = Box::pin(create_event_and_trigger_outgoing_webhook_for_endpoint(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            Some(endpoint_id),
+        ))
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to trigger outgoing webhook to profile webhook endpoint"
+            );
+        });
+    }
+
+    result
+}
+
+/// Creates an event for delivering the webhook either to the profile's `webhook_url` (when
+/// `endpoint_id` is `None`) or to one of the profile's webhook endpoints, and triggers the
+/// delivery.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_endpoint(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    endpoint_id: Option<String>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
         utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    let idempotent_event_id = match &endpoint_id {
+        Some(endpoint_id) => format!("{idempotent_event_id}_{endpoint_id}"),
+        None => idempotent_event_id,
+    };
+    let webhook_url_result =
+        get_webhook_target_from_business_profile(&state, &business_profile, endpoint_id.as_deref())
+            .await
+            .map(|(webhook_url, _)| webhook_url);
 
     if !state.conf.webhooks.outgoing_enabled
         || webhook_url_result.is_err()
@@ -90,10 +156,12 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
+    let request_content = OutgoingWebhookRequestContent {
+        endpoint_id,
+        ..get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
             .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+            .attach_printable("Failed to construct outgoing webhook request content")?
+    };
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -294,11 +362,16 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let (webhook_url, webhook_endpoint) = match (
+        get_webhook_target_from_business_profile(
+            &state,
+            &business_profile,
+            request_content.endpoint_id.as_deref(),
+        )
+        .await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_target), _) => Ok(webhook_target),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -321,11 +394,25 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
-    let headers = request_content
+    let mut headers: Vec<(String, services::request::Maskable<String>)> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+    if let Some(webhook_endpoint) = &webhook_endpoint {
+        let signing_secrets =
+            get_webhook_endpoint_signing_secrets(&state, merchant_key_store, webhook_endpoint)
+                .await?;
+        let signature = sign_webhook_payload(
+            &signing_secrets,
+            common_utils::date_time::now_unix_timestamp(),
+            request_content.body.peek(),
+        )?;
+        headers.push((
+            crate::headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+            signature.into_masked(),
+        ));
+    }
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
@@ -347,6 +434,22 @@ async fn trigger_webhook_to_merchant(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
+    if let Some(webhook_endpoint) = &webhook_endpoint {
+        let is_delivery_successful = response
+            .as_ref()
+            .is_ok_and(|response| response.status().is_success());
+        let _ = update_webhook_endpoint_delivery_status(
+            &state,
+            business_profile.get_id(),
+            &webhook_endpoint.endpoint_id,
+            is_delivery_successful,
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to update webhook endpoint delivery status");
+        });
+    }
+
     match delivery_attempt {
         enums::WebhookDeliveryAttempt::InitialAttempt => match response {
             Err(client_error) => {
@@ -655,6 +758,280 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+fn get_subscribed_webhook_endpoint_ids(
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+) -> Vec<String> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_endpoints.as_ref())
+        .map(|webhook_endpoints| {
+            webhook_endpoints
+                .iter()
+                .filter(|webhook_endpoint| {
+                    !webhook_endpoint.disabled.unwrap_or(false)
+                        && webhook_endpoint
+                            .enabled_events
+                            .as_ref()
+                            .map_or(true, |enabled_events| enabled_events.contains(&event_type))
+                })
+                .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Obtains the URL to which a webhook is to be delivered, along with the webhook endpoint
+/// configuration when the webhook targets one of the profile's webhook endpoints.
+async fn get_webhook_target_from_business_profile(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    endpoint_id: Option<&str>,
+) -> CustomResult<(String, Option<WebhookEndpoint>), errors::WebhooksFlowError> {
+    let Some(endpoint_id) = endpoint_id else {
+        return get_webhook_url_from_business_profile(business_profile)
+            .map(|webhook_url| (webhook_url, None));
+    };
+
+    let webhook_endpoint = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_endpoints.as_ref())
+        .and_then(|webhook_endpoints| {
+            webhook_endpoints
+                .iter()
+                .find(|webhook_endpoint| webhook_endpoint.endpoint_id == endpoint_id)
+        })
+        .cloned()
+        .ok_or(errors::WebhooksFlowError::WebhookEndpointNotFound)
+        .attach_printable_lazy(|| format!("webhook endpoint `{endpoint_id}` not found"))?;
+
+    if webhook_endpoint.disabled.unwrap_or(false)
+        || is_webhook_endpoint_auto_disabled(state, business_profile.get_id(), endpoint_id).await
+    {
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+            .attach_printable_lazy(|| format!("webhook endpoint `{endpoint_id}` is disabled"));
+    }
+
+    Ok((
+        webhook_endpoint.url.clone().expose(),
+        Some(webhook_endpoint),
+    ))
+}
+
+/// Decrypts the secrets the webhooks delivered to a webhook endpoint are to be signed with: the
+/// current signing secret, and the previous signing secret while it is being rotated out.
+async fn get_webhook_endpoint_signing_secrets(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &WebhookEndpoint,
+) -> CustomResult<Vec<Secret<String>>, errors::WebhooksFlowError> {
+    let now = common_utils::date_time::now();
+    let previous_signing_secret = webhook_endpoint
+        .previous_signing_secret
+        .as_ref()
+        .filter(|_| {
+            webhook_endpoint
+                .previous_signing_secret_expires_at
+                .map_or(true, |expires_at| expires_at > now)
+        });
+
+    let key_manager_state = &state.into();
+    let mut signing_secrets = Vec::new();
+    for signing_secret in
+        std::iter::once(&webhook_endpoint.signing_secret).chain(previous_signing_secret)
+    {
+        let signing_secret = crypto_operation::<String, masking::WithType>(
+            key_manager_state,
+            type_name!(domain::Profile),
+            CryptoOperation::Decrypt(signing_secret.clone()),
+            Identifier::Merchant(merchant_key_store.merchant_id.clone()),
+            merchant_key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|signing_secret| signing_secret.try_into_operation())
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to decrypt webhook endpoint signing secret")?
+        .into_inner();
+        signing_secrets.push(signing_secret);
+    }
+
+    Ok(signing_secrets)
+}
+
+/// Signs the webhook payload for a webhook endpoint, in the format
+/// `t={timestamp},v1={signature}[,v1={signature}]`.
+///
+/// The signature is the hex encoded HMAC-SHA512 of `{timestamp}.{payload}`, computed at delivery
+/// time so that merchants can reject stale or replayed deliveries. A signature is included for
+/// each of the signing secrets, so that merchants can verify deliveries while a signing secret is
+/// being rotated.
+fn sign_webhook_payload(
+    signing_secrets: &[Secret<String>],
+    timestamp: i64,
+    payload: &str,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let signature_payload = format!("{timestamp}.{payload}");
+
+    let signatures = signing_secrets
+        .iter()
+        .map(|signing_secret| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                signing_secret.peek().as_bytes(),
+                signature_payload.as_bytes(),
+            )
+            .map(|signature| format!("v1={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the webhook payload for webhook endpoint")?;
+
+    Ok(format!("t={timestamp},{}", signatures.join(",")))
+}
+
+const WEBHOOK_ENDPOINT_FAILURES_PREFIX: &str = "WEBHOOK_ENDPOINT_FAILURES";
+const WEBHOOK_ENDPOINT_DISABLED_PREFIX: &str = "WEBHOOK_ENDPOINT_DISABLED";
+
+fn get_webhook_endpoint_redis_key(
+    prefix: &str,
+    profile_id: &common_utils::id_type::ProfileId,
+    endpoint_id: &str,
+) -> String {
+    format!("{prefix}_{}_{endpoint_id}", profile_id.get_string_repr())
+}
+
+/// Checks whether a webhook endpoint has been disabled after sustained delivery failures.
+///
+/// Endpoints are treated as enabled when the status cannot be read, so that a redis outage does
+/// not stop webhook deliveries.
+async fn is_webhook_endpoint_auto_disabled(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    endpoint_id: &str,
+) -> bool {
+    let disabled_key =
+        get_webhook_endpoint_redis_key(WEBHOOK_ENDPOINT_DISABLED_PREFIX, profile_id, endpoint_id);
+
+    match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .exists::<Vec<u8>>(&disabled_key.as_str().into())
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to read webhook endpoint status");
+            })
+            .unwrap_or(false),
+        Err(error) => {
+            logger::error!(?error, "Error connecting to redis");
+            false
+        }
+    }
+}
+
+/// Re-enables webhook endpoints which were disabled after sustained delivery failures, and resets
+/// their failure counts.
+pub(crate) async fn reset_webhook_endpoint_delivery_status(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    endpoint_ids: &[String],
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+        .attach_printable("Error connecting to redis")?;
+
+    for endpoint_id in endpoint_ids {
+        for prefix in [
+            WEBHOOK_ENDPOINT_DISABLED_PREFIX,
+            WEBHOOK_ENDPOINT_FAILURES_PREFIX,
+        ] {
+            let key = get_webhook_endpoint_redis_key(prefix, profile_id, endpoint_id);
+            redis_conn
+                .delete_key(&key.as_str().into())
+                .await
+                .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+                .attach_printable("Failed to reset webhook endpoint delivery status")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Tracks consecutive delivery failures to a webhook endpoint, and disables the endpoint once the
+/// configured failure threshold is reached.
+///
+/// The disabled status is kept in redis rather than in the profile, so that concurrent deliveries
+/// cannot overwrite profile updates made in the meantime. Updating the profile with the endpoint
+/// re-enables it.
+async fn update_webhook_endpoint_delivery_status(
+    state: &SessionState,
+    profile_id: &common_utils::id_type::ProfileId,
+    endpoint_id: &str,
+    is_delivery_successful: bool,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let failure_threshold = state.conf.webhooks.endpoint_failure_threshold;
+    if failure_threshold == 0 {
+        return Ok(());
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+        .attach_printable("Error connecting to redis")?;
+    let failures_key =
+        get_webhook_endpoint_redis_key(WEBHOOK_ENDPOINT_FAILURES_PREFIX, profile_id, endpoint_id);
+
+    if is_delivery_successful {
+        redis_conn
+            .delete_key(&failures_key.as_str().into())
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+            .attach_printable("Failed to reset webhook endpoint failure count")?;
+        return Ok(());
+    }
+
+    let failure_count = redis_conn
+        .increment_fields_in_hash(&failures_key.as_str().into(), &[("failures", 1)])
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+        .attach_printable("Failed to increment webhook endpoint failure count")?
+        .first()
+        .copied()
+        .unwrap_or_default();
+    redis_conn
+        .set_expiry(
+            &failures_key.as_str().into(),
+            i64::from(state.conf.webhooks.endpoint_failure_window_seconds),
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+        .attach_printable("Failed to set expiry for webhook endpoint failure count")?;
+
+    if u32::try_from(failure_count).unwrap_or(u32::MAX) < failure_threshold {
+        return Ok(());
+    }
+
+    let disabled_key =
+        get_webhook_endpoint_redis_key(WEBHOOK_ENDPOINT_DISABLED_PREFIX, profile_id, endpoint_id);
+    redis_conn
+        .set_key(&disabled_key.as_str().into(), "true")
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEndpointStatusUpdateFailed)
+        .attach_printable("Failed to disable webhook endpoint")?;
+
+    logger::warn!(
+        business_profile_id = ?profile_id,
+        %endpoint_id,
+        failure_count,
+        "Disabled webhook endpoint after sustained delivery failures"
+    );
+
+    Ok(())
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
@@ -709,6 +1086,7 @@ pub(crate) fn get_outgoing_webhook_request(
                 .into_iter()
                 .map(|(name, value)| (name, Secret::new(value.into_inner())))
                 .collect(),
+            endpoint_id: None,
         })
     }
 
@@ -1026,6 +1404,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1070,5 +1453,58 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use common_utils::crypto::VerifySignature;
+
+    use super::*;
+
+    #[test]
+    fn test_sign_webhook_payload_with_rotated_secrets() {
+        let signing_secrets = [
+            Secret::new("whsec_current".to_string()),
+            Secret::new("whsec_previous".to_string()),
+        ];
+        let payload = r#"{"event_type":"payment_succeeded"}"#;
+
+        let signature = sign_webhook_payload(&signing_secrets, 1_700_000_000, payload).unwrap();
+        let parts = signature.split(',').collect::<Vec<_>>();
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], "t=1700000000");
+        for (part, signing_secret) in parts[1..].iter().zip(signing_secrets.iter()) {
+            let signature = hex::decode(part.strip_prefix("v1=").unwrap()).unwrap();
+            assert!(common_utils::crypto::HmacSha512
+                .verify_signature(
+                    signing_secret.peek().as_bytes(),
+                    &signature,
+                    format!("1700000000.{payload}").as_bytes(),
+                )
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_sign_webhook_payload_depends_on_timestamp() {
+        let signing_secrets = [Secret::new("whsec_current".to_string())];
+
+        let first = sign_webhook_payload(&signing_secrets, 1_700_000_000, "{}").unwrap();
+        let second = sign_webhook_payload(&signing_secrets, 1_700_000_001, "{}").unwrap();
+
+        assert_ne!(
+            first.split_once(',').unwrap().1,
+            second.split_once(',').unwrap().1
+        );
+    }
+}
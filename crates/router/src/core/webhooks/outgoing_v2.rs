@@ -365,6 +365,7 @@ pub(crate) fn get_outgoing_webhook_request(
                 .into_iter()
                 .map(|(name, value)| (name, masking::Secret::new(value.into_inner())))
                 .collect(),
+            endpoint_id: None,
         })
     }
 
@@ -621,6 +622,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.id.get_string_repr().to_owned(),
+                }
+            }
         }
     }
 }
@@ -662,6 +668,12 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+                Self::PaymentMethod {
+                    payment_method_id,
+                    content: serde_json::Value::Null,
+                }
+            }
         }
     }
 }
@@ -80,6 +80,75 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) initial_attempt_id: Option<String>,
 }
 
+/// Maximum number of identifiers of events that could not be delivered, retained in the tracking
+/// data of a bulk resend job.
+pub(crate) const WEBHOOK_EVENTS_BULK_RESEND_MAX_FAILED_EVENT_IDS: usize = 100;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WebhookEventsBulkResendTrackingData {
+    pub(crate) batch_id: String,
+    pub(crate) merchant_id: common_utils::id_type::MerchantId,
+    pub(crate) business_profile_id: common_utils::id_type::ProfileId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub(crate) created_after: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub(crate) created_before: time::PrimitiveDateTime,
+    pub(crate) event_types: std::collections::HashSet<enums::EventType>,
+    pub(crate) is_delivered: Option<bool>,
+    pub(crate) total_count: i64,
+    pub(crate) processed_count: i64,
+    pub(crate) succeeded_count: i64,
+    pub(crate) failed_count: i64,
+    /// Identifiers of the first events that could not be delivered, capped at
+    /// `WEBHOOK_EVENTS_BULK_RESEND_MAX_FAILED_EVENT_IDS`.
+    pub(crate) failed_event_ids: Vec<String>,
+    /// Number of batches of events resent so far.
+    #[serde(default)]
+    pub(crate) batch_count: u32,
+    /// `(created_at, event_id)` of the last event resent, the next batch starts after this event.
+    #[serde(default)]
+    pub(crate) last_event_key: Option<WebhookEventsBulkResendKey>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WebhookEventsBulkResendKey {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub(crate) created_at: time::PrimitiveDateTime,
+    pub(crate) event_id: String,
+}
+
+impl WebhookEventsBulkResendTrackingData {
+    /// Records the outcome of resending an event, and moves the position of the job past the
+    /// event.
+    pub(crate) fn record_resent_event(
+        &mut self,
+        event_id: String,
+        created_at: time::PrimitiveDateTime,
+        is_delivered: bool,
+    ) {
+        self.processed_count += 1;
+        if is_delivered {
+            self.succeeded_count += 1;
+        } else {
+            self.failed_count += 1;
+            if self.failed_event_ids.len() < WEBHOOK_EVENTS_BULK_RESEND_MAX_FAILED_EVENT_IDS {
+                self.failed_event_ids.push(event_id.clone());
+            }
+        }
+        self.last_event_key = Some(WebhookEventsBulkResendKey {
+            created_at,
+            event_id,
+        });
+    }
+
+    /// `(created_at, event_id)` after which the next batch of events to be resent starts.
+    pub(crate) fn get_next_batch_key(&self) -> Option<(time::PrimitiveDateTime, String)> {
+        self.last_event_key
+            .clone()
+            .map(|key| (key.created_at, key.event_id))
+    }
+}
+
 pub struct WebhookResponse {
     pub response: reqwest::Response,
 }
@@ -126,3 +195,88 @@ impl WebhookResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_tracking_data() -> WebhookEventsBulkResendTrackingData {
+        let now = common_utils::date_time::now();
+        WebhookEventsBulkResendTrackingData {
+            batch_id: "whbatch_test".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            business_profile_id: common_utils::id_type::ProfileId::try_from(
+                std::borrow::Cow::from("pro_1"),
+            )
+            .unwrap(),
+            created_after: now,
+            created_before: now,
+            event_types: std::collections::HashSet::new(),
+            is_delivered: Some(false),
+            total_count: 0,
+            processed_count: 0,
+            succeeded_count: 0,
+            failed_count: 0,
+            failed_event_ids: Vec::new(),
+            batch_count: 0,
+            last_event_key: None,
+        }
+    }
+
+    #[test]
+    fn test_record_resent_event_moves_past_delivered_and_failed_events() {
+        let mut tracking_data = get_tracking_data();
+        let created_at = common_utils::date_time::now();
+
+        tracking_data.record_resent_event("evt_1".to_string(), created_at, true);
+        tracking_data.record_resent_event("evt_2".to_string(), created_at, false);
+
+        assert_eq!(tracking_data.processed_count, 2);
+        assert_eq!(tracking_data.succeeded_count, 1);
+        assert_eq!(tracking_data.failed_count, 1);
+        assert_eq!(tracking_data.failed_event_ids, vec!["evt_2".to_string()]);
+        assert_eq!(
+            tracking_data.get_next_batch_key(),
+            Some((created_at, "evt_2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_record_resent_event_caps_failed_event_ids() {
+        let mut tracking_data = get_tracking_data();
+        let created_at = common_utils::date_time::now();
+
+        for index in 0..=WEBHOOK_EVENTS_BULK_RESEND_MAX_FAILED_EVENT_IDS {
+            tracking_data.record_resent_event(format!("evt_{index}"), created_at, false);
+        }
+
+        assert_eq!(
+            tracking_data.failed_event_ids.len(),
+            WEBHOOK_EVENTS_BULK_RESEND_MAX_FAILED_EVENT_IDS
+        );
+        assert_eq!(
+            tracking_data.failed_count,
+            i64::try_from(WEBHOOK_EVENTS_BULK_RESEND_MAX_FAILED_EVENT_IDS + 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tracking_data_without_key_starts_from_first_event() {
+        let tracking_data = get_tracking_data();
+        let value = serde_json::to_value(&tracking_data).unwrap();
+        let mut value = value.as_object().unwrap().clone();
+        value.remove("batch_count");
+        value.remove("last_event_key");
+
+        let tracking_data: WebhookEventsBulkResendTrackingData =
+            serde_json::from_value(serde_json::Value::Object(value)).unwrap();
+
+        assert_eq!(tracking_data.batch_count, 0);
+        assert_eq!(tracking_data.get_next_batch_key(), None);
+    }
+}
@@ -3,18 +3,20 @@ use std::collections::HashSet;
 use common_utils::{self, errors::CustomResult, fp_utils};
 use error_stack::ResultExt;
 use masking::PeekInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
     services::ApplicationResponse,
     types::{api, domain, storage, transformers::ForeignTryFrom},
-    utils::{OptionExt, StringExt},
+    utils::{OptionExt, StringExt, ValueExt},
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+pub(crate) const WEBHOOK_EVENTS_BULK_RESEND_TASK: &str = "WEBHOOK_EVENTS_BULK_RESEND";
+const WEBHOOK_EVENTS_BULK_RESEND_TAG: &str = "OUTGOING_WEBHOOKS";
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -272,6 +274,7 @@ pub async fn retry_delivery_attempt(
 
     let business_profile_id = event_to_retry
         .business_profile_id
+        .clone()
         .get_required_value("business_profile_id")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to read business profile ID from event to retry")?;
@@ -281,6 +284,25 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to find business profile")?;
 
+    let updated_event = resend_event(&state, &key_store, business_profile, event_to_retry).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+/// Creates a manual retry delivery attempt for the specified event, delivers it and returns the
+/// delivery attempt with the delivery outcome populated.
+#[cfg(feature = "v1")]
+pub(crate) async fn resend_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    event_to_retry: domain::Event,
+) -> errors::RouterResult<domain::Event> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
@@ -311,7 +333,7 @@ pub async fn retry_delivery_attempt(
     };
 
     let event = store
-        .insert_event(key_manager_state, new_event, &key_store)
+        .insert_event(key_manager_state, new_event, key_store)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert event")?;
@@ -330,7 +352,7 @@ pub async fn retry_delivery_attempt(
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
-        &key_store,
+        key_store,
         event,
         request_content,
         delivery_attempt,
@@ -339,19 +361,197 @@ pub async fn retry_delivery_attempt(
     ))
     .await;
 
-    let updated_event = store
+    store
         .find_event_by_merchant_id_event_id(
             key_manager_state,
             &key_store.merchant_id,
             &new_event_id,
-            &key_store,
+            key_store,
         )
         .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+}
 
-    Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
-    ))
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn bulk_resend_events(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookEventsBulkResendRequest,
+) -> RouterResponse<api::webhook_events::WebhookEventsBulkResendResponse> {
+    let store = state.store.as_ref();
+
+    let (account, _) = get_account_and_key_store(
+        state.clone(),
+        merchant_id.clone(),
+        Some(request.profile_id.clone()),
+    )
+    .await?;
+    let business_profile = match account {
+        MerchantAccountOrProfile::Profile(business_profile) => Ok(business_profile),
+        MerchantAccountOrProfile::MerchantAccount(_) => {
+            Err(errors::ApiErrorResponse::InternalServerError)
+        }
+    }?;
+
+    let now = common_utils::date_time::now();
+    let events_list_begin_time =
+        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
+    let created_after = request.created_after;
+    let created_before = request.created_before.unwrap_or(now);
+
+    fp_utils::when(created_after > created_before, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        })
+    })?;
+    fp_utils::when(created_after < events_list_begin_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."),
+        })
+    })?;
+
+    let event_types = request.event_types.unwrap_or_default();
+    let total_count = store
+        .count_initial_events_by_constraints(
+            &merchant_id,
+            Some(business_profile.get_id().to_owned()),
+            created_after,
+            created_before,
+            event_types.clone(),
+            request.is_delivered,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get total events count")?;
+
+    if total_count == 0 {
+        return Err(error_stack::report!(errors::ApiErrorResponse::EventNotFound))
+            .attach_printable("No events found matching the specified constraints");
+    }
+
+    let batch_id = common_utils::generate_time_ordered_id("whbatch");
+    let tracking_data = super::types::WebhookEventsBulkResendTrackingData {
+        batch_id: batch_id.clone(),
+        merchant_id: merchant_id.clone(),
+        business_profile_id: business_profile.get_id().to_owned(),
+        created_after,
+        created_before,
+        event_types,
+        is_delivered: request.is_delivered,
+        total_count,
+        processed_count: 0,
+        succeeded_count: 0,
+        failed_count: 0,
+        failed_event_ids: Vec::new(),
+        batch_count: 0,
+        last_event_key: None,
+    };
+
+    let runner = storage::ProcessTrackerRunner::WebhookEventsBulkResendWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        WEBHOOK_EVENTS_BULK_RESEND_TASK,
+        &batch_id,
+        &merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        WEBHOOK_EVENTS_BULK_RESEND_TASK,
+        runner,
+        [WEBHOOK_EVENTS_BULK_RESEND_TAG],
+        &tracking_data,
+        None,
+        now,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct bulk resend process tracker task")?;
+
+    let attributes = router_env::metric_attributes!(("flow", "WebhookEventsBulkResend"));
+    let process_tracker = store
+        .insert_process(process_tracker_entry)
+        .await
+        .inspect(|_| crate::routes::metrics::TASKS_ADDED_COUNT.add(1, attributes))
+        .inspect_err(|_| crate::routes::metrics::TASK_ADDITION_FAILURES_COUNT.add(1, attributes))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert bulk resend process tracker task")?;
+
+    logger::info!(%batch_id, total_count, "Scheduled bulk resend of webhook events");
+
+    Ok(ApplicationResponse::Json(get_bulk_resend_response(
+        &process_tracker,
+        tracking_data,
+    )))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_bulk_resend_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    batch_id: String,
+) -> RouterResponse<api::webhook_events::WebhookEventsBulkResendResponse> {
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::WebhookEventsBulkResendWorkflow,
+        WEBHOOK_EVENTS_BULK_RESEND_TASK,
+        &batch_id,
+        &merchant_id,
+    );
+
+    let process_tracker = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find bulk resend process tracker task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Bulk resend job `{batch_id}` not found"),
+        })?;
+
+    let tracking_data: super::types::WebhookEventsBulkResendTrackingData = process_tracker
+        .tracking_data
+        .clone()
+        .parse_value("WebhookEventsBulkResendTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse bulk resend tracking data")?;
+
+    Ok(ApplicationResponse::Json(get_bulk_resend_response(
+        &process_tracker,
+        tracking_data,
+    )))
+}
+
+#[cfg(feature = "v1")]
+fn get_bulk_resend_response(
+    process_tracker: &storage::ProcessTracker,
+    tracking_data: super::types::WebhookEventsBulkResendTrackingData,
+) -> api::webhook_events::WebhookEventsBulkResendResponse {
+    use api::webhook_events::WebhookEventsBulkResendStatus;
+
+    let status = match process_tracker.status {
+        storage::enums::ProcessTrackerStatus::Finish
+            if process_tracker.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            WebhookEventsBulkResendStatus::Completed
+        }
+        storage::enums::ProcessTrackerStatus::Finish => WebhookEventsBulkResendStatus::Failed,
+        _ if tracking_data.processed_count == 0 => WebhookEventsBulkResendStatus::Scheduled,
+        _ => WebhookEventsBulkResendStatus::InProgress,
+    };
+
+    api::webhook_events::WebhookEventsBulkResendResponse {
+        batch_id: tracking_data.batch_id,
+        merchant_id: tracking_data.merchant_id,
+        profile_id: tracking_data.business_profile_id,
+        status,
+        total_count: tracking_data.total_count,
+        processed_count: tracking_data.processed_count,
+        succeeded_count: tracking_data.succeeded_count,
+        failed_count: tracking_data.failed_count,
+        failed_event_ids: tracking_data.failed_event_ids,
+        created: process_tracker.created_at,
+    }
 }
 
 async fn get_account_and_key_store(
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod approval_request;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -117,6 +118,7 @@ pub trait StorageInterface:
     + blocklist::BlocklistInterface
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + dynamic_routing_stats::DynamicRoutingStatsInterface
+    + approval_request::ApprovalRequestInterface
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
@@ -158,6 +160,7 @@ pub trait GlobalStorageInterface:
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
     + role::RoleInterface
+    + user::scim_user::ScimUserInterface
     + RedisConnInterface
     + 'static
 {
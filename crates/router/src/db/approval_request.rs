@@ -0,0 +1,295 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ApprovalRequestInterface {
+    async fn insert_approval_request(
+        &self,
+        approval_request: storage::ApprovalRequestNew,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError>;
+
+    async fn find_approval_request_by_merchant_id_approval_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError>;
+
+    async fn list_approval_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<enums::ApprovalRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApprovalRequest>, errors::StorageError>;
+
+    async fn update_approval_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+        current_status: enums::ApprovalRequestStatus,
+        approval_request_update: storage::ApprovalRequestUpdate,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ApprovalRequestInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_approval_request(
+        &self,
+        approval_request: storage::ApprovalRequestNew,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        approval_request
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_approval_request_by_merchant_id_approval_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ApprovalRequest::find_by_merchant_id_approval_request_id(
+            &conn,
+            merchant_id,
+            approval_request_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_approval_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<enums::ApprovalRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApprovalRequest>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ApprovalRequest::list_by_merchant_id(&conn, merchant_id, status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_approval_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+        current_status: enums::ApprovalRequestStatus,
+        approval_request_update: storage::ApprovalRequestUpdate,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ApprovalRequest::update_by_merchant_id_approval_request_id_status(
+            &conn,
+            merchant_id,
+            approval_request_id,
+            current_status,
+            approval_request_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ApprovalRequestInterface for MockDb {
+    async fn insert_approval_request(
+        &self,
+        approval_request: storage::ApprovalRequestNew,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        let mut approval_requests = self.approval_requests.lock().await;
+        if approval_requests
+            .iter()
+            .any(|existing| existing.approval_request_id == approval_request.approval_request_id)
+        {
+            return Err(errors::StorageError::DuplicateValue {
+                entity: "approval_request",
+                key: Some(approval_request.approval_request_id),
+            }
+            .into());
+        }
+
+        let approval_request = storage::ApprovalRequest {
+            approval_request_id: approval_request.approval_request_id,
+            merchant_id: approval_request.merchant_id,
+            org_id: approval_request.org_id,
+            profile_id: approval_request.profile_id,
+            action: approval_request.action,
+            status: approval_request.status,
+            resource_id: approval_request.resource_id,
+            payload: approval_request.payload,
+            requested_by: approval_request.requested_by,
+            reviewed_by: None,
+            review_comment: None,
+            error_message: None,
+            created_at: approval_request.created_at,
+            modified_at: approval_request.modified_at,
+        };
+        approval_requests.push(approval_request.clone());
+
+        Ok(approval_request)
+    }
+
+    async fn find_approval_request_by_merchant_id_approval_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        self.approval_requests
+            .lock()
+            .await
+            .iter()
+            .find(|approval_request| {
+                approval_request.merchant_id == *merchant_id
+                    && approval_request.approval_request_id == approval_request_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No approval request available for approval_request_id = {approval_request_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_approval_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<enums::ApprovalRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApprovalRequest>, errors::StorageError> {
+        let mut approval_requests: Vec<_> = self
+            .approval_requests
+            .lock()
+            .await
+            .iter()
+            .filter(|approval_request| {
+                approval_request.merchant_id == *merchant_id
+                    && status.map_or(true, |status| approval_request.status == status)
+            })
+            .cloned()
+            .collect();
+        approval_requests.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let offset = offset.and_then(|offset| usize::try_from(offset).ok());
+        let limit = limit.and_then(|limit| usize::try_from(limit).ok());
+
+        Ok(approval_requests
+            .into_iter()
+            .skip(offset.unwrap_or_default())
+            .take(limit.unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    async fn update_approval_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+        current_status: enums::ApprovalRequestStatus,
+        approval_request_update: storage::ApprovalRequestUpdate,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        let mut approval_requests = self.approval_requests.lock().await;
+        let approval_request = approval_requests
+            .iter_mut()
+            .find(|approval_request| {
+                approval_request.merchant_id == *merchant_id
+                    && approval_request.approval_request_id == approval_request_id
+                    && approval_request.status == current_status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No approval request available for approval_request_id = {approval_request_id}"
+            )))?;
+
+        match approval_request_update {
+            storage::ApprovalRequestUpdate::Review {
+                status,
+                reviewed_by,
+                review_comment,
+            } => {
+                approval_request.status = status;
+                approval_request.reviewed_by = Some(reviewed_by);
+                approval_request.review_comment = review_comment;
+            }
+            storage::ApprovalRequestUpdate::ExecutionFailed { error_message } => {
+                approval_request.status = enums::ApprovalRequestStatus::Failed;
+                approval_request.error_message = Some(error_message);
+            }
+            storage::ApprovalRequestUpdate::Expire => {
+                approval_request.status = enums::ApprovalRequestStatus::Expired;
+            }
+        }
+        approval_request.modified_at = common_utils::date_time::now();
+
+        Ok(approval_request.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl ApprovalRequestInterface for KafkaStore {
+    async fn insert_approval_request(
+        &self,
+        approval_request: storage::ApprovalRequestNew,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        self.diesel_store
+            .insert_approval_request(approval_request)
+            .await
+    }
+
+    async fn find_approval_request_by_merchant_id_approval_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        self.diesel_store
+            .find_approval_request_by_merchant_id_approval_request_id(
+                merchant_id,
+                approval_request_id,
+            )
+            .await
+    }
+
+    async fn list_approval_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<enums::ApprovalRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ApprovalRequest>, errors::StorageError> {
+        self.diesel_store
+            .list_approval_requests_by_merchant_id(merchant_id, status, limit, offset)
+            .await
+    }
+
+    async fn update_approval_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        approval_request_id: &str,
+        current_status: enums::ApprovalRequestStatus,
+        approval_request_update: storage::ApprovalRequestUpdate,
+    ) -> CustomResult<storage::ApprovalRequest, errors::StorageError> {
+        self.diesel_store
+            .update_approval_request_with_status(
+                merchant_id,
+                approval_request_id,
+                current_status,
+                approval_request_update,
+            )
+            .await
+    }
+}
@@ -152,6 +152,8 @@ impl AuthenticationInterface for MockDb {
             acquirer_country_code: authentication.acquirer_country_code,
             service_details: authentication.service_details,
             organization_id: authentication.organization_id,
+            psd2_sca_exemption_type: authentication.psd2_sca_exemption_type,
+            is_merchant_whitelisted: authentication.is_merchant_whitelisted,
         };
         authentications.push(authentication.clone());
         Ok(authentication)
@@ -90,6 +90,20 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
+    async fn list_initial_events_by_profile_id_constraints_after_key(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -354,6 +368,53 @@ impl EventInterface for Store {
         .await
     }
 
+    #[instrument(skip_all)]
+    async fn list_initial_events_by_profile_id_constraints_after_key(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempts_by_profile_id_constraints_after_key(
+            &conn,
+            profile_id,
+            created_after,
+            created_before,
+            after_key,
+            limit,
+            event_types,
+            is_delivered,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            common_utils::types::keymanager::Identifier::Merchant(
+                                merchant_key_store.merchant_id.clone(),
+                            ),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn update_event_by_merchant_id_event_id(
         &self,
@@ -699,6 +760,59 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
+    async fn list_initial_events_by_profile_id_constraints_after_key(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id == Some(profile_id.to_owned())
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && (event.created_at >= created_after)
+                    && (event.created_at <= created_before)
+                    && (event_types.is_empty() || event_types.contains(&event.event_type))
+                    && (is_delivered.is_none()
+                        || event.is_overall_delivery_successful == is_delivered)
+                    && after_key
+                        .as_ref()
+                        .map_or(true, |(last_created_at, last_event_id)| {
+                            (event.created_at, &event.event_id) > (*last_created_at, last_event_id)
+                        })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| (a.created_at, &a.event_id).cmp(&(b.created_at, &b.event_id)));
+
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let mut domain_events = Vec::with_capacity(events.len().min(limit));
+
+        for event in events.into_iter().take(limit) {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -44,7 +44,10 @@ use super::{
     dashboard_metadata::DashboardMetadataInterface,
     ephemeral_key::ClientSecretInterface,
     role::RoleInterface,
-    user::{sample_data::BatchSampleDataInterface, theme::ThemeInterface, UserInterface},
+    user::{
+        sample_data::BatchSampleDataInterface, scim_user::ScimUserInterface, theme::ThemeInterface,
+        UserInterface,
+    },
     user_authentication_method::UserAuthenticationMethodInterface,
     user_key_store::UserKeyStoreInterface,
     user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload, UserRoleInterface},
@@ -854,6 +857,33 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn list_initial_events_by_profile_id_constraints_after_key(
+        &self,
+        state: &KeyManagerState,
+        profile_id: &id_type::ProfileId,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        after_key: Option<(PrimitiveDateTime, String)>,
+        limit: i64,
+        event_types: HashSet<common_enums::EventType>,
+        is_delivered: Option<bool>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_events_by_profile_id_constraints_after_key(
+                state,
+                profile_id,
+                created_after,
+                created_before,
+                after_key,
+                limit,
+                event_types,
+                is_delivered,
+                merchant_key_store,
+            )
+            .await
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -2236,6 +2266,31 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_list_by_merchant_id_status_after_key(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        after_key: Option<(PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_list_by_merchant_id_status_after_key(
+                state,
+                key_store,
+                merchant_id,
+                status,
+                after_key,
+                limit,
+            )
+            .await
+    }
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -2252,6 +2307,29 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_network_token_requestor_reference_id(
+                state,
+                key_store,
+                merchant_id,
+                network_token_requestor_reference_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn insert_payment_method(
         &self,
         state: &KeyManagerState,
@@ -3474,6 +3552,27 @@ impl UserRoleInterface for KafkaStore {
             .await
     }
 
+    async fn delete_user_role_by_user_id_and_role_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: Option<&id_type::MerchantId>,
+        profile_id: Option<&id_type::ProfileId>,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_role_by_user_id_and_role_id(
+                user_id,
+                tenant_id,
+                org_id,
+                merchant_id,
+                profile_id,
+                role_id,
+            )
+            .await
+    }
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -4158,6 +4257,58 @@ impl ThemeInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl ScimUserInterface for KafkaStore {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::scim_user::ScimUserNew,
+    ) -> CustomResult<storage::scim_user::ScimUser, errors::StorageError> {
+        self.diesel_store.insert_scim_user(scim_user).await
+    }
+
+    async fn find_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::scim_user::ScimUser, errors::StorageError> {
+        self.diesel_store
+            .find_scim_user_by_org_id_user_id(org_id, user_id)
+            .await
+    }
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::scim_user::ScimUser>, errors::StorageError> {
+        self.diesel_store
+            .list_scim_users_by_org_id(org_id, limit, offset)
+            .await
+    }
+
+    async fn update_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: storage::scim_user::ScimUserUpdate,
+    ) -> CustomResult<storage::scim_user::ScimUser, errors::StorageError> {
+        self.diesel_store
+            .update_scim_user_by_org_id_user_id(org_id, user_id, scim_user_update)
+            .await
+    }
+
+    async fn delete_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_scim_user_by_org_id_user_id(org_id, user_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 #[cfg(feature = "v2")]
 impl db::payment_method_session::PaymentMethodsSessionInterface for KafkaStore {
@@ -10,6 +10,7 @@ use crate::{
     services::Store,
 };
 pub mod sample_data;
+pub mod scim_user;
 pub mod theme;
 
 #[async_trait::async_trait]
@@ -164,6 +165,7 @@ impl UserInterface for MockDb {
             totp_recovery_codes: user_data.totp_recovery_codes,
             last_password_modified_at: user_data.last_password_modified_at,
             lineage_context: user_data.lineage_context,
+            passkeys: user_data.passkeys,
         };
         users.push(user.clone());
         Ok(user)
@@ -251,6 +253,11 @@ impl UserInterface for MockDb {
                             ..user.to_owned()
                         }
                     }
+                    storage::UserUpdate::PasskeyUpdate { passkeys } => storage::User {
+                        last_modified_at,
+                        passkeys: Some(passkeys.clone()),
+                        ..user.to_owned()
+                    },
                 };
                 user.to_owned()
             })
@@ -310,6 +317,11 @@ impl UserInterface for MockDb {
                             ..user.to_owned()
                         }
                     }
+                    storage::UserUpdate::PasskeyUpdate { passkeys } => storage::User {
+                        last_modified_at,
+                        passkeys: Some(passkeys.clone()),
+                        ..user.to_owned()
+                    },
                 };
                 user.to_owned()
             })
@@ -0,0 +1,235 @@
+use common_utils::id_type;
+use diesel_models::user::scim_user::{self as storage, ScimUserUpdate};
+use error_stack::report;
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait ScimUserInterface {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn find_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError>;
+
+    async fn update_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn delete_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ScimUserInterface for Store {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        scim_user
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn find_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::find_by_org_id_user_id(&conn, org_id, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::list_by_org_id(&conn, org_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn update_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimUser::update_by_org_id_user_id(&conn, org_id, user_id, scim_user_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn delete_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimUser::delete_by_org_id_user_id(&conn, org_id, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ScimUserInterface for MockDb {
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        if scim_users.iter().any(|existing| {
+            existing.org_id == scim_user.org_id && existing.user_id == scim_user.user_id
+        }) {
+            return Err(errors::StorageError::DuplicateValue {
+                entity: "scim_user",
+                key: Some(scim_user.user_id),
+            }
+            .into());
+        }
+
+        let scim_user = storage::ScimUser {
+            org_id: scim_user.org_id,
+            user_id: scim_user.user_id,
+            tenant_id: scim_user.tenant_id,
+            external_id: scim_user.external_id,
+            active: scim_user.active,
+            created_at: scim_user.created_at,
+            last_modified_at: scim_user.last_modified_at,
+            is_user_created_by_scim: scim_user.is_user_created_by_scim,
+        };
+        scim_users.push(scim_user.clone());
+
+        Ok(scim_user)
+    }
+
+    async fn find_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let scim_users = self.scim_users.lock().await;
+        scim_users
+            .iter()
+            .find(|scim_user| &scim_user.org_id == org_id && scim_user.user_id == user_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No SCIM user found for user_id = {} in org_id = {}",
+                    user_id,
+                    org_id.get_string_repr()
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let scim_users = self.scim_users.lock().await;
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        Ok(scim_users
+            .iter()
+            .filter(|scim_user| &scim_user.org_id == org_id)
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        scim_users
+            .iter_mut()
+            .find(|scim_user| &scim_user.org_id == org_id && scim_user.user_id == user_id)
+            .map(|scim_user| {
+                match &scim_user_update {
+                    ScimUserUpdate::Update {
+                        external_id,
+                        active,
+                    } => {
+                        if let Some(external_id) = external_id {
+                            scim_user.external_id = Some(external_id.clone());
+                        }
+                        if let Some(active) = active {
+                            scim_user.active = *active;
+                        }
+                    }
+                }
+                scim_user.last_modified_at = common_utils::date_time::now();
+                scim_user.clone()
+            })
+            .ok_or_else(|| {
+                report!(errors::StorageError::ValueNotFound(format!(
+                    "No SCIM user found for user_id = {} in org_id = {}",
+                    user_id,
+                    org_id.get_string_repr()
+                )))
+            })
+    }
+
+    async fn delete_scim_user_by_org_id_user_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        let index = scim_users
+            .iter()
+            .position(|scim_user| &scim_user.org_id == org_id && scim_user.user_id == user_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No SCIM user found for user_id = {} in org_id = {}",
+                user_id,
+                org_id.get_string_repr()
+            )))?;
+        scim_users.remove(index);
+
+        Ok(true)
+    }
+}
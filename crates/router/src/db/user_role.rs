@@ -74,6 +74,16 @@ pub trait UserRoleInterface {
         version: enums::UserRoleVersion,
     ) -> CustomResult<storage::UserRole, errors::StorageError>;
 
+    async fn delete_user_role_by_user_id_and_role_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: Option<&id_type::MerchantId>,
+        profile_id: Option<&id_type::ProfileId>,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -180,6 +190,30 @@ impl UserRoleInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn delete_user_role_by_user_id_and_role_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: Option<&id_type::MerchantId>,
+        profile_id: Option<&id_type::ProfileId>,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::delete_by_user_id_tenant_id_org_id_role_id(
+            &conn,
+            user_id.to_owned(),
+            tenant_id.to_owned(),
+            org_id.to_owned(),
+            merchant_id.cloned(),
+            profile_id.cloned(),
+            role_id.to_owned(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -444,6 +478,38 @@ impl UserRoleInterface for MockDb {
         }
     }
 
+    async fn delete_user_role_by_user_id_and_role_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        merchant_id: Option<&id_type::MerchantId>,
+        profile_id: Option<&id_type::ProfileId>,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut user_roles = self.user_roles.lock().await;
+        let initial_len = user_roles.len();
+
+        user_roles.retain(|role| {
+            !(role.user_id == user_id
+                && role.tenant_id == *tenant_id
+                && role.org_id.as_ref() == Some(org_id)
+                && role.merchant_id.as_ref() == merchant_id
+                && role.profile_id.as_ref() == profile_id
+                && role.role_id == role_id
+                && role.version == enums::UserRoleVersion::V2)
+        });
+
+        if user_roles.len() == initial_len {
+            return Err(errors::StorageError::ValueNotFound(
+                "Cannot find user role to delete".to_string(),
+            )
+            .into());
+        }
+
+        Ok(true)
+    }
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
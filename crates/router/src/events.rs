@@ -21,6 +21,8 @@ pub mod audit_events;
 pub mod connector_api_logs;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
+#[cfg(feature = "recon")]
+pub mod recon_events;
 #[derive(Debug, Serialize, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum EventType {
@@ -37,6 +39,7 @@ pub enum EventType {
     Payout,
     Consolidated,
     Authentication,
+    Recon,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
@@ -8,10 +8,10 @@ use time::OffsetDateTime;
 use super::EventType;
 #[cfg(feature = "dummy_connector")]
 use crate::routes::dummy_connector::types::{
-    DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
-    DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
-    DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
-    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorCaptureRequest, DummyConnectorPaymentCompleteRequest,
+    DummyConnectorPaymentConfirmRequest, DummyConnectorPaymentRequest,
+    DummyConnectorPaymentResponse, DummyConnectorPaymentRetrieveRequest,
+    DummyConnectorRefundRequest, DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
 };
 use crate::{
     core::payments::PaymentsRedirectResponseData,
@@ -123,7 +123,8 @@ impl_api_event_type!(
         DummyConnectorPaymentConfirmRequest,
         DummyConnectorRefundRetrieveRequest,
         DummyConnectorRefundResponse,
-        DummyConnectorRefundRequest
+        DummyConnectorRefundRequest,
+        DummyConnectorCaptureRequest
     )
 );
 
@@ -39,6 +39,64 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    ProxyDetokenization {
+        profile_id: common_utils::id_type::ProfileId,
+        route_id: String,
+        destination_host: String,
+        token: String,
+        path: String,
+        data: api_models::proxy::ProxyDetokenizedData,
+    },
+    ApprovalPolicyUpdated {
+        entity_type: common_enums::EntityType,
+        updated_by: String,
+        rules: Vec<api_models::approvals::ApprovalRule>,
+    },
+    ApprovalRequested {
+        approval_request_id: String,
+        action: common_enums::ApprovalAction,
+        resource_id: Option<String>,
+        requested_by: String,
+    },
+    ApprovalApproved {
+        approval_request_id: String,
+        action: common_enums::ApprovalAction,
+        requested_by: String,
+        reviewed_by: String,
+    },
+    ApprovalRejected {
+        approval_request_id: String,
+        action: common_enums::ApprovalAction,
+        requested_by: String,
+        reviewed_by: String,
+        review_comment: Option<String>,
+    },
+    CardVaultExportStarted {
+        export_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
+        approval_request_id: String,
+        recipient_key_id: Option<String>,
+    },
+    CardVaultExportFileWritten {
+        export_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
+        file_name: String,
+        record_count: u32,
+        failure_count: u32,
+    },
+    CardVaultExportCompleted {
+        export_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
+        exported_cards: u32,
+        failed_payment_methods: u32,
+        file_count: usize,
+    },
+    CardVaultExportFileDownloaded {
+        export_id: String,
+        merchant_id: common_utils::id_type::MerchantId,
+        file_name: String,
+        downloaded_by: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +141,17 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::ProxyDetokenization { .. } => "proxy_detokenization",
+            AuditEventType::ApprovalPolicyUpdated { .. } => "approval_policy_updated",
+            AuditEventType::ApprovalRequested { .. } => "approval_requested",
+            AuditEventType::ApprovalApproved { .. } => "approval_approved",
+            AuditEventType::ApprovalRejected { .. } => "approval_rejected",
+            AuditEventType::CardVaultExportStarted { .. } => "card_vault_export_started",
+            AuditEventType::CardVaultExportFileWritten { .. } => "card_vault_export_file_written",
+            AuditEventType::CardVaultExportCompleted { .. } => "card_vault_export_completed",
+            AuditEventType::CardVaultExportFileDownloaded { .. } => {
+                "card_vault_export_file_downloaded"
+            }
         };
         format!(
             "{event_type}-{}",
@@ -72,6 +72,10 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    PaymentMethod {
+        payment_method_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -111,6 +115,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::PaymentMethodDetails(payment_method_payload) => {
+                Some(OutgoingWebhookEventContent::PaymentMethod {
+                    payment_method_id: payment_method_payload.payment_method_id.clone(),
+                    content: masking::masked_serialize(&payment_method_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -146,6 +157,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::PaymentMethodDetails(payment_method_payload) => {
+                Some(OutgoingWebhookEventContent::PaymentMethod {
+                    payment_method_id: payment_method_payload.id.get_string_repr().to_owned(),
+                    content: masking::masked_serialize(&payment_method_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
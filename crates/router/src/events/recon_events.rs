@@ -0,0 +1,68 @@
+use api_models::{enums, recon as recon_api};
+use common_utils::types::MinorUnit;
+use serde::Serialize;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+/// Outcome of reconciling a single settlement line, published for recon analytics
+#[derive(Clone, Debug, Serialize)]
+pub struct ReconRecordEvent {
+    recon_id: String,
+    merchant_id: common_utils::id_type::MerchantId,
+    connector: enums::Connector,
+    file_name: String,
+    connector_transaction_id: String,
+    transaction_type: recon_api::SettlementTransactionType,
+    status: recon_api::ReconRecordStatus,
+    payment_id: Option<common_utils::id_type::PaymentId>,
+    attempt_id: Option<String>,
+    refund_id: Option<String>,
+    currency: enums::Currency,
+    settled_amount: Option<MinorUnit>,
+    expected_amount: Option<MinorUnit>,
+    settled_fee: Option<MinorUnit>,
+    expected_fee: Option<MinorUnit>,
+    created_at_timestamp: i128,
+}
+
+impl ReconRecordEvent {
+    pub fn new(
+        report: &recon_api::ReconSettlementReport,
+        record: &recon_api::ReconRecordResult,
+    ) -> Self {
+        Self {
+            recon_id: report.recon_id.clone(),
+            merchant_id: report.merchant_id.clone(),
+            connector: report.connector,
+            file_name: report.file_name.clone(),
+            connector_transaction_id: record.connector_transaction_id.clone(),
+            transaction_type: record.transaction_type,
+            status: record.status,
+            payment_id: record.payment_id.clone(),
+            attempt_id: record.attempt_id.clone(),
+            refund_id: record.refund_id.clone(),
+            currency: record.currency,
+            settled_amount: record.settled_amount,
+            expected_amount: record.expected_amount,
+            settled_fee: record.settled_fee,
+            expected_fee: record.expected_fee,
+            created_at_timestamp: report.created_at.assume_utc().unix_timestamp_nanos() / 1_000_000,
+        }
+    }
+}
+
+impl KafkaMessage for ReconRecordEvent {
+    fn key(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.recon_id,
+            self.connector_transaction_id
+        )
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::Recon
+    }
+}
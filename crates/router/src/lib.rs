@@ -75,6 +75,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_TIMESTAMPED: &str = "X-Webhook-Signature-Timestamped";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
@@ -137,6 +138,12 @@ pub fn mk_app(
 
             server_app = server_app.service(routes::Profile::server(state.clone()));
         }
+        // This is a more specific route as compared to `Webhooks`
+        // so it is registered before `Webhooks`.
+        #[cfg(all(feature = "oltp", feature = "v1"))]
+        {
+            server_app = server_app.service(routes::NetworkTokenWebhooks::server(state.clone()));
+        }
         server_app = server_app
             .service(routes::Payments::server(state.clone()))
             .service(routes::Customers::server(state.clone()))
@@ -205,7 +212,9 @@ pub fn mk_app(
                 .service(routes::Verify::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
-                .service(routes::FeatureMatrix::server(state.clone()));
+                .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::Scim::server(state.clone()))
+                .service(routes::Approvals::server(state.clone()));
         }
 
         #[cfg(feature = "v2")]
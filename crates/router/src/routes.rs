@@ -4,6 +4,8 @@ pub mod app;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod approvals;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
@@ -84,12 +86,14 @@ pub use self::app::Tokenization;
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
     Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm, Health, Hypersense,
-    Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments,
-    Poll, ProcessTracker, Profile, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, User,
-    Webhooks,
+    Mandates, MerchantAccount, MerchantConnectorAccount, NetworkTokenWebhooks, PaymentLink,
+    PaymentMethods, Payments, Poll, ProcessTracker, Profile, ProfileNew, Refunds, Relay,
+    RelayWebhooks, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Approvals, Blocklist, Organization, Routing, Scim, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
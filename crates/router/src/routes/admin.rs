@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::approvals;
 use crate::{
     core::{admin::*, api_locking},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -815,20 +817,39 @@ pub async fn connector_update(
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsUpdate;
     let (merchant_id, merchant_connector_id) = path.into_inner();
+    let request_headers = req.headers();
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth, req, _| {
-            update_connector(
-                state,
-                &merchant_id,
-                auth.profile_id,
-                &merchant_connector_id,
-                req,
-            )
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_id = &merchant_id;
+            let merchant_connector_id = &merchant_connector_id;
+            async move {
+                approvals::check_approval_required(
+                    &state,
+                    &req_state,
+                    request_headers,
+                    &auth.merchant_account,
+                    &auth.key_store,
+                    auth.profile_id.clone(),
+                    approvals::ApprovalPayload::ConnectorCredentialsUpdate {
+                        merchant_connector_id: merchant_connector_id.clone(),
+                        request: req.clone(),
+                    },
+                )
+                .await?;
+                update_connector(
+                    state,
+                    merchant_id,
+                    auth.profile_id,
+                    merchant_connector_id,
+                    req,
+                )
+                .await
+            }
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::approvals;
 use crate::{
     core::{api_keys, api_locking},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -19,13 +21,24 @@ pub async fn api_key_create(
     let flow = Flow::ApiKeyCreate;
     let payload = json_payload.into_inner();
     let merchant_id = path.into_inner();
+    let request_headers = req.headers();
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, auth_data, payload, _| async {
+        |state, auth_data: auth::AuthenticationData, payload, req_state| async move {
+            approvals::check_approval_required(
+                &state,
+                &req_state,
+                request_headers,
+                &auth_data.merchant_account,
+                &auth_data.key_store,
+                auth_data.profile_id.clone(),
+                approvals::ApprovalPayload::ApiKeyCreate(payload.clone()),
+            )
+            .await?;
             api_keys::create_api_key(state, payload, auth_data.key_store).await
         },
         auth::auth_type(
@@ -158,13 +171,29 @@ pub async fn api_key_update(
     let mut payload = json_payload.into_inner();
     payload.key_id = key_id;
     payload.merchant_id.clone_from(&merchant_id);
+    let request_headers = req.headers();
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |state, _, payload, _| api_keys::update_api_key(state, payload),
+        |state, auth_data: auth::AuthenticationData, payload, req_state| async move {
+            approvals::check_approval_required(
+                &state,
+                &req_state,
+                request_headers,
+                &auth_data.merchant_account,
+                &auth_data.key_store,
+                auth_data.profile_id.clone(),
+                approvals::ApprovalPayload::ApiKeyUpdate {
+                    key_id: payload.key_id.clone(),
+                    request: payload.clone(),
+                },
+            )
+            .await?;
+            api_keys::update_api_key(state, payload).await
+        },
         auth::auth_type(
             &auth::AdminApiAuthWithApiKeyFallbackAndMerchantIdFromRoute(merchant_id.clone()),
             &auth::JWTAuthMerchantFromRoute {
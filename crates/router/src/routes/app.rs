@@ -23,10 +23,16 @@ use hyperswitch_interfaces::{
 };
 use router_env::tracing_actix_web::RequestId;
 use scheduler::SchedulerInterface;
-use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
+use storage_impl::{
+    config::TenantConfig,
+    redis::{kv_store::RedisConnInterface, RedisStore},
+    MockDb,
+};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::approvals;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::currency;
 #[cfg(feature = "dummy_connector")]
@@ -405,7 +411,10 @@ impl AppState {
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
 
-            let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
+            let grpc_client = conf
+                .grpc_client
+                .get_grpc_client_interface(cache_store.get_redis_conn().ok())
+                .await;
 
             Self {
                 flow_name: String::from("default"),
@@ -554,6 +563,10 @@ impl DummyConnector {
                 web::resource("/payments/{payment_id}")
                     .route(web::get().to(dummy_connector_payment_data)),
             )
+            .service(
+                web::resource("/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture)),
+            )
             .service(
                 web::resource("/{payment_id}/refund").route(web::post().to(dummy_connector_refund)),
             )
@@ -679,6 +692,11 @@ impl Proxy {
         web::scope("/proxy")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(proxy::proxy)))
+            .service(
+                web::resource("/routes")
+                    .route(web::put().to(proxy::update_proxy_routes))
+                    .route(web::get().to(proxy::retrieve_proxy_routes)),
+            )
     }
 }
 
@@ -937,6 +955,10 @@ impl Routing {
                     },
                 )),
             )
+            .service(
+                web::resource("/eligibility/profile/{profile_id}")
+                    .route(web::post().to(routing::routing_explain_payment_method_eligibility)),
+            )
             .service(
                 web::resource("/default/profile").route(web::get().to(|state, req| {
                     routing::routing_retrieve_default_config(state, req, &TransactionType::Payment)
@@ -1252,6 +1274,14 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(
+                web::resource("/batch/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batch/{batch_id}/results")
+                    .route(web::get().to(payouts_batch_results_download)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1318,6 +1348,19 @@ impl PaymentMethods {
                         payment_methods::list_countries_currencies_for_connector_payment_method,
                     ),
                 ));
+            route = route
+                .service(
+                    web::resource("/vault-exports")
+                        .route(web::post().to(payment_methods::card_vault_export_create)),
+                )
+                .service(
+                    web::resource("/vault-exports/{export_id}")
+                        .route(web::get().to(payment_methods::card_vault_export_retrieve)),
+                )
+                .service(
+                    web::resource("/vault-exports/{export_id}/files/{file_name}")
+                        .route(web::get().to(payment_methods::card_vault_export_file_download)),
+                );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1335,6 +1378,22 @@ impl PaymentMethods {
                     web::resource("/migrate-batch")
                         .route(web::post().to(payment_methods::migrate_payment_methods)),
                 )
+                .service(
+                    web::resource("/migrate-batch/jobs").route(
+                        web::post().to(payment_methods::payment_methods_migration_batch_create),
+                    ),
+                )
+                .service(
+                    web::resource("/migrate-batch/jobs/{merchant_id}/{batch_id}").route(
+                        web::get().to(payment_methods::payment_methods_migration_batch_retrieve),
+                    ),
+                )
+                .service(
+                    web::resource("/migrate-batch/jobs/{merchant_id}/{batch_id}/results").route(
+                        web::get()
+                            .to(payment_methods::payment_methods_migration_batch_results_download),
+                    ),
+                )
                 .service(
                     web::resource("/tokenize-card")
                         .route(web::post().to(payment_methods::tokenize_card_api)),
@@ -1454,6 +1513,25 @@ impl Recon {
                 web::resource("/verify_token")
                     .route(web::get().to(recon_routes::verify_recon_token)),
             )
+            .service(
+                web::scope("/settlements")
+                    .service(
+                        web::resource("/run")
+                            .route(web::post().to(recon_routes::run_settlement_recon)),
+                    )
+                    .service(
+                        web::resource("/reports/{recon_id}")
+                            .route(web::get().to(recon_routes::retrieve_recon_report)),
+                    )
+                    .service(
+                        web::resource("/{connector}/upload")
+                            .route(web::post().to(recon_routes::upload_settlement_file)),
+                    ),
+            )
+            .service(
+                web::resource("/bank_statements/upload")
+                    .route(web::post().to(recon_routes::upload_bank_statement)),
+            )
     }
 }
 
@@ -1748,6 +1826,20 @@ impl RelayWebhooks {
     }
 }
 
+pub struct NetworkTokenWebhooks;
+
+#[cfg(all(feature = "oltp", feature = "v1"))]
+impl NetworkTokenWebhooks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/webhooks/network_token")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(web::post().to(receive_network_token_webhook)),
+            )
+    }
+}
+
 #[cfg(all(feature = "oltp", feature = "v2"))]
 impl Webhooks {
     pub fn server(config: AppState) -> Scope {
@@ -1950,6 +2042,10 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payment_link::payments_link_list)))
+            .service(
+                web::resource("/templates")
+                    .route(web::post().to(payment_link::upload_payment_link_template)),
+            )
             .service(
                 web::resource("/{payment_link_id}")
                     .route(web::get().to(payment_link::payment_link_retrieve)),
@@ -2246,6 +2342,13 @@ impl User {
             .service(web::resource("").route(web::get().to(user::get_user_details)))
             .service(web::resource("/signin").route(web::post().to(user::user_signin)))
             .service(web::resource("/v2/signin").route(web::post().to(user::user_signin)))
+            .service(
+                web::scope("/passkey/signin")
+                    .service(
+                        web::resource("/begin").route(web::post().to(user::passkey_signin_begin)),
+                    )
+                    .service(web::resource("").route(web::post().to(user::passkey_signin))),
+            )
             // signin/signup with sso using openidconnect
             .service(web::resource("/oidc").route(web::post().to(user::sso_sign)))
             .service(web::resource("/signout").route(web::post().to(user::signout)))
@@ -2339,6 +2442,13 @@ impl User {
                                 .route(web::put().to(user::totp_update)),
                         ),
                 )
+                .service(
+                    web::scope("/passkey")
+                        .service(web::resource("/begin").route(web::get().to(user::passkey_begin)))
+                        .service(
+                            web::resource("/verify").route(web::post().to(user::passkey_verify)),
+                        ),
+                )
                 .service(
                     web::scope("/recovery_code")
                         .service(
@@ -2356,6 +2466,19 @@ impl User {
                 ),
         );
 
+        route = route.service(
+            web::scope("/passkey")
+                .service(web::resource("").route(web::get().to(user::list_passkeys)))
+                .service(
+                    web::resource("/register")
+                        .route(web::get().to(user::passkey_registration_begin))
+                        .route(web::post().to(user::passkey_registration_finish)),
+                )
+                .service(
+                    web::resource("/{passkey_id}").route(web::delete().to(user::revoke_passkey)),
+                ),
+        );
+
         route = route.service(
             web::scope("/auth")
                 .service(
@@ -2538,6 +2661,86 @@ impl User {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct Scim;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Scim {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/scim/v2/{org_id}")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/token")
+                    .route(web::post().to(user::scim::create_scim_token))
+                    .route(web::delete().to(user::scim::revoke_scim_token)),
+            )
+            .service(
+                web::resource("/Users")
+                    .route(web::get().to(user::scim::list_scim_users))
+                    .route(web::post().to(user::scim::create_scim_user)),
+            )
+            .service(
+                web::resource("/Users/{user_id}")
+                    .route(web::get().to(user::scim::get_scim_user))
+                    .route(web::put().to(user::scim::replace_scim_user))
+                    .route(web::patch().to(user::scim::patch_scim_user))
+                    .route(web::delete().to(user::scim::delete_scim_user)),
+            )
+            .service(
+                web::resource("/Groups")
+                    .route(web::get().to(user::scim::list_scim_groups))
+                    .route(web::post().to(user::scim::create_scim_group)),
+            )
+            .service(
+                web::resource("/Groups/{group_id}")
+                    .route(web::get().to(user::scim::get_scim_group))
+                    .route(web::put().to(user::scim::replace_scim_group))
+                    .route(web::patch().to(user::scim::patch_scim_group))
+                    .route(web::delete().to(user::scim::delete_scim_group)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct Approvals;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Approvals {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/approvals")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/policy")
+                    .route(web::get().to(approvals::retrieve_approval_policy))
+                    .route(web::put().to(approvals::update_merchant_approval_policy))
+                    .route(web::delete().to(approvals::delete_merchant_approval_policy)),
+            )
+            .service(
+                web::resource("/policy/organization")
+                    .route(web::put().to(approvals::update_organization_approval_policy)),
+            )
+            .service(
+                web::resource("/requests").route(web::get().to(approvals::list_approval_requests)),
+            )
+            .service(
+                web::resource("/requests/{approval_request_id}")
+                    .route(web::get().to(approvals::retrieve_approval_request)),
+            )
+            .service(
+                web::resource("/requests/{approval_request_id}/approve")
+                    .route(web::post().to(approvals::approve_approval_request)),
+            )
+            .service(
+                web::resource("/requests/{approval_request_id}/reject")
+                    .route(web::post().to(approvals::reject_approval_request)),
+            )
+            .service(
+                web::resource("/requests/{approval_request_id}/result")
+                    .route(web::get().to(approvals::retrieve_approval_result)),
+            )
+    }
+}
+
 pub struct ConnectorOnboarding;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -2576,6 +2779,13 @@ impl WebhookEvents {
                     .service(web::resource("").route(
                         web::post().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(
+                        web::resource("bulk_resend")
+                            .route(web::post().to(webhook_events::bulk_resend_webhook_events)),
+                    )
+                    .service(web::resource("bulk_resend/{batch_id}").route(
+                        web::get().to(webhook_events::retrieve_webhook_events_bulk_resend),
+                    ))
                     .service(
                         web::scope("/{event_id}")
                             .service(web::resource("attempts").route(
@@ -2614,5 +2824,9 @@ impl ProcessTracker {
                 web::resource("/{revenue_recovery_id}")
                     .route(web::get().to(revenue_recovery::revenue_recovery_pt_retrieve_api)),
             )
+            .service(
+                web::resource("/{revenue_recovery_id}/retry_timeline")
+                    .route(web::get().to(revenue_recovery::revenue_recovery_retry_timeline_api)),
+            )
     }
 }
@@ -0,0 +1,233 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::approvals as approvals_api;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, approvals},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalPolicyRetrieve))]
+pub async fn retrieve_approval_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ApprovalPolicyRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| approvals::retrieve_approval_policy(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalPolicyUpdate))]
+pub async fn update_merchant_approval_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<approvals_api::ApprovalPolicy>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalPolicyUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, policy, req_state| {
+            approvals::update_merchant_approval_policy(state, req_state, user, policy)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalPolicyUpdate))]
+pub async fn update_organization_approval_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<approvals_api::ApprovalPolicy>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalPolicyUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, policy, req_state| {
+            approvals::update_organization_approval_policy(state, req_state, user, policy)
+        },
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalPolicyDelete))]
+pub async fn delete_merchant_approval_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ApprovalPolicyDelete;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| approvals::delete_merchant_approval_policy(state, user),
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalRequestList))]
+pub async fn list_approval_requests(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<approvals_api::ApprovalRequestListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalRequestList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, user, constraints, _| approvals::list_approval_requests(state, user, constraints),
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalRequestRetrieve))]
+pub async fn retrieve_approval_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalRequestRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user, approval_request_id, _| {
+            approvals::retrieve_approval_request(state, user, approval_request_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalRequestApprove))]
+pub async fn approve_approval_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<approvals_api::ApprovalDecisionRequest>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalRequestApprove;
+    let approval_request_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, decision, req_state| {
+            approvals::approve_approval_request(
+                state,
+                req_state,
+                user,
+                approval_request_id.clone(),
+                decision,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalRequestReject))]
+pub async fn reject_approval_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<approvals_api::ApprovalDecisionRequest>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalRequestReject;
+    let approval_request_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, decision, req_state| {
+            approvals::reject_approval_request(
+                state,
+                req_state,
+                user,
+                approval_request_id.clone(),
+                decision,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApprovalResultRetrieve))]
+pub async fn retrieve_approval_result(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ApprovalResultRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user, approval_request_id, _| {
+            approvals::retrieve_approval_result(state, user, approval_request_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
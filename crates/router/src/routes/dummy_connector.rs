@@ -68,7 +68,8 @@ pub async fn dummy_connector_payment(
     req: actix_web::HttpRequest,
     json_payload: web::Json<types::DummyConnectorPaymentRequest>,
 ) -> impl actix_web::Responder {
-    let payload = json_payload.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.authorization_fingerprint = utils::get_authorization_fingerprint(&req);
     let flow = types::Flow::DummyPaymentCreate;
     Box::pin(api::server_wrap(
         flow,
@@ -104,6 +105,30 @@ pub async fn dummy_connector_payment_data(
     .await
 }
 
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCapture))]
+pub async fn dummy_connector_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorCaptureRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentCapture;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    payload.authorization_fingerprint = utils::get_authorization_fingerprint(&req);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::capture_payment(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?types::Flow::DummyRefundCreate))]
 pub async fn dummy_connector_refund(
@@ -115,6 +140,7 @@ pub async fn dummy_connector_refund(
     let flow = types::Flow::DummyRefundCreate;
     let mut payload = json_payload.into_inner();
     payload.payment_id = Some(path.into_inner());
+    payload.authorization_fingerprint = utils::get_authorization_fingerprint(&req);
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -3,3 +3,13 @@ pub const REFUND_ID_PREFIX: &str = "dummy_ref";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
 pub const DUMMY_CONNECTOR_UPI_FAILURE_VPA_ID: &str = "failure@upi";
 pub const DUMMY_CONNECTOR_UPI_SUCCESS_VPA_ID: &str = "success@upi";
+pub const DISPUTE_ID_PREFIX: &str = "dummy_dp";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-dummy-connector-signature";
+pub const DUMMY_CONNECTOR_DISPUTE_REASON: &str = "fraudulent";
+
+// Magic amounts, in the lowest denomination of the currency, which simulate asynchronous flows
+pub const DUMMY_CONNECTOR_DELAYED_AUTHORIZATION_AMOUNT: i64 = 1001;
+pub const DUMMY_CONNECTOR_WEBHOOK_COMPLETION_AMOUNT: i64 = 1002;
+pub const DUMMY_CONNECTOR_DELAYED_FAILURE_AMOUNT: i64 = 1003;
+pub const DUMMY_CONNECTOR_DISPUTE_AMOUNT: i64 = 1004;
+pub const DUMMY_CONNECTOR_REFUND_FAILURE_AMOUNT: i64 = 1005;
@@ -20,6 +20,9 @@ pub async fn payment(
     )
     .await;
 
+    if let Some(webhook_url) = req.webhook_url.as_deref() {
+        utils::validate_webhook_url(&state.base_url, webhook_url)?;
+    }
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
     let payment_data =
         types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
@@ -38,6 +41,7 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+    utils::spawn_payment_async_flow(&state, &payment_data);
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
@@ -97,12 +101,6 @@ pub async fn payment_complete(
 
     let payment_data = utils::get_payment_data_by_attempt_id(&state, req.attempt_id.clone()).await;
 
-    let payment_status = if req.confirm {
-        types::DummyConnectorStatus::Succeeded
-    } else {
-        types::DummyConnectorStatus::Failed
-    };
-
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -112,11 +110,17 @@ pub async fn payment_complete(
     let _ = redis_conn.delete_key(&req.attempt_id.as_str().into()).await;
 
     if let Ok(payment_data) = payment_data {
+        let payment_status = if req.confirm {
+            payment_data.get_approved_status()
+        } else {
+            types::DummyConnectorStatus::Failed
+        };
         let updated_payment_data = types::DummyConnectorPaymentData {
             status: payment_status,
             next_action: None,
             ..payment_data
-        };
+        }
+        .defer_approval();
         utils::store_data_in_redis(
             &state,
             updated_payment_data.payment_id.get_string_repr().to_owned(),
@@ -124,6 +128,7 @@ pub async fn payment_complete(
             state.conf.dummy_connector.payment_ttl,
         )
         .await?;
+        utils::spawn_payment_async_flow(&state, &updated_payment_data);
         return Ok(api::ApplicationResponse::JsonForRedirection(
             api_models::payments::RedirectionResponse {
                 return_url: String::new(),
@@ -147,6 +152,51 @@ pub async fn payment_complete(
     ))
 }
 
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn capture_payment(
+    state: SessionState,
+    req: types::DummyConnectorCaptureRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+
+    let payment_data =
+        utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
+            .await?;
+
+    payment_data.is_accessible_with(req.authorization_fingerprint.as_deref())?;
+    payment_data.is_eligible_for_capture(req.amount)?;
+
+    // Captures of a part of the authorized amount are supported, and only the captured amount
+    // can be refunded afterwards
+    let updated_payment_data = types::DummyConnectorPaymentData {
+        status: types::DummyConnectorStatus::Succeeded,
+        eligible_amount: req.amount,
+        captured_amount: Some(req.amount),
+        ..payment_data
+    };
+
+    utils::store_data_in_redis(
+        &state,
+        payment_id.get_string_repr().to_owned(),
+        updated_payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    utils::spawn_payment_async_flow(&state, &updated_payment_data);
+    Ok(api::ApplicationResponse::Json(updated_payment_data.into()))
+}
+
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
 pub async fn refund_payment(
     state: SessionState,
@@ -169,11 +219,23 @@ pub async fn refund_payment(
         utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
             .await?;
 
+    payment_data.is_accessible_with(req.authorization_fingerprint.as_deref())?;
     payment_data.is_eligible_for_refund(req.amount)?;
+    if let Some(webhook_url) = req.webhook_url.as_deref() {
+        utils::validate_webhook_url(&state.base_url, webhook_url)?;
+    }
 
     let refund_id = generate_id_with_default_len(consts::REFUND_ID_PREFIX);
     payment_data.eligible_amount -= req.amount;
 
+    let is_refund_failure =
+        payment_data.async_flow == Some(types::DummyConnectorAsyncFlow::RefundFailure);
+    let refund_status = if is_refund_failure {
+        types::DummyConnectorStatus::Processing
+    } else {
+        types::DummyConnectorStatus::Succeeded
+    };
+
     utils::store_data_in_redis(
         &state,
         payment_id.get_string_repr().to_owned(),
@@ -183,7 +245,7 @@ pub async fn refund_payment(
     .await?;
 
     let refund_data = types::DummyConnectorRefundResponse::new(
-        types::DummyConnectorStatus::Succeeded,
+        refund_status,
         refund_id.to_owned(),
         payment_data.currency,
         common_utils::date_time::now(),
@@ -193,11 +255,20 @@ pub async fn refund_payment(
 
     utils::store_data_in_redis(
         &state,
-        refund_id,
+        refund_id.to_owned(),
         refund_data.to_owned(),
         state.conf.dummy_connector.refund_ttl,
     )
     .await?;
+
+    if is_refund_failure {
+        utils::spawn_refund_failure(
+            &state,
+            payment_id.get_string_repr().to_owned(),
+            refund_id,
+            req.webhook_url.or(payment_data.webhook_url),
+        );
+    }
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
@@ -213,17 +284,6 @@ pub async fn refund_data(
     )
     .await;
 
-    let redis_conn = state
-        .store
-        .get_redis_conn()
-        .change_context(errors::DummyConnectorErrors::InternalServerError)
-        .attach_printable("Failed to get redis connection")?;
-    let refund_data = redis_conn
-        .get_and_deserialize_key::<types::DummyConnectorRefundResponse>(
-            &refund_id.as_str().into(),
-            "DummyConnectorRefundResponse",
-        )
-        .await
-        .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
+    let refund_data = utils::get_refund_data_from_refund_id(&state, refund_id).await?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
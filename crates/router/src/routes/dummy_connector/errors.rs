@@ -37,6 +37,18 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Payment is not authorized")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_10", message = "The capture amount exceeds the amount authorized")]
+    CaptureAmountExceedsPaymentAmount,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_11", message = "The capture amount must be greater than zero")]
+    InvalidCaptureAmount,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_12", message = "Webhooks can only be sent to the webhooks endpoint of the router")]
+    InvalidWebhookUrl,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +95,18 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::CaptureAmountExceedsPaymentAmount => {
+                AER::BadRequest(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::InvalidCaptureAmount => {
+                AER::BadRequest(ApiError::new("DC", 11, self.error_message(), None))
+            }
+            Self::InvalidWebhookUrl => {
+                AER::BadRequest(ApiError::new("DC", 12, self.error_message(), None))
+            }
         }
     }
 }
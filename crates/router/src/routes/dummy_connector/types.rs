@@ -1,4 +1,4 @@
-use api_models::enums::Currency;
+use api_models::enums::{CaptureMethod, Currency};
 use common_utils::{errors::CustomResult, generate_id_with_default_len, pii};
 use error_stack::report;
 use masking::Secret;
@@ -16,6 +16,7 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
     DummyRefundCreate,
     DummyRefundRetrieve,
 }
@@ -61,11 +62,28 @@ impl DummyConnectors {
 #[serde(rename_all = "lowercase")]
 pub enum DummyConnectorStatus {
     Succeeded,
+    Authorized,
     #[default]
     Processing,
     Failed,
 }
 
+/// Asynchronous behaviour simulated for a payment, selected using the magic amounts in [`consts`]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorAsyncFlow {
+    /// The payment stays in processing and is approved after a delay, without any webhook
+    DelayedAuthorization,
+    /// The payment stays in processing and is approved after a delay, followed by a webhook
+    WebhookCompletion,
+    /// The payment stays in processing and fails after a delay, followed by a webhook
+    DelayedFailure,
+    /// The payment succeeds and a dispute is opened after a delay, followed by a webhook
+    Dispute,
+    /// Refunds of the payment stay in processing and fail after a delay, followed by a webhook
+    RefundFailure,
+}
+
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorPaymentAttempt {
     pub timestamp: PrimitiveDateTime,
@@ -101,12 +119,17 @@ impl DummyConnectorPaymentAttempt {
             status,
             amount: self.payment_request.amount,
             eligible_amount: self.payment_request.amount,
+            captured_amount: None,
             connector: self.payment_request.connector,
             created: self.timestamp,
             currency: self.payment_request.currency,
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            capture_method: self.payment_request.capture_method,
+            async_flow: DummyConnectorAsyncFlow::from_amount(self.payment_request.amount),
+            webhook_url: self.payment_request.webhook_url,
+            authorization_fingerprint: self.payment_request.authorization_fingerprint,
         }
     }
 }
@@ -118,6 +141,11 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    pub capture_method: Option<CaptureMethod>,
+    pub webhook_url: Option<String>,
+    /// Fingerprint of the authorization header the payment was created with
+    #[serde(skip)]
+    pub authorization_fingerprint: Option<String>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -289,6 +317,7 @@ pub struct DummyConnectorPaymentData {
     pub status: DummyConnectorStatus,
     pub amount: i64,
     pub eligible_amount: i64,
+    pub captured_amount: Option<i64>,
     pub currency: Currency,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
@@ -296,9 +325,29 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    pub capture_method: Option<CaptureMethod>,
+    pub async_flow: Option<DummyConnectorAsyncFlow>,
+    pub webhook_url: Option<String>,
+    #[serde(default)]
+    pub authorization_fingerprint: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
+    /// Payments created with an authorization header can only be captured or refunded using the
+    /// same authorization. Other requests are rejected as if the payment does not exist.
+    pub fn is_accessible_with(
+        &self,
+        authorization_fingerprint: Option<&str>,
+    ) -> DummyConnectorResult<()> {
+        match self.authorization_fingerprint.as_deref() {
+            Some(fingerprint) if Some(fingerprint) != authorization_fingerprint => {
+                Err(report!(DummyConnectorErrors::PaymentNotFound)
+                    .attach_printable("Authorization does not match the one of the payment"))
+            }
+            Some(_) | None => Ok(()),
+        }
+    }
+
     pub fn is_eligible_for_refund(&self, refund_amount: i64) -> DummyConnectorResult<()> {
         if self.eligible_amount < refund_amount {
             return Err(
@@ -312,6 +361,40 @@ impl DummyConnectorPaymentData {
         }
         Ok(())
     }
+
+    pub fn is_eligible_for_capture(&self, capture_amount: i64) -> DummyConnectorResult<()> {
+        if capture_amount <= 0 {
+            return Err(report!(DummyConnectorErrors::InvalidCaptureAmount)
+                .attach_printable("Capture amount is not positive"));
+        }
+        if self.amount < capture_amount {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+                    .attach_printable("Authorized amount is lesser than capture amount"),
+            );
+        }
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the capture"));
+        }
+        Ok(())
+    }
+
+    /// Status of the payment once it is approved, which depends on whether the payment has to be
+    /// captured manually
+    pub fn get_approved_status(&self) -> DummyConnectorStatus {
+        match self.capture_method {
+            Some(CaptureMethod::Manual | CaptureMethod::ManualMultiple) => {
+                DummyConnectorStatus::Authorized
+            }
+            Some(
+                CaptureMethod::Automatic
+                | CaptureMethod::Scheduled
+                | CaptureMethod::SequentialAutomatic,
+            )
+            | None => DummyConnectorStatus::Succeeded,
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -330,6 +413,7 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub captured_amount: Option<i64>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -342,6 +426,7 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            captured_amount: value.captured_amount,
         }
     }
 }
@@ -367,10 +452,21 @@ pub struct DummyConnectorPaymentCompleteBody {
     pub confirm: bool,
 }
 
+#[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    pub amount: i64,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    #[serde(skip)]
+    pub authorization_fingerprint: Option<String>,
+}
+
 #[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
     pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub webhook_url: Option<String>,
+    #[serde(skip)]
+    pub authorization_fingerprint: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -409,6 +505,44 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DummyConnectorDispute {
+    pub id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentAuthorized,
+    PaymentSucceeded,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum DummyConnectorWebhookData {
+    Payment(DummyConnectorPaymentResponse),
+    Refund(DummyConnectorRefundResponse),
+    Dispute(DummyConnectorDispute),
+}
+
+/// Body of the webhooks sent to the router, signed using the configured webhook secret
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DummyConnectorWebhookPayload {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorWebhookData,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
@@ -419,3 +553,81 @@ pub struct DummyConnectorUpiFlow {
     pub error: Option<DummyConnectorErrors>,
     pub is_next_action_required: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_payment_data(status: DummyConnectorStatus) -> DummyConnectorPaymentData {
+        DummyConnectorPaymentData {
+            attempt_id: "attempt_1".to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            status,
+            amount: 1000,
+            eligible_amount: 1000,
+            captured_amount: None,
+            currency: Currency::USD,
+            created: common_utils::date_time::now(),
+            payment_method_type: DummyConnectorPaymentMethodType::Card,
+            connector: DummyConnectors::PhonyPay,
+            next_action: None,
+            return_url: None,
+            capture_method: Some(CaptureMethod::Manual),
+            async_flow: None,
+            webhook_url: None,
+            authorization_fingerprint: Some("fingerprint".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_capture_eligibility() {
+        let payment_data = get_payment_data(DummyConnectorStatus::Authorized);
+
+        assert!(payment_data.is_eligible_for_capture(1000).is_ok());
+        assert!(payment_data.is_eligible_for_capture(500).is_ok());
+        for capture_amount in [0, -100] {
+            assert!(matches!(
+                payment_data
+                    .is_eligible_for_capture(capture_amount)
+                    .map_err(|error| error.current_context().clone()),
+                Err(DummyConnectorErrors::InvalidCaptureAmount)
+            ));
+        }
+        assert!(matches!(
+            payment_data
+                .is_eligible_for_capture(1001)
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+        ));
+
+        let captured_payment_data = get_payment_data(DummyConnectorStatus::Succeeded);
+        assert!(matches!(
+            captured_payment_data
+                .is_eligible_for_capture(1000)
+                .map_err(|error| error.current_context().clone()),
+            Err(DummyConnectorErrors::PaymentNotAuthorized)
+        ));
+    }
+
+    #[test]
+    fn test_payment_access_with_authorization() {
+        let payment_data = get_payment_data(DummyConnectorStatus::Authorized);
+
+        assert!(payment_data.is_accessible_with(Some("fingerprint")).is_ok());
+        for authorization_fingerprint in [Some("other_fingerprint"), None] {
+            assert!(matches!(
+                payment_data
+                    .is_accessible_with(authorization_fingerprint)
+                    .map_err(|error| error.current_context().clone()),
+                Err(DummyConnectorErrors::PaymentNotFound)
+            ));
+        }
+
+        let payment_data = DummyConnectorPaymentData {
+            authorization_fingerprint: None,
+            ..payment_data
+        };
+        assert!(payment_data.is_accessible_with(Some("fingerprint")).is_ok());
+        assert!(payment_data.is_accessible_with(None).is_ok());
+    }
+}
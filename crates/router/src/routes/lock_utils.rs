@@ -45,6 +45,7 @@ pub enum ApiIdentifier {
     ProcessTracker,
     Proxy,
     GenericTokenization,
+    Approvals,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -68,6 +69,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingRetrieveConfig
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
+            | Flow::RoutingExplainPaymentMethodEligibility
             | Flow::RoutingRetrieveDictionary
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
@@ -112,6 +114,12 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
+            | Flow::PaymentMethodsMigrationBatchCreate
+            | Flow::PaymentMethodsMigrationBatchRetrieve
+            | Flow::PaymentMethodsMigrationBatchResultsDownload
+            | Flow::CardVaultExportCreate
+            | Flow::CardVaultExportRetrieve
+            | Flow::CardVaultExportFileDownload
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
             | Flow::GetPaymentMethodTokenData
@@ -167,6 +175,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFulfill
             | Flow::PayoutsList
             | Flow::PayoutsFilter
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsBatchRetrieve
+            | Flow::PayoutsBatchResultsDownload
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
             | Flow::PayoutLinkInitiate => Self::Payouts,
@@ -184,9 +195,12 @@ impl From<Flow> for ApiIdentifier {
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::IncomingRelayWebhookReceive
+            | Flow::IncomingNetworkTokenWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventsBulkResend
+            | Flow::WebhookEventsBulkResendRetrieve
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -225,7 +239,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkTemplateUpload => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -274,6 +289,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RecoveryCodeVerify
             | Flow::RecoveryCodesGenerate
             | Flow::TerminateTwoFactorAuth
+            | Flow::PasskeyRegistrationBegin
+            | Flow::PasskeyRegistrationFinish
+            | Flow::PasskeyList
+            | Flow::PasskeyRevoke
+            | Flow::PasskeyBegin
+            | Flow::PasskeyVerify
+            | Flow::PasskeySignInBegin
+            | Flow::PasskeySignIn
             | Flow::TwoFactorAuthStatus
             | Flow::CreateUserAuthenticationMethod
             | Flow::UpdateUserAuthenticationMethod
@@ -292,6 +315,20 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CreateTheme
             | Flow::UpdateTheme
             | Flow::DeleteTheme
+            | Flow::CreateScimToken
+            | Flow::RevokeScimToken
+            | Flow::CreateScimUser
+            | Flow::GetScimUser
+            | Flow::ListScimUsers
+            | Flow::ReplaceScimUser
+            | Flow::PatchScimUser
+            | Flow::DeleteScimUser
+            | Flow::CreateScimGroup
+            | Flow::GetScimGroup
+            | Flow::ListScimGroups
+            | Flow::ReplaceScimGroup
+            | Flow::PatchScimGroup
+            | Flow::DeleteScimGroup
             | Flow::CloneConnector => Self::User,
 
             Flow::ListRolesV2
@@ -320,7 +357,11 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::ReconSettlementFileUpload
+            | Flow::ReconBankStatementUpload
+            | Flow::ReconSettlementRun
+            | Flow::ReconReportRetrieve => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
 
@@ -341,10 +382,21 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionDeleteSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
 
-            Flow::RevenueRecoveryRetrieve => Self::ProcessTracker,
-            Flow::Proxy => Self::Proxy,
+            Flow::RevenueRecoveryRetrieve | Flow::RevenueRecoveryRetryTimeline => {
+                Self::ProcessTracker
+            }
+            Flow::Proxy | Flow::ProxyRoutesUpdate | Flow::ProxyRoutesRetrieve => Self::Proxy,
 
             Flow::TokenizationCreate | Flow::TokenizationRetrieve => Self::GenericTokenization,
+
+            Flow::ApprovalPolicyRetrieve
+            | Flow::ApprovalPolicyUpdate
+            | Flow::ApprovalPolicyDelete
+            | Flow::ApprovalRequestList
+            | Flow::ApprovalRequestRetrieve
+            | Flow::ApprovalRequestApprove
+            | Flow::ApprovalRequestReject
+            | Flow::ApprovalResultRetrieve => Self::Approvals,
         }
     }
 }
@@ -214,3 +214,30 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkTemplateUpload))]
+pub async fn upload_payment_link_template(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::PaymentLinkTemplateUploadRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkTemplateUpload;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            upload_payment_link_template(state, merchant_context, payload)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -17,6 +17,8 @@ use hyperswitch_domain_models::{
 use router_env::{instrument, logger, tracing, Flow};
 
 use super::app::{AppState, SessionState};
+#[cfg(feature = "v1")]
+use crate::core::payment_methods::{migration_batch, vault_export};
 use crate::{
     core::{
         api_locking,
@@ -399,6 +401,193 @@ pub async fn migrate_payment_methods(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationBatchCreate))]
+pub async fn payment_methods_migration_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<migration_batch::PaymentMethodMigrationBatchForm>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationBatchCreate;
+    let merchant_id = form.merchant_id.into_inner();
+    let merchant_connector_id = form
+        .merchant_connector_id
+        .map(|merchant_connector_id| merchant_connector_id.into_inner());
+    let is_encrypted = form
+        .is_encrypted
+        .is_some_and(|is_encrypted| is_encrypted.into_inner());
+    let dry_run = form.dry_run.is_some_and(|dry_run| dry_run.into_inner());
+    let file = form.file.data.to_vec();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            migration_batch::create_payment_method_migration_batch(
+                state,
+                merchant_context,
+                file.clone(),
+                is_encrypted,
+                merchant_connector_id.clone(),
+                dry_run,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationBatchRetrieve))]
+pub async fn payment_methods_migration_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationBatchRetrieve;
+    let (merchant_id, batch_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            migration_batch::retrieve_payment_method_migration_batch(
+                state,
+                merchant_context,
+                batch_id,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsMigrationBatchResultsDownload))]
+pub async fn payment_methods_migration_batch_results_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsMigrationBatchResultsDownload;
+    let (merchant_id, batch_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            migration_batch::download_payment_method_migration_batch_results(
+                state,
+                merchant_context,
+                batch_id,
+            )
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardVaultExportCreate))]
+pub async fn card_vault_export_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::CardVaultExportRequest>,
+) -> HttpResponse {
+    let flow = Flow::CardVaultExportCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, request, req_state| {
+            vault_export::create_card_vault_export(state, req_state, user, request)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardVaultExportRetrieve))]
+pub async fn card_vault_export_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CardVaultExportRetrieve;
+    let export_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        export_id,
+        |state, user, export_id, _| {
+            vault_export::retrieve_card_vault_export(state, user, export_id)
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardVaultExportFileDownload))]
+pub async fn card_vault_export_file_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::CardVaultExportFileDownload;
+    let (export_id, file_name) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        file_name,
+        |state, user, file_name, req_state| {
+            vault_export::download_card_vault_export_file(
+                state,
+                req_state,
+                user,
+                export_id.clone(),
+                file_name,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
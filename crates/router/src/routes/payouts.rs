@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{
     body::{BoxBody, MessageBody},
     web, HttpRequest, HttpResponse, Responder,
@@ -455,6 +457,122 @@ pub async fn payouts_list_available_filters_for_profile(
     .await
 }
 
+/// Payouts - Batch Create
+#[cfg(all(feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<batch::PayoutBatchUploadForm>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    let file = form.file.data.to_vec();
+    let file_format = form
+        .file_format
+        .map(|file_format| file_format.into_inner())
+        .unwrap_or_default();
+    let concurrency = form.concurrency.map(|concurrency| concurrency.into_inner());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::create_payout_batch(
+                state,
+                merchant_context,
+                file.clone(),
+                file_format,
+                concurrency,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Retrieve
+#[cfg(all(feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchRetrieve))]
+pub async fn payouts_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchRetrieve;
+    let batch_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::retrieve_payout_batch(state, merchant_context, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Results Download
+#[cfg(all(feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchResultsDownload))]
+pub async fn payouts_batch_results_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchResultsDownload;
+    let batch_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::download_payout_batch_results(state, merchant_context, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -21,11 +21,60 @@ pub async fn proxy(
         state,
         &req,
         payload.into_inner(),
-        |state, auth: auth::AuthenticationData, req, _| {
+        |state, auth: auth::AuthenticationData, req, req_state| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            proxy::proxy_core(state, merchant_context, req)
+            proxy::proxy_core(state, merchant_context, auth.profile, req_state, req)
+        },
+        &auth::V2ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProxyRoutesUpdate))]
+pub async fn update_proxy_routes(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::proxy::ProxyRoutesRequest>,
+) -> impl Responder {
+    let flow = Flow::ProxyRoutesUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            proxy::update_proxy_routes(state, auth.profile, req)
+        },
+        &auth::V2ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProxyRoutesRetrieve))]
+pub async fn retrieve_proxy_routes(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+) -> impl Responder {
+    let flow = Flow::ProxyRoutesRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            proxy::retrieve_proxy_routes(state, auth.profile)
         },
         &auth::V2ApiKeyAuth {
             is_connected_allowed: false,
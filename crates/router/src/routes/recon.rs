@@ -1,8 +1,12 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::recon as recon_api;
 use router_env::Flow;
 
 use super::AppState;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{core::recon::settlement, types::domain};
 use crate::{
     core::{api_locking, recon},
     services::{api, authentication, authorization::permissions::Permission},
@@ -77,3 +81,151 @@ pub async fn verify_recon_token(state: web::Data<AppState>, http_req: HttpReques
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn upload_settlement_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_models::enums::Connector>,
+    MultipartForm(form): MultipartForm<settlement::SettlementFileUploadForm>,
+) -> HttpResponse {
+    let flow = Flow::ReconSettlementFileUpload;
+    let connector = path.into_inner();
+    let file_name = form.file_name.into_inner();
+    let file = form.file.data.to_vec();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        file_name,
+        |state, auth: authentication::AuthenticationData, file_name, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::upload_settlement_file(
+                state,
+                merchant_context,
+                connector,
+                file_name,
+                file.clone(),
+            )
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconUploadWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn upload_bank_statement(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<settlement::SettlementFileUploadForm>,
+) -> HttpResponse {
+    let flow = Flow::ReconBankStatementUpload;
+    let file_name = form.file_name.into_inner();
+    let file = form.file.data.to_vec();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        file_name,
+        |state, auth: authentication::AuthenticationData, file_name, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::upload_bank_statement(state, merchant_context, file_name, file.clone())
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconUploadWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn run_settlement_recon(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_api::ReconSettlementRunRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconSettlementRun;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: authentication::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::run_settlement_recon(state, merchant_context, req)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantRunReconWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn retrieve_recon_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReconReportRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: authentication::AuthenticationData, recon_id, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::retrieve_recon_report(state, merchant_context, recon_id)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -3,6 +3,8 @@ use common_utils;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::approvals;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
 use crate::core::refunds::*;
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
@@ -68,16 +70,27 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let request_headers = req.headers();
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, req, _| {
+        |state, auth: auth::AuthenticationData, req, req_state| async move {
+            approvals::check_approval_required(
+                &state,
+                &req_state,
+                request_headers,
+                &auth.merchant_account,
+                &auth.key_store,
+                auth.profile_id.clone(),
+                approvals::ApprovalPayload::RefundCreate(req.clone()),
+            )
+            .await?;
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refund_create_core(state, merchant_context, auth.profile_id, req)
+            refund_create_core(state, merchant_context, auth.profile_id, req).await
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -1161,6 +1161,50 @@ pub async fn routing_update_default_config_for_profile(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_explain_payment_method_eligibility(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::ProfileId>,
+    json_payload: web::Json<routing_types::PaymentMethodEligibilityRequest>,
+) -> impl Responder {
+    let wrapper = routing_types::PaymentMethodEligibilityWrapper {
+        profile_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        Flow::RoutingExplainPaymentMethodEligibility,
+        state,
+        &req,
+        wrapper.clone(),
+        |state, auth: auth::AuthenticationData, wrapper, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::explain_payment_method_eligibility(
+                state,
+                merchant_context,
+                wrapper.profile_id,
+                wrapper.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: wrapper.profile_id,
+                required_permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v1", feature = "dynamic_routing"))]
 #[instrument(skip_all)]
 pub async fn toggle_success_based_routing(
@@ -0,0 +1,332 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::user::scim as scim_api;
+use common_utils::id_type;
+use router_env::Flow;
+
+use crate::{
+    core::{api_locking, user::scim as scim_core},
+    routes::AppState,
+    services::{
+        api,
+        authentication::{self as auth, AuthenticationDataWithOrg},
+        authorization::permissions::Permission,
+    },
+};
+
+pub async fn create_scim_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::OrganizationId>,
+) -> HttpResponse {
+    let flow = Flow::CreateScimToken;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| scim_core::generate_scim_token(state, org_id.clone()),
+        &auth::JWTAuthOrganizationFromRoute {
+            organization_id: org_id.clone(),
+            required_permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn revoke_scim_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::OrganizationId>,
+) -> HttpResponse {
+    let flow = Flow::RevokeScimToken;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| scim_core::revoke_scim_token(state, org_id.clone()),
+        &auth::JWTAuthOrganizationFromRoute {
+            organization_id: org_id.clone(),
+            required_permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::OrganizationId>,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateScimUser;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, payload, _| {
+            scim_core::create_scim_user(state, auth.organization_id, payload)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_scim_users(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::OrganizationId>,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ListScimUsers;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, query, _| {
+            scim_core::list_scim_users(state, auth.organization_id, query)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+) -> HttpResponse {
+    let flow = Flow::GetScimUser;
+    let (org_id, user_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        user_id,
+        |state, auth: AuthenticationDataWithOrg, user_id, _| {
+            scim_core::get_scim_user(state, auth.organization_id, user_id)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn replace_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReplaceScimUser;
+    let (org_id, user_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, payload, _| {
+            scim_core::replace_scim_user(state, auth.organization_id, user_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::PatchScimUser;
+    let (org_id, user_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, payload, _| {
+            scim_core::patch_scim_user(state, auth.organization_id, user_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_scim_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+) -> HttpResponse {
+    let flow = Flow::DeleteScimUser;
+    let (org_id, user_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        user_id,
+        |state, auth: AuthenticationDataWithOrg, user_id, _| {
+            scim_core::delete_scim_user(state, auth.organization_id, user_id)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::OrganizationId>,
+    json_payload: web::Json<scim_api::ScimGroupRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateScimGroup;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, payload, _| {
+            scim_core::create_scim_group(state, auth.organization_id, payload)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_scim_groups(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::OrganizationId>,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ListScimGroups;
+    let org_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, query, _| {
+            scim_core::list_scim_groups(state, auth.organization_id, query)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+) -> HttpResponse {
+    let flow = Flow::GetScimGroup;
+    let (org_id, group_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        group_id,
+        |state, auth: AuthenticationDataWithOrg, group_id, _| {
+            scim_core::get_scim_group(state, auth.organization_id, group_id)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn replace_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+    json_payload: web::Json<scim_api::ScimGroupRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReplaceScimGroup;
+    let (org_id, group_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, payload, _| {
+            scim_core::replace_scim_group(state, auth.organization_id, group_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::PatchScimGroup;
+    let (org_id, group_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: AuthenticationDataWithOrg, payload, _| {
+            scim_core::patch_scim_group(state, auth.organization_id, group_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_scim_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::OrganizationId, String)>,
+) -> HttpResponse {
+    let flow = Flow::DeleteScimGroup;
+    let (org_id, group_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        group_id,
+        |state, auth: AuthenticationDataWithOrg, group_id, _| {
+            scim_core::delete_scim_group(state, auth.organization_id, group_id)
+        },
+        &auth::ScimTokenAuth(org_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
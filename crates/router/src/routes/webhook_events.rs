@@ -11,7 +11,8 @@ use crate::{
     },
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookEventsBulkResendRequest,
+        WebhookEventsBulkResendRequestInternal, WebhookEventsBulkResendRetrieveRequestInternal,
     },
 };
 
@@ -174,3 +175,84 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsBulkResend))]
+#[cfg(feature = "v1")]
+pub async fn bulk_resend_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookEventsBulkResendRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventsBulkResend;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookEventsBulkResendRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::bulk_resend_events(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventsBulkResendRetrieve))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_events_bulk_resend(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventsBulkResendRetrieve;
+    let (merchant_id, batch_id) = path.into_inner();
+
+    let request_internal = WebhookEventsBulkResendRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        batch_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_bulk_resend_job(
+                state,
+                request_internal.merchant_id,
+                request_internal.batch_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
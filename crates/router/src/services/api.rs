@@ -1,4 +1,6 @@
 pub mod client;
+#[cfg(feature = "connector_fixtures")]
+pub mod fixtures;
 pub mod generic_link_response;
 pub mod request;
 use std::{
@@ -25,6 +27,8 @@ use common_utils::{
     request::RequestContent,
 };
 use error_stack::{report, Report, ResultExt};
+#[cfg(feature = "connector_fixtures")]
+pub use fixtures::{FixtureApiClient, FixtureMode};
 use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types;
 pub use hyperswitch_domain_models::{
     api::{
@@ -1892,11 +1896,28 @@ fn build_payment_link_template(
     //Locale template
     let locale_template = include_str!("../core/payment_link/locale.js").to_string();
 
-    // Modify Html template with rendered js and rendered css files
-    let html_template =
-        include_str!("../core/payment_link/payment_link_initiate/payment_link.html").to_string();
+    // Modify Html template with rendered js and rendered css files, using the merchant's custom
+    // template if one is configured
+    let html_template = match &payment_link_data.custom_template {
+        Some(custom_template) => {
+            generic_link_response::context::insert_locales_in_context_for_payment_link(
+                &mut context,
+                &custom_template.locale,
+                &custom_template.locale_bundles,
+            );
+            custom_template.template.clone()
+        }
+        None => {
+            include_str!("../core/payment_link/payment_link_initiate/payment_link.html").to_string()
+        }
+    };
 
-    let _ = tera.add_raw_template("payment_link", &html_template);
+    tera.add_raw_template("payment_link", &html_template)
+        .map_err(|tera_error: TeraError| {
+            crate::logger::warn!("{tera_error}");
+            report!(errors::ApiErrorResponse::InternalServerError)
+        })
+        .attach_printable("Failed to parse payment link's HTML template")?;
 
     context.insert("rendered_meta_tag_html", &payment_link_data.html_meta_tags);
 
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common_utils::consts::DEFAULT_LOCALE;
 use rust_i18n::t;
 use tera::Context;
@@ -102,3 +104,44 @@ pub fn insert_locales_in_context_for_payout_link_status(context: &mut Context, l
     context.insert("i18n_redirecting_in_text", &i18n_redirecting_in_text);
     context.insert("i18n_seconds_text", &i18n_seconds_text);
 }
+
+/// Inserts the localized strings available to custom payment link templates, preferring the
+/// strings configured by the merchant for the locale over the default translations.
+pub fn insert_locales_in_context_for_payment_link(
+    context: &mut Context,
+    locale: &str,
+    locale_bundles: &HashMap<String, HashMap<String, String>>,
+) {
+    let language = get_language(locale);
+    let locale = language.as_str();
+    let default_strings = [
+        ("expires_on", t!("payment_link.expires_on", locale = locale)),
+        ("ref_id", t!("payment_link.ref_id", locale = locale)),
+        (
+            "requested_by",
+            t!("payment_link.requested_by", locale = locale),
+        ),
+        ("pay_now", t!("payment_link.pay_now", locale = locale)),
+        ("your_cart", t!("payment_link.your_cart", locale = locale)),
+        ("quantity", t!("payment_link.quantity", locale = locale)),
+        ("show_less", t!("payment_link.show_less", locale = locale)),
+        ("show_more", t!("payment_link.show_more", locale = locale)),
+        (
+            "payment_link_expired",
+            t!("payment_link.payment_link_expired", locale = locale),
+        ),
+        (
+            "payment_link_expired_message",
+            t!("payment_link.payment_link_expired_message", locale = locale),
+        ),
+    ];
+    let locale_bundle = locale_bundles.get(locale);
+
+    for (key, default_string) in default_strings {
+        let string = locale_bundle
+            .and_then(|locale_bundle| locale_bundle.get(key))
+            .map(String::as_str)
+            .unwrap_or(default_string.as_ref());
+        context.insert(format!("i18n_{key}"), &tera::escape_html(string));
+    }
+}
@@ -158,6 +158,9 @@ pub enum AuthenticationType {
     WebhookAuth {
         merchant_id: id_type::MerchantId,
     },
+    ScimToken {
+        org_id: id_type::OrganizationId,
+    },
     NoAuth,
 }
 
@@ -193,6 +196,7 @@ impl AuthenticationType {
             | Self::UserJwt { .. }
             | Self::SinglePurposeJwt { .. }
             | Self::SinglePurposeOrLoginJwt { .. }
+            | Self::ScimToken { .. }
             | Self::NoAuth => None,
         }
     }
@@ -1127,6 +1131,55 @@ where
     }
 }
 
+/// Authenticates SCIM requests from an identity provider using the bearer token
+/// configured for the organization in the route
+#[derive(Debug)]
+pub struct ScimTokenAuth(pub id_type::OrganizationId);
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationDataWithOrg, A> for ScimTokenAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationDataWithOrg, AuthenticationType)> {
+        let request_token = get_jwt_from_authorization_header(request_headers)
+            .change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+        let hash_key = state.conf().api_keys.get_inner().get_hash_key()?;
+        let hashed_token: storage::HashedApiKey = api_keys::PlaintextApiKey::from(request_token)
+            .keyed_hash(hash_key.peek())
+            .into();
+
+        let stored_token = state
+            .store()
+            .find_config_by_key(&self.0.get_scim_token_key())
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("SCIM token is not configured for the organization")?;
+
+        // Compared as strong secrets, which compare in constant time
+        if masking::StrongSecret::<String>::new(hashed_token.into_inner())
+            != masking::StrongSecret::new(stored_token.config)
+        {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("SCIM Authentication Failure");
+        }
+
+        Ok((
+            AuthenticationDataWithOrg {
+                organization_id: self.0.clone(),
+            },
+            AuthenticationType::ScimToken {
+                org_id: self.0.clone(),
+            },
+        ))
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct V2AdminApiAuth;
 
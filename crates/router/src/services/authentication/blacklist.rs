@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 #[cfg(feature = "olap")]
 use common_utils::date_time;
+use common_utils::id_type;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionPool;
 
@@ -11,7 +12,10 @@ use super::{SinglePurposeOrLoginToken, SinglePurposeToken};
 #[cfg(feature = "email")]
 use crate::consts::{EMAIL_TOKEN_BLACKLIST_PREFIX, EMAIL_TOKEN_TIME_IN_SECS};
 use crate::{
-    consts::{JWT_TOKEN_TIME_IN_SECS, ROLE_BLACKLIST_PREFIX, USER_BLACKLIST_PREFIX},
+    consts::{
+        JWT_TOKEN_TIME_IN_SECS, ROLE_BLACKLIST_PREFIX, USER_BLACKLIST_PREFIX,
+        USER_ORG_BLACKLIST_PREFIX,
+    },
     core::errors::{ApiErrorResponse, RouterResult},
     routes::app::SessionStateInfo,
 };
@@ -39,6 +43,29 @@ pub async fn insert_user_in_blacklist(state: &SessionState, user_id: &str) -> Us
         .change_context(UserErrors::InternalServerError)
 }
 
+/// Invalidates the tokens issued to the user for the organization, tokens issued for other
+/// organizations remain valid
+#[cfg(feature = "olap")]
+pub async fn insert_user_org_in_blacklist(
+    state: &SessionState,
+    user_id: &str,
+    org_id: &id_type::OrganizationId,
+) -> UserResult<()> {
+    let user_org_blacklist_key = get_user_org_blacklist_key(user_id, org_id);
+    let expiry =
+        expiry_to_i64(JWT_TOKEN_TIME_IN_SECS).change_context(UserErrors::InternalServerError)?;
+    let redis_conn = get_redis_connection_for_global_tenant(state)
+        .change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_key_with_expiry(
+            &user_org_blacklist_key.as_str().into(),
+            date_time::now_unix_timestamp(),
+            expiry,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
 #[cfg(feature = "olap")]
 pub async fn insert_role_in_blacklist(state: &SessionState, role_id: &str) -> UserResult<()> {
     let role_blacklist_key = format!("{}{}", ROLE_BLACKLIST_PREFIX, role_id);
@@ -84,6 +111,22 @@ pub async fn check_user_in_blacklist<A: SessionStateInfo>(
         .map(|timestamp| timestamp > Some(token_issued_at))
 }
 
+pub async fn check_user_org_in_blacklist<A: SessionStateInfo>(
+    state: &A,
+    user_id: &str,
+    org_id: &id_type::OrganizationId,
+    token_expiry: u64,
+) -> RouterResult<bool> {
+    let token = get_user_org_blacklist_key(user_id, org_id);
+    let token_issued_at = expiry_to_i64(token_expiry - JWT_TOKEN_TIME_IN_SECS)?;
+    let redis_conn = get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .get_key::<Option<i64>>(&token.as_str().into())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .map(|timestamp| timestamp > Some(token_issued_at))
+}
+
 pub async fn check_role_in_blacklist<A: SessionStateInfo>(
     state: &A,
     role_id: &str,
@@ -128,6 +171,15 @@ pub async fn check_email_token_in_blacklist(state: &SessionState, token: &str) -
     Ok(())
 }
 
+fn get_user_org_blacklist_key(user_id: &str, org_id: &id_type::OrganizationId) -> String {
+    format!(
+        "{}{}_{}",
+        USER_ORG_BLACKLIST_PREFIX,
+        user_id,
+        org_id.get_string_repr()
+    )
+}
+
 fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
     state: &A,
 ) -> RouterResult<Arc<RedisConnectionPool>> {
@@ -157,6 +209,8 @@ impl BlackList for AuthToken {
     {
         Ok(
             check_user_in_blacklist(state, &self.user_id, self.exp).await?
+                || check_user_org_in_blacklist(state, &self.user_id, &self.org_id, self.exp)
+                    .await?
                 || check_role_in_blacklist(state, &self.role_id, self.exp).await?,
         )
     }
@@ -161,9 +161,17 @@ pub struct KafkaSettings {
     payout_analytics_topic: String,
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
+    #[serde(default = "KafkaSettings::default_recon_analytics_topic")]
+    recon_analytics_topic: String,
 }
 
 impl KafkaSettings {
+    /// Recon events were added after the other topics, deployments which do not configure a
+    /// topic for them fall back to this one instead of failing validation
+    fn default_recon_analytics_topic() -> String {
+        String::from("hyperswitch-recon-events")
+    }
+
     pub fn validate(&self) -> Result<(), crate::core::errors::ApplicationError> {
         use common_utils::ext_traits::ConfigExt;
 
@@ -248,6 +256,12 @@ impl KafkaSettings {
             },
         )?;
 
+        common_utils::fp_utils::when(self.recon_analytics_topic.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka Recon Analytics topic must not be empty".into(),
+            ))
+        })?;
+
         Ok(())
     }
 }
@@ -268,6 +282,7 @@ pub struct KafkaProducer {
     payout_analytics_topic: String,
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
+    recon_analytics_topic: String,
     ckh_database_name: Option<String>,
 }
 
@@ -317,6 +332,7 @@ impl KafkaProducer {
             payout_analytics_topic: conf.payout_analytics_topic.clone(),
             consolidated_events_topic: conf.consolidated_events_topic.clone(),
             authentication_analytics_topic: conf.authentication_analytics_topic.clone(),
+            recon_analytics_topic: conf.recon_analytics_topic.clone(),
             ckh_database_name: None,
         })
     }
@@ -653,6 +669,7 @@ impl KafkaProducer {
             EventType::Payout => &self.payout_analytics_topic,
             EventType::Consolidated => &self.consolidated_events_topic,
             EventType::Authentication => &self.authentication_analytics_topic,
+            EventType::Recon => &self.recon_analytics_topic,
         }
     }
 }
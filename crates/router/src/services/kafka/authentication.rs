@@ -42,6 +42,7 @@ pub struct KafkaAuthentication<'a> {
     pub directory_server_id: Option<&'a String>,
     pub acquirer_country_code: Option<&'a String>,
     pub organization_id: &'a common_utils::id_type::OrganizationId,
+    pub psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
 }
 
 impl<'a> KafkaAuthentication<'a> {
@@ -84,6 +85,7 @@ impl<'a> KafkaAuthentication<'a> {
             directory_server_id: authentication.directory_server_id.as_ref(),
             acquirer_country_code: authentication.acquirer_country_code.as_ref(),
             organization_id: &authentication.organization_id,
+            psd2_sca_exemption_type: authentication.psd2_sca_exemption_type,
         }
     }
 }
@@ -43,6 +43,7 @@ pub struct KafkaAuthenticationEvent<'a> {
     pub directory_server_id: Option<&'a String>,
     pub acquirer_country_code: Option<&'a String>,
     pub organization_id: &'a common_utils::id_type::OrganizationId,
+    pub psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
 }
 
 impl<'a> KafkaAuthenticationEvent<'a> {
@@ -85,6 +86,7 @@ impl<'a> KafkaAuthenticationEvent<'a> {
             directory_server_id: authentication.directory_server_id.as_ref(),
             acquirer_country_code: authentication.acquirer_country_code.as_ref(),
             organization_id: &authentication.organization_id,
+            psd2_sca_exemption_type: authentication.psd2_sca_exemption_type,
         }
     }
 }
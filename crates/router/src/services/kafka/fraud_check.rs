@@ -27,6 +27,8 @@ pub struct KafkaFraudCheck<'a> {
     pub modified_at: OffsetDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    pub feedback_type: Option<storage_enums::FrmFeedbackType>,
+    pub dispute_status: Option<storage_enums::DisputeStatus>,
 }
 
 impl<'a> KafkaFraudCheck<'a> {
@@ -49,6 +51,8 @@ impl<'a> KafkaFraudCheck<'a> {
             modified_at: check.modified_at.assume_utc(),
             last_step: check.last_step,
             payment_capture_method: check.payment_capture_method,
+            feedback_type: check.feedback_type,
+            dispute_status: check.dispute_status,
         }
     }
 }
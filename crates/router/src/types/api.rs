@@ -617,9 +617,12 @@ impl ConnectorData {
                 }
                 enums::Connector::Signifyd
                 | enums::Connector::Riskified
+                | enums::Connector::HyperswitchFrm
                 | enums::Connector::Gpayments
                 | enums::Connector::Threedsecureio
-                | enums::Connector::Taxjar => {
+                | enums::Connector::Taxjar
+                | enums::Connector::Truelayer
+                | enums::Connector::GocardlessBankData => {
                     Err(report!(errors::ConnectorError::InvalidConnectorName)
                         .attach_printable(format!("invalid connector name: {connector_name}")))
                     .change_context(errors::ApiErrorResponse::InternalServerError)
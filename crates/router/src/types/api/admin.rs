@@ -187,6 +187,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             is_network_tokenization_enabled: item.is_network_tokenization_enabled,
             is_auto_retries_enabled: item.is_auto_retries_enabled,
             max_auto_retries_enabled: item.max_auto_retries_enabled,
+            is_payout_auto_retries_enabled: item.is_payout_auto_retries_enabled,
+            max_payout_auto_retries_enabled: item.max_payout_auto_retries_enabled,
             always_request_extended_authorization: item.always_request_extended_authorization,
             is_click_to_pay_enabled: item.is_click_to_pay_enabled,
             authentication_product_ids: item.authentication_product_ids,
@@ -302,7 +304,13 @@ pub async fn create_profile_from_merchant_account(
 
     let current_time = common_utils::date_time::now();
 
-    let webhook_details = request.webhook_details.map(ForeignInto::foreign_into);
+    let webhook_details = request
+        .webhook_details
+        .async_map(|webhook_details| {
+            core::admin::get_webhook_details_for_storage(state, key_store, webhook_details, None)
+        })
+        .await
+        .transpose()?;
 
     let payment_response_hash_key = request
         .payment_response_hash_key
@@ -420,6 +428,8 @@ pub async fn create_profile_from_merchant_account(
         is_network_tokenization_enabled: request.is_network_tokenization_enabled,
         is_auto_retries_enabled: request.is_auto_retries_enabled.unwrap_or_default(),
         max_auto_retries_enabled: request.max_auto_retries_enabled.map(i16::from),
+        is_payout_auto_retries_enabled: request.is_payout_auto_retries_enabled.unwrap_or_default(),
+        max_payout_auto_retries_enabled: request.max_payout_auto_retries_enabled.map(i16::from),
         always_request_extended_authorization: request.always_request_extended_authorization,
         is_click_to_pay_enabled: request.is_click_to_pay_enabled,
         authentication_product_ids: request.authentication_product_ids,
@@ -59,6 +59,10 @@ impl FraudCheckConnectorData {
             enums::FrmConnectors::Riskified => {
                 Ok(ConnectorEnum::Old(Box::new(connector::Riskified::new())))
             }
+            enums::FrmConnectors::HyperswitchFrm => {
+                Err(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+                    .attach_printable("hyperswitch_frm is evaluated natively and has no connector")
+            }
         }
     }
 }
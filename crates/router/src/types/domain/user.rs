@@ -936,6 +936,7 @@ impl TryFrom<NewUser> for storage_user::UserNew {
                 .password
                 .and_then(|password_inner| password_inner.is_temporary.not().then_some(now)),
             lineage_context: None,
+            passkeys: None,
         })
     }
 }
@@ -1078,6 +1079,49 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUser {
     }
 }
 
+impl TryFrom<user_api::scim::ScimUserRequest> for NewUser {
+    type Error = error_stack::Report<UserErrors>;
+
+    fn try_from(value: user_api::scim::ScimUserRequest) -> UserResult<Self> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let email = value.user_name.clone().try_into()?;
+        let display_name = value.display_name.or_else(|| {
+            value.name.and_then(|name| {
+                name.formatted.or_else(|| {
+                    let full_name = [name.given_name, name.family_name]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    (!full_name.is_empty()).then_some(full_name)
+                })
+            })
+        });
+        let name = match display_name {
+            Some(display_name) => UserName::new(display_name.into())?,
+            None => UserName::try_from(value.user_name)?,
+        };
+        // SCIM provisioned users sign in through the organization's identity provider,
+        // the merchant here is never persisted
+        let new_merchant = NewUserMerchant {
+            merchant_id: id_type::MerchantId::default(),
+            company_name: None,
+            new_organization: NewUserOrganization(ForeignFrom::foreign_from(
+                api_org::OrganizationNew::new(common_enums::OrganizationType::Standard, None),
+            )),
+            product_type: None,
+        };
+
+        Ok(Self {
+            user_id,
+            name,
+            email,
+            password: None,
+            new_merchant,
+        })
+    }
+}
+
 impl TryFrom<(user_api::CreateTenantUserRequest, MerchantAccountIdentifier)> for NewUser {
     type Error = error_stack::Report<UserErrors>;
 
@@ -1290,6 +1334,41 @@ impl UserFromStorage {
         .change_context(UserErrors::InternalServerError)?
         .map(Encryptable::into_inner))
     }
+
+    pub async fn decrypt_and_get_passkeys(
+        &self,
+        state: &SessionState,
+    ) -> UserResult<Vec<utils::user::passkey::UserPasskey>> {
+        if self.0.passkeys.is_none() {
+            return Ok(Vec::new());
+        }
+        let key_manager_state = &state.into();
+        let user_key_store = state
+            .global_store
+            .get_user_key_store_by_user_id(
+                key_manager_state,
+                self.get_user_id(),
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+
+        domain_types::crypto_operation::<String, masking::WithType>(
+            key_manager_state,
+            type_name!(storage_user::User),
+            domain_types::CryptoOperation::DecryptOptional(self.0.passkeys.clone()),
+            Identifier::User(user_key_store.user_id.clone()),
+            user_key_store.key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_optionaloperation())
+        .change_context(UserErrors::InternalServerError)?
+        .map(|passkeys| serde_json::from_str(passkeys.into_inner().peek()))
+        .transpose()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to deserialize passkeys")
+        .map(Option::unwrap_or_default)
+    }
 }
 
 impl ForeignFrom<UserStatus> for user_role_api::UserStatus {
@@ -382,3 +382,38 @@ pub struct CheckTokenStatusResponsePayload {
 pub struct CheckTokenStatusResponse {
     pub payload: CheckTokenStatusResponsePayload,
 }
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum NetworkTokenLifecycleEvent {
+    /// The underlying card was re-issued or its details changed, the token stays usable
+    CardUpdated,
+    TokenSuspended,
+    TokenResumed,
+    TokenDeleted,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedNetworkTokenDetails {
+    pub token_last_four: Option<String>,
+    pub token_expiry_month: Option<Secret<String>>,
+    pub token_expiry_year: Option<Secret<String>>,
+    pub card_last_four: Option<String>,
+    pub card_expiry_month: Option<Secret<String>>,
+    pub card_expiry_year: Option<Secret<String>>,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenLifecycleNotification {
+    pub card_reference: String,
+    pub customer_id: id_type::CustomerId,
+    pub event_type: NetworkTokenLifecycleEvent,
+    pub token_details: Option<UpdatedNetworkTokenDetails>,
+}
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use error_stack::ResultExt;
 use pm_auth::{
-    connector::plaid,
+    connector::{gocardless_bank_data, plaid, truelayer},
     types::{
         self as pm_auth_types,
         api::{BoxedPaymentAuthConnector, PaymentAuthConnectorData},
@@ -32,6 +32,20 @@ impl PaymentAuthConnectorDataExt for PaymentAuthConnectorData {
     ) -> errors::CustomResult<BoxedPaymentAuthConnector, ApiErrorResponse> {
         match connector_name {
             pm_auth_types::PaymentMethodAuthConnectors::Plaid => Ok(Box::new(&plaid::Plaid)),
+            pm_auth_types::PaymentMethodAuthConnectors::Truelayer => {
+                Ok(Box::new(&truelayer::Truelayer))
+            }
+            pm_auth_types::PaymentMethodAuthConnectors::GocardlessBankData => {
+                Ok(Box::new(&gocardless_bank_data::GocardlessBankData))
+            }
+            #[cfg(feature = "dummy_connector")]
+            pm_auth_types::PaymentMethodAuthConnectors::Mock => {
+                Ok(Box::new(&pm_auth::connector::mock::Mock))
+            }
+            #[cfg(not(feature = "dummy_connector"))]
+            pm_auth_types::PaymentMethodAuthConnectors::Mock => {
+                Err(ApiErrorResponse::IncorrectConnectorNameGiven.into())
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod approval_request;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -68,10 +69,10 @@ pub use hyperswitch_domain_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    address::*, api_keys::*, approval_request::*, authentication::*, authorization::*,
+    blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*,
+    callback_mapper::*, capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*,
+    dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
     generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
     process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
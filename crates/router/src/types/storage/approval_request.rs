@@ -0,0 +1,3 @@
+pub use diesel_models::approval_request::{
+    ApprovalRequest, ApprovalRequestNew, ApprovalRequestUpdate,
+};
@@ -4,10 +4,13 @@ use common_enums::enums;
 use common_utils::id_type;
 use hyperswitch_domain_models::{
     business_profile, merchant_account, merchant_connector_account, merchant_key_store,
+    payments::payment_attempt,
 };
 use router_env::logger;
 
-use crate::{db::StorageInterface, workflows::revenue_recovery};
+use crate::{
+    core::revenue_recovery::retry_strategy, routes::SessionState, workflows::revenue_recovery,
+};
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct RevenueRecoveryWorkflowTrackingData {
     pub merchant_id: id_type::MerchantId,
@@ -29,9 +32,10 @@ pub struct RevenueRecoveryPaymentData {
 impl RevenueRecoveryPaymentData {
     pub async fn get_schedule_time_based_on_retry_type(
         &self,
-        db: &dyn StorageInterface,
+        state: &SessionState,
         merchant_id: &id_type::MerchantId,
         retry_count: i32,
+        payment_attempt: &payment_attempt::PaymentAttempt,
     ) -> Option<time::PrimitiveDateTime> {
         match self.retry_algorithm {
             enums::RevenueRecoveryAlgorithmType::Monitoring => {
@@ -40,15 +44,20 @@ impl RevenueRecoveryPaymentData {
             }
             enums::RevenueRecoveryAlgorithmType::Cascading => {
                 revenue_recovery::get_schedule_time_to_retry_mit_payments(
-                    db,
+                    &*state.store,
                     merchant_id,
                     retry_count,
                 )
                 .await
             }
             enums::RevenueRecoveryAlgorithmType::Smart => {
-                // TODO: Integrate the smart retry call to return back a schedule time
-                None
+                retry_strategy::get_schedule_time_for_smart_retry(
+                    state,
+                    self,
+                    payment_attempt,
+                    retry_count,
+                )
+                .await
             }
         }
     }
@@ -317,6 +317,11 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
                     message: "riskified is not a routable connector".to_string(),
                 })?
             }
+            api_enums::Connector::HyperswitchFrm => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "hyperswitch_frm is not a routable connector".to_string(),
+                })?
+            }
             api_enums::Connector::Square => Self::Square,
             api_enums::Connector::Stax => Self::Stax,
             api_enums::Connector::Stripe => Self::Stripe,
@@ -374,6 +379,16 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
                     message: "Taxjar is not a routable connector".to_string(),
                 })?
             }
+            api_enums::Connector::Truelayer => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "Truelayer is not a routable connector".to_string(),
+                })?
+            }
+            api_enums::Connector::GocardlessBankData => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "GocardlessBankData is not a routable connector".to_string(),
+                })?
+            }
         })
     }
 }
@@ -2050,6 +2065,9 @@ impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
             authentication_connectors: item.authentication_connectors,
             three_ds_requestor_url: item.three_ds_requestor_url,
             three_ds_requestor_app_url: item.three_ds_requestor_app_url,
+            three_ds_exemption_policy: item
+                .three_ds_exemption_policy
+                .map(ForeignFrom::foreign_from),
         }
     }
 }
@@ -2062,6 +2080,41 @@ impl ForeignFrom<diesel_models::business_profile::AuthenticationConnectorDetails
             authentication_connectors: item.authentication_connectors,
             three_ds_requestor_url: item.three_ds_requestor_url,
             three_ds_requestor_app_url: item.three_ds_requestor_app_url,
+            three_ds_exemption_policy: item
+                .three_ds_exemption_policy
+                .map(ForeignFrom::foreign_from),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::ThreeDsExemptionPolicy>
+    for diesel_models::business_profile::ThreeDsExemptionPolicy
+{
+    fn foreign_from(item: api_models::admin::ThreeDsExemptionPolicy) -> Self {
+        Self {
+            currency: item.currency,
+            low_value_max_amount: item.low_value_max_amount,
+            acquirer_fraud_rate: item.acquirer_fraud_rate,
+            trusted_beneficiary: item.trusted_beneficiary,
+            secure_corporate_payment: item.secure_corporate_payment,
+            min_prior_authentications: item.min_prior_authentications,
+            excluded_issuer_countries: item.excluded_issuer_countries,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::ThreeDsExemptionPolicy>
+    for api_models::admin::ThreeDsExemptionPolicy
+{
+    fn foreign_from(item: diesel_models::business_profile::ThreeDsExemptionPolicy) -> Self {
+        Self {
+            currency: item.currency,
+            low_value_max_amount: item.low_value_max_amount,
+            acquirer_fraud_rate: item.acquirer_fraud_rate,
+            trusted_beneficiary: item.trusted_beneficiary,
+            secure_corporate_payment: item.secure_corporate_payment,
+            min_prior_authentications: item.min_prior_authentications,
+            excluded_issuer_countries: item.excluded_issuer_countries,
         }
     }
 }
@@ -2136,6 +2189,9 @@ impl ForeignFrom<diesel_models::business_profile::CardTestingGuardConfig>
     }
 }
 
+/// Webhook endpoints are not converted, their signing secrets have to be encrypted before being
+/// stored. Use `core::admin::get_webhook_details_for_storage` to convert webhook details received
+/// in a request.
 impl ForeignFrom<api_models::admin::WebhookDetails>
     for diesel_models::business_profile::WebhookDetails
 {
@@ -2148,6 +2204,7 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            webhook_endpoints: None,
         }
     }
 }
@@ -2164,6 +2221,25 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            webhook_endpoints: item
+                .webhook_endpoints
+                .map(|endpoints| endpoints.into_iter().map(ForeignFrom::foreign_from).collect()),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::WebhookEndpoint>
+    for api_models::admin::WebhookEndpoint
+{
+    fn foreign_from(item: diesel_models::business_profile::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            url: item.url,
+            enabled_events: item.enabled_events,
+            signing_secret: None,
+            previous_signing_secret: None,
+            previous_signing_secret_expires_at: item.previous_signing_secret_expires_at,
+            disabled: item.disabled,
         }
     }
 }
@@ -2182,6 +2258,8 @@ impl ForeignFrom<api_models::admin::BusinessPaymentLinkConfig>
             }),
             allowed_domains: item.allowed_domains,
             branding_visibility: item.branding_visibility,
+            template_id: item.template_id,
+            locale_bundles: item.locale_bundles,
         }
     }
 }
@@ -2200,6 +2278,8 @@ impl ForeignFrom<diesel_models::business_profile::BusinessPaymentLinkConfig>
             }),
             allowed_domains: item.allowed_domains,
             branding_visibility: item.branding_visibility,
+            template_id: item.template_id,
+            locale_bundles: item.locale_bundles,
         }
     }
 }
@@ -41,6 +41,7 @@ use crate::{
 };
 
 pub mod dashboard_metadata;
+pub mod passkey;
 pub mod password;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
@@ -0,0 +1,486 @@
+use base64::Engine;
+use common_utils::{encryption::Encryption, type_name, types::keymanager::Identifier};
+use diesel_models::user as storage_user;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use redis_interface::errors::RedisError;
+use time::PrimitiveDateTime;
+use webauthn_rs::prelude::{
+    CreationChallengeResponse, Passkey, PasskeyAuthentication, PasskeyRegistration,
+    PublicKeyCredential, RegisterPublicKeyCredential, RequestChallengeResponse, Url, Uuid,
+    Webauthn, WebauthnBuilder,
+};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    routes::SessionState,
+    types::domain,
+};
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct UserPasskey {
+    pub passkey_id: String,
+    pub name: String,
+    pub passkey: Passkey,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+/// Passwordless sign-in starts before the user has authenticated, so the ceremony state is kept
+/// against a random challenge id along with the user it was issued for.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub enum PasskeySignInState {
+    User {
+        user_id: String,
+        authentication: PasskeyAuthentication,
+    },
+    /// Issued for an email without passkeys, no assertion is accepted against it
+    Decoy,
+}
+
+pub fn build_webauthn(base_url: &str, rp_name: &str) -> UserResult<Webauthn> {
+    let rp_origin = Url::parse(base_url)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse base_url as passkey relying party origin")?;
+    let rp_id = rp_origin
+        .host_str()
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("base_url doesn't have a host for passkey relying party id")?;
+
+    WebauthnBuilder::new(rp_id, &rp_origin)
+        .and_then(|builder| builder.rp_name(rp_name).build())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to build passkey relying party")
+}
+
+pub fn get_webauthn(state: &SessionState) -> UserResult<Webauthn> {
+    build_webauthn(&state.conf.user.base_url, &state.conf.user.totp_issuer_name)
+}
+
+/// The user handle is stored on the authenticator, so it is derived from the user id instead of
+/// being random. This keeps re-registrations on the same authenticator under a single account.
+pub fn get_user_handle(user_id: &str) -> UserResult<Uuid> {
+    let digest = ring::digest::digest(&ring::digest::SHA256, user_id.as_bytes());
+    digest
+        .as_ref()
+        .get(..16)
+        .and_then(|bytes| <[u8; 16]>::try_from(bytes).ok())
+        .map(Uuid::from_bytes)
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("Failed to derive passkey user handle")
+}
+
+pub fn start_registration(
+    webauthn: &Webauthn,
+    user_id: &str,
+    user_name: &str,
+    display_name: &str,
+    existing_passkeys: &[UserPasskey],
+) -> UserResult<(CreationChallengeResponse, PasskeyRegistration)> {
+    let exclude_credentials = existing_passkeys
+        .iter()
+        .map(|user_passkey| user_passkey.passkey.cred_id().clone())
+        .collect();
+
+    webauthn
+        .start_passkey_registration(
+            get_user_handle(user_id)?,
+            user_name,
+            display_name,
+            Some(exclude_credentials),
+        )
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey registration")
+}
+
+pub fn finish_registration(
+    webauthn: &Webauthn,
+    credential: &RegisterPublicKeyCredential,
+    registration: &PasskeyRegistration,
+) -> UserResult<Passkey> {
+    webauthn
+        .finish_passkey_registration(credential, registration)
+        .change_context(UserErrors::InvalidPasskey)
+}
+
+pub fn start_authentication(
+    webauthn: &Webauthn,
+    passkeys: &[UserPasskey],
+) -> UserResult<(RequestChallengeResponse, PasskeyAuthentication)> {
+    if passkeys.is_empty() {
+        return Err(UserErrors::PasskeyNotSetup.into());
+    }
+
+    let allowed_passkeys = passkeys
+        .iter()
+        .map(|user_passkey| user_passkey.passkey.clone())
+        .collect::<Vec<_>>();
+
+    webauthn
+        .start_passkey_authentication(&allowed_passkeys)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey authentication")
+}
+
+/// Sign-in challenges are issued for every email, so that the response doesn't reveal whether a
+/// user exists or has passkeys. Emails without passkeys get a challenge for a decoy credential,
+/// derived from the email so that it stays the same across requests.
+pub fn start_decoy_authentication(
+    webauthn: &Webauthn,
+    secret: &[u8],
+    email: &str,
+) -> UserResult<serde_json::Value> {
+    let (request_challenge, _) = webauthn
+        .start_discoverable_authentication()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to start passkey authentication")?;
+    let mut options =
+        serde_json::to_value(request_challenge).change_context(UserErrors::InternalServerError)?;
+
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret);
+    let decoy_credential_id = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .encode(ring::hmac::sign(&key, email.as_bytes()));
+    options
+        .get_mut("publicKey")
+        .and_then(serde_json::Value::as_object_mut)
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("Passkey challenge doesn't have public key options")?
+        .insert(
+            "allowCredentials".to_string(),
+            serde_json::json!([{ "type": "public-key", "id": decoy_credential_id }]),
+        );
+
+    Ok(options)
+}
+
+/// Verifies the assertion and updates the signature counter and last used time of the passkey
+/// that answered it. Callers are expected to persist `passkeys` afterwards.
+pub fn finish_authentication(
+    webauthn: &Webauthn,
+    credential: &PublicKeyCredential,
+    authentication: &PasskeyAuthentication,
+    passkeys: &mut [UserPasskey],
+) -> UserResult<()> {
+    let authentication_result = webauthn
+        .finish_passkey_authentication(credential, authentication)
+        .change_context(UserErrors::InvalidPasskey)?;
+
+    let user_passkey = passkeys
+        .iter_mut()
+        .find(|user_passkey| user_passkey.passkey.cred_id() == authentication_result.cred_id())
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Verified credential doesn't belong to the user")?;
+
+    user_passkey
+        .passkey
+        .update_credential(&authentication_result);
+    user_passkey.last_used_at = Some(common_utils::date_time::now());
+
+    Ok(())
+}
+
+pub async fn encrypt_passkeys(
+    state: &SessionState,
+    key_store: &domain::UserKeyStore,
+    passkeys: &[UserPasskey],
+) -> UserResult<Encryption> {
+    let passkeys = serde_json::to_string(passkeys)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize passkeys")?;
+
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage_user::User),
+        domain::types::CryptoOperation::Encrypt(Secret::new(passkeys)),
+        Identifier::User(key_store.user_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(UserErrors::InternalServerError)
+    .map(Into::into)
+}
+
+pub async fn update_passkeys_in_db(
+    state: &SessionState,
+    user_from_db: &domain::UserFromStorage,
+    passkeys: &[UserPasskey],
+) -> UserResult<()> {
+    let key_store = user_from_db.get_or_create_key_store(state).await?;
+    let passkeys = encrypt_passkeys(state, &key_store, passkeys).await?;
+
+    state
+        .global_store
+        .update_user_by_user_id(
+            user_from_db.get_user_id(),
+            storage_user::UserUpdate::PasskeyUpdate { passkeys },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+fn get_passkey_registration_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_REGISTRATION_PREFIX,
+        user_id
+    )
+}
+
+fn get_passkey_authentication_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_AUTHENTICATION_PREFIX,
+        user_id
+    )
+}
+
+fn get_passkey_sign_in_key(challenge_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::REDIS_PASSKEY_SIGN_IN_PREFIX,
+        challenge_id
+    )
+}
+
+pub async fn insert_passkey_registration_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    registration: &PasskeyRegistration,
+) -> UserResult<()> {
+    insert_ceremony_state_in_redis(state, get_passkey_registration_key(user_id), registration).await
+}
+
+pub async fn get_passkey_registration_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<PasskeyRegistration> {
+    take_ceremony_state_from_redis(
+        state,
+        get_passkey_registration_key(user_id),
+        "PasskeyRegistration",
+    )
+    .await
+}
+
+pub async fn insert_passkey_authentication_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    authentication: &PasskeyAuthentication,
+) -> UserResult<()> {
+    insert_ceremony_state_in_redis(
+        state,
+        get_passkey_authentication_key(user_id),
+        authentication,
+    )
+    .await
+}
+
+pub async fn get_passkey_authentication_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<PasskeyAuthentication> {
+    take_ceremony_state_from_redis(
+        state,
+        get_passkey_authentication_key(user_id),
+        "PasskeyAuthentication",
+    )
+    .await
+}
+
+pub async fn insert_passkey_sign_in_state_in_redis(
+    state: &SessionState,
+    challenge_id: &str,
+    sign_in_state: &PasskeySignInState,
+) -> UserResult<()> {
+    insert_ceremony_state_in_redis(state, get_passkey_sign_in_key(challenge_id), sign_in_state)
+        .await
+}
+
+pub async fn get_passkey_sign_in_state_from_redis(
+    state: &SessionState,
+    challenge_id: &str,
+) -> UserResult<PasskeySignInState> {
+    take_ceremony_state_from_redis(
+        state,
+        get_passkey_sign_in_key(challenge_id),
+        "PasskeySignInState",
+    )
+    .await
+}
+
+async fn insert_ceremony_state_in_redis<T>(
+    state: &SessionState,
+    key: String,
+    ceremony_state: &T,
+) -> UserResult<()>
+where
+    T: serde::Serialize + std::fmt::Debug,
+{
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &key.into(),
+            ceremony_state,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Challenges are single use, so the ceremony state is deleted as soon as it is read.
+async fn take_ceremony_state_from_redis<T>(
+    state: &SessionState,
+    key: String,
+    type_name: &'static str,
+) -> UserResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let ceremony_state = redis_conn
+        .get_and_deserialize_key::<T>(&key.as_str().into(), type_name)
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => error.change_context(UserErrors::PasskeyChallengeNotFound),
+            _ => error.change_context(UserErrors::InternalServerError),
+        })?;
+
+    redis_conn
+        .delete_key(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ceremony_state)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+    use webauthn_authenticator_rs::{softpasskey::SoftPasskey, WebauthnAuthenticator};
+
+    use super::*;
+
+    const BASE_URL: &str = "https://app.hyperswitch.io";
+
+    fn register(
+        webauthn: &Webauthn,
+        authenticator: &mut WebauthnAuthenticator<SoftPasskey>,
+        existing_passkeys: &[UserPasskey],
+    ) -> UserPasskey {
+        let (creation_challenge, registration) = start_registration(
+            webauthn,
+            "user_id",
+            "user@example.com",
+            "user",
+            existing_passkeys,
+        )
+        .unwrap();
+        let credential = authenticator
+            .do_registration(Url::parse(BASE_URL).unwrap(), creation_challenge)
+            .expect("software authenticator failed to register");
+        let passkey = finish_registration(webauthn, &credential, &registration).unwrap();
+
+        UserPasskey {
+            passkey_id: uuid::Uuid::new_v4().to_string(),
+            name: "test".to_string(),
+            passkey,
+            created_at: common_utils::date_time::now(),
+            last_used_at: None,
+        }
+    }
+
+    #[test]
+    fn test_passkey_registration_and_authentication() {
+        let webauthn = build_webauthn(BASE_URL, "Hyperswitch").unwrap();
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+        let mut passkeys = vec![register(&webauthn, &mut authenticator, &[])];
+
+        let (request_challenge, authentication) =
+            start_authentication(&webauthn, &passkeys).unwrap();
+        let credential = authenticator
+            .do_authentication(Url::parse(BASE_URL).unwrap(), request_challenge)
+            .expect("software authenticator failed to authenticate");
+
+        finish_authentication(&webauthn, &credential, &authentication, &mut passkeys).unwrap();
+        assert!(passkeys[0].last_used_at.is_some());
+    }
+
+    #[test]
+    fn test_passkey_authentication_rejects_replayed_challenge_response() {
+        let webauthn = build_webauthn(BASE_URL, "Hyperswitch").unwrap();
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+        let mut passkeys = vec![register(&webauthn, &mut authenticator, &[])];
+
+        let (request_challenge, _) = start_authentication(&webauthn, &passkeys).unwrap();
+        let credential = authenticator
+            .do_authentication(Url::parse(BASE_URL).unwrap(), request_challenge)
+            .unwrap();
+
+        // A response to one challenge must not verify against a different one
+        let (_, other_authentication) = start_authentication(&webauthn, &passkeys).unwrap();
+        let error =
+            finish_authentication(&webauthn, &credential, &other_authentication, &mut passkeys)
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::InvalidPasskey
+        ));
+        assert!(passkeys[0].last_used_at.is_none());
+    }
+
+    #[test]
+    fn test_decoy_challenge_matches_passkey_challenge() {
+        let webauthn = build_webauthn(BASE_URL, "Hyperswitch").unwrap();
+        let mut authenticator = WebauthnAuthenticator::new(SoftPasskey::new(true));
+        let passkeys = vec![register(&webauthn, &mut authenticator, &[])];
+
+        let (request_challenge, _) = start_authentication(&webauthn, &passkeys).unwrap();
+        let options = serde_json::to_value(request_challenge).unwrap();
+        let decoy_options =
+            start_decoy_authentication(&webauthn, b"secret", "user@example.com").unwrap();
+        let other_decoy_options =
+            start_decoy_authentication(&webauthn, b"secret", "user@example.com").unwrap();
+
+        let mut keys = options["publicKey"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>();
+        let mut decoy_keys = decoy_options["publicKey"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>();
+        keys.sort();
+        decoy_keys.sort();
+        assert_eq!(keys, decoy_keys);
+        assert_eq!(
+            options["publicKey"]["userVerification"],
+            decoy_options["publicKey"]["userVerification"]
+        );
+        assert_eq!(
+            decoy_options["publicKey"]["allowCredentials"],
+            other_decoy_options["publicKey"]["allowCredentials"]
+        );
+        assert_ne!(
+            decoy_options["publicKey"]["challenge"],
+            other_decoy_options["publicKey"]["challenge"]
+        );
+    }
+
+    #[test]
+    fn test_passkey_authentication_requires_registered_passkeys() {
+        let webauthn = build_webauthn(BASE_URL, "Hyperswitch").unwrap();
+        let error = start_authentication(&webauthn, &[]).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::PasskeyNotSetup
+        ));
+    }
+}
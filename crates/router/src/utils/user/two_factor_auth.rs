@@ -226,3 +226,79 @@ pub async fn delete_recovery_code_attempts_from_redis(
         .change_context(UserErrors::InternalServerError)
         .map(|_| ())
 }
+
+pub async fn check_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<bool> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .exists::<()>(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn insert_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .set_key_with_expiry(
+            &key.as_str().into(),
+            common_utils::date_time::now_unix_timestamp(),
+            state.conf.user.two_factor_auth_expiry_in_secs,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn delete_passkey_from_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .delete_key(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+fn get_passkey_attempts_key(user_id: &str) -> String {
+    format!("{}{}", consts::user::REDIS_PASSKEY_ATTEMPTS_PREFIX, user_id)
+}
+
+pub async fn insert_passkey_attempts_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    user_passkey_attempts: u8,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .set_key_with_expiry(
+            &get_passkey_attempts_key(user_id).into(),
+            user_passkey_attempts,
+            consts::user::REDIS_PASSKEY_ATTEMPTS_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<u8> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .get_key::<Option<u8>>(&get_passkey_attempts_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|v| v.unwrap_or(0))
+}
+
+pub async fn delete_passkey_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .delete_key(&get_passkey_attempts_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
@@ -2,12 +2,25 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+#[cfg(feature = "v1")]
+pub mod card_vault_export;
+#[cfg(all(feature = "frm", feature = "v1"))]
+pub mod frm_feedback;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
 pub mod outgoing_webhook_retry;
+#[cfg(feature = "v1")]
+pub mod payment_method_migration_batch;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(all(feature = "recon", feature = "olap", feature = "v1"))]
+pub mod recon_settlement;
 
 pub mod refund_router;
 
 pub mod tokenized_data;
 
 pub mod revenue_recovery;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_events_bulk_resend;
@@ -0,0 +1,118 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::tracing::{self, instrument};
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    utils as scheduler_utils,
+};
+
+use crate::{
+    core::fraud_check::{self as frm_core, types::FrmFeedbackTrackingData},
+    db::StorageInterface,
+    errors, logger,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct FrmFeedbackWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for FrmFeedbackWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: FrmFeedbackTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("FrmFeedbackTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &tracking_data.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        match frm_core::send_frm_feedback(
+            state,
+            &merchant_context,
+            &payment_attempt,
+            tracking_data.feedback,
+        )
+        .await
+        {
+            Ok(()) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to send frm feedback");
+                retry_frm_feedback_task(db, &tracking_data.merchant_id, process).await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+async fn retry_frm_feedback_task(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = scheduler_utils::get_time_from_delta(scheduler_utils::get_schedule_time(
+        process_data::ConnectorPTMapping::default(),
+        merchant_id,
+        process.retry_count + 1,
+    ));
+
+    match schedule_time {
+        Some(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await?
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?
+        }
+    }
+
+    Ok(())
+}
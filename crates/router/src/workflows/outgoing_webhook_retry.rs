@@ -368,6 +368,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::network_tokenization,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -569,5 +570,27 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method = state
+                .store
+                .find_payment_method(
+                    &(&state).into(),
+                    merchant_context.get_merchant_key_store(),
+                    &tracking_data.primary_object_id,
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await?;
+            logger::debug!(current_resource_status=%payment_method.status);
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+                    network_tokenization::lifecycle::get_payment_method_webhook_response(
+                        payment_method,
+                    ),
+                )),
+                Some(EventType::PaymentMethodUpdated),
+            ))
+        }
     }
 }
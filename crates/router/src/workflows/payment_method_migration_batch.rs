@@ -0,0 +1,107 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::payment_methods::migration_batch::{self, PaymentMethodMigrationBatchTrackingData},
+    db::StorageInterface,
+    errors, logger,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct PaymentMethodMigrationBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodMigrationBatchWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let mut tracking_data: PaymentMethodMigrationBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodMigrationBatchTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let is_completed = Box::pin(
+            migration_batch::process_payment_method_migration_batch_chunk(
+                state,
+                &merchant_context,
+                &mut tracking_data,
+            ),
+        )
+        .await?;
+
+        let tracking_data_value = tracking_data
+            .encode_to_value()
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+        let process_tracker_update = if is_completed {
+            logger::info!(
+                batch_id = %tracking_data.batch_id,
+                succeeded_rows = tracking_data.succeeded_rows,
+                duplicate_rows = tracking_data.duplicate_rows,
+                failed_rows = tracking_data.failed_rows,
+                "Completed payment method migration batch"
+            );
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data_value),
+                business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+                status: Some(storage::enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        } else {
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(process.retry_count + 1),
+                schedule_time: Some(common_utils::date_time::now()),
+                tracking_data: Some(tracking_data_value),
+                business_status: None,
+                status: Some(storage::enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        };
+
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,74 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::recon::settlement::{self, ReconSettlementTrackingData},
+    db::StorageInterface,
+    errors, logger,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct ReconSettlementWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReconSettlementWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: ReconSettlementTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReconSettlementTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        Box::pin(settlement::perform_settlement_recon(
+            state,
+            &merchant_context,
+            &tracking_data,
+        ))
+        .await?;
+
+        logger::info!(recon_id = %tracking_data.recon_id, "Completed settlement recon");
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
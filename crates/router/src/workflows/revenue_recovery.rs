@@ -166,6 +166,18 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
     merchant_id: &id_type::MerchantId,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
+    let mapping = get_pcr_payments_retry_mapping(db).await;
+
+    let time_delta =
+        scheduler_utils::get_pcr_payments_retry_schedule_time(mapping, merchant_id, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+#[cfg(feature = "v2")]
+pub(crate) async fn get_pcr_payments_retry_mapping(
+    db: &dyn StorageInterface,
+) -> process_data::RevenueRecoveryPaymentProcessTrackerMapping {
     let key = "pt_mapping_pcr_retries";
     let result = db
         .find_config_by_key(key)
@@ -177,7 +189,7 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
                 .change_context(StorageError::DeserializationFailed)
         });
 
-    let mapping = result.map_or_else(
+    result.map_or_else(
         |error| {
             if error.current_context().is_db_not_found() {
                 logger::debug!("Revenue Recovery retry config `{key}` not found, ignoring");
@@ -193,10 +205,5 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
             logger::debug!(?mapping, "Using custom pcr payments retry config");
             mapping
         },
-    );
-
-    let time_delta =
-        scheduler_utils::get_pcr_payments_retry_schedule_time(mapping, merchant_id, retry_count);
-
-    scheduler_utils::get_time_from_delta(time_delta)
+    )
 }
@@ -0,0 +1,142 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::tracing::{self, instrument};
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::webhooks::{types::WebhookEventsBulkResendTrackingData, webhook_events},
+    db::StorageInterface,
+    errors, logger,
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct WebhookEventsBulkResendWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookEventsBulkResendWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let mut tracking_data: WebhookEventsBulkResendTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookEventsBulkResendTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.business_profile_id,
+            )
+            .await?;
+
+        let bulk_resend_config = &state.conf.webhooks.bulk_resend;
+        let batch_size = i64::from(bulk_resend_config.batch_size);
+        let events = db
+            .list_initial_events_by_profile_id_constraints_after_key(
+                key_manager_state,
+                &tracking_data.business_profile_id,
+                tracking_data.created_after,
+                tracking_data.created_before,
+                tracking_data.get_next_batch_key(),
+                batch_size,
+                tracking_data.event_types.clone(),
+                tracking_data.is_delivered,
+                &key_store,
+            )
+            .await?;
+        let is_last_batch = i64::try_from(events.len())
+            .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?
+            < batch_size;
+
+        for event in events {
+            let initial_attempt_id = event.event_id.clone();
+            let created_at = event.created_at;
+            let is_delivered = match Box::pin(webhook_events::resend_event(
+                state,
+                &key_store,
+                business_profile.clone(),
+                event,
+            ))
+            .await
+            {
+                Ok(updated_event) => updated_event.is_webhook_notified,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        %initial_attempt_id,
+                        "Failed to resend webhook event in bulk resend job"
+                    );
+                    false
+                }
+            };
+
+            tracking_data.record_resent_event(initial_attempt_id, created_at, is_delivered);
+        }
+        tracking_data.batch_count = tracking_data.batch_count.saturating_add(1);
+
+        let tracking_data_value = tracking_data
+            .encode_to_value()
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+        let process_tracker_update = if is_last_batch {
+            logger::info!(
+                batch_id = %tracking_data.batch_id,
+                batch_count = tracking_data.batch_count,
+                processed_count = tracking_data.processed_count,
+                failed_count = tracking_data.failed_count,
+                "Completed bulk resend of webhook events"
+            );
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data_value),
+                business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+                status: Some(storage::enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        } else {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(i64::from(bulk_resend_config.batch_interval_seconds)),
+            );
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data_value),
+                business_status: None,
+                status: Some(storage::enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(common_utils::date_time::now()),
+            }
+        };
+
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "connector_fixtures")]
+use std::{cell::RefCell, collections::HashMap, path::PathBuf};
 use std::{fmt::Debug, marker::PhantomData, str::FromStr, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
@@ -605,7 +607,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client::<types::api::PoEligibility>(&request.connector),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -650,7 +652,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client::<types::api::PoFulfill>(&request.connector),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -696,7 +698,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client::<types::api::PoCreate>(&request.connector),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -741,7 +743,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client::<types::api::PoCancel>(&request.connector),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -837,7 +839,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client::<types::api::PoRecipient>(&request.connector),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -879,7 +881,7 @@ async fn call_connector<
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        get_api_client::<T>(&request.connector),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -900,6 +902,86 @@ async fn call_connector<
     .await
 }
 
+/// Environment variable which selects whether the requests sent to connectors are recorded into
+/// fixtures or replayed from them, when set to `record` or `replay` respectively
+#[cfg(feature = "connector_fixtures")]
+const CONNECTOR_FIXTURES_MODE: &str = "CONNECTOR_FIXTURES_MODE";
+
+/// Environment variable with the comma separated response fields whose values are recorded in
+/// addition to the ones preserved by default
+#[cfg(feature = "connector_fixtures")]
+const CONNECTOR_FIXTURES_PRESERVED_FIELDS: &str = "CONNECTOR_FIXTURES_PRESERVED_FIELDS";
+
+#[cfg(feature = "connector_fixtures")]
+thread_local! {
+    /// Fixture clients used by the test running on this thread, shared across the calls to the
+    /// same flow so that repeated calls record and replay consecutive interactions
+    static FIXTURE_API_CLIENTS: RefCell<HashMap<PathBuf, services::FixtureApiClient>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Api client for sending the requests for flow `F` to the connector
+#[cfg(not(feature = "connector_fixtures"))]
+fn get_api_client<F>(_connector_name: &str) -> Box<dyn services::ApiClient> {
+    Box::new(services::MockApiClient)
+}
+
+/// Api client for sending the requests for flow `F` to the connector.
+///
+/// The requests are recorded into or replayed from
+/// `tests/connectors/fixtures/<connector>/<test>/<flow>.json`, as selected by
+/// [`CONNECTOR_FIXTURES_MODE`]. When the mode is not set, the fixture is replayed if it exists.
+#[cfg(feature = "connector_fixtures")]
+fn get_api_client<F>(connector_name: &str) -> Box<dyn services::ApiClient> {
+    // Tests are run on threads named after them
+    let current_thread = std::thread::current();
+    let test_name = current_thread
+        .name()
+        .and_then(|name| name.rsplit("::").next())
+        .expect("Failed to obtain the test name");
+    let flow_name = std::any::type_name::<F>()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/connectors/fixtures")
+        .join(connector_name)
+        .join(test_name)
+        .join(format!("{flow_name}.json"));
+
+    let mode = match std::env::var(CONNECTOR_FIXTURES_MODE) {
+        Ok(mode) => {
+            services::FixtureMode::from_str(&mode).expect("Invalid connector fixtures mode")
+        }
+        Err(_) if path.exists() => services::FixtureMode::Replay,
+        Err(_) => return Box::new(services::MockApiClient),
+    };
+    let preserved_fields = std::env::var(CONNECTOR_FIXTURES_PRESERVED_FIELDS)
+        .map(|fields| {
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    FIXTURE_API_CLIENTS.with(|clients| {
+        let client = clients
+            .borrow_mut()
+            .entry(path.clone())
+            .or_insert_with(|| {
+                services::FixtureApiClient::new(mode, path)
+                    .expect("Failed to create connector fixture client")
+                    .with_preserved_response_fields(preserved_fields)
+            })
+            .clone();
+        Box::new(client) as Box<dyn services::ApiClient>
+    })
+}
+
 pub struct MockConfig {
     pub address: Option<String>,
     pub mocks: Vec<Mock>,
@@ -118,6 +118,18 @@ pub enum Flow {
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
     PaymentMethodsMigrate,
+    /// Payment methods migration batch create flow.
+    PaymentMethodsMigrationBatchCreate,
+    /// Payment methods migration batch retrieve flow.
+    PaymentMethodsMigrationBatchRetrieve,
+    /// Payment methods migration batch results download flow.
+    PaymentMethodsMigrationBatchResultsDownload,
+    /// Card vault export create flow.
+    CardVaultExportCreate,
+    /// Card vault export retrieve flow.
+    CardVaultExportRetrieve,
+    /// Card vault export file download flow.
+    CardVaultExportFileDownload,
     /// Payment methods list flow.
     PaymentMethodsList,
     /// Payment method save flow
@@ -203,6 +215,15 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts filter flow.
     PayoutsFilter,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch create flow.
+    PayoutsBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch retrieve flow.
+    PayoutsBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch results download flow.
+    PayoutsBatchResultsDownload,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payout link initiate flow
@@ -235,6 +256,14 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Upload a connector settlement file for reconciliation.
+    ReconSettlementFileUpload,
+    /// Upload a bank statement for reconciliation against settlement files.
+    ReconBankStatementUpload,
+    /// Reconcile a connector settlement file against payments and refunds.
+    ReconSettlementRun,
+    /// Retrieve a reconciliation report.
+    ReconReportRetrieve,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -247,6 +276,8 @@ pub enum Flow {
     RoutingRetrieveActiveConfig,
     /// Routing retrieve default config
     RoutingRetrieveDefaultConfig,
+    /// Routing explain payment method eligibility
+    RoutingExplainPaymentMethodEligibility,
     /// Routing retrieve dictionary
     RoutingRetrieveDictionary,
     /// Routing update config
@@ -321,6 +352,8 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment Link Template Upload flow
+    PaymentLinkTemplateUpload,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -481,6 +514,22 @@ pub enum Flow {
     RecoveryCodesGenerate,
     /// Terminate two factor authentication
     TerminateTwoFactorAuth,
+    /// Begin passkey registration
+    PasskeyRegistrationBegin,
+    /// Finish passkey registration
+    PasskeyRegistrationFinish,
+    /// List passkeys of a user
+    PasskeyList,
+    /// Revoke a passkey
+    PasskeyRevoke,
+    /// Begin passkey verification for two factor authentication
+    PasskeyBegin,
+    /// Verify passkey for two factor authentication
+    PasskeyVerify,
+    /// Begin sign in with passkey
+    PasskeySignInBegin,
+    /// Sign in with passkey
+    PasskeySignIn,
     /// Check 2FA status
     TwoFactorAuthStatus,
     /// Create user authentication method
@@ -517,12 +566,44 @@ pub enum Flow {
     UpdateTheme,
     /// Delete theme
     DeleteTheme,
+    /// Create SCIM token for organization
+    CreateScimToken,
+    /// Revoke SCIM token of organization
+    RevokeScimToken,
+    /// Provision user through SCIM
+    CreateScimUser,
+    /// Retrieve SCIM user
+    GetScimUser,
+    /// List SCIM users
+    ListScimUsers,
+    /// Replace SCIM user
+    ReplaceScimUser,
+    /// Patch SCIM user
+    PatchScimUser,
+    /// Deprovision SCIM user
+    DeleteScimUser,
+    /// Create SCIM group
+    CreateScimGroup,
+    /// Retrieve SCIM group
+    GetScimGroup,
+    /// List SCIM groups
+    ListScimGroups,
+    /// Replace SCIM group
+    ReplaceScimGroup,
+    /// Patch SCIM group
+    PatchScimGroup,
+    /// Delete SCIM group
+    DeleteScimGroup,
     /// List initial webhook delivery attempts
     WebhookEventInitialDeliveryAttemptList,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Resend all webhook events of a profile within a time range
+    WebhookEventsBulkResend,
+    /// Retrieve the status of a webhook events bulk resend job
+    WebhookEventsBulkResendRetrieve,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -558,6 +639,8 @@ pub enum Flow {
     TokenizeCardBatch,
     /// Incoming Relay Webhook Receive
     IncomingRelayWebhookReceive,
+    /// Incoming Network Token Lifecycle Webhook Receive
+    IncomingNetworkTokenWebhookReceive,
     /// Generate Hypersense Token
     HypersenseTokenRequest,
     /// Verify Hypersense Token
@@ -586,6 +669,8 @@ pub enum Flow {
     TotalPaymentMethodCount,
     /// Process Tracker Revenue Recovery Workflow Retrieve
     RevenueRecoveryRetrieve,
+    /// Process Tracker Revenue Recovery Workflow Retry Timeline
+    RevenueRecoveryRetryTimeline,
     /// Tokenization flow
     TokenizationCreate,
     /// Tokenization retrieve flow
@@ -594,6 +679,26 @@ pub enum Flow {
     CloneConnector,
     ///Proxy Flow
     Proxy,
+    /// Proxy routes update flow
+    ProxyRoutesUpdate,
+    /// Proxy routes retrieve flow
+    ProxyRoutesRetrieve,
+    /// Retrieve the approval policy in effect for a merchant
+    ApprovalPolicyRetrieve,
+    /// Update the approval policy of a merchant or an organization
+    ApprovalPolicyUpdate,
+    /// Delete the approval policy of a merchant
+    ApprovalPolicyDelete,
+    /// List approval requests of a merchant
+    ApprovalRequestList,
+    /// Retrieve an approval request
+    ApprovalRequestRetrieve,
+    /// Approve a pending approval request and execute the action
+    ApprovalRequestApprove,
+    /// Reject a pending approval request
+    ApprovalRequestReject,
+    /// Retrieve the result of an approved action held back for the requester
+    ApprovalResultRetrieve,
 }
 
 /// Trait for providing generic behaviour to flow metric
@@ -65,6 +65,8 @@ pub struct MockDb {
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
+    pub scim_users: Arc<Mutex<Vec<store::user::scim_user::ScimUser>>>,
+    pub approval_requests: Arc<Mutex<Vec<store::approval_request::ApprovalRequest>>>,
 }
 
 impl MockDb {
@@ -113,6 +115,8 @@ impl MockDb {
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
             themes: Default::default(),
+            scim_users: Default::default(),
+            approval_requests: Default::default(),
         })
     }
 
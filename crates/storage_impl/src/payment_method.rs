@@ -102,6 +102,31 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
         .await
     }
 
+    // not supported in kv
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        self.router_store
+            .find_payment_method_by_network_token_requestor_reference_id(
+                state,
+                key_store,
+                merchant_id,
+                network_token_requestor_reference_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     // not supported in kv
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
@@ -134,6 +159,32 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_status_after_key(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_list_by_merchant_id_status_after_key(
+                state,
+                key_store,
+                merchant_id,
+                status,
+                after_key,
+                limit,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
     #[instrument(skip_all)]
     async fn insert_payment_method(
@@ -487,6 +538,32 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.call_database(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_network_token_requestor_reference_id(
+                &conn,
+                merchant_id,
+                network_token_requestor_reference_id,
+            ),
+        )
+        .await
+    }
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -527,6 +604,35 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
             })
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_status_after_key(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_status_after_key(
+                &conn,
+                merchant_id,
+                status,
+                after_key,
+                limit,
+            ),
+        )
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn insert_payment_method(
         &self,
@@ -820,6 +926,33 @@ impl PaymentMethodInterface for MockDb {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        self.get_resource::<PaymentMethod, _>(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.network_token_requestor_reference_id.as_deref()
+                        == Some(network_token_requestor_reference_id)
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+    }
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -855,6 +988,55 @@ impl PaymentMethodInterface for MockDb {
         i64::try_from(count).change_context(errors::StorageError::MockDbError)
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_list_by_merchant_id_status_after_key(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        after_key: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let mut payment_methods = self
+            .payment_methods
+            .lock()
+            .await
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.status == status
+                    && after_key.as_ref().map_or(
+                        true,
+                        |(last_created_at, last_payment_method_id)| {
+                            (pm.created_at, &pm.payment_method_id)
+                                > (*last_created_at, last_payment_method_id)
+                        },
+                    )
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_methods.sort_by(|a, b| {
+            (a.created_at, &a.payment_method_id).cmp(&(b.created_at, &b.payment_method_id))
+        });
+        let limit = usize::try_from(limit).change_context(errors::StorageError::MockDbError)?;
+        payment_methods.truncate(limit);
+
+        futures::future::try_join_all(payment_methods.into_iter().map(|pm| async {
+            pm.convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+        }))
+        .await
+    }
+
     async fn insert_payment_method(
         &self,
         _state: &KeyManagerState,
@@ -12,7 +12,7 @@ use common_utils::{
 use dyn_clone::DynClone;
 use error_stack::{Report, ResultExt};
 use moka::future::Cache as MokaCache;
-use redis_interface::{errors::RedisError, RedisConnectionPool, RedisValue};
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisKey, RedisValue};
 use router_env::{
     logger,
     tracing::{self, instrument},
@@ -30,6 +30,17 @@ pub const IMC_INVALIDATION_CHANNEL: &str = "hyperswitch_invalidate";
 /// Time to live 30 mins
 const CACHE_TTL: u64 = 30 * 60;
 
+/// Bumps the version of a cached value which is being invalidated, so that a value computed
+/// before the invalidation can not be written to redis afterwards.
+///
+/// KEYS: version key
+/// ARGV: ttl
+const BUMP_CACHE_VERSION_SCRIPT: &str = r#"
+local version = redis.call('INCR', KEYS[1])
+redis.call('EXPIRE', KEYS[1], ARGV[1])
+return version
+"#;
+
 /// Time to idle 10 mins
 const CACHE_TTI: u64 = 10 * 60;
 
@@ -148,6 +159,18 @@ impl CacheKind<'_> {
             | CacheKind::All(key) => key,
         }
     }
+
+    /// Whether writes of the value to redis are guarded by a version, which has to be bumped
+    /// whenever the value is invalidated
+    fn is_versioned(&self) -> bool {
+        matches!(self, CacheKind::CGraph(_))
+    }
+}
+
+/// Key of the version which guards writes of a cached value to redis. Writers read the version
+/// before computing the value, and only write the value if the version has not changed since.
+pub fn get_cache_version_key(key: &str) -> String {
+    format!("{key}_version")
 }
 
 impl<'a> TryFrom<CacheRedact<'a>> for RedisValue {
@@ -395,6 +418,22 @@ pub async fn redact_from_redis_and_publish<
         ))
         .attach_printable("Failed to get redis connection")?;
 
+    // The versions are bumped before the values are deleted, so that a value computed before
+    // the invalidation is either rejected or deleted along with the rest
+    for key in keys.clone().into_iter().filter(CacheKind::is_versioned) {
+        redis_conn
+            .evaluate_redis_script::<_, i64>(
+                BUMP_CACHE_VERSION_SCRIPT,
+                vec![
+                    RedisKey::from(get_cache_version_key(key.get_key_without_prefix()))
+                        .tenant_aware_key(&redis_conn),
+                ],
+                vec![CACHE_TTL.to_string()],
+            )
+            .await
+            .map_err(StorageError::RedisError)?;
+    }
+
     let redis_keys_to_be_deleted = keys
         .clone()
         .into_iter()
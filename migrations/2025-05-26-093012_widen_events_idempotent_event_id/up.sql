@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(128);
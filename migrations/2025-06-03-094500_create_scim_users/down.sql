@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS scim_users_user_id_index;
+DROP TABLE IF EXISTS scim_users;
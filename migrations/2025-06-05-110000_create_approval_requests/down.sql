@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS approval_requests_merchant_id_status_index;
+DROP TABLE IF EXISTS approval_requests;
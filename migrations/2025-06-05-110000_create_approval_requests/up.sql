@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS approval_requests (
+    approval_request_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64),
+    action VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    resource_id VARCHAR(255),
+    payload BYTEA NOT NULL,
+    requested_by VARCHAR(64) NOT NULL,
+    reviewed_by VARCHAR(64),
+    review_comment VARCHAR(1024),
+    error_message VARCHAR(1024),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS approval_requests_merchant_id_status_index ON approval_requests (merchant_id, status);
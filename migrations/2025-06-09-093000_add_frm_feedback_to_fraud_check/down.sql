@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE fraud_check
+DROP COLUMN IF EXISTS feedback_type,
+DROP COLUMN IF EXISTS dispute_status;
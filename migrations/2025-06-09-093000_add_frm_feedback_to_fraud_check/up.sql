@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE fraud_check
+ADD COLUMN IF NOT EXISTS feedback_type VARCHAR(64),
+ADD COLUMN IF NOT EXISTS dispute_status "DisputeStatus";
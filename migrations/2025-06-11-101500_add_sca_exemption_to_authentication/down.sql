@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE authentication DROP COLUMN IF EXISTS psd2_sca_exemption_type;
+
+ALTER TABLE authentication DROP COLUMN IF EXISTS is_merchant_whitelisted;
+
+-- Values cannot be removed from a postgres enum type
+SELECT 1;
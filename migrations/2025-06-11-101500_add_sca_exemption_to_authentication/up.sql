@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "ScaExemptionType" ADD VALUE IF NOT EXISTS 'trusted_beneficiary';
+
+ALTER TYPE "ScaExemptionType" ADD VALUE IF NOT EXISTS 'secure_corporate_payment';
+
+ALTER TABLE authentication ADD COLUMN IF NOT EXISTS psd2_sca_exemption_type "ScaExemptionType";
+
+ALTER TABLE authentication ADD COLUMN IF NOT EXISTS is_merchant_whitelisted BOOLEAN;
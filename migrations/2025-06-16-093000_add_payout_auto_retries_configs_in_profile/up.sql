@@ -0,0 +1,6 @@
+-- Your SQL goes here
+-- Add is_payout_auto_retries_enabled column in business_profile table
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS is_payout_auto_retries_enabled BOOLEAN;
+
+-- Add max_payout_auto_retries_enabled column in business_profile table
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS max_payout_auto_retries_enabled SMALLINT;
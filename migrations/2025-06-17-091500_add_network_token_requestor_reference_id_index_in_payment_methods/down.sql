@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_methods_merchant_id_network_token_requestor_reference_id_index;
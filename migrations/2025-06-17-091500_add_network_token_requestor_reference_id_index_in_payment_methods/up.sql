@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_network_token_requestor_reference_id_index ON payment_methods (merchant_id, network_token_requestor_reference_id);
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE scim_users DROP COLUMN IF EXISTS is_user_created_by_scim;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE scim_users
+ADD COLUMN IF NOT EXISTS is_user_created_by_scim BOOLEAN NOT NULL DEFAULT FALSE;